@@ -0,0 +1,64 @@
+//! Config trees of 10, 100 and 1000 scalars shared by the benchmarks.
+
+use bevy_app::App;
+use bevy_mod_config::{AppExt, ConfigFieldFor, Manager};
+use criterion::BenchmarkId;
+
+#[derive(bevy_mod_config::Config)]
+pub struct Tree10 {
+    #[config(default = 1)]
+    a: u32,
+    b: u32,
+    c: u32,
+    d: u32,
+    e: i64,
+    #[config(default = 0.5)]
+    f: f32,
+    g: f32,
+    h: f64,
+    i: bool,
+    j: String,
+}
+
+#[derive(bevy_mod_config::Config)]
+pub struct Tree100 {
+    a: Tree10,
+    b: Tree10,
+    c: Tree10,
+    d: Tree10,
+    e: Tree10,
+    f: Tree10,
+    g: Tree10,
+    h: Tree10,
+    i: Tree10,
+    j: Tree10,
+}
+
+#[derive(bevy_mod_config::Config)]
+pub struct Tree1000 {
+    a: Tree100,
+    b: Tree100,
+    c: Tree100,
+    d: Tree100,
+    e: Tree100,
+    f: Tree100,
+    g: Tree100,
+    h: Tree100,
+    i: Tree100,
+    j: Tree100,
+}
+
+/// Creates an app with a single config root of type `C`.
+pub fn new_app<M, C>() -> App
+where
+    M: Manager + Default,
+    C: ConfigFieldFor<M>,
+    C::Metadata: Default,
+{
+    let mut app = App::new();
+    app.init_config::<M, C>("bench");
+    app
+}
+
+/// The benchmark ID of a tree with `scalars` scalar fields.
+pub fn id(scalars: usize) -> BenchmarkId { BenchmarkId::from_parameter(scalars) }
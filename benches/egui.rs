@@ -0,0 +1,40 @@
+//! Benchmarks for drawing config trees with the egui editor.
+
+use bevy_ecs::system::SystemState;
+use bevy_egui::egui;
+use bevy_mod_config::ConfigFieldFor;
+use bevy_mod_config::manager::Egui;
+use bevy_mod_config::manager::egui::Display;
+use criterion::measurement::WallTime;
+use criterion::{BenchmarkGroup, BenchmarkId, Criterion, criterion_group, criterion_main};
+
+mod common;
+use common::{Tree10, Tree100, Tree1000, id, new_app};
+
+fn draw<C: ConfigFieldFor<Egui, Metadata: Default>>(
+    group: &mut BenchmarkGroup<WallTime>,
+    id: BenchmarkId,
+) {
+    let mut app = new_app::<Egui, C>();
+    let mut state = SystemState::<Display<'static, 'static>>::new(app.world_mut());
+    let ctx = egui::Context::default();
+    group.bench_function(id, |b| {
+        b.iter(|| {
+            let mut display = state.get_mut(app.world_mut()).unwrap();
+            ctx.run_ui(egui::RawInput::default(), |ui| {
+                egui::CentralPanel::default().show_inside(ui, |ui| display.show(ui));
+            })
+        });
+    });
+}
+
+fn bench_egui(c: &mut Criterion) {
+    let mut group = c.benchmark_group("egui_draw");
+    draw::<Tree10>(&mut group, id(10));
+    draw::<Tree100>(&mut group, id(100));
+    draw::<Tree1000>(&mut group, id(1000));
+    group.finish();
+}
+
+criterion_group!(benches, bench_egui);
+criterion_main!(benches);
@@ -0,0 +1,185 @@
+//! Benchmarks for spawning, reading, change detection and serialization of config trees.
+//!
+//! Each tree is measured both with one config node per scalar
+//! and with its leaf structs stored as `#[config(compact)]` scalars.
+
+use std::hint::black_box;
+use std::io::Cursor;
+
+use bevy_app::App;
+use bevy_ecs::system::SystemState;
+use bevy_mod_config::manager::serde::Json;
+use bevy_mod_config::{AppExt, ConfigFieldFor, ReadConfig, ReadConfigChange, manager};
+use criterion::measurement::WallTime;
+use criterion::{
+    BatchSize, BenchmarkGroup, BenchmarkId, Criterion, criterion_group, criterion_main,
+};
+
+mod common;
+use common::{Tree10, Tree100, Tree1000, id, new_app};
+
+/// [`Tree10`] stored as a single compact scalar.
+#[derive(bevy_mod_config::Config, Clone, serde::Serialize, serde::Deserialize)]
+#[config(compact)]
+struct Compact10 {
+    #[config(default = 1)]
+    a: u32,
+    b: u32,
+    c: u32,
+    d: u32,
+    e: i64,
+    #[config(default = 0.5)]
+    f: f32,
+    g: f32,
+    h: f64,
+    i: bool,
+    j: String,
+}
+
+#[derive(bevy_mod_config::Config)]
+struct Compact100 {
+    a: Compact10,
+    b: Compact10,
+    c: Compact10,
+    d: Compact10,
+    e: Compact10,
+    f: Compact10,
+    g: Compact10,
+    h: Compact10,
+    i: Compact10,
+    j: Compact10,
+}
+
+#[derive(bevy_mod_config::Config)]
+struct Compact1000 {
+    a: Compact100,
+    b: Compact100,
+    c: Compact100,
+    d: Compact100,
+    e: Compact100,
+    f: Compact100,
+    g: Compact100,
+    h: Compact100,
+    i: Compact100,
+    j: Compact100,
+}
+
+/// The benchmark ID of a tree with `scalars` scalar fields packed into compact structs.
+fn compact_id(scalars: usize) -> BenchmarkId { BenchmarkId::new("compact", scalars) }
+
+fn spawn<C: ConfigFieldFor<Json, Metadata: Default>>(
+    group: &mut BenchmarkGroup<WallTime>,
+    id: BenchmarkId,
+) {
+    group.bench_function(id, |b| {
+        b.iter_batched(
+            App::new,
+            |mut app| {
+                app.init_config::<Json, C>("bench");
+                app
+            },
+            BatchSize::LargeInput,
+        );
+    });
+}
+
+fn read<C: ConfigFieldFor<Json, Metadata: Default>>(
+    group: &mut BenchmarkGroup<WallTime>,
+    id: BenchmarkId,
+) {
+    let mut app = new_app::<Json, C>();
+    let mut state = SystemState::<ReadConfig<'static, 'static, C>>::new(app.world_mut());
+    let world = app.world();
+    group.bench_function(id, |b| {
+        b.iter(|| {
+            let config = state.get(world).unwrap();
+            black_box(config.read());
+        });
+    });
+}
+
+fn changed<C: ConfigFieldFor<Json, Metadata: Default>>(
+    group: &mut BenchmarkGroup<WallTime>,
+    id: BenchmarkId,
+) {
+    let mut app = new_app::<Json, C>();
+    let mut state = SystemState::<ReadConfigChange<'static, 'static, C>>::new(app.world_mut());
+    let world = app.world();
+    state.get(world).unwrap().consume_change();
+    group.bench_function(id, |b| b.iter(|| black_box(state.get(world).unwrap().consume_change())));
+}
+
+fn serialize<C: ConfigFieldFor<Json, Metadata: Default>>(
+    group: &mut BenchmarkGroup<WallTime>,
+    id: BenchmarkId,
+) {
+    let mut app = new_app::<Json, C>();
+    let json = app.world().resource::<manager::Instance<Json>>().instance.clone();
+    group.bench_function(id, |b| b.iter(|| json.to_string(app.world_mut()).unwrap()));
+}
+
+fn deserialize<C: ConfigFieldFor<Json, Metadata: Default>>(
+    group: &mut BenchmarkGroup<WallTime>,
+    id: BenchmarkId,
+) {
+    let mut app = new_app::<Json, C>();
+    let json = app.world().resource::<manager::Instance<Json>>().instance.clone();
+    let data = json.to_string(app.world_mut()).unwrap();
+    group.bench_function(id, |b| {
+        b.iter_batched(
+            || Cursor::new(data.clone().into_bytes()),
+            |reader| json.from_reader(app.world_mut(), reader).unwrap(),
+            BatchSize::SmallInput,
+        );
+    });
+}
+
+fn bench_tree(c: &mut Criterion) {
+    let mut group = c.benchmark_group("spawn");
+    spawn::<Tree10>(&mut group, id(10));
+    spawn::<Tree100>(&mut group, id(100));
+    spawn::<Tree1000>(&mut group, id(1000));
+    spawn::<Compact10>(&mut group, compact_id(10));
+    spawn::<Compact100>(&mut group, compact_id(100));
+    spawn::<Compact1000>(&mut group, compact_id(1000));
+    group.finish();
+
+    let mut group = c.benchmark_group("read");
+    read::<Tree10>(&mut group, id(10));
+    read::<Tree100>(&mut group, id(100));
+    read::<Tree1000>(&mut group, id(1000));
+    read::<Compact10>(&mut group, compact_id(10));
+    read::<Compact100>(&mut group, compact_id(100));
+    read::<Compact1000>(&mut group, compact_id(1000));
+    group.finish();
+
+    let mut group = c.benchmark_group("changed");
+    changed::<Tree10>(&mut group, id(10));
+    changed::<Tree100>(&mut group, id(100));
+    changed::<Tree1000>(&mut group, id(1000));
+    changed::<Compact10>(&mut group, compact_id(10));
+    changed::<Compact100>(&mut group, compact_id(100));
+    changed::<Compact1000>(&mut group, compact_id(1000));
+    group.finish();
+
+    let mut group = c.benchmark_group("serialize");
+    serialize::<Tree10>(&mut group, id(10));
+    serialize::<Tree100>(&mut group, id(100));
+    serialize::<Tree1000>(&mut group, id(1000));
+    serialize::<Compact10>(&mut group, compact_id(10));
+    serialize::<Compact100>(&mut group, compact_id(100));
+    serialize::<Compact1000>(&mut group, compact_id(1000));
+    group.finish();
+
+    let mut group = c.benchmark_group("deserialize");
+    deserialize::<Tree10>(&mut group, id(10));
+    deserialize::<Tree100>(&mut group, id(100));
+    deserialize::<Tree1000>(&mut group, id(1000));
+    deserialize::<Compact10>(&mut group, compact_id(10));
+    deserialize::<Compact100>(&mut group, compact_id(100));
+    deserialize::<Compact1000>(&mut group, compact_id(1000));
+    group.finish();
+}
+
+criterion_group!(benches, bench_tree);
+criterion_main!(benches);
@@ -41,7 +41,7 @@ struct Settings {
 struct Line {
     #[config(default = "Rect width = length of this field")]
     text:      String,
-    #[config(default = 10.)]
+    #[config(advanced, default = 10.)]
     thickness: f32,
     rotate:    bool,
 
@@ -84,11 +84,19 @@ fn main() -> AppExit {
     app.init_config::<ManagerType, Settings>("ui");
 
     #[cfg(feature = "serde_json")]
-    app.init_resource::<JsonEditorText>();
+    {
+        app.init_resource::<JsonEditorText>();
+        let baseline = manager::egui::diff::DiffBaseline::capture(app.world_mut());
+        app.insert_resource(baseline);
+    }
     app.add_systems(bevy_app::Startup, |mut commands: Commands| {
         commands.spawn(Camera2d);
     });
-    app.add_systems(EguiPrimaryContextPass, show_settings.before(DisplayLines));
+    app.init_resource::<manager::egui::nav::NavBindings>();
+    app.add_systems(
+        EguiPrimaryContextPass,
+        (manager::egui::nav::gamepad_navigation, show_settings).chain().before(DisplayLines),
+    );
     app.add_systems(bevy_app::Startup, init_line);
     app.add_systems(bevy_app::Update, set_clear_color);
     app.add_systems(bevy_app::Update, display_line::<MainShape1>.in_set(DisplayLines));
@@ -103,9 +111,10 @@ struct DisplayLines;
 fn show_settings(
     mut contexts: EguiContexts,
     mut display: manager::egui::Display,
-    #[cfg(feature = "serde_json")] (mut editor_text, mut commands): (
+    #[cfg(feature = "serde_json")] (mut editor_text, mut commands, baseline): (
         ResMut<JsonEditorText>,
         Commands,
+        Res<manager::egui::diff::DiffBaseline>,
     ),
 ) {
     let Ok(ctx) = contexts.ctx_mut() else { return };
@@ -117,7 +126,11 @@ fn show_settings(
     );
     egui::Panel::left("settings").show_inside(&mut ui, |ui| {
         ui.heading("Settings");
+        ui.checkbox(&mut display.options().show_irrelevant, "Preview inactive variants");
+        ui.checkbox(&mut display.options().show_advanced, "Show advanced");
         display.show(ui);
+        #[cfg(feature = "serde_json")]
+        ui.collapsing("Changes from defaults", |ui| display.show_diff(ui, &baseline));
     });
     #[cfg(feature = "serde_json")]
     show_json_editor(&mut ui, &mut editor_text, &mut commands);
@@ -172,6 +185,12 @@ impl Command for LoadJsonCommand {
     }
 }
 
+#[cfg(feature = "serde_json")]
+const SETTINGS_FILE: &str = "settings.json";
+
+#[cfg(feature = "serde_json")]
+fn json_manager(managers: &ManagerType) -> &SerdeJsonManager { &managers.0 }
+
 #[cfg(feature = "serde_json")]
 fn show_json_editor(
     ui: &mut egui::Ui,
@@ -187,6 +206,16 @@ fn show_json_editor(
         if ui.button("Reload JSON").clicked() {
             commands.queue(LoadJsonCommand);
         }
+        ui.horizontal(|ui| {
+            use bevy_mod_config::manager::serde::json::{LoadConfigCommand, SaveConfigCommand};
+
+            if ui.button("Save to settings.json").clicked() {
+                commands.queue(SaveConfigCommand::with_manager(SETTINGS_FILE, json_manager));
+            }
+            if ui.button("Load from settings.json").clicked() {
+                commands.queue(LoadConfigCommand::with_manager(SETTINGS_FILE, json_manager));
+            }
+        });
 
         if let Some(text) = &mut editor_text.text {
             ui.add(egui::TextEdit::multiline(text).code_editor().desired_rows(20));
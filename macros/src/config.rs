@@ -3,7 +3,7 @@ use std::iter;
 use either::Either;
 use itertools::Itertools;
 use proc_macro2::{Span, TokenStream};
-use quote::{ToTokens, format_ident, quote};
+use quote::{ToTokens, format_ident, quote, quote_spanned};
 use syn::parse::{Parse, ParseStream};
 use syn::punctuated::Punctuated;
 use syn::spanned::Spanned;
@@ -24,9 +24,15 @@ pub fn derive(input: TokenStream) -> syn::Result<TokenStream> {
     let idents = Idents::new(&input, &item_attrs)?;
     let input = Input::new(&input, &item_attrs, &idents)?;
 
+    if let Some(span) = item_attrs.compact {
+        return derive_compact(&item_attrs, &idents, &input, span);
+    }
+
     let spawn_handle = gen_spawn_handle(&item_attrs.crate_path, &idents, &input);
-    let read = gen_read(&item_attrs.crate_path, &idents, &input);
-    let changed = gen_changed(&item_attrs.crate_path, &idents, &input);
+    let read = gen_read(&item_attrs.crate_path, &idents, &input, &item_attrs.reader_derives);
+    let owned = gen_owned(&item_attrs.crate_path, &idents, &input);
+    let write = gen_write(&item_attrs.crate_path, &idents, &input);
+    let changed = gen_changed(&item_attrs.crate_path, &idents, &input, &item_attrs.changed_derives);
     let discrim = gen_discrim(&item_attrs.crate_path, &idents, &input);
     let metadata = gen_metadata(&item_attrs.crate_path, &idents, &input);
     let impl_config_field = gen_impl_config_field(&item_attrs.crate_path, &idents, &input);
@@ -34,6 +40,8 @@ pub fn derive(input: TokenStream) -> syn::Result<TokenStream> {
     let (spawn_handle_expose, spawn_handle_hidden) =
         ifelse_tuple(item_attrs.expose_spawn_handle.expose, spawn_handle);
     let (read_expose, read_hidden) = ifelse_tuple(item_attrs.expose_read.expose, read);
+    let (owned_expose, owned_hidden) = ifelse_tuple(item_attrs.expose_owned.expose, owned);
+    let (write_expose, write_hidden) = ifelse_tuple(item_attrs.expose_write.expose, write);
     let (changed_expose, changed_hidden) = ifelse_tuple(item_attrs.expose_changed.expose, changed);
     let (metadata_expose, metadata_hidden) =
         ifelse_tuple(item_attrs.expose_metadata.expose, metadata);
@@ -44,12 +52,16 @@ pub fn derive(input: TokenStream) -> syn::Result<TokenStream> {
     let output = quote! {
         #spawn_handle_expose
         #read_expose
+        #owned_expose
+        #write_expose
         #changed_expose
         #metadata_expose
         #discrim_expose
         const _: () = {
             #spawn_handle_hidden
             #read_hidden
+            #owned_hidden
+            #write_hidden
             #changed_hidden
             #metadata_hidden
             #discrim_hidden
@@ -64,6 +76,236 @@ pub fn derive(input: TokenStream) -> syn::Result<TokenStream> {
     Ok(output)
 }
 
+/// Derives a `#[config(compact)]` struct,
+/// which is stored as a single scalar field instead of one config node per field.
+fn derive_compact(
+    item_attrs: &ItemAttrs,
+    idents: &Idents,
+    input: &Input,
+    span: Span,
+) -> syn::Result<TokenStream> {
+    let InputData::Struct(struct_input) = &input.data else {
+        return Err(syn::Error::new(span, "`compact` can only be used on structs"));
+    };
+    if !input.generics.params.is_empty() {
+        return Err(syn::Error::new_spanned(input.generics, "compact structs cannot be generic"));
+    }
+    if let Some(computed) = struct_input.computed.first() {
+        return Err(syn::Error::new_spanned(
+            &computed.expr,
+            "computed fields cannot be used in compact structs",
+        ));
+    }
+    for field in &struct_input.fields {
+        let universal = &field.data.universal;
+        let unsupported = [
+            universal.advanced.as_ref().map(|expr| ("advanced", expr.span())),
+            universal.deprecated.as_ref().map(|attr| ("deprecated", attr.span)),
+            universal.permission.as_ref().map(|expr| ("permission", expr.span())),
+            universal.relevant_if.as_ref().map(|attr| ("relevant_if", attr.span)),
+            universal.in_state.as_ref().map(|states| ("in_state", states.span())),
+            universal.skip_serde.map(|span| ("serde(skip)", span)),
+            universal.serde_rename.as_ref().map(|rename| ("serde(rename)", rename.span())),
+        ];
+        if let Some((name, span)) = unsupported.into_iter().flatten().next() {
+            return Err(syn::Error::new(
+                span,
+                format!(
+                    "`{name}` cannot be used in compact structs, which do not spawn a config node \
+                     for each field"
+                ),
+            ));
+        }
+    }
+
+    let crate_path = &item_attrs.crate_path;
+    let read = gen_read(crate_path, idents, input, &item_attrs.reader_derives);
+    let metadata = gen_metadata(crate_path, idents, input);
+    let impl_compact = gen_impl_compact(crate_path, idents, input, struct_input);
+
+    let (read_expose, read_hidden) = match item_attrs.expose_read.expose {
+        true => (Some(read), None),
+        false => (None, Some(read)),
+    };
+    let (metadata_expose, metadata_hidden) = match item_attrs.expose_metadata.expose {
+        true => (Some(metadata), None),
+        false => (None, Some(metadata)),
+    };
+
+    let output = quote! {
+        #read_expose
+        #metadata_expose
+        const _: () = {
+            #read_hidden
+            #metadata_hidden
+            #impl_compact
+        };
+    };
+    if item_attrs.debug_print {
+        println!("#[derive(Config)] output:\n{output}");
+    }
+    Ok(output)
+}
+
+fn gen_impl_compact(
+    crate_path: &syn::Path,
+    idents: &Idents,
+    input: &Input,
+    struct_input: &StructInput,
+) -> TokenStream {
+    let input_ident = input.ident;
+    let Idents { read_ident, metadata_ident, .. } = idents;
+    let read_ident_lifetime = input.read_ident_lifetime(false);
+    let import = quote!(#crate_path::__import);
+
+    let field_idents: Vec<_> = struct_input.fields.iter().map(|field| &field.ident).collect();
+    let read_owned = struct_input.fields.iter().map(|field| {
+        let field_ident = &field.ident;
+        let field_ty = field.data.ty;
+        quote_spanned! { field_ty.span() =>
+            <#field_ty as #crate_path::CompactField>::read_owned(&__config_owned.#field_ident)
+        }
+    });
+    let to_owned = struct_input.fields.iter().map(|field| {
+        let field_ident = &field.ident;
+        let field_ty = field.data.ty;
+        quote! {
+            <#field_ty as #crate_path::ConfigField>::to_owned(__config_reader.#field_ident)
+        }
+    });
+    let default_from_metadata = struct_input.fields.iter().map(|field| {
+        let field_ident = &field.ident;
+        let field_ty = field.data.ty;
+        quote! {
+            <#field_ty as #crate_path::DefaultFromMetadata>::default_from_metadata(
+                &__config_metadata.#field_ident,
+            )
+        }
+    });
+    let constrain = struct_input.fields.iter().map(|field| {
+        let field_ident = &field.ident;
+        let field_ty = field.data.ty;
+        quote! {
+            if let #import::Some(__config_value) = <#field_ty as #crate_path::DefaultFromMetadata>::constrain(
+                &self.#field_ident,
+                &__config_metadata.#field_ident,
+            ) {
+                __config_constrained.get_or_insert_with(|| #import::Clone::clone(self)).#field_ident =
+                    __config_value;
+            }
+        }
+    });
+    let default_fns = struct_input.fields.iter().filter_map(|field| {
+        let default_fn = field.data.universal.default_fn.as_ref()?;
+        let field_ident = &field.ident;
+        Some(quote! {
+            __config_metadata.#field_ident.default =
+                #crate_path::DefaultFn::call_default(#default_fn, &*__config_world);
+        })
+    });
+    let with_state_relevance =
+        input.in_state.as_ref().map(|states| gen_with_state_relevance(crate_path, states));
+    let register_validate = struct_input.validate.as_ref().map(|validate| {
+        quote! {
+            #crate_path::validate::register::<Self>(__config_world, __config_entity, #validate);
+        }
+    });
+
+    quote! {
+        impl #crate_path::ConfigField for #input_ident {
+            type SpawnHandle = #import::Entity;
+            type Reader<'a> = #read_ident #read_ident_lifetime;
+            type Owned = Self;
+            type ReadQueryData = #import::Option<&'static #crate_path::ScalarData<Self>>;
+            type Metadata = #metadata_ident;
+            type Changed = #crate_path::FieldGeneration;
+            type ChangedQueryData = ();
+            type Writer<'a> = #crate_path::ScalarWriter<'a, Self>;
+
+            fn read_world<'a, 's>(
+                __config_query: impl #crate_path::QueryLike<
+                    Item = <<Self::ReadQueryData as #import::QueryData>::ReadOnly as #import::QueryData>::Item<'a, 's>,
+                >,
+                &__config_spawn_handle: &#import::Entity,
+            ) -> Self::Reader<'a> {
+                let __config_data = __config_query.get(__config_spawn_handle).expect(
+                    "entity managed by config field must remain active as long as the config \
+                     handle is used",
+                );
+                <Self as #crate_path::CompactField>::read_owned(
+                    &__config_data.expect("scalar data component must remain valid with Self type").0,
+                )
+            }
+
+            fn to_owned(__config_reader: Self::Reader<'_>) -> Self {
+                Self { #(#field_idents: #to_owned,)* }
+            }
+
+            fn changed<'a, 's>(
+                __config_query: impl #crate_path::QueryLike<
+                    Item = (
+                        &'a #crate_path::ConfigNode,
+                        <<Self::ChangedQueryData as #import::QueryData>::ReadOnly as #import::QueryData>::Item<'a, 's>,
+                    ),
+                >,
+                &__config_spawn_handle: &#import::Entity,
+            ) -> Self::Changed {
+                let __config_entity = __config_query.get(__config_spawn_handle).expect(
+                    "entity managed by config field must remain active as long as the config \
+                     handle is used",
+                );
+                __config_entity.0.generation
+            }
+
+            fn write_world<'a>(
+                __config_writes: &'a #crate_path::ConfigWrites,
+                &__config_spawn_handle: &#import::Entity,
+            ) -> Self::Writer<'a> {
+                #crate_path::ScalarWriter::new(__config_spawn_handle, __config_writes)
+            }
+        }
+
+        impl #crate_path::CompactField for #input_ident {
+            fn read_owned(__config_owned: &Self) -> Self::Reader<'_> {
+                #read_ident { #(#field_idents: #read_owned,)* }
+            }
+        }
+
+        impl #crate_path::DefaultFromMetadata for #input_ident {
+            fn default_from_metadata(__config_metadata: &#metadata_ident) -> Self {
+                Self { #(#field_idents: #default_from_metadata,)* }
+            }
+
+            fn constrain(&self, __config_metadata: &#metadata_ident) -> #import::Option<Self> {
+                #[allow(unused_mut)]
+                let mut __config_constrained: #import::Option<Self> = #import::None;
+                #(#constrain)*
+                __config_constrained
+            }
+        }
+
+        impl<__ConfigManager: #crate_path::manager::Supports<#input_ident>>
+        #crate_path::ConfigFieldFor<__ConfigManager> for #input_ident {
+            fn spawn_world(
+                __config_world: &mut #import::World,
+                __config_ctx: #crate_path::SpawnContext,
+                __config_metadata: Self::Metadata,
+            ) -> #import::Entity {
+                #[allow(unused_mut)]
+                let mut __config_metadata = __config_metadata;
+                #(#default_fns)*
+                let __config_entity = #crate_path::spawn_scalar_field::<__ConfigManager, Self>(
+                    __config_world,
+                    __config_ctx #with_state_relevance,
+                    __config_metadata,
+                );
+                #register_validate
+                __config_entity
+            }
+        }
+    }
+}
+
 fn gen_spawn_handle(crate_path: &syn::Path, idents: &Idents, input: &Input) -> TokenStream {
     let vis = input.vis;
     let spawn_fields = input.data.iter_field_data().map(|field| {
@@ -77,8 +319,10 @@ fn gen_spawn_handle(crate_path: &syn::Path, idents: &Idents, input: &Input) -> T
 
     let generics = input.generics;
     let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+    let derives = derivative_generic(crate_path, generics, &[quote!(Clone)]);
 
     quote! {
+        #derives
         #[allow(non_snake_case)]
         #vis struct #spawn_handle_ident #generics #where_clause {
             node: #crate_path::__import::Entity,
@@ -94,15 +338,23 @@ fn gen_spawn_handle(crate_path: &syn::Path, idents: &Idents, input: &Input) -> T
     }
 }
 
-fn gen_read(crate_path: &syn::Path, idents: &Idents, input: &Input) -> TokenStream {
-    match input.data {
+fn gen_read(
+    crate_path: &syn::Path,
+    idents: &Idents,
+    input: &Input,
+    reader_derives: &[syn::Path],
+) -> TokenStream {
+    let reader_derives =
+        (!reader_derives.is_empty()).then(|| quote!(#[derive(#(#reader_derives),*)]));
+    let read = match input.data {
         InputData::Struct(ref struct_input) => {
             gen_read_struct(crate_path, input.vis, input.generics, idents, struct_input)
         }
         InputData::Enum(ref enum_input) => {
             gen_read_enum(crate_path, input.vis, input.generics, idents, enum_input)
         }
-    }
+    };
+    quote!(#reader_derives #read)
 }
 
 fn gen_read_struct(
@@ -125,10 +377,17 @@ fn gen_read_struct(
                 #field_vis #field_ident: <#field_ty as #crate_path::ConfigField>::Reader<'a>,
             }
         });
+        let computed_fields =
+            input.computed.iter().map(|ComputedField { vis, ident, ty, .. }| {
+                quote! {
+                    #vis #ident: #ty,
+                }
+            });
         quote! {
             #derives
             #vis struct #read_ident #read_ident_lifetime #generics_where {
                 #(#read_fields)*
+                #(#computed_fields)*
             }
         }
     } else {
@@ -189,24 +448,369 @@ fn gen_read_enum(
                 FieldSyntax::Unit => quote!(#variant_ident),
             }
         })
-        .collect();
-    let derives = derivative_generic(crate_path, generics, &[quote!(Clone), quote!(Copy)]);
+        .collect();
+    let derives = derivative_generic(crate_path, generics, &[quote!(Clone), quote!(Copy)]);
+    quote! {
+        #derives
+        #vis enum #read_ident #read_ident_lifetime #generics_where {
+            #(#read_variants,)*
+        }
+    }
+}
+
+fn gen_owned(crate_path: &syn::Path, idents: &Idents, input: &Input) -> TokenStream {
+    match input.data {
+        InputData::Struct(ref struct_input) => {
+            gen_owned_struct(crate_path, input.vis, input.generics, idents, struct_input)
+        }
+        InputData::Enum(ref enum_input) => {
+            gen_owned_enum(crate_path, input.vis, input.generics, idents, enum_input)
+        }
+    }
+}
+
+fn gen_owned_struct(
+    crate_path: &syn::Path,
+    vis: &syn::Visibility,
+    generics: &syn::Generics,
+    idents: &Idents,
+    input: &StructInput,
+) -> TokenStream {
+    let owned_ident = &idents.owned_ident;
+    let generics_where = &generics.where_clause;
+    let derives = derivative_generic(crate_path, generics, &[quote!(Clone)]);
+    if input.named_fields {
+        let owned_fields = input.fields.iter().map(|field| {
+            let field_vis = field.vis;
+            let field_ident = field.ident.ident().expect("named_fields implies Ident");
+            let field_ty = field.data.ty;
+            quote! {
+                #field_vis #field_ident: <#field_ty as #crate_path::ConfigField>::Owned,
+            }
+        });
+        let computed_fields =
+            input.computed.iter().map(|ComputedField { vis, ident, ty, .. }| {
+                quote! {
+                    #vis #ident: #ty,
+                }
+            });
+        quote! {
+            #derives
+            #[allow(dead_code, reason = "owned readers are only used for the fields being read")]
+            #vis struct #owned_ident #generics #generics_where {
+                #(#owned_fields)*
+                #(#computed_fields)*
+            }
+        }
+    } else {
+        let owned_fields = input.fields.iter().map(|field| {
+            let field_vis = field.vis;
+            let field_ty = &field.data.ty;
+            quote! {
+                #field_vis <#field_ty as #crate_path::ConfigField>::Owned,
+            }
+        });
+        quote! {
+            #derives
+            #[allow(dead_code, reason = "owned readers are only used for the fields being read")]
+            #vis struct #owned_ident #generics (
+                #(#owned_fields)*
+            ) #generics_where ;
+        }
+    }
+}
+
+fn gen_owned_enum(
+    crate_path: &syn::Path,
+    vis: &syn::Visibility,
+    generics: &syn::Generics,
+    idents: &Idents,
+    input: &EnumInput,
+) -> TokenStream {
+    let owned_ident = &idents.owned_ident;
+    let generics_where = &generics.where_clause;
+    let owned_variants = input.variants.iter().map(|variant| {
+        let variant_ident = &variant.ident;
+        match variant.field_syntax {
+            FieldSyntax::Named => {
+                let owned_fields = variant.fields.iter().map(|field| {
+                    let field_ident = field.ident.ident().expect("named_fields implies Ident");
+                    let field_ty = &field.data.ty;
+                    quote! {
+                        #field_ident: <#field_ty as #crate_path::ConfigField>::Owned,
+                    }
+                });
+                quote! {
+                    #variant_ident { #(#owned_fields)* }
+                }
+            }
+            FieldSyntax::Unnamed => {
+                let owned_fields = variant.fields.iter().map(|field| {
+                    let field_ty = &field.data.ty;
+                    quote! {
+                        <#field_ty as #crate_path::ConfigField>::Owned,
+                    }
+                });
+                quote! {
+                    #variant_ident(#(#owned_fields)*)
+                }
+            }
+            FieldSyntax::Unit => quote!(#variant_ident),
+        }
+    });
+    let derives = derivative_generic(crate_path, generics, &[quote!(Clone)]);
+    quote! {
+        #derives
+        #[allow(dead_code, reason = "owned readers are only used for the fields being read")]
+        #vis enum #owned_ident #generics #generics_where {
+            #(#owned_variants,)*
+        }
+    }
+}
+
+/// Generates the inherent `to_owned` method on the reader type.
+fn gen_to_owned(crate_path: &syn::Path, idents: &Idents, input: &Input) -> TokenStream {
+    let vis = input.vis;
+    let Idents { read_ident, owned_ident, .. } = idents;
+    let impl_generics = input.read_ident_lifetime(true);
+    let read_generics = input.read_ident_lifetime(false);
+    let (_, ty_generics, where_clause) = input.generics.split_for_impl();
+
+    let convert_field = |field: &InputField, value: TokenStream| {
+        let field_ident = &field.ident;
+        let field_ty = &field.data.ty;
+        quote! {
+            #field_ident: <#field_ty as #crate_path::ConfigField>::to_owned(#value),
+        }
+    };
+
+    let body = match input.data {
+        InputData::Struct(ref struct_input) => {
+            let owned_fields = struct_input.fields.iter().map(|field| {
+                let field_ident = &field.ident;
+                convert_field(field, quote!(self.#field_ident))
+            });
+            let computed_fields = struct_input.computed.iter().map(|field| &field.ident);
+            quote! {
+                #owned_ident {
+                    #(#owned_fields)*
+                    #(#computed_fields: self.#computed_fields,)*
+                }
+            }
+        }
+        InputData::Enum(ref enum_input) => {
+            let arms = enum_input.variants.iter().map(|variant| {
+                let variant_ident = &variant.ident;
+                let (bindings, owned_fields): (Vec<_>, Vec<_>) = variant
+                    .fields
+                    .iter()
+                    .enumerate()
+                    .map(|(index, field)| {
+                        let field_ident = &field.ident;
+                        let binding = format_ident!("__config_field_{index}");
+                        (quote!(#field_ident: #binding,), convert_field(field, quote!(#binding)))
+                    })
+                    .unzip();
+                quote! {
+                    #read_ident::#variant_ident { #(#bindings)* } => #owned_ident::#variant_ident {
+                        #(#owned_fields)*
+                    },
+                }
+            });
+            quote! {
+                match self {
+                    #(#arms)*
+                }
+            }
+        }
+    };
+
+    quote! {
+        impl #impl_generics #read_ident #read_generics #where_clause {
+            /// Clones the config data into an owned value
+            /// that does not borrow the world.
+            #[must_use]
+            #vis fn to_owned(self) -> #owned_ident #ty_generics { #body }
+        }
+    }
+}
+
+fn gen_write(crate_path: &syn::Path, idents: &Idents, input: &Input) -> TokenStream {
+    match input.data {
+        InputData::Struct(ref struct_input) => {
+            gen_write_struct(crate_path, input.vis, input.generics, idents, struct_input)
+        }
+        InputData::Enum(ref enum_input) => {
+            gen_write_enum(crate_path, input.vis, input.generics, idents, enum_input)
+        }
+    }
+}
+
+fn gen_write_struct(
+    crate_path: &syn::Path,
+    vis: &syn::Visibility,
+    generics: &syn::Generics,
+    idents: &Idents,
+    input: &StructInput,
+) -> TokenStream {
+    let write_ident = &idents.write_ident;
+    let write_ident_lifetime = input.read_ident_lifetime(true, generics);
+    let generics_where = &generics.where_clause;
+    if input.named_fields {
+        let write_fields = input.fields.iter().map(|field| {
+            let field_vis = field.vis;
+            let field_ident = field.ident.ident().expect("named_fields implies Ident");
+            let field_ty = field.data.ty;
+            quote! {
+                #field_vis #field_ident: <#field_ty as #crate_path::ConfigField>::Writer<'a>,
+            }
+        });
+        quote! {
+            #[allow(dead_code, reason = "writers are only used for the fields being written")]
+            #vis struct #write_ident #write_ident_lifetime #generics_where {
+                #(#write_fields)*
+            }
+        }
+    } else {
+        let write_fields = input.fields.iter().map(|field| {
+            let field_vis = field.vis;
+            let field_ty = &field.data.ty;
+            quote! {
+                #field_vis <#field_ty as #crate_path::ConfigField>::Writer<'a>,
+            }
+        });
+        quote! {
+            #[allow(dead_code, reason = "writers are only used for the fields being written")]
+            #vis struct #write_ident #write_ident_lifetime(
+                #(#write_fields)*
+            ) #generics_where ;
+        }
+    }
+}
+
+fn gen_write_enum(
+    crate_path: &syn::Path,
+    vis: &syn::Visibility,
+    generics: &syn::Generics,
+    idents: &Idents,
+    input: &EnumInput,
+) -> TokenStream {
+    let write_ident = &idents.write_ident;
+    let write_ident_lifetime = enum_write_ident_lifetime(true, generics);
+    let write_ident_args = enum_write_ident_lifetime(false, generics);
+    let generics_where = &generics.where_clause;
+    let discrim_ident = idents.discrim_ident().expect("Enum must have a discriminant type");
+
+    let all_field_types: Vec<_> = input
+        .variants
+        .iter()
+        .flat_map(|variant| variant.fields.iter())
+        .map(|field| field.data.ty)
+        .collect();
+    let phantom_data = quote!(#crate_path::__import::PhantomData<fn() -> (#(#all_field_types,)*)>);
+
+    let (variant_fields, variant_structs): (Vec<_>, Vec<_>) = input
+        .variants
+        .iter()
+        .filter(|variant| !variant.fields.is_empty())
+        .map(|variant| {
+            let variant_field_ident = &variant.metadata_field;
+            let variant_struct_ident = format_ident!("{write_ident}Variant{}", &variant.ident);
+            let variant_field = quote! {
+                #vis #variant_field_ident: #variant_struct_ident #write_ident_args,
+            };
+
+            let variant_struct = match variant.field_syntax {
+                FieldSyntax::Named => {
+                    let inner_fields = variant.fields.iter().map(|field| {
+                        let field_ident = field.ident.ident().expect("named_fields implies Ident");
+                        let field_ty = &field.data.ty;
+                        quote! {
+                            #vis #field_ident: <#field_ty as #crate_path::ConfigField>::Writer<'a>,
+                        }
+                    });
+                    quote! {
+                        #[allow(dead_code, reason = "writers are only used for the fields being written")]
+                        #vis struct #variant_struct_ident #write_ident_lifetime #generics_where {
+                            #(#inner_fields)*
+                            __config_phantom: #phantom_data,
+                        }
+                    }
+                }
+                FieldSyntax::Unnamed => {
+                    let inner_fields = variant.fields.iter().map(|field| {
+                        let field_ty = &field.data.ty;
+                        quote! {
+                            #vis <#field_ty as #crate_path::ConfigField>::Writer<'a>,
+                        }
+                    });
+                    quote! {
+                        #[allow(dead_code, reason = "writers are only used for the fields being written")]
+                        #vis struct #variant_struct_ident #write_ident_lifetime (
+                            #(#inner_fields)*
+                            #phantom_data,
+                        ) #generics_where;
+                    }
+                }
+                FieldSyntax::Unit => unreachable!("unit variants have no fields"),
+            };
+            (variant_field, variant_struct)
+        })
+        .unzip();
+
     quote! {
-        #derives
-        #vis enum #read_ident #read_ident_lifetime #generics_where {
-            #(#read_variants,)*
+        #[allow(non_snake_case)]
+        #[allow(dead_code, reason = "writers are only used for the fields being written")]
+        #vis struct #write_ident #write_ident_lifetime #generics_where {
+            #vis discrim: <#discrim_ident as #crate_path::ConfigField>::Writer<'a>,
+            #(#variant_fields)*
         }
+
+        #(#variant_structs)*
     }
 }
 
-fn gen_changed(crate_path: &syn::Path, idents: &Idents, input: &Input) -> TokenStream {
+/// The generics of the writer types of an enum,
+/// which always contain a lifetime for the discriminant writer.
+fn enum_write_ident_lifetime(with_bounds: bool, generics: &syn::Generics) -> TokenStream {
+    let generic_idents =
+        generics.type_params().map(|syn::TypeParam { ident, colon_token, bounds, .. }| {
+            if with_bounds { quote! (#ident #colon_token #bounds) } else { quote!(#ident) }
+        });
+    quote! {<'a, #(#generic_idents,)*>}
+}
+
+fn gen_changed(
+    crate_path: &syn::Path,
+    idents: &Idents,
+    input: &Input,
+    changed_derives: &[syn::Path],
+) -> TokenStream {
+    let changed_derives = derivative_generic(
+        crate_path,
+        input.generics,
+        &[quote!(Clone), quote!(PartialEq), quote!(Eq), quote!(Debug)]
+            .into_iter()
+            .chain(changed_derives.iter().map(|path| quote!(#path)))
+            .collect::<Vec<_>>(),
+    );
     match input.data {
-        InputData::Struct(ref struct_input) => {
-            gen_changed_struct(crate_path, input.vis, input.generics, idents, struct_input)
-        }
-        InputData::Enum(ref enum_input) => {
-            gen_changed_enum(crate_path, input.vis, input.generics, idents, enum_input)
-        }
+        InputData::Struct(ref struct_input) => gen_changed_struct(
+            crate_path,
+            input.vis,
+            input.generics,
+            idents,
+            struct_input,
+            &changed_derives,
+        ),
+        InputData::Enum(ref enum_input) => gen_changed_enum(
+            crate_path,
+            input.vis,
+            input.generics,
+            idents,
+            enum_input,
+            &changed_derives,
+        ),
     }
 }
 
@@ -216,6 +820,7 @@ fn gen_changed_struct(
     generics: &syn::Generics,
     idents: &Idents,
     input: &StructInput,
+    changed_derives: &TokenStream,
 ) -> TokenStream {
     let changed_ident = &idents.changed_ident;
     let where_clause = &generics.where_clause;
@@ -229,11 +834,6 @@ fn gen_changed_struct(
                 #field_vis #field_ident: <#field_ty as #crate_path::ConfigField>::Changed,
             }
         });
-        let changed_derives = derivative_generic(
-            crate_path,
-            generics,
-            &[quote!(Clone), quote!(PartialEq), quote!(Eq)],
-        );
         quote! {
             #changed_derives
             #vis struct #changed_ident #generics #where_clause {
@@ -247,11 +847,6 @@ fn gen_changed_struct(
                 <#field_ty as #crate_path::ConfigField>::Changed,
             }
         });
-        let changed_derives = derivative_generic(
-            crate_path,
-            generics,
-            &[quote!(Clone), quote!(PartialEq), quote!(Eq)],
-        );
         quote! {
             #changed_derives
             #vis struct #changed_ident #generics (
@@ -267,6 +862,7 @@ fn gen_changed_enum(
     generics: &syn::Generics,
     idents: &Idents,
     input: &EnumInput,
+    changed_derives: &TokenStream,
 ) -> TokenStream {
     let changed_ident = &idents.changed_ident;
     let changed_variants = input.variants.iter().map(|variant| {
@@ -298,8 +894,6 @@ fn gen_changed_enum(
             FieldSyntax::Unit => quote!(#variant_ident),
         }
     });
-    let changed_derives =
-        derivative_generic(crate_path, generics, &[quote!(Clone), quote!(PartialEq), quote!(Eq)]);
     let where_clause = &generics.where_clause;
     quote! {
         #changed_derives
@@ -319,6 +913,15 @@ fn gen_discrim(crate_path: &syn::Path, idents: &Idents, input: &Input) -> TokenS
 
     let default_variant_name =
         enum_input.variants.first().expect("checked during Input::new").ident;
+    // `#[config(discrim(default = ...))]` also overrides the `Default` of the discriminant,
+    // so that the default of the discriminant metadata is consistent with the enum.
+    let default_variant = match enum_input.discrim.metadata.iter().rfind(|entry| {
+        entry.path.len() == 1
+            && matches!(&entry.path[0], syn::Member::Named(ident) if ident == "default")
+    }) {
+        Some(entry) => entry.value.to_token_stream(),
+        None => quote!(#discrim_ident::#default_variant_name),
+    };
 
     let variants_const = enum_input.variants.iter().map(|variant| {
         let variant_ident = &variant.ident;
@@ -344,6 +947,8 @@ fn gen_discrim(crate_path: &syn::Path, idents: &Idents, input: &Input) -> TokenS
         }
     });
 
+    let discrim_metadata_ident = format_ident!("{discrim_ident}Metadata");
+
     let import = quote!(#crate_path::__import);
     quote! {
         #[derive(
@@ -355,9 +960,12 @@ fn gen_discrim(crate_path: &syn::Path, idents: &Idents, input: &Input) -> TokenS
         )]
         #vis enum #discrim_ident { #(#variant_names,)* }
 
+        #[allow(dead_code, reason = "only used when referenced by users or managers")]
+        #vis type #discrim_metadata_ident = #crate_path::EnumDiscriminantMetadata<#discrim_ident>;
+
         impl #crate_path::__import::Default for #discrim_ident {
             fn default() -> Self {
-                #discrim_ident::#default_variant_name
+                #default_variant
             }
         }
 
@@ -387,10 +995,12 @@ fn gen_discrim(crate_path: &syn::Path, idents: &Idents, input: &Input) -> TokenS
         impl #crate_path::ConfigField for #discrim_ident {
             type SpawnHandle = #import::Entity;
             type Reader<'a> = #discrim_ident;
+            type Owned = #discrim_ident;
             type ReadQueryData = Option<&'static #crate_path::ScalarData<#crate_path::EnumDiscriminantWrapper<#discrim_ident>>>;
             type Metadata = #crate_path::EnumDiscriminantMetadata<#discrim_ident>;
             type Changed = #crate_path::FieldGeneration;
             type ChangedQueryData = ();
+            type Writer<'a> = #crate_path::ScalarWriter<'a, #crate_path::EnumDiscriminantWrapper<#discrim_ident>>;
 
             fn read_world<'a, 's>(
                 __config_query: impl #crate_path::QueryLike<
@@ -406,6 +1016,10 @@ fn gen_discrim(crate_path: &syn::Path, idents: &Idents, input: &Input) -> TokenS
                     .0 // Discrim
             }
 
+            fn to_owned(__config_reader: Self::Reader<'_>) -> Self::Owned {
+                __config_reader
+            }
+
             fn changed<'a, 's>(
                 __config_query: impl #crate_path::QueryLike<Item = (
                     &'a #crate_path::ConfigNode,
@@ -418,6 +1032,13 @@ fn gen_discrim(crate_path: &syn::Path, idents: &Idents, input: &Input) -> TokenS
                     .expect("entity managed by config field must remain active as long as the config handle is used");
                 entity.0.generation
             }
+
+            fn write_world<'a>(
+                __config_writes: &'a #crate_path::ConfigWrites,
+                &__config_spawn_handle: &Self::SpawnHandle,
+            ) -> Self::Writer<'a> {
+                #crate_path::ScalarWriter::new(__config_spawn_handle, __config_writes)
+            }
         }
 
         impl<__ConfigManager: #crate_path::Manager> #crate_path::ConfigFieldFor<__ConfigManager> for #discrim_ident
@@ -434,6 +1055,10 @@ fn gen_discrim(crate_path: &syn::Path, idents: &Idents, input: &Input) -> TokenS
                     #crate_path::__import::BevyName::new("Config enum discrim"),
                     #crate_path::ScalarData(#crate_path::EnumDiscriminantWrapper(__config_metadata.default)),
                     #crate_path::ScalarMetadata::<Self>(__config_metadata),
+                    #crate_path::ScalarField,
+                    #crate_path::ScalarDefault::of_discriminant::<#discrim_ident>(),
+                    #crate_path::DiscriminantField::of::<#discrim_ident>(),
+                    #crate_path::reset::ActiveVariant::default(),
                     __config_manager_comp,
                 ));
                 #crate_path::init_config_node(&mut __config_entity, __config_ctx);
@@ -516,6 +1141,7 @@ fn gen_metadata_enum(
 ) -> TokenStream {
     let metadata_ident = &idents.metadata_ident;
     let discrim_ident = idents.discrim_ident().expect("Enum must have a discriminant type");
+    let discrim_metadata = input.discrim.default_metadata(crate_path);
 
     let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
 
@@ -635,7 +1261,7 @@ fn gen_metadata_enum(
         for #metadata_ident #ty_generics #where_clause {
             fn default() -> Self {
                 Self {
-                    __deref: #crate_path::EnumFieldMetadata::default(),
+                    __deref: #crate_path::EnumFieldMetadata { discrim: #discrim_metadata },
                     #(#variant_defaults)*
                 }
             }
@@ -645,8 +1271,19 @@ fn gen_metadata_enum(
 
 fn gen_impl_config_field(crate_path: &syn::Path, idents: &Idents, input: &Input) -> TokenStream {
     let input_ident = &input.ident;
-    let Idents { spawn_handle_ident, read_ident, changed_ident, metadata_ident, .. } = idents;
+    let Idents {
+        spawn_handle_ident,
+        read_ident,
+        owned_ident,
+        write_ident,
+        changed_ident,
+        metadata_ident,
+        ..
+    } = idents;
     let read_ident_lifetime = input.read_ident_lifetime(false);
+    let write_ident_lifetime = input.write_ident_lifetime(false);
+    let to_owned = gen_to_owned(crate_path, idents, input);
+    let write_world = gen_write_world(crate_path, idents, input);
     let spawn_world = gen_spawn_world(crate_path, idents, input);
     let (read_query_data, read_world) = gen_read_world(crate_path, idents, input);
     let (changed_query_data, changed_fn) = gen_changed_fn(crate_path, idents, input);
@@ -654,9 +1291,12 @@ fn gen_impl_config_field(crate_path: &syn::Path, idents: &Idents, input: &Input)
     let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
     let generic_params = input.generics.params.iter();
 
+    // Span each bound at the field type,
+    // so that unsupported field types are reported at the offending field
+    // instead of the derive invocation.
     let where_clauses = input.data.iter_field_data().map(|field| {
         let field_ty = &field.ty;
-        quote! {
+        quote_spanned! { field_ty.span() =>
             #field_ty: #crate_path::ConfigFieldFor<__ConfigManager>,
         }
     });
@@ -675,10 +1315,12 @@ fn gen_impl_config_field(crate_path: &syn::Path, idents: &Idents, input: &Input)
         for #input_ident #ty_generics #where_clause {
             type SpawnHandle = #spawn_handle_ident #ty_generics;
             type Reader<'a> = #read_ident #read_ident_lifetime;
+            type Owned = #owned_ident #ty_generics;
             type ReadQueryData = #read_query_data;
             type Metadata = #metadata_ident #ty_generics;
             type Changed = #changed_ident #ty_generics;
             type ChangedQueryData = #changed_query_data;
+            type Writer<'a> = #write_ident #write_ident_lifetime;
 
             fn read_world<'a, 's>(
                 __config_query: impl #crate_path::QueryLike<
@@ -687,6 +1329,10 @@ fn gen_impl_config_field(crate_path: &syn::Path, idents: &Idents, input: &Input)
                 __config_spawn_handle: &Self::SpawnHandle,
             ) -> Self::Reader<'a> { #read_world }
 
+            fn to_owned(__config_reader: Self::Reader<'_>) -> Self::Owned {
+                __config_reader.to_owned()
+            }
+
             fn changed<'a, 's>(
                 __config_query: impl #crate_path::QueryLike<
                     Item = (
@@ -696,6 +1342,11 @@ fn gen_impl_config_field(crate_path: &syn::Path, idents: &Idents, input: &Input)
                 >,
                 __config_spawn_handle: &Self::SpawnHandle,
             ) -> Self::Changed { #changed_fn }
+
+            fn write_world<'a>(
+                __config_writes: &'a #crate_path::ConfigWrites,
+                __config_spawn_handle: &Self::SpawnHandle,
+            ) -> Self::Writer<'a> { #write_world }
         }
 
         impl<__ConfigManager: #crate_path::Manager, #(#generic_params,)*>
@@ -707,11 +1358,23 @@ fn gen_impl_config_field(crate_path: &syn::Path, idents: &Idents, input: &Input)
                 __config_outer_metadata: Self::Metadata,
             ) -> Self::SpawnHandle { #spawn_world }
         }
+
+        #to_owned
     }
 }
 
 fn gen_spawn_world(crate_path: &syn::Path, idents: &Idents, input: &Input) -> TokenStream {
     let spawn_handle_ident = &idents.spawn_handle_ident;
+    let register_validate = match &input.data {
+        InputData::Struct(StructInput { validate: Some(validate), .. }) => Some(quote! {
+            #crate_path::validate::register::<Self>(
+                __config_world,
+                #crate_path::__import::Clone::clone(&__config_spawn_handle),
+                #validate,
+            );
+        }),
+        _ => None,
+    };
     let field_iter = match &input.data {
         InputData::Struct(struct_input) => Either::Left(
             struct_input
@@ -740,8 +1403,16 @@ fn gen_spawn_world(crate_path: &syn::Path, idents: &Idents, input: &Input) -> To
         let field_ident = &field.spawn_handle_field;
         let field_ty = &field.ty;
         let hierarchy_key = &field.hierarchy_key;
-        let metadata = quote! {
-            __config_outer_metadata.#initial_config_field
+        let metadata = match &field.universal.default_fn {
+            None => quote! {
+                __config_outer_metadata.#initial_config_field
+            },
+            Some(default_fn) => quote! {{
+                let mut __config_metadata = __config_outer_metadata.#initial_config_field;
+                __config_metadata.default =
+                    #crate_path::DefaultFn::call_default(#default_fn, &*__config_world);
+                __config_metadata
+            }},
         };
         let assign_discrim_entity = is_enum_discrim.then(|| quote! {
             __config_discrim_entity = __config_field_entity;
@@ -762,11 +1433,77 @@ fn gen_spawn_world(crate_path: &syn::Path, idents: &Idents, input: &Input) -> To
             }
         });
 
+        let with_description = field.description.as_ref().map(|description| quote! {
+            .with_description(#description)
+        });
+        let with_advanced = field.universal.advanced.as_ref().map(|advanced| quote! {
+            .with_advanced(#advanced)
+        });
+        let with_skip_serde = field.universal.skip_serde.map(|_| quote! {
+            .with_skip_serde()
+        });
+        let with_serde_rename = field.universal.serde_rename.as_ref().map(|rename| quote! {
+            .with_serde_rename(#rename)
+        });
+        let with_permission = field.universal.permission.as_ref().map(|permission| quote! {
+            .with_permission(#permission)
+        });
+        let with_state_relevance =
+            field.universal.in_state.as_ref().map(|states| gen_with_state_relevance(crate_path, states));
+        let with_relevance_path = field.universal.relevant_if.as_ref().map(|relevant_if| {
+            let path = &relevant_if.path;
+            let when = match &relevant_if.when {
+                Some(when) => quote!(#when),
+                None => quote!(|__config_value: &bool| *__config_value),
+            };
+            quote! {
+                .with_relevance_path(
+                    [#(#path),*],
+                    |__config_entity| #crate_path::scalar_relevance(__config_entity, #when),
+                )
+            }
+        });
+        let with_deprecated = field.universal.deprecated.as_ref().map(|deprecated| {
+            let (replaced_by, migrate) = match &deprecated.replaced_by {
+                None => (quote!(#crate_path::__import::None), quote!(#crate_path::__import::None)),
+                Some(segments) => {
+                    let map = match &deprecated.map {
+                        Some(map) => quote!(#map),
+                        None => quote!(|__config_value| __config_value),
+                    };
+                    (
+                        quote! {
+                            #crate_path::__import::Some(
+                                __config_ctx.join([#(#segments),*], #crate_path::__import::None).path
+                            )
+                        },
+                        quote! {
+                            #crate_path::__import::Some(|
+                                __config_world: &mut #crate_path::__import::World,
+                                __config_from: #crate_path::__import::Entity,
+                                __config_to: #crate_path::__import::Entity,
+                            | {
+                                #crate_path::migrate_scalar::<#field_ty, _>(
+                                    __config_world, __config_from, __config_to, #map,
+                                )
+                            })
+                        },
+                    )
+                }
+            };
+            quote! {
+                .with_deprecated(#crate_path::Deprecated {
+                    replaced_by: #replaced_by,
+                    migrate: #migrate,
+                })
+            }
+        });
+
         quote! {
             #field_ident: {
                 let __config_field_entity = <#field_ty as #crate_path::ConfigFieldFor<__ConfigManager>>::spawn_world(
                     __config_world,
-                    __config_ctx.join([#(#hierarchy_key),*], #crate_path::__import::Some(__config_node)) #with_dependency,
+                    __config_ctx.join([#(#hierarchy_key),*], #crate_path::__import::Some(__config_node)) #with_dependency #with_description #with_advanced #with_permission #with_deprecated #with_relevance_path #with_state_relevance #with_skip_serde #with_serde_rename,
                     #metadata,
                 );
                 #assign_discrim_entity
@@ -774,15 +1511,39 @@ fn gen_spawn_world(crate_path: &syn::Path, idents: &Idents, input: &Input) -> To
             },
         }
     });
+    let with_state_relevance =
+        input.in_state.as_ref().map(|states| gen_with_state_relevance(crate_path, states));
+    let insert_section = match &input.data {
+        InputData::Struct(StructInput { fields, .. }) if fields.is_empty() => Some(quote! {
+            __config_node_entity.insert(#crate_path::Section);
+        }),
+        _ => None,
+    };
     quote! {
         let mut __config_node_entity = __config_world.spawn(#crate_path::__import::BevyName::new("Config node"));
-        #crate_path::init_config_node(&mut __config_node_entity, __config_ctx.clone());
+        #crate_path::init_config_node(&mut __config_node_entity, __config_ctx.clone() #with_state_relevance);
+        #insert_section
         let __config_node = __config_node_entity.id();
         let __config_discrim_entity: #crate_path::__import::Entity;
-        #spawn_handle_ident {
+        let __config_spawn_handle = #spawn_handle_ident {
             node: __config_node,
             #(#spawn_fields)*
-        }
+        };
+        #register_validate
+        __config_spawn_handle
+    }
+}
+
+/// Generates a `SpawnContext::with_state_relevance` call for an `in_state(...)` attribute.
+fn gen_with_state_relevance(
+    crate_path: &syn::Path,
+    states: &Punctuated<syn::Expr, syn::Token![,]>,
+) -> TokenStream {
+    let states = states.iter();
+    quote! {
+        .with_state_relevance(|__config_world| {
+            #crate_path::state::in_states(__config_world, &[#(#states),*])
+        })
     }
 }
 
@@ -806,20 +1567,45 @@ fn gen_read_world_struct(
 ) -> (TokenStream, TokenStream) {
     let read_ident = &idents.read_ident;
 
-    let (field_read_query_data, read_fields): (Vec<_>, Vec<_>) = input.fields.iter().enumerate().map(|(field_index, field)| {
+    let (field_read_query_data, field_reads): (Vec<_>, Vec<_>) = input.fields.iter().enumerate().map(|(field_index, field)| {
         let field_index = syn::Index { index: field_index as u32, span: field.span };
-        let field_ident = &field.ident;
         let field_ty = &field.data.ty;
         let spawn_handle_ident = &field.data.spawn_handle_field;
         let read_query_data = quote!(<#field_ty as #crate_path::ConfigField>::ReadQueryData);
-        let ctor_field = quote! {
-            #field_ident: <#field_ty as #crate_path::ConfigField>::read_world(
+        let field_read = quote! {
+            <#field_ty as #crate_path::ConfigField>::read_world(
                 #crate_path::QueryLike::map(__config_query, |__config_data_item| __config_data_item.#field_index),
                 &__config_spawn_handle.#spawn_handle_ident,
             )
         };
-        (read_query_data, ctor_field)
+        (read_query_data, field_read)
     }).unzip();
+    let field_idents: Vec<_> = input.fields.iter().map(|field| &field.ident).collect();
+
+    let read_world = if input.computed.is_empty() {
+        quote! {
+            #read_ident {
+                #(#field_idents: #field_reads,)*
+            }
+        }
+    } else {
+        // Bind the fields as locals so that computed expressions can refer to their siblings.
+        let computed_idents = input.computed.iter().map(|field| field.ident);
+        let computed_bindings =
+            input.computed.iter().map(|ComputedField { ident, ty, expr, .. }| {
+                quote! {
+                    let #ident: #ty = #expr;
+                }
+            });
+        quote! {
+            #(let #field_idents = #field_reads;)*
+            #(#computed_bindings)*
+            #read_ident {
+                #(#field_idents,)*
+                #(#computed_idents,)*
+            }
+        }
+    };
 
     (
         quote! {
@@ -827,11 +1613,7 @@ fn gen_read_world_struct(
                 #(#field_read_query_data,)*
             )
         },
-        quote! {
-            #read_ident {
-                #(#read_fields,)*
-            }
-        },
+        read_world,
     )
 }
 
@@ -892,6 +1674,74 @@ fn gen_read_world_enum(
     )
 }
 
+fn gen_write_world(crate_path: &syn::Path, idents: &Idents, input: &Input) -> TokenStream {
+    let write_ident = &idents.write_ident;
+    match input.data {
+        InputData::Struct(ref struct_input) => {
+            let write_fields = struct_input.fields.iter().map(|field| {
+                let field_ident = &field.ident;
+                let field_ty = &field.data.ty;
+                let spawn_handle_ident = &field.data.spawn_handle_field;
+                quote! {
+                    #field_ident: <#field_ty as #crate_path::ConfigField>::write_world(
+                        __config_writes,
+                        &__config_spawn_handle.#spawn_handle_ident,
+                    ),
+                }
+            });
+            quote! {
+                #write_ident {
+                    #(#write_fields)*
+                }
+            }
+        }
+        InputData::Enum(ref enum_input) => {
+            let discrim_ident = idents.discrim_ident().expect("Enum must have a discriminant type");
+            let discrim_spawn_handle_field = &enum_input.discrim.spawn_handle_field;
+            let write_variants =
+                enum_input.variants.iter().filter(|variant| !variant.fields.is_empty()).map(
+                    |variant| {
+                        let variant_field_ident = &variant.metadata_field;
+                        let variant_struct_ident =
+                            format_ident!("{write_ident}Variant{}", &variant.ident);
+                        let write_fields = variant.fields.iter().map(|field| {
+                            let field_ident = &field.ident;
+                            let field_ty = &field.data.ty;
+                            let spawn_handle_ident = &field.data.spawn_handle_field;
+                            quote! {
+                                #field_ident: <#field_ty as #crate_path::ConfigField>::write_world(
+                                    __config_writes,
+                                    &__config_spawn_handle.#spawn_handle_ident,
+                                ),
+                            }
+                        });
+                        let phantom_name = if let FieldSyntax::Unnamed = variant.field_syntax {
+                            let len = proc_macro2::Literal::usize_unsuffixed(variant.fields.len());
+                            quote!(#len)
+                        } else {
+                            quote!(__config_phantom)
+                        };
+                        quote! {
+                            #variant_field_ident: #variant_struct_ident {
+                                #(#write_fields)*
+                                #phantom_name: #crate_path::__import::PhantomData,
+                            },
+                        }
+                    },
+                );
+            quote! {
+                #write_ident {
+                    discrim: <#discrim_ident as #crate_path::ConfigField>::write_world(
+                        __config_writes,
+                        &__config_spawn_handle.#discrim_spawn_handle_field,
+                    ),
+                    #(#write_variants)*
+                }
+            }
+        }
+    }
+}
+
 fn gen_changed_fn(
     crate_path: &syn::Path,
     idents: &Idents,
@@ -1029,8 +1879,9 @@ fn dead_code_workaround(input: &Input) -> TokenStream {
         InputData::Struct(struct_input) => struct_input
             .fields
             .iter()
-            .map(|field| {
-                let field_ident = &field.ident;
+            .map(|field| field.ident.to_token_stream())
+            .chain(struct_input.computed.iter().map(|field| field.ident.to_token_stream()))
+            .map(|field_ident| {
                 quote! {
                     drop(v.#field_ident);
                 }
@@ -1120,10 +1971,18 @@ struct ItemAttrs {
     debug_print:         bool,
     expose_spawn_handle: ExposureAttrs,
     expose_read:         ExposureAttrs,
+    expose_owned:        ExposureAttrs,
+    expose_write:        ExposureAttrs,
     expose_changed:      ExposureAttrs,
     expose_metadata:     ExposureAttrs,
     expose_discrim:      ExposureAttrs,
     discrim_metadata:    Vec<MetadataEntry>,
+    reader_derives:      Vec<syn::Path>,
+    changed_derives:     Vec<syn::Path>,
+    validate_struct:     Option<syn::Expr>,
+    in_state:            Option<Punctuated<syn::Expr, syn::Token![,]>>,
+    reset_variants:      bool,
+    compact:             Option<Span>,
 }
 
 impl Default for ItemAttrs {
@@ -1133,10 +1992,18 @@ impl Default for ItemAttrs {
             debug_print:         false,
             expose_spawn_handle: ExposureAttrs::default(),
             expose_read:         ExposureAttrs::default(),
+            expose_owned:        ExposureAttrs::default(),
+            expose_write:        ExposureAttrs::default(),
             expose_changed:      ExposureAttrs::default(),
             expose_metadata:     ExposureAttrs::default(),
             expose_discrim:      ExposureAttrs::default(),
             discrim_metadata:    Vec::new(),
+            reader_derives:      Vec::new(),
+            changed_derives:     Vec::new(),
+            validate_struct:     None,
+            in_state:            None,
+            reset_variants:      false,
+            compact:             None,
         }
     }
 }
@@ -1195,6 +2062,35 @@ impl Parse for ItemAttrParse {
                         ));
                     }
                     Ok(ItemAttrParseItem::DiscrimMetadata(metadata))
+                } else if lookahead.peek(kw::reader_derive) {
+                    input.parse::<kw::reader_derive>()?;
+                    let inner;
+                    syn::parenthesized!(inner in input);
+                    let paths = inner.parse_terminated(syn::Path::parse, syn::Token![,])?;
+                    Ok(ItemAttrParseItem::ReaderDerive(paths))
+                } else if lookahead.peek(kw::changed_derive) {
+                    input.parse::<kw::changed_derive>()?;
+                    let inner;
+                    syn::parenthesized!(inner in input);
+                    let paths = inner.parse_terminated(syn::Path::parse, syn::Token![,])?;
+                    Ok(ItemAttrParseItem::ChangedDerive(paths))
+                } else if lookahead.peek(kw::validate_struct) {
+                    input.parse::<kw::validate_struct>()?;
+                    input.parse::<syn::Token![=]>()?;
+                    Ok(ItemAttrParseItem::ValidateStruct(input.parse()?))
+                } else if lookahead.peek(kw::reset_variants) {
+                    input.parse::<kw::reset_variants>()?;
+                    Ok(ItemAttrParseItem::ResetVariants)
+                } else if lookahead.peek(kw::compact) {
+                    let keyword: kw::compact = input.parse()?;
+                    Ok(ItemAttrParseItem::Compact(keyword.span))
+                } else if lookahead.peek(kw::in_state) {
+                    input.parse::<kw::in_state>()?;
+                    let inner;
+                    syn::parenthesized!(inner in input);
+                    Ok(ItemAttrParseItem::InState(
+                        inner.parse_terminated(syn::Expr::parse, syn::Token![,])?,
+                    ))
                 } else {
                     Err(lookahead.error())
                 }
@@ -1209,6 +2105,12 @@ enum ItemAttrParseItem {
     DebugPrint,
     Expose(Option<Punctuated<ItemAttrExposeItem, syn::Token![,]>>),
     DiscrimMetadata(Punctuated<MetadataEntry, syn::Token![,]>),
+    ReaderDerive(Punctuated<syn::Path, syn::Token![,]>),
+    ChangedDerive(Punctuated<syn::Path, syn::Token![,]>),
+    ValidateStruct(syn::Expr),
+    InState(Punctuated<syn::Expr, syn::Token![,]>),
+    ResetVariants,
+    Compact(Span),
 }
 
 struct ItemAttrExposeItem {
@@ -1236,6 +2138,8 @@ impl ItemAttrExposeItem {
 enum ItemAttrExposeItemType {
     SpawnHandle,
     Read,
+    Owned,
+    Write,
     Changed,
     Discrim,
     Metadata,
@@ -1251,6 +2155,10 @@ impl Parse for ItemAttrExposeItem {
             )
         } else if lookahead.peek(kw::read) {
             ItemAttrExposeItem::parse_known::<kw::read>(input, ItemAttrExposeItemType::Read)
+        } else if lookahead.peek(kw::owned) {
+            ItemAttrExposeItem::parse_known::<kw::owned>(input, ItemAttrExposeItemType::Owned)
+        } else if lookahead.peek(kw::write) {
+            ItemAttrExposeItem::parse_known::<kw::write>(input, ItemAttrExposeItemType::Write)
         } else if lookahead.peek(kw::changed) {
             ItemAttrExposeItem::parse_known::<kw::changed>(input, ItemAttrExposeItemType::Changed)
         } else if lookahead.peek(kw::metadata) {
@@ -1275,6 +2183,8 @@ impl ItemAttrParseItem {
             ItemAttrParseItem::Expose(None) => {
                 attrs.expose_spawn_handle.expose = true;
                 attrs.expose_read.expose = true;
+                attrs.expose_owned.expose = true;
+                attrs.expose_write.expose = true;
                 attrs.expose_changed.expose = true;
                 attrs.expose_metadata.expose = true;
                 attrs.expose_discrim.expose = true;
@@ -1284,6 +2194,8 @@ impl ItemAttrParseItem {
                     *match item.item_type {
                         ItemAttrExposeItemType::SpawnHandle => &mut attrs.expose_spawn_handle,
                         ItemAttrExposeItemType::Read => &mut attrs.expose_read,
+                        ItemAttrExposeItemType::Owned => &mut attrs.expose_owned,
+                        ItemAttrExposeItemType::Write => &mut attrs.expose_write,
                         ItemAttrExposeItemType::Changed => &mut attrs.expose_changed,
                         ItemAttrExposeItemType::Metadata => &mut attrs.expose_metadata,
                         ItemAttrExposeItemType::Discrim => &mut attrs.expose_discrim,
@@ -1293,6 +2205,24 @@ impl ItemAttrParseItem {
             ItemAttrParseItem::DiscrimMetadata(metadata) => {
                 attrs.discrim_metadata.extend(metadata);
             }
+            ItemAttrParseItem::ReaderDerive(paths) => {
+                attrs.reader_derives.extend(paths);
+            }
+            ItemAttrParseItem::ChangedDerive(paths) => {
+                attrs.changed_derives.extend(paths);
+            }
+            ItemAttrParseItem::ValidateStruct(validate) => {
+                attrs.validate_struct = Some(validate);
+            }
+            ItemAttrParseItem::InState(states) => {
+                attrs.in_state = Some(states);
+            }
+            ItemAttrParseItem::ResetVariants => {
+                attrs.reset_variants = true;
+            }
+            ItemAttrParseItem::Compact(span) => {
+                attrs.compact = Some(span);
+            }
         }
     }
 }
@@ -1303,14 +2233,37 @@ mod kw {
     syn::custom_keyword!(expose);
     syn::custom_keyword!(spawn_handle);
     syn::custom_keyword!(read);
+    syn::custom_keyword!(owned);
+    syn::custom_keyword!(write);
     syn::custom_keyword!(changed);
     syn::custom_keyword!(metadata);
     syn::custom_keyword!(discrim);
+    syn::custom_keyword!(reader_derive);
+    syn::custom_keyword!(changed_derive);
+    syn::custom_keyword!(validate_struct);
+    syn::custom_keyword!(advanced);
+    syn::custom_keyword!(deprecated);
+    syn::custom_keyword!(replaced_by);
+    syn::custom_keyword!(map);
+    syn::custom_keyword!(permission);
+    syn::custom_keyword!(computed);
+    syn::custom_keyword!(relevant_if);
+    syn::custom_keyword!(path);
+    syn::custom_keyword!(when);
+    syn::custom_keyword!(in_state);
+    syn::custom_keyword!(reset_variants);
+    syn::custom_keyword!(compact);
+    syn::custom_keyword!(default_fn);
+    syn::custom_keyword!(serde);
+    syn::custom_keyword!(skip);
+    syn::custom_keyword!(rename);
 }
 
 struct Idents {
     spawn_handle_ident: syn::Ident,
     read_ident:         syn::Ident,
+    owned_ident:        syn::Ident,
+    write_ident:        syn::Ident,
     changed_ident:      syn::Ident,
     metadata_ident:     syn::Ident,
     discrim_ty:         Option<syn::Type>,
@@ -1329,6 +2282,16 @@ impl Idents {
             .ident
             .clone()
             .unwrap_or_else(|| format_ident!("{input_ident}Read"));
+        let owned_ident = item_attrs
+            .expose_owned
+            .ident
+            .clone()
+            .unwrap_or_else(|| format_ident!("{input_ident}Owned"));
+        let write_ident = item_attrs
+            .expose_write
+            .ident
+            .clone()
+            .unwrap_or_else(|| format_ident!("{input_ident}Write"));
         let changed_ident = item_attrs
             .expose_changed
             .ident
@@ -1351,7 +2314,15 @@ impl Idents {
             _ => None,
         };
 
-        Ok(Self { spawn_handle_ident, read_ident, changed_ident, metadata_ident, discrim_ty })
+        Ok(Self {
+            spawn_handle_ident,
+            read_ident,
+            owned_ident,
+            write_ident,
+            changed_ident,
+            metadata_ident,
+            discrim_ty,
+        })
     }
 
     fn discrim_ident(&self) -> Option<&syn::Ident> {
@@ -1367,6 +2338,7 @@ struct Input<'a> {
     vis:      &'a syn::Visibility,
     generics: &'a syn::Generics,
     data:     InputData<'a>,
+    in_state: Option<Punctuated<syn::Expr, syn::Token![,]>>,
 }
 
 impl<'a> Input<'a> {
@@ -1376,7 +2348,13 @@ impl<'a> Input<'a> {
         idents: &'a Idents,
     ) -> syn::Result<Self> {
         let data = InputData::new(input, item_attrs, idents)?;
-        Ok(Self { ident: &input.ident, vis: &input.vis, generics: &input.generics, data })
+        Ok(Self {
+            ident: &input.ident,
+            vis: &input.vis,
+            generics: &input.generics,
+            data,
+            in_state: item_attrs.in_state.clone(),
+        })
     }
 
     fn read_ident_lifetime(&self, with_bounds: bool) -> TokenStream {
@@ -1385,8 +2363,16 @@ impl<'a> Input<'a> {
             InputData::Enum(input) => input.read_ident_lifetime(with_bounds, self.generics),
         }
     }
+
+    fn write_ident_lifetime(&self, with_bounds: bool) -> TokenStream {
+        match &self.data {
+            InputData::Struct(input) => input.read_ident_lifetime(with_bounds, self.generics),
+            InputData::Enum(_) => enum_write_ident_lifetime(with_bounds, self.generics),
+        }
+    }
 }
 
+#[allow(clippy::large_enum_variant, reason = "constructed once per derive input")]
 enum InputData<'a> {
     Struct(StructInput<'a>),
     Enum(EnumInput<'a>),
@@ -1399,9 +2385,17 @@ impl<'a> InputData<'a> {
         idents: &'a Idents,
     ) -> syn::Result<Self> {
         match &input.data {
-            syn::Data::Struct(data_struct) => Ok(InputData::Struct(StructInput::new(data_struct)?)),
+            syn::Data::Struct(data_struct) => {
+                Ok(InputData::Struct(StructInput::new(data_struct, item_attrs)?))
+            }
 
             syn::Data::Enum(data_enum) => {
+                if let Some(validate) = &item_attrs.validate_struct {
+                    return Err(syn::Error::new_spanned(
+                        validate,
+                        "validate_struct can only be used on structs",
+                    ));
+                }
                 Ok(InputData::Enum(EnumInput::new(data_enum, item_attrs, idents)?))
             }
 
@@ -1432,15 +2426,40 @@ impl<'a> InputData<'a> {
 struct StructInput<'a> {
     named_fields: bool,
     fields:       Vec<InputField<'a>>,
+    /// Fields declared with `#[config(computed = expr)]`, which are only present in the reader.
+    computed:     Vec<ComputedField<'a>>,
+    validate:     Option<syn::Expr>,
 }
 
 impl<'a> StructInput<'a> {
-    fn new(data: &'a syn::DataStruct) -> syn::Result<Self> {
+    fn new(data: &'a syn::DataStruct, item_attrs: &ItemAttrs) -> syn::Result<Self> {
+        let named_fields = matches!(data.fields, syn::Fields::Named(_));
+        let mut computed = Vec::new();
         let fields = data
             .fields
             .iter()
             .enumerate()
-            .map(|(index, field)| {
+            .filter_map(|(index, field)| match computed_from_attrs(&field.attrs) {
+                Ok(None) => Some(Ok((index, field))),
+                Ok(Some(expr)) => match field.ident {
+                    Some(ref ident) if named_fields => {
+                        computed.push(ComputedField {
+                            vis: &field.vis,
+                            ident,
+                            ty: &field.ty,
+                            expr,
+                        });
+                        None
+                    }
+                    _ => Some(Err(syn::Error::new_spanned(
+                        expr,
+                        "computed fields are only supported in structs with named fields",
+                    ))),
+                },
+                Err(err) => Some(Err(err)),
+            })
+            .map(|result| {
+                let (index, field) = result?;
                 let (ident, spawn_handle_field) = match field.ident {
                     None => (
                         InputFieldIdent::Index(index),
@@ -1454,7 +2473,7 @@ impl<'a> StructInput<'a> {
                     InputFieldIdent::Index(index) => index.to_string(),
                     InputFieldIdent::Ident(ident) => ident.to_string(),
                 };
-                let metadata = metadata_from_attrs(&field.attrs)?;
+                let (metadata, universal) = metadata_from_attrs(&field.attrs)?;
                 Ok(InputField {
                     vis: &field.vis,
                     ident,
@@ -1464,12 +2483,14 @@ impl<'a> StructInput<'a> {
                         spawn_handle_field,
                         hierarchy_key: [hierarchy_key].into(),
                         metadata,
+                        description: description_from_attrs(&field.attrs),
+                        universal,
                     },
                 })
             })
             .collect::<syn::Result<Vec<_>>>()?;
 
-        Ok(Self { fields, named_fields: matches!(data.fields, syn::Fields::Named(_)) })
+        Ok(Self { fields, named_fields, computed, validate: item_attrs.validate_struct.clone() })
     }
 
     fn read_ident_lifetime(&self, with_bounds: bool, generics: &syn::Generics) -> TokenStream {
@@ -1511,11 +2532,21 @@ impl<'a> EnumInput<'a> {
         item_attrs: &ItemAttrs,
         idents: &'a Idents,
     ) -> syn::Result<Self> {
+        let mut discrim_metadata = item_attrs.discrim_metadata.clone();
+        if item_attrs.reset_variants {
+            let crate_path = &item_attrs.crate_path;
+            discrim_metadata.push(MetadataEntry {
+                path:  syn::parse_quote!(on_switch),
+                value: syn::parse_quote!(#crate_path::VariantSwitch::Reset),
+            });
+        }
         let discrim = InputFieldData {
             ty:                 idents.discrim_ty.as_ref().unwrap(),
             spawn_handle_field: format_ident!("discrim"),
             hierarchy_key:      ["discrim".to_string()].into(),
-            metadata:           item_attrs.discrim_metadata.clone(),
+            metadata:           discrim_metadata,
+            description:        None,
+            universal:          UniversalAttrs::default(),
         };
 
         let variants = data
@@ -1545,7 +2576,20 @@ impl<'a> EnumInput<'a> {
                                 [variant.ident.to_string(), ident.to_string()].into()
                             }
                         };
-                        let metadata = metadata_from_attrs(&field.attrs)?;
+                        if let Some(expr) = computed_from_attrs(&field.attrs)? {
+                            return Err(syn::Error::new_spanned(
+                                expr,
+                                "computed fields are only supported in structs",
+                            ));
+                        }
+                        let (metadata, universal) = metadata_from_attrs(&field.attrs)?;
+                        if let Some(relevant_if) = &universal.relevant_if {
+                            return Err(syn::Error::new(
+                                relevant_if.span,
+                                "`relevant_if` cannot be used on enum variant fields, which \
+                                 already depend on the discriminant",
+                            ));
+                        }
                         Ok(InputField {
                             vis: &field.vis,
                             ident,
@@ -1555,6 +2599,8 @@ impl<'a> EnumInput<'a> {
                                 spawn_handle_field,
                                 hierarchy_key,
                                 metadata,
+                                description: description_from_attrs(&field.attrs),
+                                universal,
                             },
                         })
                     })
@@ -1602,6 +2648,223 @@ impl<'a> EnumInput<'a> {
 
 type MetadataPath = Punctuated<syn::Member, syn::Token![.]>;
 
+/// An item in a field-level `#[config(...)]` attribute.
+enum FieldAttrItem {
+    /// Assigns a value to the metadata of the field type.
+    Metadata(MetadataEntry),
+    /// `advanced` or `advanced = expr`, universal to all field types.
+    Advanced(syn::Expr),
+    /// `deprecated` or `deprecated(replaced_by = "path", map = expr)`,
+    /// universal to all field types.
+    Deprecated(DeprecatedAttr),
+    /// `permission = expr`, universal to all field types.
+    Permission(syn::Expr),
+    /// `relevant_if = "path"` or `relevant_if(path = "path", when = expr)`,
+    /// universal to all field types.
+    RelevantIf(RelevantIfAttr),
+    /// `in_state(expr, ...)`, universal to all field types.
+    InState(Punctuated<syn::Expr, syn::Token![,]>),
+    /// `default_fn = expr`, assigning the result of `expr` to the `default` metadata at spawn time.
+    DefaultFn(syn::Expr),
+    /// `serde(skip, rename = "key")`, universal to all field types.
+    Serde(SerdeAttr),
+}
+
+impl Parse for FieldAttrItem {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        if input.peek(kw::advanced) && !input.peek2(syn::Token![.]) {
+            let _: kw::advanced = input.parse()?;
+            if input.peek(syn::Token![=]) {
+                let _: syn::Token![=] = input.parse()?;
+                Ok(Self::Advanced(input.parse()?))
+            } else {
+                Ok(Self::Advanced(syn::parse_quote!(true)))
+            }
+        } else if input.peek(kw::deprecated) && !input.peek2(syn::Token![.]) {
+            let keyword: kw::deprecated = input.parse()?;
+            let mut attr =
+                DeprecatedAttr { span: keyword.span, replaced_by: None, map: None };
+            if input.peek(syn::token::Paren) {
+                let inner;
+                syn::parenthesized!(inner in input);
+                attr.parse_items(&inner)?;
+            }
+            Ok(Self::Deprecated(attr))
+        } else if input.peek(kw::permission) && input.peek2(syn::Token![=]) {
+            let _: kw::permission = input.parse()?;
+            let _: syn::Token![=] = input.parse()?;
+            Ok(Self::Permission(input.parse()?))
+        } else if input.peek(kw::relevant_if) && !input.peek2(syn::Token![.]) {
+            let keyword: kw::relevant_if = input.parse()?;
+            let mut attr = RelevantIfAttr { span: keyword.span, path: Vec::new(), when: None };
+            if input.peek(syn::Token![=]) {
+                let _: syn::Token![=] = input.parse()?;
+                attr.path = parse_field_path(input.parse()?, "relevant_if")?;
+            } else {
+                let inner;
+                syn::parenthesized!(inner in input);
+                attr.parse_items(&inner)?;
+            }
+            Ok(Self::RelevantIf(attr))
+        } else if input.peek(kw::in_state) && input.peek2(syn::token::Paren) {
+            let _: kw::in_state = input.parse()?;
+            let inner;
+            syn::parenthesized!(inner in input);
+            let states = inner.parse_terminated(syn::Expr::parse, syn::Token![,])?;
+            if states.is_empty() {
+                return Err(syn::Error::new(
+                    inner.span(),
+                    "`in_state` requires at least one state",
+                ));
+            }
+            Ok(Self::InState(states))
+        } else if input.peek(kw::default_fn) && input.peek2(syn::Token![=]) {
+            let _: kw::default_fn = input.parse()?;
+            let _: syn::Token![=] = input.parse()?;
+            Ok(Self::DefaultFn(input.parse()?))
+        } else if input.peek(kw::serde) && input.peek2(syn::token::Paren) {
+            let _: kw::serde = input.parse()?;
+            let inner;
+            syn::parenthesized!(inner in input);
+            let mut attr = SerdeAttr { skip: None, rename: None };
+            attr.parse_items(&inner)?;
+            Ok(Self::Serde(attr))
+        } else {
+            Ok(Self::Metadata(input.parse()?))
+        }
+    }
+}
+
+/// The parameters of a `#[config(deprecated(...))]` attribute.
+#[derive(Clone)]
+struct DeprecatedAttr {
+    span:        Span,
+    /// The path of the replacement field relative to the parent node, split by `.`.
+    replaced_by: Option<Vec<String>>,
+    /// Converts the deprecated value to the replacement value.
+    map:         Option<syn::Expr>,
+}
+
+impl DeprecatedAttr {
+    fn parse_items(&mut self, input: ParseStream) -> syn::Result<()> {
+        let span = input.span();
+        while !input.is_empty() {
+            let lookahead = input.lookahead1();
+            if lookahead.peek(kw::replaced_by) {
+                let _: kw::replaced_by = input.parse()?;
+                let _: syn::Token![=] = input.parse()?;
+                self.replaced_by = Some(parse_field_path(input.parse()?, "replaced_by")?);
+            } else if lookahead.peek(kw::map) {
+                let _: kw::map = input.parse()?;
+                let _: syn::Token![=] = input.parse()?;
+                self.map = Some(input.parse()?);
+            } else {
+                return Err(lookahead.error());
+            }
+
+            if !input.is_empty() {
+                let _: syn::Token![,] = input.parse()?;
+            }
+        }
+
+        if self.map.is_some() && self.replaced_by.is_none() {
+            return Err(syn::Error::new(span, "`map` requires `replaced_by`"));
+        }
+        Ok(())
+    }
+}
+
+/// The parameters of a `#[config(serde(...))]` attribute.
+struct SerdeAttr {
+    /// Excludes the field from persistence.
+    skip:   Option<Span>,
+    /// Replaces the key of the field in persisted data.
+    rename: Option<syn::LitStr>,
+}
+
+impl SerdeAttr {
+    fn parse_items(&mut self, input: ParseStream) -> syn::Result<()> {
+        let span = input.span();
+        while !input.is_empty() {
+            let lookahead = input.lookahead1();
+            if lookahead.peek(kw::skip) {
+                let keyword: kw::skip = input.parse()?;
+                self.skip = Some(keyword.span);
+            } else if lookahead.peek(kw::rename) {
+                let _: kw::rename = input.parse()?;
+                let _: syn::Token![=] = input.parse()?;
+                let rename: syn::LitStr = input.parse()?;
+                if rename.value().is_empty() {
+                    return Err(syn::Error::new_spanned(rename, "`rename` must be nonempty"));
+                }
+                self.rename = Some(rename);
+            } else {
+                return Err(lookahead.error());
+            }
+
+            if !input.is_empty() {
+                let _: syn::Token![,] = input.parse()?;
+            }
+        }
+
+        if self.skip.is_none() && self.rename.is_none() {
+            return Err(syn::Error::new(span, "expected `skip` or `rename`"));
+        }
+        Ok(())
+    }
+}
+
+/// Splits a `.`-separated path of field names.
+fn parse_field_path(path: syn::LitStr, key: &str) -> syn::Result<Vec<String>> {
+    let segments: Vec<String> = path.value().split('.').map(String::from).collect();
+    if segments.iter().any(String::is_empty) {
+        return Err(syn::Error::new_spanned(
+            path,
+            format!("`{key}` must be a `.`-separated path of field names"),
+        ));
+    }
+    Ok(segments)
+}
+
+/// The parameters of a `#[config(relevant_if(...))]` attribute.
+struct RelevantIfAttr {
+    span: Span,
+    /// The absolute path of the dependency field, starting with its root key.
+    path: Vec<String>,
+    /// Tests the value of the dependency field.
+    /// Defaults to testing whether a `bool` field is true.
+    when: Option<syn::Expr>,
+}
+
+impl RelevantIfAttr {
+    fn parse_items(&mut self, input: ParseStream) -> syn::Result<()> {
+        let span = input.span();
+        while !input.is_empty() {
+            let lookahead = input.lookahead1();
+            if lookahead.peek(kw::path) {
+                let _: kw::path = input.parse()?;
+                let _: syn::Token![=] = input.parse()?;
+                self.path = parse_field_path(input.parse()?, "path")?;
+            } else if lookahead.peek(kw::when) {
+                let _: kw::when = input.parse()?;
+                let _: syn::Token![=] = input.parse()?;
+                self.when = Some(input.parse()?);
+            } else {
+                return Err(lookahead.error());
+            }
+
+            if !input.is_empty() {
+                let _: syn::Token![,] = input.parse()?;
+            }
+        }
+
+        if self.path.is_empty() {
+            return Err(syn::Error::new(span, "`relevant_if` requires `path`"));
+        }
+        Ok(())
+    }
+}
+
 #[derive(Clone)]
 struct MetadataEntry {
     path:  MetadataPath,
@@ -1617,20 +2880,163 @@ impl Parse for MetadataEntry {
     }
 }
 
-fn metadata_from_attrs(attrs: &[syn::Attribute]) -> syn::Result<Vec<MetadataEntry>> {
-    attrs
+/// Parses the field-level `#[config(...)]` attributes
+/// into the metadata entries and the universal attributes.
+fn metadata_from_attrs(
+    attrs: &[syn::Attribute],
+) -> syn::Result<(Vec<MetadataEntry>, UniversalAttrs)> {
+    let items = attrs
         .iter()
         .filter(|attr| attr.path().is_ident("config"))
         .flat_map(|attr| match parse_config_metadata(attr) {
-            Ok(metadata) => Either::Left(metadata.into_iter().map(Ok)),
+            Ok(items) => Either::Left(items.into_iter().map(Ok)),
             Err(err) => Either::Right(iter::once(Err(err))),
         })
-        .collect()
+        .collect::<syn::Result<Vec<_>>>()?;
+
+    let mut metadata = Vec::new();
+    let mut universal = UniversalAttrs::default();
+    for item in items {
+        match item {
+            FieldAttrItem::Metadata(entry) => metadata.push(entry),
+            FieldAttrItem::Advanced(expr) => {
+                if universal.advanced.is_some() {
+                    return Err(syn::Error::new_spanned(expr, "duplicate `advanced` attribute"));
+                }
+                universal.advanced = Some(expr);
+            }
+            FieldAttrItem::Deprecated(attr) => {
+                if universal.deprecated.is_some() {
+                    return Err(syn::Error::new(attr.span, "duplicate `deprecated` attribute"));
+                }
+                universal.deprecated = Some(attr);
+            }
+            FieldAttrItem::Permission(expr) => {
+                if universal.permission.is_some() {
+                    return Err(syn::Error::new_spanned(expr, "duplicate `permission` attribute"));
+                }
+                universal.permission = Some(expr);
+            }
+            FieldAttrItem::RelevantIf(attr) => {
+                if universal.relevant_if.is_some() {
+                    return Err(syn::Error::new(attr.span, "duplicate `relevant_if` attribute"));
+                }
+                universal.relevant_if = Some(attr);
+            }
+            FieldAttrItem::InState(states) => {
+                if universal.in_state.is_some() {
+                    return Err(syn::Error::new_spanned(states, "duplicate `in_state` attribute"));
+                }
+                universal.in_state = Some(states);
+            }
+            FieldAttrItem::DefaultFn(expr) => {
+                if universal.default_fn.is_some() {
+                    return Err(syn::Error::new_spanned(expr, "duplicate `default_fn` attribute"));
+                }
+                universal.default_fn = Some(expr);
+            }
+            FieldAttrItem::Serde(SerdeAttr { skip, rename }) => {
+                if let Some(span) = skip {
+                    if universal.skip_serde.is_some() {
+                        return Err(syn::Error::new(span, "duplicate `serde(skip)` attribute"));
+                    }
+                    universal.skip_serde = Some(span);
+                }
+                if let Some(rename) = rename {
+                    if universal.serde_rename.is_some() {
+                        return Err(syn::Error::new_spanned(
+                            rename,
+                            "duplicate `serde(rename)` attribute",
+                        ));
+                    }
+                    universal.serde_rename = Some(rename);
+                }
+            }
+        }
+    }
+    if let Some(default_fn) = &universal.default_fn
+        && metadata.iter().any(|entry| {
+            entry.path.len() == 1
+                && matches!(&entry.path[0], syn::Member::Named(ident) if ident == "default")
+        })
+    {
+        return Err(syn::Error::new_spanned(
+            default_fn,
+            "`default_fn` cannot be combined with `default`",
+        ));
+    }
+    Ok((metadata, universal))
+}
+
+/// Field-level attributes that apply to fields of any type.
+#[derive(Default)]
+struct UniversalAttrs {
+    advanced:     Option<syn::Expr>,
+    deprecated:   Option<DeprecatedAttr>,
+    permission:   Option<syn::Expr>,
+    relevant_if:  Option<RelevantIfAttr>,
+    in_state:     Option<Punctuated<syn::Expr, syn::Token![,]>>,
+    default_fn:   Option<syn::Expr>,
+    skip_serde:   Option<Span>,
+    serde_rename: Option<syn::LitStr>,
+}
+
+/// Parses `#[config(computed = expr)]` on a field.
+///
+/// A computed field must not have any other `#[config]` attributes,
+/// since it does not spawn a config node.
+fn computed_from_attrs(attrs: &[syn::Attribute]) -> syn::Result<Option<syn::Expr>> {
+    let config_attrs: Vec<_> = attrs.iter().filter(|attr| attr.path().is_ident("config")).collect();
+    let mut computed = None;
+    for attr in &config_attrs {
+        let expr = attr.parse_args_with(|input: ParseStream| {
+            if input.peek(kw::computed) && input.peek2(syn::Token![=]) {
+                let _: kw::computed = input.parse()?;
+                let _: syn::Token![=] = input.parse()?;
+                let expr: syn::Expr = input.parse()?;
+                if !input.is_empty() {
+                    return Err(input.error("computed fields cannot have other config attributes"));
+                }
+                Ok(Some(expr))
+            } else {
+                input.parse::<TokenStream>()?;
+                Ok(None)
+            }
+        })?;
+        if let Some(expr) = expr {
+            if config_attrs.len() > 1 {
+                return Err(syn::Error::new_spanned(
+                    expr,
+                    "computed fields cannot have other config attributes",
+                ));
+            }
+            computed = Some(expr);
+        }
+    }
+    Ok(computed)
+}
+
+/// Joins the doc comment lines of a field into a description.
+fn description_from_attrs(attrs: &[syn::Attribute]) -> Option<String> {
+    let lines: Vec<_> = attrs
+        .iter()
+        .filter(|attr| attr.path().is_ident("doc"))
+        .filter_map(|attr| match &attr.meta {
+            syn::Meta::NameValue(syn::MetaNameValue {
+                value: syn::Expr::Lit(syn::ExprLit { lit: syn::Lit::Str(lit), .. }),
+                ..
+            }) => Some(lit.value()),
+            _ => None,
+        })
+        .collect();
+    let description = lines.iter().map(|line| line.strip_prefix(' ').unwrap_or(line)).join("\n");
+    let description = description.trim();
+    (!description.is_empty()).then(|| description.to_string())
 }
 
-fn parse_config_metadata(attr: &syn::Attribute) -> syn::Result<Vec<MetadataEntry>> {
+fn parse_config_metadata(attr: &syn::Attribute) -> syn::Result<Vec<FieldAttrItem>> {
     let punctuated =
-        attr.parse_args_with(Punctuated::<MetadataEntry, syn::Token![,]>::parse_terminated)?;
+        attr.parse_args_with(Punctuated::<FieldAttrItem, syn::Token![,]>::parse_terminated)?;
     Ok(punctuated.into_iter().collect())
 }
 
@@ -1692,11 +3098,24 @@ impl ToTokens for InputFieldIdent<'_> {
     }
 }
 
+/// A struct field declared with `#[config(computed = expr)]`.
+///
+/// `expr` is evaluated in the reader with the reader fields of all storage fields
+/// and preceding computed fields in scope as local variables.
+struct ComputedField<'a> {
+    vis:   &'a syn::Visibility,
+    ident: &'a syn::Ident,
+    ty:    &'a syn::Type,
+    expr:  syn::Expr,
+}
+
 struct InputFieldData<'a> {
     ty:                 &'a syn::Type,
     spawn_handle_field: syn::Ident,
     hierarchy_key:      Vec<String>,
     metadata:           Vec<MetadataEntry>,
+    description:        Option<String>,
+    universal:          UniversalAttrs,
 }
 
 impl InputFieldData<'_> {
@@ -1,14 +1,17 @@
 //! Re-exported types referenced in macros.
 #![doc(hidden)]
 
+pub use alloc::string::String;
 pub use core::clone::Clone;
 pub use core::cmp::{Eq, PartialEq};
-pub use core::convert::Into;
+pub use core::convert::{From, Into, identity};
 pub use core::default::Default;
 pub use core::fmt::Debug;
+pub use core::hash::Hash;
 pub use core::marker::{Copy, PhantomData, Send, Sync};
 pub use core::ops::{Deref, DerefMut};
 pub use core::option::Option::{self, None, Some};
+pub use core::result::Result;
 pub use core::stringify;
 
 pub use bevy_ecs::component::Component;
@@ -17,4 +20,8 @@ pub use bevy_ecs::name::Name as BevyName;
 pub use bevy_ecs::query::{QueryData, With};
 pub use bevy_ecs::system::Query;
 pub use bevy_ecs::world::{EntityRef, World};
+#[cfg(feature = "egui")]
+pub use bevy_egui::egui;
 pub use derivative::Derivative;
+#[cfg(feature = "serde")]
+pub use serde;
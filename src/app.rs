@@ -1,13 +1,28 @@
+use alloc::borrow::ToOwned as _;
 use alloc::string::String;
+use alloc::sync::Arc;
+use alloc::vec::Vec;
 use core::any::{TypeId, type_name};
 
-use bevy_app::App;
+use bevy_app::{App, AppLabel, First, Last, PreUpdate};
+use bevy_ecs::component::Component;
+use bevy_ecs::entity::Entity;
+use bevy_ecs::lifecycle::HookContext;
+use bevy_ecs::query::{Has, ReadOnlyQueryData, With};
 use bevy_ecs::resource::Resource;
-use bevy_ecs::system::{Local, Query, Res, SystemParam};
-use hashbrown::HashSet;
+use bevy_ecs::schedule::IntoScheduleConfigs;
+use bevy_ecs::system::{Commands, EntityCommand, Local, Query, Res, SystemParam};
+use bevy_ecs::world::{DeferredWorld, EntityWorldMut, Mut, World};
+use hashbrown::{HashMap, HashSet};
 
+use crate::mirror::ConfigMirror;
+use crate::tree::SharedState;
+use crate::validate::{ValidationFailed, validate_structs};
 use crate::{
-    ConfigField, ConfigFieldFor, ConfigNode, Manager, RootNode, SpawnContext, SpawnHandle, manager,
+    ChildNodeList, ConfigCommitted, ConfigField, ConfigFieldFor, ConfigNode, ConfigSnapshot,
+    ConfigWrites, FieldGeneration, GenerationOverflow, Manager, OutOfState, RootNode, RootOrder,
+    ScalarField, SpawnContext, SpawnHandle, TreeRevision, manager, reset, resolve_relevance_paths,
+    temporary,
 };
 
 /// Extension trait for [App] to initialize config systems.
@@ -36,6 +51,9 @@ pub trait AppExt {
     /// - `C` must be **unique** for each call.
     /// - `key` must be **unique** for each call.
     ///
+    /// The last two requirements can be relaxed by inserting a [`KeyCollision`] resource
+    /// before this call, e.g. for plugins that may be added to the same app twice.
+    ///
     /// To ensure the same manager type is used across your game,
     /// it is recommended to reuse a type alias for the desired manager type.
     fn init_config_with<M, C>(
@@ -47,6 +65,53 @@ pub trait AppExt {
         M: Manager,
         C: ConfigFieldFor<M>,
         C::Metadata: Default;
+
+    /// Registers a config type `C` to be instantiated per entity
+    /// using the default manager constructor.
+    ///
+    /// See [`App::init_entity_config_with`] for more information.
+    fn init_entity_config<M, C>(&mut self) -> &mut Self
+    where
+        M: Manager + Default,
+        C: ConfigFieldFor<M>,
+        C::Metadata: Default,
+    {
+        self.init_entity_config_with::<M, C>(M::default)
+    }
+
+    /// Registers a config type `C` to be instantiated per entity,
+    /// e.g. a separate `ControlSettings` tree for each local player.
+    ///
+    /// Instances are spawned with the [`InsertEntityConfig`] entity command
+    /// and read with [`ReadEntityConfig`].
+    /// `init` is only called if no config has been initialized in the app yet.
+    ///
+    /// # Panics
+    /// `M` must be **the same** as the manager type used for all other configs in the app.
+    fn init_entity_config_with<M, C>(&mut self, init: impl FnOnce() -> M) -> &mut Self
+    where
+        M: Manager,
+        C: ConfigFieldFor<M>,
+        C::Metadata: Default;
+
+    /// Mirrors a value derived from the config `C` into the sub-app `sub_app`
+    /// as a [`Mirrored<T>`](crate::mirror::Mirrored) resource.
+    ///
+    /// The value is recomputed with `map` during extraction whenever the config changes,
+    /// after running the existing extract function of the sub-app, if any.
+    /// See the [`mirror`](crate::mirror) module for more information.
+    ///
+    /// # Panics
+    /// Panics if `C` was not initialized with [`init_config`](AppExt::init_config)
+    /// or the sub-app does not exist.
+    fn mirror_config<C, T>(
+        &mut self,
+        sub_app: impl AppLabel,
+        map: for<'a> fn(C::Reader<'a>) -> T,
+    ) -> &mut Self
+    where
+        C: ConfigField,
+        T: Send + Sync + 'static;
 }
 
 #[derive(Resource)]
@@ -56,11 +121,180 @@ struct ManagerType {
     root_keys: HashSet<String>,
 }
 
+/// Determines how [`App::init_config`] handles a root key or config type
+/// that has already been initialized.
+///
+/// Insert this resource into the app before calling `init_config` to change the policy.
+/// This does not affect [`InsertEntityConfig`], which always panics on duplicate keys.
+///
+/// ```
+/// # use bevy_mod_config::{AppExt, Config, KeyCollision};
+/// #[derive(Config)]
+/// struct Audio {
+///     #[config(default = 1.0)]
+///     volume: f32,
+/// }
+///
+/// let mut app = bevy_app::App::new();
+/// app.insert_resource(KeyCollision::Skip);
+/// app.init_config::<(), Audio>("audio");
+/// // A plugin initializing the same config again no longer panics.
+/// app.init_config::<(), Audio>("audio");
+/// ```
+#[derive(Resource, Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum KeyCollision {
+    /// Panic on duplicate keys and types.
+    #[default]
+    Panic,
+    /// Ignore a later call for the same type, keeping the existing config tree.
+    ///
+    /// This still panics if a different type is initialized under an existing key,
+    /// since that type would remain uninitialized and could not be read.
+    Skip,
+    /// Spawn the tree of the new type as another root under the same key,
+    /// such that its fields appear next to those of the existing type
+    /// in path-based managers such as serialization.
+    ///
+    /// Panics if the two types declare fields with the same name.
+    /// Initializing the same type twice is ignored as in [`Skip`](Self::Skip).
+    Merge,
+}
+
 #[derive(Resource)]
 struct RootField<C: ConfigField> {
     spawn_handle: C::SpawnHandle,
 }
 
+/// Registration of a config type initialized with [`App::init_entity_config`].
+#[derive(Resource)]
+struct EntityConfigType<C: ConfigField> {
+    spawn: fn(&mut World, &str) -> C::SpawnHandle,
+}
+
+/// Inserts the manager instance if no manager has been initialized yet,
+/// or asserts that the existing manager has the same type.
+fn ensure_manager<M: Manager>(app: &mut App, init: impl FnOnce() -> M) {
+    if let Some(&ManagerType { id, name, .. }) = app.world().get_resource() {
+        assert!(
+            id == TypeId::of::<M>(),
+            "Use of multiple different config managers in the same app is not allowed: {name} vs \
+             {}",
+            type_name::<M>()
+        );
+    } else {
+        app.insert_resource(ManagerType {
+            id:        TypeId::of::<M>(),
+            name:      type_name::<M>(),
+            root_keys: HashSet::new(),
+        });
+        app.insert_resource(manager::Instance { instance: init() });
+        app.add_message::<ValidationFailed>();
+        app.add_systems(
+            Last,
+            (
+                temporary::revert_expired,
+                reset::reset_switched_variants,
+                (validate_structs, manager::notify_field_changes::<M>),
+            )
+                .chain(),
+        );
+        app.add_systems(PreUpdate, resolve_relevance_paths);
+        app.add_systems(First, apply_batched_changes);
+        #[cfg(feature = "bevy_state")]
+        app.add_systems(bevy_app::RunFixedMainLoop, crate::state::update_state_relevance);
+        M::build(app);
+    }
+}
+
+/// Spawns the config tree of `C` as a new root with the given key.
+fn spawn_root<M, C>(world: &mut World, key: &str) -> C::SpawnHandle
+where
+    M: Manager,
+    C: ConfigFieldFor<M>,
+    C::Metadata: Default,
+{
+    spawn_root_with::<M, C>(world, key, KeyCollision::Panic)
+}
+
+/// Spawns the config tree of `C` as a new root with the given key,
+/// resolving a duplicate key with `collision`.
+fn spawn_root_with<M, C>(world: &mut World, key: &str, collision: KeyCollision) -> C::SpawnHandle
+where
+    M: Manager,
+    C: ConfigFieldFor<M>,
+    C::Metadata: Default,
+{
+    let key_exists = world
+        .get_resource_mut::<ManagerType>()
+        .expect("manager must be initialized before spawning roots")
+        .root_keys
+        .replace(key.to_owned());
+    if let Some(key) = &key_exists {
+        match collision {
+            KeyCollision::Panic => panic!("Cannot reuse config key {key:?} in the same app"),
+            KeyCollision::Skip => panic!(
+                "Cannot initialize {} under the config key {key:?} used by another type",
+                type_name::<C>()
+            ),
+            KeyCollision::Merge => {}
+        }
+    }
+
+    let revision = TreeRevision::new(world.get_resource_or_init::<ChangeBatch>().state.clone());
+    let merged_revision = key_exists.is_some().then(|| revision.clone());
+    let spawn_handle = C::spawn_world(
+        world,
+        SpawnContext {
+            path: [key.to_owned()].into(),
+            parent: None,
+            dependency: None,
+            description: None,
+            advanced: false,
+            deprecated: None,
+            permission: None,
+            relevance: None,
+            in_state: None,
+            skip_serde: false,
+            serde_rename: None,
+            revision,
+        },
+        Default::default(),
+    );
+    if let Some(revision) = merged_revision {
+        assert_no_merged_duplicates(world, &revision);
+    }
+
+    let position =
+        world.query::<&RootOrder>().iter(world).map(|order| order.position + 1).max().unwrap_or(0);
+    world.entity_mut(spawn_handle.node()).insert((RootNode, RootOrder { pinned: false, position }));
+    world.resource_scope(|world, mut instance: Mut<manager::Instance<M>>| {
+        instance.init_root(&manager::RootInfo {
+            key,
+            entity: spawn_handle.node(),
+            type_id: TypeId::of::<C>(),
+            type_name: type_name::<C>(),
+            world,
+        });
+    });
+    spawn_handle
+}
+
+/// Asserts that no field of the tree with `revision`, merged under an existing key,
+/// has the same path as a field of another tree.
+fn assert_no_merged_duplicates(world: &mut World, revision: &TreeRevision) {
+    let mut query = world.query::<&ConfigNode>();
+    let (merged, others): (Vec<_>, Vec<_>) =
+        query.iter(world).partition(|node| node.revision.same_tree(revision));
+    let others: HashSet<&[String]> = others.iter().map(|node| node.path.as_slice()).collect();
+    for node in merged {
+        assert!(
+            node.path.len() <= 1 || !others.contains(node.path.as_slice()),
+            "Cannot merge config field {:?} into an existing field with the same path",
+            node.path.join("."),
+        );
+    }
+}
+
 impl AppExt for App {
     fn init_config_with<M, C>(
         &mut self,
@@ -72,48 +306,418 @@ impl AppExt for App {
         C: ConfigFieldFor<M>,
         C::Metadata: Default,
     {
-        if let Some(&ManagerType { id, name, .. }) = self.world().get_resource() {
+        ensure_manager(self, init);
+
+        let collision = self.world().get_resource::<KeyCollision>().copied().unwrap_or_default();
+        if self.world().get_resource::<RootField<C>>().is_some() {
             assert!(
-                id == TypeId::of::<M>(),
-                "Use of multiple different config managers in the same app is not allowed: {name} \
-                 vs {}",
-                type_name::<M>()
+                collision != KeyCollision::Panic,
+                "Cannot initialize multiple root config fields of the same type in the same app: \
+                 {}",
+                type_name::<C>()
             );
-        } else {
-            self.insert_resource(ManagerType {
-                id:        TypeId::of::<M>(),
-                name:      type_name::<M>(),
-                root_keys: HashSet::new(),
-            });
-            self.insert_resource(manager::Instance { instance: init() });
+            return self;
         }
 
-        let key = key.into();
-        let key_exists = self
-            .world_mut()
-            .get_resource_mut::<ManagerType>()
-            .expect("just checked")
-            .root_keys
-            .replace(key.clone());
-        if let Some(key) = key_exists {
-            panic!("Cannot reuse config key {key:?} in the same app");
-        }
+        let spawn_handle = spawn_root_with::<M, C>(self.world_mut(), &key.into(), collision);
+        self.insert_resource(RootField::<C> { spawn_handle });
+        self
+    }
+
+    fn init_entity_config_with<M, C>(&mut self, init: impl FnOnce() -> M) -> &mut Self
+    where
+        M: Manager,
+        C: ConfigFieldFor<M>,
+        C::Metadata: Default,
+    {
+        ensure_manager(self, init);
+        self.insert_resource(EntityConfigType::<C> { spawn: spawn_root::<M, C> });
+        self
+    }
 
+    fn mirror_config<C, T>(
+        &mut self,
+        sub_app: impl AppLabel,
+        map: for<'a> fn(C::Reader<'a>) -> T,
+    ) -> &mut Self
+    where
+        C: ConfigField,
+        T: Send + Sync + 'static,
+    {
         assert!(
-            self.world().get_resource::<RootField<C>>().is_none(),
-            "Cannot initialize multiple root config fields of the same type in the same app: {}",
+            self.world().contains_resource::<RootField<C>>(),
+            "{} must be initialized with init_config before mirroring",
             type_name::<C>()
         );
 
-        let spawn_handle = C::spawn_world(
-            self.world_mut(),
-            SpawnContext { path: [key].into(), parent: None, dependency: None },
-            Default::default(),
-        );
+        let mirror = ConfigMirror::<C, T>::new(self.world_mut(), map);
+        let sub_app = self.sub_app_mut(sub_app);
+        sub_app.world_mut().insert_resource(mirror);
 
-        self.world_mut().entity_mut(spawn_handle.node()).insert(RootNode);
-        self.insert_resource(RootField::<C> { spawn_handle });
+        let mut previous = sub_app.take_extract();
+        sub_app.set_extract(move |main_world, sub_world| {
+            if let Some(previous) = &mut previous {
+                previous(main_world, sub_world);
+            }
+            sub_world.resource_scope(|sub_world, mut mirror: Mut<ConfigMirror<C, T>>| {
+                mirror.sync(main_world, sub_world);
+            });
+        });
+        self
+    }
+}
+
+/// Spawns an instance of the config type `C` owned by the target entity.
+///
+/// The config tree is spawned as a new root with the given key,
+/// which must be unique among all roots in the app
+/// since it identifies the instance in persistence managers.
+/// The tree is despawned when the owner entity is despawned
+/// or loses its [`EntityConfig<C>`] component.
+///
+/// ```
+/// # use bevy_app::App;
+/// # use bevy_ecs::system::RunSystemOnce;
+/// use bevy_mod_config::{AppExt, Config, InsertEntityConfig, ReadEntityConfig};
+///
+/// #[derive(Config)]
+/// struct Controls {
+///     #[config(default = 1.0)]
+///     sensitivity: f32,
+/// }
+///
+/// let mut app = App::new();
+/// app.init_entity_config::<(), Controls>();
+///
+/// let player = app.world_mut().spawn_empty().id();
+/// app.world_mut().commands().entity(player).queue(InsertEntityConfig::<Controls>::new("player1"));
+/// app.world_mut().flush();
+///
+/// app.world_mut()
+///     .run_system_once(move |controls: ReadEntityConfig<Controls>| {
+///         assert_eq!(controls.read(player).unwrap().sensitivity, 1.0);
+///     })
+///     .unwrap();
+/// ```
+///
+/// # Panics
+/// Panics if `C` was not registered with [`init_entity_config`](AppExt::init_entity_config)
+/// or the key is already in use.
+pub struct InsertEntityConfig<C> {
+    key:     String,
+    _config: core::marker::PhantomData<fn() -> C>,
+}
+
+impl<C> InsertEntityConfig<C> {
+    /// Creates a command spawning a config instance with the root key `key`.
+    pub fn new(key: impl Into<String>) -> Self {
+        Self { key: key.into(), _config: core::marker::PhantomData }
+    }
+}
+
+impl<C: ConfigField> EntityCommand for InsertEntityConfig<C> {
+    type Out = ();
+
+    fn apply(self, mut entity: EntityWorldMut) {
+        // Despawn the replaced instance first, so that its root key can be reused.
+        entity.remove::<EntityConfig<C>>();
+        let spawn_handle = entity.world_scope(|world| {
+            world.flush();
+            let spawn = world
+                .get_resource::<EntityConfigType<C>>()
+                .unwrap_or_else(|| {
+                    panic!(
+                        "{} must be registered with init_entity_config before insertion",
+                        type_name::<C>()
+                    )
+                })
+                .spawn;
+            spawn(world, &self.key)
+        });
+        entity.insert(EntityConfig::<C> { spawn_handle });
+    }
+}
+
+/// Owns an instance of the config type `C` spawned with [`InsertEntityConfig`].
+///
+/// Removing or replacing this component despawns the config tree.
+#[derive(Component)]
+#[component(on_discard = despawn_entity_config::<C>)]
+pub struct EntityConfig<C: ConfigField> {
+    spawn_handle: C::SpawnHandle,
+}
+
+impl<C: ConfigField> EntityConfig<C> {
+    /// Returns the root node entity of the config tree.
+    #[must_use]
+    pub fn root(&self) -> Entity { self.spawn_handle.node() }
+}
+
+fn despawn_entity_config<C: ConfigField>(mut world: DeferredWorld, ctx: HookContext) {
+    let root = world.get::<EntityConfig<C>>(ctx.entity).expect("hook of this component").root();
+    world.commands().queue(move |world: &mut World| despawn_tree(world, root));
+}
+
+/// Despawns a config root and all its descendants,
+/// releasing the root key unless another root is merged under the same key.
+fn despawn_tree(world: &mut World, root: Entity) {
+    if let Some(node) = world.get::<ConfigNode>(root)
+        && let Some(key) = node.path.first().cloned()
+    {
+        let key_shared = world
+            .query_filtered::<(Entity, &ConfigNode), With<RootNode>>()
+            .iter(world)
+            .any(|(entity, node)| entity != root && node.path.first() == Some(&key));
+        if !key_shared && let Some(mut manager_type) = world.get_resource_mut::<ManagerType>() {
+            manager_type.root_keys.remove(&key);
+        }
+    }
+
+    let mut nodes = Vec::from([root]);
+    let mut index = 0;
+    while let Some(&entity) = nodes.get(index) {
+        if let Some(children) = world.get::<ChildNodeList>(entity) {
+            nodes.extend(children.iter().copied());
+        }
+        index += 1;
+    }
+    // Despawn descendants before their parents.
+    for entity in nodes.into_iter().rev() {
+        world.despawn(entity);
+    }
+}
+
+/// Extension trait for [World] to modify configs with exclusive access.
+pub trait WorldExt {
+    /// Modifies the root config `C` through its [`Writer`](ConfigField::Writer).
+    ///
+    /// The writes made in `update` are applied together after it returns,
+    /// so systems never observe a partially updated config.
+    /// This is intended for startup code and one-shot systems
+    /// that need to write several fields at once.
+    ///
+    /// ```
+    /// # use bevy_app::App;
+    /// # use bevy_mod_config::{AppExt, Config, WorldExt};
+    /// #[derive(Config)]
+    /// struct Video {
+    ///     width:  u32,
+    ///     height: u32,
+    /// }
+    ///
+    /// let mut app = App::new();
+    /// app.init_config::<(), Video>("video");
+    /// app.world_mut().update_config::<Video>(|video| {
+    ///     video.width.set(1920);
+    ///     video.height.set(1080);
+    /// });
+    /// ```
+    ///
+    /// # Panics
+    /// Panics if `C` was not initialized with [`App::init_config`].
+    fn update_config<C: ConfigField>(&mut self, update: impl FnOnce(C::Writer<'_>));
+
+    /// Runs `batch` within a [`ChangeBatch`],
+    /// e.g. to load many config fields from a file.
+    ///
+    /// [`ReadConfigChange::consume_change`] does not report any changes made during `batch`
+    /// until `batch` returns,
+    /// so systems reacting to changes only observe the final state.
+    fn batch_changes<R>(&mut self, batch: impl FnOnce(&mut World) -> R) -> R;
+
+    /// Writes multiple fields of the root config `C` as a single coherent change.
+    ///
+    /// This is similar to [`update_config`](Self::update_config),
+    /// but the writes are applied within a [`ChangeBatch`],
+    /// the [generation](ConfigNode::generation) of the root node is bumped once,
+    /// and a single [`ConfigCommitted`] event listing all written fields
+    /// is triggered on the root node afterwards.
+    ///
+    /// ```
+    /// # use bevy_app::App;
+    /// # use bevy_mod_config::{AppExt, Config, WorldExt};
+    /// #[derive(Config)]
+    /// struct Video {
+    ///     width:  u32,
+    ///     height: u32,
+    /// }
+    ///
+    /// let mut app = App::new();
+    /// app.init_config::<(), Video>("video");
+    /// app.world_mut().transaction::<Video>(|tx| {
+    ///     tx.width.set(1920);
+    ///     tx.height.set(1080);
+    /// });
+    /// ```
+    ///
+    /// # Panics
+    /// Panics if `C` was not initialized with [`App::init_config`].
+    fn transaction<C: ConfigField>(&mut self, transaction: impl FnOnce(C::Writer<'_>));
+
+    /// Returns the [`RootOrder`] of the root config `C` to reorder or pin it.
+    ///
+    /// ```
+    /// # use bevy_app::App;
+    /// # use bevy_mod_config::{AppExt, Config, WorldExt};
+    /// # #[derive(Config)]
+    /// # struct Audio { volume: f32 }
+    /// # #[derive(Config)]
+    /// # struct Video { fps: u32 }
+    /// let mut app = App::new();
+    /// app.init_config::<(), Audio>("audio");
+    /// app.init_config::<(), Video>("video");
+    ///
+    /// // List video before audio.
+    /// app.world_mut().root_order::<Video>().pinned = true;
+    /// ```
+    ///
+    /// # Panics
+    /// Panics if `C` was not initialized with [`App::init_config`].
+    fn root_order<C: ConfigField>(&mut self) -> Mut<'_, RootOrder>;
+
+    /// Sets the behavior of [`ConfigNode::bump`] when a [generation](ConfigNode::generation)
+    /// of a config node in this world overflows.
+    ///
+    /// ```
+    /// # use bevy_app::App;
+    /// use bevy_mod_config::{GenerationOverflow, WorldExt};
+    ///
+    /// let mut app = App::new();
+    /// app.world_mut().set_generation_overflow(GenerationOverflow::Wrap);
+    /// ```
+    fn set_generation_overflow(&mut self, overflow: GenerationOverflow);
+}
+
+/// Collects the writes made by `update` to the root config `C` without applying them.
+pub(crate) fn collect_writes<C: ConfigField>(
+    world: &World,
+    update: impl FnOnce(C::Writer<'_>),
+) -> ConfigWrites {
+    let writes = ConfigWrites::default();
+    let root = world.get_resource::<RootField<C>>().unwrap_or_else(|| {
+        panic!("{} must be initialized with init_config before updating", type_name::<C>())
+    });
+    update(C::write_world(&writes, &root.spawn_handle));
+    writes
+}
+
+impl WorldExt for World {
+    fn update_config<C: ConfigField>(&mut self, update: impl FnOnce(C::Writer<'_>)) {
+        collect_writes::<C>(self, update).apply(self);
+    }
+
+    fn batch_changes<R>(&mut self, batch: impl FnOnce(&mut World) -> R) -> R {
+        self.get_resource_or_init::<ChangeBatch>().begin();
+        let output = batch(self);
+        self.resource_mut::<ChangeBatch>().end();
+        apply_batched_changes(self);
+        output
+    }
+
+    fn transaction<C: ConfigField>(&mut self, transaction: impl FnOnce(C::Writer<'_>)) {
+        let writes = ConfigWrites::default();
+        let root = self.get_resource::<RootField<C>>().unwrap_or_else(|| {
+            panic!("{} must be initialized with init_config before updating", type_name::<C>())
+        });
+        transaction(C::write_world(&writes, &root.spawn_handle));
+        let root = root.spawn_handle.node();
+        let fields = writes.fields();
+        if fields.is_empty() {
+            return;
+        }
+
+        self.batch_changes(|world| {
+            writes.apply(world);
+            world.get_mut::<ConfigNode>(root).expect("root must be a config node").bump();
+        });
+        self.trigger(ConfigCommitted { root, fields });
+    }
+
+    fn root_order<C: ConfigField>(&mut self) -> Mut<'_, RootOrder> {
+        let root = self.get_resource::<RootField<C>>().unwrap_or_else(|| {
+            panic!("{} must be initialized with init_config before reordering", type_name::<C>())
+        });
+        let root = root.spawn_handle.node();
+        self.get_mut::<RootOrder>(root).expect("root config nodes have a RootOrder")
+    }
+
+    fn set_generation_overflow(&mut self, overflow: GenerationOverflow) {
+        self.get_resource_or_init::<ChangeBatch>().state.set_overflow(overflow);
+    }
+}
 
+/// Defers the change detection of bulk modifications,
+/// such as loading a config file or applying a preset.
+///
+/// Fields are still modified immediately,
+/// but [bumping](ConfigNode::bump) their [generations](ConfigNode::generation)
+/// is deferred while any batch is active.
+/// After the last batch ends, the deferred bumps are applied as a single step
+/// by [`apply_batched_changes`],
+/// so [`ReadConfigChange::consume_change`] reports each changed config once
+/// against its final state.
+///
+/// Use [`WorldExt::batch_changes`] for batches within a single command,
+/// which applies the deferred bumps immediately after the batch.
+/// Alternatively, call [`begin`](Self::begin) and [`end`](Self::end) on this resource directly
+/// for batches spanning multiple frames;
+/// the deferred bumps are then applied in [`First`](bevy_app::First) of the next frame.
+/// Batches may be nested.
+///
+/// This resource also stores the policy set with [`WorldExt::set_generation_overflow`].
+#[derive(Resource, Debug, Default)]
+pub struct ChangeBatch {
+    state: Arc<SharedState>,
+}
+
+impl ChangeBatch {
+    /// Starts a batch.
+    pub fn begin(&mut self) { self.state.begin(); }
+
+    /// Ends the batch started by the last unmatched [`begin`](Self::begin).
+    ///
+    /// # Panics
+    /// Panics if there is no active batch.
+    pub fn end(&mut self) {
+        assert!(self.state.end(), "ChangeBatch::end called without begin");
+    }
+
+    /// Returns whether any batch is active.
+    #[must_use]
+    pub fn is_active(&self) -> bool { self.state.is_active() }
+}
+
+/// Applies the [bumps](ConfigNode::bump) deferred by a [`ChangeBatch`]
+/// if no batch is active anymore.
+///
+/// This is an exclusive system that [`App::init_config`] adds to [`First`](bevy_app::First).
+pub fn apply_batched_changes(world: &mut World) {
+    let Some(batch) = world.get_resource::<ChangeBatch>() else { return };
+    if batch.is_active() || !batch.state.take_pending() {
+        return;
+    }
+    for mut node in world.query::<&mut ConfigNode>().iter_mut(world) {
+        if node.pending {
+            node.apply_pending();
+        }
+    }
+}
+
+/// Extension trait for [Commands] to modify configs.
+pub trait CommandsExt {
+    /// Queues a command that calls [`WorldExt::update_config`] with `update`.
+    fn update_config<C: ConfigField>(
+        &mut self,
+        update: impl FnOnce(C::Writer<'_>) + Send + 'static,
+    ) -> &mut Self;
+}
+
+impl CommandsExt for Commands<'_, '_> {
+    fn update_config<C: ConfigField>(
+        &mut self,
+        update: impl FnOnce(C::Writer<'_>) + Send + 'static,
+    ) -> &mut Self {
+        self.queue(move |world: &mut World| world.update_config::<C>(update));
         self
     }
 }
@@ -141,14 +745,130 @@ impl<C: ConfigField> ReadConfig<'_, '_, C> {
     pub fn changed(&self) -> C::Changed {
         C::changed(&self.changed_query, &self.root_field.spawn_handle)
     }
+
+    /// Returns the generation of the scalar field at the dot-separated `path`
+    /// relative to the config root, e.g. `"line1.thickness"`,
+    /// or `None` if there is no such field.
+    ///
+    /// This allows reacting to changes of a single field
+    /// without computing the whole [`Changed`](ConfigField::Changed) tree.
+    /// The lookup scans all config nodes,
+    /// so prefer [`changed`](Self::changed) when checking many fields.
+    #[must_use]
+    pub fn field_generation(&self, path: &str) -> Option<FieldGeneration> {
+        field_generation(&self.changed_query, self.root_field.spawn_handle.node(), path)
+    }
+
+    /// Captures a value derived from the config into a [`ConfigSnapshot`]
+    /// that can be sent to async tasks.
+    ///
+    /// `map` converts the borrowed reader into an owned value.
+    #[must_use]
+    pub fn snapshot<T>(&self, map: impl FnOnce(C::Reader<'_>) -> T) -> ConfigSnapshot<C, T> {
+        ConfigSnapshot::new(map(self.read()), self.changed())
+    }
+}
+
+/// Access to a root config type `C` like [`ReadConfig`],
+/// caching an [owned](ConfigField::Owned) copy of the config between calls.
+///
+/// The cache is keyed by the [`TreeRevision`] of the config tree,
+/// so reading an unchanged config only costs a single revision comparison
+/// instead of walking all fields of the tree.
+/// This is useful for systems that read the config every frame.
+#[derive(SystemParam)]
+pub struct CachedReadConfig<'w, 's, C: ConfigField> {
+    read_config: ReadConfig<'w, 's, C>,
+    cache:       Local<'s, Option<(u64, <C as ConfigField>::Owned)>>,
+}
+
+impl<C: ConfigField> CachedReadConfig<'_, '_, C> {
+    /// Returns the cached config, refreshing it if the config has changed since the last call.
+    pub fn read(&mut self) -> &C::Owned {
+        let root = self.read_config.root_field.spawn_handle.node();
+        let (node, _) =
+            self.read_config.changed_query.get(root).expect("root config node must exist");
+        let revision = node.revision.get();
+
+        let cache = &mut *self.cache;
+        if cache.as_ref().is_none_or(|&(cached, _)| cached != revision) {
+            *cache = Some((revision, C::to_owned(self.read_config.read())));
+        }
+        &cache.as_ref().expect("cache is populated above").1
+    }
+}
+
+/// Access to the per-entity instances of a config type `C`
+/// that was registered with [`App::init_entity_config`].
+#[derive(SystemParam)]
+pub struct ReadEntityConfig<'w, 's, C: ConfigField> {
+    read_query:    Query<'w, 's, <C as ConfigField>::ReadQueryData>,
+    changed_query: Query<'w, 's, (&'static ConfigNode, <C as ConfigField>::ChangedQueryData)>,
+    owners:        Query<'w, 's, (Entity, &'static EntityConfig<C>)>,
+}
+
+impl<C: ConfigField> ReadEntityConfig<'_, '_, C> {
+    /// Reads the config instance owned by `owner`,
+    /// or returns `None` if `owner` has no instance of `C`.
+    #[must_use]
+    pub fn read(&self, owner: Entity) -> Option<C::Reader<'_>> {
+        let (_, config) = self.owners.get(owner).ok()?;
+        Some(C::read_world(&self.read_query, &config.spawn_handle))
+    }
+
+    /// Returns a value that changes when the config instance owned by `owner` is modified,
+    /// or `None` if `owner` has no instance of `C`.
+    ///
+    /// See [`ConfigField::Changed`] for details.
+    #[must_use]
+    pub fn changed(&self, owner: Entity) -> Option<C::Changed> {
+        let (_, config) = self.owners.get(owner).ok()?;
+        Some(C::changed(&self.changed_query, &config.spawn_handle))
+    }
+
+    /// Returns the generation of the scalar field at the dot-separated `path`
+    /// in the config instance owned by `owner`,
+    /// or `None` if `owner` has no instance of `C` or there is no such field.
+    ///
+    /// See [`ReadConfig::field_generation`] for details.
+    #[must_use]
+    pub fn field_generation(&self, owner: Entity, path: &str) -> Option<FieldGeneration> {
+        let (_, config) = self.owners.get(owner).ok()?;
+        field_generation(&self.changed_query, config.root(), path)
+    }
+
+    /// Iterates over all owners and their config instances.
+    pub fn iter(&self) -> impl Iterator<Item = (Entity, C::Reader<'_>)> {
+        self.owners
+            .iter()
+            .map(|(owner, config)| (owner, C::read_world(&self.read_query, &config.spawn_handle)))
+    }
+}
+
+/// Finds the generation of the node at the dot-separated `path` relative to `root`.
+fn field_generation<D: ReadOnlyQueryData>(
+    query: &Query<(&'static ConfigNode, D)>,
+    root: Entity,
+    path: &str,
+) -> Option<FieldGeneration> {
+    let (root, _) = query.get(root).ok()?;
+    query.iter().find_map(|(node, _)| {
+        let (prefix, rest) = node.path.split_at_checked(root.path.len())?;
+        (prefix == root.path && rest.iter().map(String::as_str).eq(path.split('.')))
+            .then_some(node.generation)
+    })
 }
 
 /// Access to a tree of config fields from a root config type `C`,
 /// and maintains a local state to track changes since the last check.
 #[derive(SystemParam)]
 pub struct ReadConfigChange<'w, 's, C: ConfigField> {
-    last_value:  Local<'s, Option<<C as ConfigField>::Changed>>,
-    read_config: ReadConfig<'w, 's, C>,
+    last_change:      Local<'s, HashMap<Entity, FieldGeneration>>,
+    last_generations: Local<'s, HashMap<Entity, FieldGeneration>>,
+    read_config:      ReadConfig<'w, 's, C>,
+    batch:            Option<Res<'w, ChangeBatch>>,
+    out_of_state:     Query<'w, 's, &'static ConfigNode, With<OutOfState>>,
+    nodes:            Query<'w, 's, (Entity, &'static ConfigNode, Has<ScalarField>)>,
 }
 
 impl<C: ConfigField> ReadConfigChange<'_, '_, C> {
@@ -157,13 +877,111 @@ impl<C: ConfigField> ReadConfigChange<'_, '_, C> {
     pub fn read(&self) -> C::Reader<'_> { self.read_config.read() }
 
     /// Returns whether the config field has changed since the last check.
+    ///
+    /// Always returns `false` while a [`ChangeBatch`] is active
+    /// or the root is marked as [`OutOfState`].
+    /// Changes of fields marked as `OutOfState`, or under such a field, are not reported either.
+    /// The changes made in the meantime are reported afterwards.
     pub fn consume_change(&mut self) -> bool {
-        let changed = self.read_config.changed();
-        if self.last_value.as_ref().is_none_or(|v| *v != changed) {
-            *self.last_value = Some(changed);
-            true
-        } else {
-            false
+        if self.is_paused() {
+            return false;
+        }
+
+        let mut changed = false;
+        for entity in self.tracked_nodes() {
+            let Ok((_, node, _)) = self.nodes.get(entity) else { continue };
+            changed |= self.last_change.insert(entity, node.generation) != Some(node.generation);
+        }
+        self.last_change.retain(|&entity, _| self.nodes.contains(entity));
+        changed
+    }
+
+    /// Returns the scalar fields that have changed since the last check,
+    /// like [`consume_change`](Self::consume_change) but with field-level detail.
+    ///
+    /// All fields are reported on the first call.
+    /// This keeps its own state, so it does not affect the result of `consume_change`
+    /// on the same parameter and vice versa.
+    pub fn consume_changes(&mut self) -> ChangedFields {
+        if self.is_paused() {
+            return ChangedFields::default();
         }
+
+        let root_len = self
+            .nodes
+            .get(self.read_config.root_field.spawn_handle.node())
+            .map_or(0, |(_, root, _)| root.path.len());
+
+        let mut paths = Vec::new();
+        for entity in self.tracked_nodes() {
+            let Ok((_, node, is_scalar)) = self.nodes.get(entity) else { continue };
+            if is_scalar
+                && self.last_generations.insert(entity, node.generation) != Some(node.generation)
+            {
+                paths.push(node.path[root_len..].to_vec());
+            }
+        }
+        self.last_generations.retain(|&entity, _| self.nodes.contains(entity));
+        paths.sort();
+        ChangedFields { paths }
+    }
+
+    fn is_paused(&self) -> bool {
+        self.batch.as_ref().is_some_and(|batch| batch.is_active())
+            || self.out_of_state.contains(self.read_config.root_field.spawn_handle.node())
+    }
+
+    /// Returns the nodes in the subtree of `C`,
+    /// excluding the nodes marked as [`OutOfState`] and their descendants.
+    fn tracked_nodes(&self) -> Vec<Entity> {
+        let Ok((_, root, _)) = self.nodes.get(self.read_config.root_field.spawn_handle.node())
+        else {
+            return Vec::new();
+        };
+        let in_subtree = |node: &ConfigNode| {
+            node.revision.same_tree(&root.revision) && node.path.starts_with(&root.path)
+        };
+
+        let deferred: Vec<&[String]> = self
+            .out_of_state
+            .iter()
+            .filter(|node| in_subtree(node))
+            .map(|node| node.path.as_slice())
+            .collect();
+        self.nodes
+            .iter()
+            .filter(|&(_, node, _)| {
+                in_subtree(node) && !deferred.iter().any(|path| node.path.starts_with(path))
+            })
+            .map(|(entity, _, _)| entity)
+            .collect()
+    }
+}
+
+/// The scalar fields reported by [`ReadConfigChange::consume_changes`].
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ChangedFields {
+    paths: Vec<Vec<String>>,
+}
+
+impl ChangedFields {
+    /// Returns whether no fields have changed.
+    #[must_use]
+    pub fn is_empty(&self) -> bool { self.paths.is_empty() }
+
+    /// Iterates over the paths of the changed fields relative to the config root, in sorted order.
+    pub fn iter(&self) -> impl Iterator<Item = &[String]> { self.paths.iter().map(Vec::as_slice) }
+
+    /// Returns whether the field at the dot-separated `path` relative to the config root,
+    /// or any field under it, has changed.
+    ///
+    /// For example, `contains("window")` checks whether any field of the `window` struct changed,
+    /// and the empty path `""` matches any change.
+    #[must_use]
+    pub fn contains(&self, path: &str) -> bool {
+        let path: Vec<&str> = if path.is_empty() { Vec::new() } else { path.split('.').collect() };
+        self.paths.iter().any(|changed| {
+            changed.len() >= path.len() && changed.iter().zip(&path).all(|(a, b)| a == b)
+        })
     }
 }
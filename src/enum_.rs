@@ -1,4 +1,7 @@
-use crate::ConfigField;
+use bevy_ecs::component::Component;
+use bevy_ecs::world::EntityRef;
+
+use crate::{ConfigField, IntoScalarData, ScalarData, ScalarMetadata};
 
 /// Implemented by the discriminant type generated by [`crate::Config`] when derived for enums.
 ///
@@ -19,17 +22,107 @@ pub trait EnumDiscriminant: ConfigField + Eq + Sized + Copy + Send + Sync + 'sta
 }
 
 /// A [`ConfigField`] implementation that wraps an [`EnumDiscriminant`] implementor.
-#[derive(PartialEq)]
+#[derive(Debug, Clone, Copy, PartialEq)]
 pub struct EnumDiscriminantWrapper<T>(pub T);
 
+impl<T: EnumDiscriminant> From<T> for EnumDiscriminantWrapper<T> {
+    fn from(discrim: T) -> Self { Self(discrim) }
+}
+
+impl<T: EnumDiscriminant> IntoScalarData<EnumDiscriminantWrapper<T>> for T {
+    fn into_scalar_data(self) -> EnumDiscriminantWrapper<T> { EnumDiscriminantWrapper(self) }
+}
+
 /// [Metadata](ConfigField::Metadata) type for enum discriminants.
 ///
 /// `T` is the discrim type derived in `#[derive(Config)]` for the corresponding enum.
 /// Use `#[config(expose(discrim))]` and reference it as `{StructName}Discrim`.
+/// This metadata type is then also nameable as `{StructName}DiscrimMetadata`.
 #[derive(Default, Clone)]
 pub struct EnumDiscriminantMetadata<T> {
     /// The default enum variant.
+    ///
+    /// Defaults to the first variant in declaration order,
+    /// unless overridden with `#[config(discrim(default = ...))]` on the enum
+    /// or `#[config(discrim.default = ...)]` on a field of the enum type.
     pub default: T,
+
+    /// What happens to the fields of a variant when another variant becomes active.
+    ///
+    /// Defaults to [`VariantSwitch::Preserve`],
+    /// unless overridden with `#[config(reset_variants)]` or
+    /// `#[config(discrim(on_switch = ...))]` on the enum
+    /// or `#[config(discrim.on_switch = ...)]` on a field of the enum type.
+    pub on_switch: VariantSwitch,
+}
+
+/// Whether the fields of an enum variant keep their values when another variant becomes active.
+///
+/// Switches are detected from any source of change,
+/// including the [`Egui`](crate::manager::Egui) editor and deserialization,
+/// by [`reset_switched_variants`](crate::reset::reset_switched_variants).
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum VariantSwitch {
+    /// The fields of inactive variants keep their values,
+    /// so switching back to a variant restores the previously entered values.
+    #[default]
+    Preserve,
+    /// The fields of a variant are reset to their defaults when the variant is deactivated,
+    /// so switching back to a variant shows its defaults.
+    ///
+    /// The fields of the newly active variant are not reset,
+    /// so values deserialized together with the discriminant are kept.
+    /// Values deserialized for the deactivated variant are overwritten by the defaults.
+    Reset,
+}
+
+/// Marks a scalar config field as the discriminant of a [`Config`](crate::Config)-derived enum,
+/// so that managers can find the active variant without knowing the enum type.
+///
+/// The discriminant field is named `discrim` under the enum node,
+/// and the fields of each variant are under the variant name.
+#[derive(Component, Clone, Copy)]
+pub struct DiscriminantField {
+    variant_name: fn(EntityRef) -> &'static str,
+    on_switch:    fn(EntityRef) -> VariantSwitch,
+}
+
+impl DiscriminantField {
+    /// Creates the marker for a discriminant field of type `T`.
+    #[must_use]
+    pub fn of<T: EnumDiscriminant<Metadata = EnumDiscriminantMetadata<T>>>() -> Self {
+        Self {
+            variant_name: |entity| {
+                entity
+                    .get::<ScalarData<EnumDiscriminantWrapper<T>>>()
+                    .expect("discriminant field must store its discriminant")
+                    .0
+                    .0
+                    .name()
+            },
+            on_switch:    |entity| {
+                entity
+                    .get::<ScalarMetadata<T>>()
+                    .expect("discriminant field must store its metadata")
+                    .0
+                    .on_switch
+            },
+        }
+    }
+
+    /// Returns the name of the active variant stored in the discriminant field `entity`.
+    ///
+    /// # Panics
+    /// Panics if `entity` is not the entity this component is attached to.
+    #[must_use]
+    pub fn variant_name(&self, entity: EntityRef) -> &'static str { (self.variant_name)(entity) }
+
+    /// Returns the [`VariantSwitch`] behavior in the metadata of the discriminant field `entity`.
+    ///
+    /// # Panics
+    /// Panics if `entity` is not the entity this component is attached to.
+    #[must_use]
+    pub fn on_switch(&self, entity: EntityRef) -> VariantSwitch { (self.on_switch)(entity) }
 }
 
 /// [Metadata](ConfigField::Metadata) type for fields whose type is a [`Config`](crate::Config)-derived enum.
@@ -1,13 +1,25 @@
 // Contains implementations of `ConfigField` for various scalar types.
 //! Exports the [metadata](crate::ConfigField::Metadata) structs for foreign scalar types.
 
+use alloc::format;
 use alloc::string::String;
+use alloc::vec::Vec;
+use core::cmp::Ordering;
+use core::fmt;
+use core::marker::PhantomData;
+use core::ops::{Deref, DerefMut};
 use core::time::Duration;
 
 use bevy_ecs::entity::Entity;
+use bevy_ecs::name::Name;
+use bevy_ecs::world::World;
 
 use super::impl_scalar_config_field_ as impl_scalar_config_field;
-use crate::{ConfigField, ConfigNode, FieldGeneration, QueryLike, ScalarData};
+use crate::{
+    ConfigField, ConfigFieldFor, ConfigNode, ConfigWrites, DefaultFromMetadata, FieldGeneration,
+    IntoScalarData, QueryLike, ScalarConstraint, ScalarData, ScalarDefault, ScalarField,
+    ScalarMetadata, ScalarWriter, SpawnContext, manager,
+};
 
 macro_rules! impl_numeric_config_field {
     ($($ty:ty,)*) => {
@@ -18,11 +30,34 @@ macro_rules! impl_numeric_config_field {
                 |metadata: &NumericMetadata<$ty>| metadata.default,
                 'a => $ty,
                 |&value: &$ty| value,
+                constrain = clamp_numeric::<$ty>,
+                describe = describe_numeric::<$ty>,
             );
         )*
     };
 }
 
+/// Clamps a loaded numeric value into `metadata.min..=metadata.max`.
+///
+/// Values that are not comparable, such as NaN, are replaced with the minimum.
+fn clamp_numeric<T: PartialOrd + Copy>(value: &T, metadata: &NumericMetadata<T>) -> Option<T> {
+    match value.partial_cmp(&metadata.min) {
+        None | Some(Ordering::Less) => Some(metadata.min),
+        _ if *value > metadata.max => Some(metadata.max),
+        _ => None,
+    }
+}
+
+/// Describes the `min..=max` range of a numeric field, unless it spans the whole type.
+fn describe_numeric<T: Numeric + PartialEq + fmt::Debug>(
+    metadata: &NumericMetadata<T>,
+) -> Option<String> {
+    if metadata.min == T::MIN && metadata.max == T::MAX {
+        return None;
+    }
+    Some(format!("{:?} ..= {:?}", metadata.min, metadata.max))
+}
+
 impl_numeric_config_field!(
     i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize, f32, f64, Duration,
 );
@@ -31,29 +66,119 @@ impl_numeric_config_field!(
 #[derive(Clone)]
 pub struct NumericMetadata<T> {
     /// The default value.
-    pub default:   T,
+    pub default:     T,
     /// The minimum possible value.
-    pub min:       T,
+    pub min:         T,
     /// The maximum possible value.
-    pub max:       T,
+    pub max:         T,
     /// The precision of the value.
-    pub precision: Option<T>,
+    pub precision:   Option<T>,
     /// Whether to display the value as a slider in the UI.
-    pub slider:    bool,
+    pub slider:      bool,
+    /// If set, the `Smoothing` manager (with the `bevy_time` feature) transitions
+    /// the smoothed value of this field to a new value over this duration.
+    pub smoothing:   Option<Duration>,
+    /// Whether the `Diagnostics` manager (with the `bevy_diagnostic` feature) publishes the value
+    /// of this field as a diagnostic measurement.
+    pub diagnostic:  bool,
+    /// How the value is formatted for display.
+    pub format:      NumberFormat,
+    /// Whether the text editor accepts arithmetic expressions such as `1920/2`,
+    /// which are evaluated when entered.
+    pub expressions: bool,
 }
 
 impl<T: Numeric> Default for NumericMetadata<T> {
     fn default() -> Self {
         Self {
-            default:   T::ZERO,
-            min:       T::MIN,
-            max:       T::MAX,
-            precision: Some(T::ONE),
-            slider:    false,
+            default:     T::ZERO,
+            min:         T::MIN,
+            max:         T::MAX,
+            precision:   Some(T::ONE),
+            slider:      false,
+            smoothing:   None,
+            diagnostic:  false,
+            format:      NumberFormat::default(),
+            expressions: false,
         }
     }
 }
 
+/// Controls how a numeric value is formatted for display.
+///
+/// The default format is the shortest representation that round-trips,
+/// e.g. `1234567.5`.
+///
+/// ```
+/// use bevy_mod_config::impls::NumberFormat;
+///
+/// let format = NumberFormat {
+///     decimals: Some(2),
+///     thousands_separator: Some(','),
+///     ..NumberFormat::default()
+/// };
+/// assert_eq!(format.format(1234567.5), "1,234,567.50");
+///
+/// let format = NumberFormat { decimals: Some(1), scientific: true, ..NumberFormat::default() };
+/// assert_eq!(format.format(0.000_000_15), "1.5e-7");
+/// ```
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct NumberFormat {
+    /// The number of digits after the decimal point.
+    ///
+    /// Integers only have decimal places in scientific notation.
+    pub decimals:            Option<usize>,
+    /// Separates groups of three digits in the integer part, e.g. `Some(',')`.
+    pub thousands_separator: Option<char>,
+    /// Whether to format the value in scientific notation, e.g. `1.5e-7`.
+    pub scientific:          bool,
+}
+
+impl NumberFormat {
+    /// Formats `value` according to this format.
+    #[must_use]
+    pub fn format<T: fmt::Display + fmt::LowerExp>(&self, value: T) -> String {
+        let formatted = match (self.scientific, self.decimals) {
+            (true, Some(decimals)) => format!("{value:.decimals$e}"),
+            (true, None) => format!("{value:e}"),
+            (false, Some(decimals)) => format!("{value:.decimals$}"),
+            (false, None) => format!("{value}"),
+        };
+        match self.thousands_separator {
+            Some(separator) => group_thousands(&formatted, separator),
+            None => formatted,
+        }
+    }
+
+    /// Removes the thousands separators from a string formatted with this format,
+    /// so that it can be parsed with [`str::parse`].
+    #[must_use]
+    pub fn strip_separators(&self, s: &str) -> String {
+        match self.thousands_separator {
+            Some(separator) => s.chars().filter(|&ch| ch != separator).collect(),
+            None => s.into(),
+        }
+    }
+}
+
+/// Inserts `separator` between groups of three digits in the leading integer part of `s`.
+fn group_thousands(s: &str, separator: char) -> String {
+    let sign_len = s.len() - s.trim_start_matches(['-', '+']).len();
+    let (sign, rest) = s.split_at(sign_len);
+    let digits_len = rest.len() - rest.trim_start_matches(|ch: char| ch.is_ascii_digit()).len();
+    let (digits, rest) = rest.split_at(digits_len);
+
+    let mut grouped = String::from(sign);
+    for (index, digit) in digits.chars().enumerate() {
+        if index > 0 && (digits_len - index) % 3 == 0 {
+            grouped.push(separator);
+        }
+        grouped.push(digit);
+    }
+    grouped.push_str(rest);
+    grouped
+}
+
 trait Numeric: Sized {
     const MIN: Self;
     const MAX: Self;
@@ -103,6 +228,14 @@ impl_scalar_config_field!(
     |metadata: &StringMetadata| metadata.default.into(),
     'a => &'a str,
     String::as_str,
+    constrain = |value: &String, metadata: &StringMetadata| {
+        let max_length = metadata.max_length?;
+        let (end, _) = value.char_indices().nth(max_length)?;
+        Some(value[..end].into())
+    },
+    describe = |metadata: &StringMetadata| {
+        metadata.max_length.map(|max_length| format!("at most {max_length} characters"))
+    },
 );
 
 /// Metadata for [`String`] fields.
@@ -155,21 +288,473 @@ pub struct ColorMetadata {
     pub alpha_additive: bool,
 }
 
-/// A [`ConfigField`] wrapper implementation with no metadata.
+impl<T> ConfigField for Vec<T>
+where
+    T: DefaultFromMetadata + Clone + Send + Sync,
+{
+    type SpawnHandle = Entity;
+    type Reader<'a> = &'a [T];
+    type Owned = Vec<T>;
+    type ReadQueryData = Option<&'static ScalarData<Self>>;
+    type Metadata = VecMetadata<T>;
+    type Changed = FieldGeneration;
+    type ChangedQueryData = ();
+    type Writer<'a> = ScalarWriter<'a, Self>;
+
+    fn read_world<'a, 's>(
+        query: impl QueryLike<
+            Item = <<Self::ReadQueryData as bevy_ecs::query::QueryData>::ReadOnly as bevy_ecs::query::QueryData>::Item<'a, 's>,
+        >,
+        &spawn_handle: &Entity,
+    ) -> Self::Reader<'a> {
+        let data = query.get(spawn_handle).expect(
+            "entity managed by config field must remain active as long as the config handle is \
+             used",
+        );
+        &data.as_ref().expect("scalar data component must remain valid with Self type").0
+    }
+
+    fn to_owned(reader: &[T]) -> Vec<T> { reader.to_vec() }
+
+    fn changed<'a, 's>(
+        query: impl QueryLike<
+            Item = (
+                &'a ConfigNode,
+                <<Self::ChangedQueryData as bevy_ecs::query::QueryData>::ReadOnly as bevy_ecs::query::QueryData>::Item<'a, 's>,
+            ),
+        >,
+        &spawn_handle: &Entity,
+    ) -> Self::Changed {
+        let entity = query.get(spawn_handle).expect(
+            "entity managed by config field must remain active as long as the config handle is \
+             used",
+        );
+        entity.0.generation
+    }
+
+    fn write_world<'a>(writes: &'a ConfigWrites, &spawn_handle: &Entity) -> Self::Writer<'a> {
+        ScalarWriter::new(spawn_handle, writes)
+    }
+}
+
+impl<T> DefaultFromMetadata for Vec<T>
+where
+    T: DefaultFromMetadata + Clone + Send + Sync,
+{
+    fn default_from_metadata(metadata: &VecMetadata<T>) -> Self { metadata.default.clone() }
+
+    fn constrain(&self, metadata: &VecMetadata<T>) -> Option<Self> {
+        let max_length = metadata.max_length.unwrap_or(usize::MAX);
+        let elements = &self[..self.len().min(max_length)];
+        let constrained: Vec<_> =
+            elements.iter().map(|element| element.constrain(&metadata.element)).collect();
+        if elements.len() == self.len() && constrained.iter().all(Option::is_none) {
+            return None;
+        }
+        Some(
+            elements
+                .iter()
+                .zip(constrained)
+                .map(|(element, constrained)| constrained.unwrap_or_else(|| element.clone()))
+                .collect(),
+        )
+    }
+
+    fn describe_constraints(metadata: &VecMetadata<T>) -> Option<String> {
+        let length = metadata.max_length.map(|max_length| format!("at most {max_length} elements"));
+        let element =
+            T::describe_constraints(&metadata.element).map(|element| format!("elements {element}"));
+        match (length, element) {
+            (Some(length), Some(element)) => Some(format!("{length}, {element}")),
+            (length, element) => length.or(element),
+        }
+    }
+}
+
+impl<T, M> ConfigFieldFor<M> for Vec<T>
+where
+    T: DefaultFromMetadata + Clone + Send + Sync,
+    M: manager::Supports<Vec<T>>,
+{
+    fn spawn_world(world: &mut World, ctx: SpawnContext, metadata: VecMetadata<T>) -> Entity {
+        let manager_comps = world.resource_mut::<manager::Instance<M>>().new_entity::<Vec<T>>();
+        let mut entity = world.spawn((
+            Name::new("Vec config field"),
+            ScalarData::<Self>(metadata.default.clone()),
+            ScalarMetadata::<Self>(metadata),
+            ScalarConstraint::of::<Self>(),
+            ScalarDefault::of::<Self>(),
+            ScalarField,
+            manager_comps,
+        ));
+        crate::init_config_node(&mut entity, ctx);
+        entity.id()
+    }
+}
+
+/// Metadata for [`Vec`] fields.
+///
+/// The whole list is stored as a single scalar field,
+/// so managers see it as a [`ScalarData<Vec<T>>`].
+pub struct VecMetadata<T: ConfigField> {
+    /// The default list.
+    pub default:    Vec<T>,
+    /// The metadata applied to each element,
+    /// which also provides the default value of newly added elements.
+    pub element:    T::Metadata,
+    /// The maximum number of elements.
+    pub max_length: Option<usize>,
+}
+
+impl<T: ConfigField> Default for VecMetadata<T>
+where
+    T::Metadata: Default,
+{
+    fn default() -> Self {
+        Self { default: Vec::new(), element: T::Metadata::default(), max_length: None }
+    }
+}
+
+impl<T: ConfigField + Clone> Clone for VecMetadata<T>
+where
+    T::Metadata: Clone,
+{
+    fn clone(&self) -> Self {
+        Self {
+            default:    self.default.clone(),
+            element:    self.element.clone(),
+            max_length: self.max_length,
+        }
+    }
+}
+
+#[cfg(feature = "url")]
+impl_scalar_config_field!(
+    url::Url,
+    UrlMetadata,
+    |metadata: &UrlMetadata| url::Url::parse(metadata.default)
+        .expect("default value of URL field must be a valid URL"),
+    'a => &'a url::Url,
+    core::convert::identity,
+    to_owned = url::Url::clone,
+);
+
+/// Metadata for [`url::Url`] fields.
+///
+/// URLs are persisted as strings.
+#[cfg(feature = "url")]
+#[derive(Clone)]
+pub struct UrlMetadata {
+    /// The default value, which must be a valid URL.
+    pub default: &'static str,
+    /// The allowed URL schemes, e.g. `&["https"]`.
+    ///
+    /// All schemes are allowed if empty.
+    pub schemes: &'static [&'static str],
+}
+
+#[cfg(feature = "url")]
+impl Default for UrlMetadata {
+    fn default() -> Self { Self { default: "about:blank", schemes: &[] } }
+}
+
+#[cfg(feature = "url")]
+impl UrlMetadata {
+    /// Parses `input` as a URL and checks that its scheme is allowed.
+    ///
+    /// # Errors
+    /// Returns a human-readable message if `input` is not an allowed URL.
+    pub fn parse(&self, input: &str) -> Result<url::Url, String> {
+        let url = url::Url::parse(input).map_err(|err| alloc::format!("Not a valid URL: {err}"))?;
+        if !self.schemes.is_empty() && !self.schemes.contains(&url.scheme()) {
+            return Err(alloc::format!("Scheme must be one of: {}", self.schemes.join(", ")));
+        }
+        Ok(url)
+    }
+}
+
+#[cfg(feature = "chrono")]
+macro_rules! impl_chrono_config_field {
+    ($($ty:ty,)*) => {
+        $(
+            impl_scalar_config_field!(
+                $ty,
+                DateTimeMetadata<$ty>,
+                |metadata: &DateTimeMetadata<$ty>| metadata.default,
+                'a => $ty,
+                |&value: &$ty| value,
+            );
+        )*
+    };
+}
+
+#[cfg(feature = "chrono")]
+impl_chrono_config_field!(chrono::NaiveTime, chrono::NaiveDate, chrono::DateTime<chrono::Utc>,);
+
+/// Metadata for date and time fields.
+///
+/// Dates and times are persisted in their RFC 3339 representation,
+/// e.g. `"2024-01-31"` for a date, `"06:30:00"` for a time of day
+/// and `"2024-01-31T06:30:00Z"` for a timestamp.
+#[cfg(feature = "chrono")]
+#[derive(Default, Clone)]
+pub struct DateTimeMetadata<T> {
+    /// The default value.
+    pub default: T,
+}
+
+/// A scalar [`ConfigField`] wrapper that managers may handle partially.
+///
+/// A field type can normally only be used with a manager tuple
+/// if every manager in the tuple supports it.
+/// Wrapping the type in `Lossy` lets managers without dedicated support fall back gracefully,
+/// e.g. a type that is serializable but not [editable](crate::manager::egui::Editable)
+/// is still persisted by [`Serde`](crate::manager::Serde),
+/// but has no widget in the [`Egui`](crate::manager::Egui) editor.
+///
+/// `T` must be a scalar type.
+/// The field reads as `&T` and uses the same metadata as `T`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Lossy<T>(pub T);
+
+impl<T> Deref for Lossy<T> {
+    type Target = T;
+
+    fn deref(&self) -> &T { &self.0 }
+}
+
+impl<T> DerefMut for Lossy<T> {
+    fn deref_mut(&mut self) -> &mut T { &mut self.0 }
+}
+
+#[cfg(feature = "serde")]
+impl<T: serde::Serialize> serde::Serialize for Lossy<T> {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        self.0.serialize(serializer)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de, T: serde::Deserialize<'de>> serde::Deserialize<'de> for Lossy<T> {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        T::deserialize(deserializer).map(Lossy)
+    }
+}
+
+impl<T> ConfigField for Lossy<T>
+where
+    T: DefaultFromMetadata + Clone + Send + Sync,
+{
+    type SpawnHandle = Entity;
+    type Reader<'a> = &'a T;
+    type Owned = T;
+    type ReadQueryData = Option<&'static ScalarData<Self>>;
+    type Metadata = T::Metadata;
+    type Changed = FieldGeneration;
+    type ChangedQueryData = ();
+    type Writer<'a> = ScalarWriter<'a, Self>;
+
+    fn read_world<'a, 's>(
+        query: impl QueryLike<
+            Item = <<Self::ReadQueryData as bevy_ecs::query::QueryData>::ReadOnly as bevy_ecs::query::QueryData>::Item<'a, 's>,
+        >,
+        &spawn_handle: &Entity,
+    ) -> Self::Reader<'a> {
+        let data = query.get(spawn_handle).expect(
+            "entity managed by config field must remain active as long as the config handle is \
+             used",
+        );
+        &data.as_ref().expect("scalar data component must remain valid with Self type").0.0
+    }
+
+    fn to_owned(reader: &T) -> T { reader.clone() }
+
+    fn changed<'a, 's>(
+        query: impl QueryLike<
+            Item = (
+                &'a ConfigNode,
+                <<Self::ChangedQueryData as bevy_ecs::query::QueryData>::ReadOnly as bevy_ecs::query::QueryData>::Item<'a, 's>,
+            ),
+        >,
+        &spawn_handle: &Entity,
+    ) -> Self::Changed {
+        let entity = query.get(spawn_handle).expect(
+            "entity managed by config field must remain active as long as the config handle is \
+             used",
+        );
+        entity.0.generation
+    }
+
+    fn write_world<'a>(writes: &'a ConfigWrites, &spawn_handle: &Entity) -> Self::Writer<'a> {
+        ScalarWriter::new(spawn_handle, writes)
+    }
+}
+
+impl<T> DefaultFromMetadata for Lossy<T>
+where
+    T: DefaultFromMetadata + Clone + Send + Sync,
+{
+    fn default_from_metadata(metadata: &T::Metadata) -> Self {
+        Lossy(T::default_from_metadata(metadata))
+    }
+
+    fn constrain(&self, metadata: &T::Metadata) -> Option<Self> {
+        self.0.constrain(metadata).map(Lossy)
+    }
+
+    fn describe_constraints(metadata: &T::Metadata) -> Option<String> {
+        T::describe_constraints(metadata)
+    }
+}
+
+impl<T, M> ConfigFieldFor<M> for Lossy<T>
+where
+    T: DefaultFromMetadata + Clone + Send + Sync,
+    M: manager::Supports<Lossy<T>>,
+{
+    fn spawn_world(world: &mut World, ctx: SpawnContext, metadata: T::Metadata) -> Entity {
+        let manager_comps = world.resource_mut::<manager::Instance<M>>().new_entity::<Lossy<T>>();
+        let mut entity = world.spawn((
+            Name::new("Scalar config field"),
+            ScalarData::<Self>(Self::default_from_metadata(&metadata)),
+            ScalarMetadata::<Self>(metadata),
+            ScalarConstraint::of::<Self>(),
+            ScalarDefault::of::<Self>(),
+            ScalarField,
+            manager_comps,
+        ));
+        crate::init_config_node(&mut entity, ctx);
+        entity.id()
+    }
+}
+
+/// A [`ConfigField`] wrapper implementation with minimal metadata.
 ///
 /// Used to implement on foreign types that do not implement [`ConfigField`] directly.
-pub struct BareField<T>(pub T);
+/// The default value is specified with [`BareMetadata`].
+///
+/// With the `serde` feature, `BareField<T>` is serialized as `T` if `T` is serializable,
+/// so the field is persisted by [`Serde`](crate::manager::Serde) managers.
+/// Wrap the field in [`Lossy`] to use it with managers that cannot handle `T`,
+/// e.g. <code>[Lossy]&lt;BareField&lt;T&gt;&gt;</code> is persisted by a `Serde` manager
+/// but has no widget in the [`Egui`](crate::manager::Egui) editor.
+///
+/// The field reads as `&T` by default.
+/// Specify another [`BareReader`] as `R` to customize the reader,
+/// e.g. [`ByValue`] to read [`Copy`] types by value.
+///
+/// ```
+/// # use bevy_mod_config::{BareField, Config};
+/// # use bevy_mod_config::impls::ByValue;
+/// #[derive(Clone)]
+/// struct Uuid(u128);
+///
+/// #[derive(Config)]
+/// struct Session {
+///     #[config(default = Some((0, 0)))]
+///     window_pos: BareField<(i32, i32), ByValue>,
+///     #[config(default_with = Some(|| Uuid(42)))]
+///     id:         BareField<Uuid>,
+/// }
+/// ```
+pub struct BareField<T, R = ByRef>(pub T, pub PhantomData<R>);
+
+impl<T, R> BareField<T, R> {
+    /// Wraps a value.
+    pub fn new(value: T) -> Self { Self(value, PhantomData) }
+}
+
+impl<T, R> From<T> for BareField<T, R> {
+    fn from(value: T) -> Self { Self::new(value) }
+}
+
+impl<T, R> IntoScalarData<BareField<T, R>> for T {
+    fn into_scalar_data(self) -> BareField<T, R> { BareField::new(self) }
+}
+
+impl<T: fmt::Debug, R> fmt::Debug for BareField<T, R> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("BareField").field(&self.0).finish()
+    }
+}
+
+impl<T: Clone, R> Clone for BareField<T, R> {
+    fn clone(&self) -> Self { Self::new(self.0.clone()) }
+}
+
+impl<T: PartialEq, R> PartialEq for BareField<T, R> {
+    fn eq(&self, other: &Self) -> bool { self.0 == other.0 }
+}
 
-impl<T> ConfigField for BareField<T>
+/// Maps the value of a [`BareField`] to its [reader](ConfigField::Reader).
+pub trait BareReader<T>: Send + Sync + 'static {
+    /// The type returned when reading the field.
+    type Reader<'a>: Copy
+    where
+        T: 'a;
+
+    /// Maps the stored value to the reader.
+    fn map(value: &T) -> Self::Reader<'_>;
+
+    /// Converts the reader back to an owned value.
+    fn to_owned(reader: Self::Reader<'_>) -> T;
+}
+
+/// The default [`BareReader`], reading the field as `&T`.
+pub struct ByRef;
+
+impl<T: Clone> BareReader<T> for ByRef {
+    type Reader<'a>
+        = &'a T
+    where
+        T: 'a;
+
+    fn map(value: &T) -> &T { value }
+
+    fn to_owned(reader: &T) -> T { reader.clone() }
+}
+
+/// A [`BareReader`] reading [`Copy`] fields by value.
+pub struct ByValue;
+
+impl<T: Copy> BareReader<T> for ByValue {
+    type Reader<'a>
+        = T
+    where
+        T: 'a;
+
+    fn map(value: &T) -> T { *value }
+
+    fn to_owned(reader: T) -> T { reader }
+}
+
+#[cfg(feature = "serde")]
+impl<T: serde::Serialize, R> serde::Serialize for BareField<T, R> {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        self.0.serialize(serializer)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de, T: serde::Deserialize<'de>, R> serde::Deserialize<'de> for BareField<T, R> {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        T::deserialize(deserializer).map(BareField::new)
+    }
+}
+
+impl<T, R> ConfigField for BareField<T, R>
 where
     T: Clone + Send + Sync + 'static,
+    R: BareReader<T>,
 {
     type SpawnHandle = Entity;
-    type Reader<'a> = &'a T;
+    type Reader<'a> = R::Reader<'a>;
+    type Owned = T;
     type ReadQueryData = Option<&'static ScalarData<Self>>;
-    type Metadata = BareMetadata;
+    type Metadata = BareMetadata<T>;
     type Changed = FieldGeneration;
     type ChangedQueryData = ();
+    type Writer<'a> = ScalarWriter<'a, Self>;
 
     // Currently cannot inline the GAT due to https://github.com/rust-lang/rust/issues/147273
     // causing a spurious E0195.
@@ -183,9 +768,11 @@ where
             "entity managed by config field must remain active as long as the config handle is \
              used",
         );
-        &data.as_ref().expect("scalar data component must remain valid with Self type").0.0
+        R::map(&data.as_ref().expect("scalar data component must remain valid with Self type").0.0)
     }
 
+    fn to_owned(reader: R::Reader<'_>) -> T { R::to_owned(reader) }
+
     fn changed<'a, 's>(
         query: impl QueryLike<
             Item = (
@@ -201,8 +788,69 @@ where
         );
         entity.0.generation
     }
+
+    fn write_world<'a>(writes: &'a ConfigWrites, &spawn_handle: &Entity) -> Self::Writer<'a> {
+        ScalarWriter::new(spawn_handle, writes)
+    }
 }
 
-/// Dummy metadata type for [`BareField`].
-#[derive(Default, Clone)]
-pub struct BareMetadata {}
+impl<T, R> DefaultFromMetadata for BareField<T, R>
+where
+    T: Clone + Send + Sync + 'static,
+    R: BareReader<T>,
+{
+    fn default_from_metadata(metadata: &BareMetadata<T>) -> Self {
+        let value = metadata.default.clone().or_else(|| metadata.default_with.map(|f| f()));
+        match value {
+            Some(value) => BareField::new(value),
+            None => panic!(
+                "BareField<{}> requires a default value in its metadata",
+                core::any::type_name::<T>()
+            ),
+        }
+    }
+}
+
+impl<T, R, M> ConfigFieldFor<M> for BareField<T, R>
+where
+    T: Clone + Send + Sync + 'static,
+    R: BareReader<T>,
+    M: manager::Supports<BareField<T, R>>,
+{
+    fn spawn_world(world: &mut World, ctx: SpawnContext, metadata: BareMetadata<T>) -> Entity {
+        let manager_comps = world.resource_mut::<manager::Instance<M>>().new_entity::<Self>();
+        let mut entity = world.spawn((
+            Name::new("Scalar config field"),
+            ScalarData::<Self>(Self::default_from_metadata(&metadata)),
+            ScalarMetadata::<Self>(metadata),
+            ScalarConstraint::of::<Self>(),
+            ScalarDefault::of::<Self>(),
+            ScalarField,
+            manager_comps,
+        ));
+        crate::init_config_node(&mut entity, ctx);
+        entity.id()
+    }
+}
+
+/// Metadata type for [`BareField`].
+///
+/// Since `T` has no known default value,
+/// either [`default`](Self::default) or [`default_with`](Self::default_with) must be set.
+/// Spawning a field without a default value panics.
+pub struct BareMetadata<T> {
+    /// The default value.
+    pub default:      Option<T>,
+    /// Computes the default value if [`default`](Self::default) is `None`.
+    pub default_with: Option<fn() -> T>,
+}
+
+impl<T> Default for BareMetadata<T> {
+    fn default() -> Self { Self { default: None, default_with: None } }
+}
+
+impl<T: Clone> Clone for BareMetadata<T> {
+    fn clone(&self) -> Self {
+        Self { default: self.default.clone(), default_with: self.default_with }
+    }
+}
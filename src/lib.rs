@@ -140,41 +140,73 @@ extern crate alloc;
 
 use alloc::string::String;
 use alloc::vec::Vec;
+use core::fmt::Debug;
+use core::hash::Hash;
 use core::num::NonZeroU64;
 
 use bevy_ecs::component::Component;
 use bevy_ecs::entity::Entity;
-use bevy_ecs::query::QueryData;
-use bevy_ecs::world::{EntityRef, EntityWorldMut, World};
+use bevy_ecs::query::{QueryData, ReadOnlyQueryData};
+use bevy_ecs::world::{EntityMut, EntityRef, EntityWorldMut, World};
 
 pub mod impls;
-pub use impls::BareField;
+pub use impls::{BareField, Lossy};
 mod query;
 pub use query::QueryLike;
 mod enum_;
 pub use enum_::{
-    EnumDiscriminant, EnumDiscriminantMetadata, EnumDiscriminantWrapper, EnumFieldMetadata,
+    DiscriminantField, EnumDiscriminant, EnumDiscriminantMetadata, EnumDiscriminantWrapper,
+    EnumFieldMetadata, VariantSwitch,
 };
 pub mod manager;
 pub use manager::Manager;
 #[doc(hidden)]
 pub mod __import;
+pub mod types;
 
 mod macro_doc;
 pub use macro_doc::Config;
 
+pub mod mirror;
+
+pub mod snapshot;
+pub use snapshot::ConfigSnapshot;
+
+pub mod validate;
+
+pub mod temporary;
+
+pub mod reset;
+
+mod newtype;
+pub use newtype::NewtypeMetadata;
+
+#[cfg(feature = "bevy_state")]
+pub mod state;
+
 mod app;
-pub use app::{AppExt, ReadConfig, ReadConfigChange};
+pub use app::{
+    AppExt, CachedReadConfig, ChangeBatch, ChangedFields, CommandsExt, EntityConfig,
+    InsertEntityConfig, KeyCollision, ReadConfig, ReadConfigChange, ReadEntityConfig, WorldExt,
+    apply_batched_changes,
+};
+
+mod write;
+pub use write::{ConfigWrites, IntoScalarData, ScalarWriter};
 
 mod tree;
 pub use tree::{
-    ChildNodeList, ChildNodeOf, ConditionalRelevance, ConfigNode, RootNode, ScalarField,
+    Advanced, ChildNodeList, ChildNodeOf, ConditionalRelevance, ConfigChanged, ConfigCommitted,
+    ConfigNode, ConfigTree, Deprecated, Description, MigrateFn, MigrationError, OutOfState,
+    Permission, RelevancePath, RootNode, RootOrder, ScalarField, Section, SerdeRename, SkipSerde,
+    StateRelevance, TreeRevision, resolve_relevance_paths,
 };
 
 /// Tracks the number of changes to a config field.
 ///
-/// After each change, the new generation is greater than the previous one.
-#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+/// After each change, the new generation is greater than the previous one,
+/// unless it wraps around under [`GenerationOverflow::Wrap`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub struct FieldGeneration(NonZeroU64);
 
 impl Default for FieldGeneration {
@@ -182,14 +214,60 @@ impl Default for FieldGeneration {
 }
 
 impl FieldGeneration {
+    /// Creates a generation with the given value.
+    ///
+    /// This is mostly useful for tests that need to start at a specific generation.
+    #[must_use]
+    pub const fn new(generation: NonZeroU64) -> Self { FieldGeneration(generation) }
+
     /// Increments the generation by one.
     ///
     /// # Panics
     /// Panics if the generation overflows.
     #[must_use]
-    pub fn next(self) -> Self {
-        FieldGeneration(self.0.checked_add(1).expect("field generation overflow"))
+    pub fn next(self) -> Self { self.next_with(GenerationOverflow::Panic) }
+
+    /// Increments the generation by one, handling overflow according to `overflow`.
+    ///
+    /// # Panics
+    /// Panics if the generation overflows under [`GenerationOverflow::Panic`].
+    #[must_use]
+    pub fn next_with(self, overflow: GenerationOverflow) -> Self {
+        match (self.0.checked_add(1), overflow) {
+            (Some(next), _) => FieldGeneration(next),
+            (None, GenerationOverflow::Wrap) => FieldGeneration::default(),
+            (None, GenerationOverflow::Panic) => panic!("field generation overflow"),
+        }
     }
+
+    /// Returns the number of increments from `earlier` to this generation,
+    /// assuming that it has wrapped around at most once.
+    #[must_use]
+    pub fn changes_since(self, earlier: Self) -> u64 {
+        if self >= earlier {
+            self.0.get() - earlier.0.get()
+        } else {
+            (u64::MAX - earlier.0.get()).wrapping_add(self.0.get())
+        }
+    }
+}
+
+/// The behavior of [`FieldGeneration::next`] when the generation reaches [`u64::MAX`].
+///
+/// [`ConfigNode::bump`] uses the policy set for the world
+/// with [`WorldExt::set_generation_overflow`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum GenerationOverflow {
+    /// Panics, since an overflow usually indicates a feedback loop of writes.
+    #[default]
+    Panic,
+    /// Wraps around to the initial generation.
+    ///
+    /// Change detection only compares generations for equality,
+    /// so a change is only missed if a reader has not observed the field
+    /// for exactly <code>2<sup>64</sup> - 1</code> changes.
+    /// However, [`Ord`] comparisons of generations across the wraparound are incorrect.
+    Wrap,
 }
 
 /// Context information of the config field from its referrers.
@@ -198,11 +276,31 @@ pub struct SpawnContext {
     /// The hierarchical path from the root config field.
     ///
     /// Uniquely identifies the config field statically.
-    pub path:       Vec<String>,
+    pub path:         Vec<String>,
     /// The parent entity of the config field, if any.
-    pub parent:     Option<Entity>,
+    pub parent:       Option<Entity>,
     /// The [`ConditionalRelevance`] dependency of the config field, if any.
-    pub dependency: Option<ConditionalRelevance>,
+    pub dependency:   Option<ConditionalRelevance>,
+    /// The human-readable [`Description`] of the config field, if any.
+    pub description:  Option<&'static str>,
+    /// Whether the config field is [`Advanced`].
+    pub advanced:     bool,
+    /// The [`Deprecated`] marker of the config field, if any.
+    pub deprecated:   Option<Deprecated>,
+    /// The [`Permission`] required to edit the config field, if specified.
+    pub permission:   Option<Permission>,
+    /// The [`RelevancePath`] dependency of the config field, if any.
+    pub relevance:    Option<RelevancePath>,
+    /// The [`StateRelevance`] of the config field, if any.
+    pub in_state:     Option<StateRelevance>,
+    /// Whether the config field is excluded from persistence with [`SkipSerde`].
+    ///
+    /// Unlike other attributes, this is inherited by the descendants of the field.
+    pub skip_serde:   bool,
+    /// The [`SerdeRename`] of the config field, if any.
+    pub serde_rename: Option<&'static str>,
+    /// The revision counter shared by all nodes in the config tree.
+    pub revision:     TreeRevision,
 }
 
 impl SpawnContext {
@@ -222,6 +320,15 @@ impl SpawnContext {
                 .collect(),
             parent,
             dependency: None,
+            description: None,
+            advanced: false,
+            deprecated: None,
+            permission: None,
+            relevance: None,
+            in_state: None,
+            skip_serde: self.skip_serde,
+            serde_rename: None,
+            revision: self.revision.clone(),
         }
     }
 
@@ -235,6 +342,69 @@ impl SpawnContext {
         self.dependency = Some(ConditionalRelevance { dependency, is_entity_relevant });
         self
     }
+
+    /// Excludes the config field and its descendants from persistence with [`SkipSerde`].
+    #[must_use]
+    pub fn with_skip_serde(mut self) -> Self {
+        self.skip_serde = true;
+        self
+    }
+
+    /// Replaces the persistence key of the config field with [`SerdeRename`].
+    #[must_use]
+    pub fn with_serde_rename(mut self, rename: &'static str) -> Self {
+        self.serde_rename = Some(rename);
+        self
+    }
+
+    /// Adds a [`Description`] to this context.
+    #[must_use]
+    pub fn with_description(mut self, description: &'static str) -> Self {
+        self.description = Some(description);
+        self
+    }
+
+    /// Marks the config field as [`Advanced`] if `advanced` is true.
+    #[must_use]
+    pub fn with_advanced(mut self, advanced: bool) -> Self {
+        self.advanced = advanced;
+        self
+    }
+
+    /// Sets the [`Permission`] required to edit the config field.
+    #[must_use]
+    pub fn with_permission(mut self, permission: Permission) -> Self {
+        self.permission = Some(permission);
+        self
+    }
+
+    /// Marks the config field as [`Deprecated`].
+    #[must_use]
+    pub fn with_deprecated(mut self, deprecated: Deprecated) -> Self {
+        self.deprecated = Some(deprecated);
+        self
+    }
+
+    /// Adds a [`RelevancePath`] dependency on the field at the absolute `path` to this context.
+    #[must_use]
+    pub fn with_relevance_path(
+        mut self,
+        path: impl IntoIterator<Item = impl Into<String>>,
+        is_entity_relevant: fn(EntityRef) -> bool,
+    ) -> Self {
+        self.relevance = Some(RelevancePath {
+            path: path.into_iter().map(Into::into).collect(),
+            is_entity_relevant,
+        });
+        self
+    }
+
+    /// Makes the config field only relevant while `is_relevant` returns true.
+    #[must_use]
+    pub fn with_state_relevance(mut self, is_relevant: fn(&World) -> bool) -> Self {
+        self.in_state = Some(StateRelevance { is_relevant });
+        self
+    }
 }
 
 /// The spawn handle of a config node.
@@ -250,16 +420,28 @@ impl SpawnHandle for Entity {
 /// Field types that can be used in a [`Config`] struct/enum.
 pub trait ConfigField: 'static {
     /// Remembers where the config data are stored in the world after spawning.
-    type SpawnHandle: SpawnHandle + 'static + Send + Sync;
+    ///
+    /// This is cloned when a copy of the handle is retained by the framework,
+    /// e.g. for [`validate`] registrations.
+    type SpawnHandle: SpawnHandle + Clone + 'static + Send + Sync;
 
     /// The type returned when reading the config data from the world.
     ///
     /// `'a` is the lifetime of the receiver in [`ReadConfig::read`].
     type Reader<'a>: Copy;
+    /// The owned counterpart of [`Reader`](ConfigField::Reader),
+    /// e.g. `String` for a `&str` reader.
+    ///
+    /// Unlike the reader, this does not borrow the world,
+    /// so it can be stored in resources or sent through events.
+    type Owned: Clone + Send + Sync + 'static;
     /// The minimal components required to read the typed config fields under this field.
     ///
     /// For scalar fields, this is always `Option<&ScalarData<Self>>`.
-    type ReadQueryData: QueryData;
+    ///
+    /// This must be read-only so that [`ReadConfig`] can be used in read-only contexts,
+    /// such as extraction into sub-apps.
+    type ReadQueryData: ReadOnlyQueryData;
 
     /// Type-specific metadata specified by the referrer.
     ///
@@ -274,13 +456,25 @@ pub trait ConfigField: 'static {
     /// - It can be [cloned](Clone) at a cheaper cost (than the original data, on average).
     /// - It can be compared for [equality](Eq) with the previous value
     ///   to determine whether the config data has changed.
-    type Changed: Clone + Eq + 'static + Send + Sync;
+    /// - It can be [debug-printed](Debug), e.g. to log changes.
+    ///
+    /// The `Changed` types of built-in scalars also implement [`Hash`].
+    /// Derived types implement it when opted in with `#[config(changed_derive(Hash))]`.
+    type Changed: Clone + Eq + Debug + 'static + Send + Sync;
     /// The minimal components required to compute whether the config data has changed.
     ///
     /// This is `()` for most types,
     /// but may contain enum discriminants for enum fields
     /// to determine which variant should be compared.
-    type ChangedQueryData: QueryData;
+    type ChangedQueryData: ReadOnlyQueryData;
+
+    /// The type used to modify the config data in [`WorldExt::update_config`].
+    ///
+    /// For scalar fields, this is always [`ScalarWriter<'a, Self>`],
+    /// or a `ScalarWriter` of the [`ScalarData`] type stored for the field.
+    ///
+    /// `'a` is the lifetime of the [`ConfigWrites`] collecting the writes.
+    type Writer<'a>;
 
     /// Reads config data for user consumption from a query of config data entities.
     fn read_world<'a, 's>(
@@ -290,6 +484,9 @@ pub trait ConfigField: 'static {
         spawn_handle: &Self::SpawnHandle,
     ) -> Self::Reader<'a>;
 
+    /// Converts a reader into its [owned](ConfigField::Owned) counterpart.
+    fn to_owned(reader: Self::Reader<'_>) -> Self::Owned;
+
     /// Computes an [equivalence class](Eq) that represents whether the config data has changed.
     ///
     /// If the config data has been changed, the result returned by this function
@@ -303,6 +500,12 @@ pub trait ConfigField: 'static {
         >,
         spawn_handle: &Self::SpawnHandle,
     ) -> Self::Changed;
+
+    /// Creates a writer that collects writes to the config data into `writes`.
+    fn write_world<'a>(
+        writes: &'a ConfigWrites,
+        spawn_handle: &Self::SpawnHandle,
+    ) -> Self::Writer<'a>;
 }
 
 /// Determines how a [`ConfigField`] implementor interacts with a [`Manager`] type.
@@ -314,6 +517,12 @@ pub trait ConfigField: 'static {
 /// ```text
 /// impl<M: manager::Supports<U>> ConfigField<M> for T { ... }
 /// ```
+#[diagnostic::on_unimplemented(
+    message = "`{Self}` cannot be used as a config field with manager `{M}`",
+    label = "not supported by manager `{M}`",
+    note = "every scalar field type must be supported by every manager in `{M}`",
+    note = "some managers, such as `Egui`, skip fields wrapped in `bevy_mod_config::Lossy`"
+)]
 pub trait ConfigFieldFor<M>: ConfigField {
     /// Spawns entities in the world to store config data.
     ///
@@ -339,6 +548,136 @@ pub struct ScalarData<T>(pub T);
 #[derive(Component)]
 pub struct ScalarMetadata<T: ConfigField>(pub T::Metadata);
 
+/// Scalar config field types that can produce a default value from their metadata.
+///
+/// Implemented by [`impl_scalar_config_field`] for every scalar type.
+/// Composite fields such as `Vec<T>` use this to create new elements at runtime.
+pub trait DefaultFromMetadata: ConfigField {
+    /// Returns the default value described by `metadata`.
+    fn default_from_metadata(metadata: &Self::Metadata) -> Self;
+
+    /// Returns the value adjusted to satisfy the constraints in `metadata`,
+    /// e.g. clamped into the `min..=max` range of a numeric field,
+    /// or `None` if the value already satisfies them.
+    ///
+    /// Managers that load values from external sources apply this through [`ScalarConstraint`].
+    /// The default implementation accepts all values.
+    fn constrain(&self, _metadata: &Self::Metadata) -> Option<Self>
+    where
+        Self: Sized,
+    {
+        None
+    }
+
+    /// Describes the constraints enforced by [`constrain`](Self::constrain) for documentation,
+    /// e.g. `0 ..= 100` for a numeric field,
+    /// or `None` if the field accepts all values.
+    ///
+    /// The default implementation returns `None`.
+    fn describe_constraints(_metadata: &Self::Metadata) -> Option<String> { None }
+}
+
+/// Field types that can be stored inline in a
+/// [`#[config(compact)]`](crate::Config#configcompact) struct.
+///
+/// Implemented by [`impl_scalar_config_field`] for the types it is invoked on
+/// and by [`#[derive(Config)]`](crate::Config) for compact structs,
+/// which store their own value as the [`Owned`](ConfigField::Owned) type.
+pub trait CompactField: DefaultFromMetadata + ConfigField<Owned = Self> {
+    /// Returns the reader of a value stored inline.
+    fn read_owned(owned: &Self) -> Self::Reader<'_>;
+}
+
+/// Enforces the metadata constraints of a scalar field
+/// without knowing the field type.
+///
+/// This component is attached to each scalar field spawned by [`impl_scalar_config_field`],
+/// so that managers loading values from external sources,
+/// such as a hand-edited config file,
+/// cannot inject values that violate [`DefaultFromMetadata::constrain`].
+#[derive(Component, Clone, Copy)]
+pub struct ScalarConstraint {
+    constrain_fn: fn(&mut EntityMut) -> bool,
+}
+
+impl ScalarConstraint {
+    /// Creates the constraint for a scalar field of type `T`.
+    #[must_use]
+    pub fn of<T: DefaultFromMetadata + Send + Sync>() -> Self {
+        Self { constrain_fn: constrain_typed::<T> }
+    }
+
+    /// Adjusts the [`ScalarData`] of `entity` to satisfy its [`ScalarMetadata`].
+    ///
+    /// Returns whether the value was adjusted.
+    ///
+    /// This does not bump the generation of the field;
+    /// callers are expected to do so after writing the value.
+    pub fn apply(&self, entity: &mut EntityMut) -> bool { (self.constrain_fn)(entity) }
+}
+
+fn constrain_typed<T: DefaultFromMetadata + Send + Sync>(entity: &mut EntityMut) -> bool {
+    let (Some(data), Some(metadata)) =
+        (entity.get::<ScalarData<T>>(), entity.get::<ScalarMetadata<T>>())
+    else {
+        return false;
+    };
+    if let Some(value) = data.0.constrain(&metadata.0)
+        && let Some(mut data) = entity.get_mut::<ScalarData<T>>()
+    {
+        data.0 = value;
+        return true;
+    }
+    false
+}
+
+/// Resets a scalar field to its default value without knowing the field type.
+///
+/// This component is attached to each scalar field spawned by [`impl_scalar_config_field`]
+/// and to enum discriminants.
+/// See [`reset::reset_to_default`] to reset a whole subtree.
+#[derive(Component, Clone, Copy)]
+pub struct ScalarDefault {
+    reset_fn: fn(&mut EntityMut),
+}
+
+impl ScalarDefault {
+    /// Creates the vtable for a scalar field of type `T`.
+    #[must_use]
+    pub fn of<T: DefaultFromMetadata + Send + Sync>() -> Self {
+        Self {
+            reset_fn: |entity| {
+                let Some(metadata) = entity.get::<ScalarMetadata<T>>() else { return };
+                let value = T::default_from_metadata(&metadata.0);
+                if let Some(mut data) = entity.get_mut::<ScalarData<T>>() {
+                    data.0 = value;
+                }
+            },
+        }
+    }
+
+    /// Creates the vtable for the discriminant field of a `#[derive(Config)]` enum.
+    #[must_use]
+    pub fn of_discriminant<T: EnumDiscriminant<Metadata = EnumDiscriminantMetadata<T>>>() -> Self {
+        Self {
+            reset_fn: |entity| {
+                let Some(metadata) = entity.get::<ScalarMetadata<T>>() else { return };
+                let value = metadata.0.default;
+                if let Some(mut data) = entity.get_mut::<ScalarData<EnumDiscriminantWrapper<T>>>() {
+                    data.0.0 = value;
+                }
+            },
+        }
+    }
+
+    /// Writes the default value described by the [`ScalarMetadata`] of `entity`
+    /// to its [`ScalarData`].
+    ///
+    /// This does not bump the generation of the field;
+    /// callers are expected to do so after writing the value.
+    pub fn apply(&self, entity: &mut EntityMut) { (self.reset_fn)(entity); }
+}
+
 /// Implements [`ConfigField`] for a scalar (non-composite) type.
 ///
 /// - `$ty`: the scalar type to implement [`ConfigField`] for.
@@ -354,16 +693,33 @@ pub struct ScalarMetadata<T: ConfigField>(pub T::Metadata);
 ///   This is the most user-friendly type used in readers,
 ///   e.g. `&str` for `String`, or the owned value for [`Copy`] types.
 /// - `$map_fn`: a function that maps the scalar data to `$mapped_ty`.
+///
+/// The following optional arguments may follow in this order:
+///
+/// - `to_owned = $to_owned`: a function that converts `$mapped_ty` back to an owned `$ty`,
+///   used by [`ConfigField::to_owned`].
+///   Defaults to [`From::from`], which works when `$mapped_ty` is the same as `$ty`.
+/// - `constrain = $constrain`: a function implementing [`DefaultFromMetadata::constrain`].
+///   Must implement `Fn(&$ty, &$metadata) -> Option<$ty>`.
+///   Defaults to accepting all values.
+/// - `describe = $describe`: a function implementing
+///   [`DefaultFromMetadata::describe_constraints`].
+///   Must implement `Fn(&$metadata) -> Option<String>`.
+///   Defaults to describing no constraints.
 #[macro_export]
 macro_rules! impl_scalar_config_field {
-    ($ty:ty, $metadata:ty, $default_from_metadata:expr, $lt:lifetime => $mapped_ty:ty, $map_fn:expr $(,)?) => {
+    (@or [] [$default:expr]) => { $default };
+    (@or [$value:expr] [$default:expr]) => { $value };
+    (@impl $ty:ty, $metadata:ty, $default_from_metadata:expr, $lt:lifetime => $mapped_ty:ty, $map_fn:expr, $to_owned:expr, $constrain:expr, $describe:expr $(,)?) => {
         impl $crate::ConfigField for $ty {
             type SpawnHandle = $crate::__import::Entity;
             type Reader<$lt> = $mapped_ty;
+            type Owned = $ty;
             type ReadQueryData = $crate::__import::Option<&'static $crate::ScalarData<Self>>;
             type Metadata = $metadata;
             type Changed = $crate::FieldGeneration;
             type ChangedQueryData = ();
+            type Writer<'a> = $crate::ScalarWriter<'a, Self>;
 
             fn read_world<'a, 's>(
                 query: impl $crate::QueryLike<Item = <<Self::ReadQueryData as $crate::__import::QueryData>::ReadOnly as $crate::__import::QueryData>::Item<'a, 's>>,
@@ -376,6 +732,10 @@ macro_rules! impl_scalar_config_field {
                 $map_fn(&data.as_ref().expect("scalar data component must remain valid with Self type").0)
             }
 
+            fn to_owned<$lt>(reader: Self::Reader<$lt>) -> Self {
+                $to_owned(reader)
+            }
+
             fn changed<'a, 's>(
                 query: impl $crate::QueryLike<Item = (&'a $crate::ConfigNode, <<Self::ChangedQueryData as $crate::__import::QueryData>::ReadOnly as $crate::__import::QueryData>::Item<'a, 's>)>,
                 &spawn_handle: &$crate::__import::Entity,
@@ -386,6 +746,35 @@ macro_rules! impl_scalar_config_field {
                 );
                 entity.0.generation
             }
+
+            fn write_world<'a>(
+                writes: &'a $crate::ConfigWrites,
+                &spawn_handle: &$crate::__import::Entity,
+            ) -> Self::Writer<'a> {
+                $crate::ScalarWriter::new(spawn_handle, writes)
+            }
+        }
+
+        impl $crate::DefaultFromMetadata for $ty {
+            fn default_from_metadata(metadata: &$metadata) -> Self {
+                $default_from_metadata(metadata)
+            }
+
+            fn constrain(&self, metadata: &$metadata) -> $crate::__import::Option<Self> {
+                $constrain(self, metadata)
+            }
+
+            fn describe_constraints(
+                metadata: &$metadata,
+            ) -> $crate::__import::Option<$crate::__import::String> {
+                $describe(metadata)
+            }
+        }
+
+        impl $crate::CompactField for $ty {
+            fn read_owned<$lt>(owned: &$lt Self) -> Self::Reader<$lt> {
+                $map_fn(owned)
+            }
         }
 
         impl<M: $crate::manager::Supports<$ty>> $crate::ConfigFieldFor<M> for $ty {
@@ -394,31 +783,156 @@ macro_rules! impl_scalar_config_field {
                 ctx: $crate::SpawnContext,
                 metadata: Self::Metadata,
             ) -> $crate::__import::Entity {
-                let manager_comps =
-                    world.resource_mut::<$crate::manager::Instance<M>>().new_entity::<$ty>();
-                let mut entity = world.spawn((
-                        $crate::__import::BevyName::new("Scalar config field"),
-                        $crate::ScalarData::<Self>($default_from_metadata(&metadata)),
-                        $crate::ScalarMetadata::<Self>(metadata),
-                        manager_comps,
-                ));
-                $crate::init_config_node(&mut entity, ctx);
-                entity.id()
+                $crate::spawn_scalar_field::<M, Self>(world, ctx, metadata)
             }
         }
     };
+    (
+        $ty:ty, $metadata:ty, $default_from_metadata:expr, $lt:lifetime => $mapped_ty:ty, $map_fn:expr
+        $(, to_owned = $to_owned:expr)?
+        $(, constrain = $constrain:expr)?
+        $(, describe = $describe:expr)?
+        $(,)?
+    ) => {
+        $crate::impl_scalar_config_field!(
+            @impl $ty, $metadata, $default_from_metadata, $lt => $mapped_ty, $map_fn,
+            $crate::impl_scalar_config_field!(@or [$($to_owned)?] [$crate::__import::From::from]),
+            $crate::impl_scalar_config_field!(
+                @or [$($constrain)?] [|_: &$ty, _: &$metadata| $crate::__import::None]
+            ),
+            $crate::impl_scalar_config_field!(
+                @or [$($describe)?] [|_: &$metadata| $crate::__import::None]
+            ),
+        );
+    };
 }
 use impl_scalar_config_field as impl_scalar_config_field_;
 
 /// Initializes a newly spawned config node entity with the required components from the context.
 pub fn init_config_node(entity: &mut EntityWorldMut, ctx: SpawnContext) {
-    entity.insert(ConfigNode { path: ctx.path, generation: FieldGeneration::default() });
+    entity.insert(ConfigNode {
+        path:       ctx.path,
+        generation: FieldGeneration::default(),
+        revision:   ctx.revision,
+        pending:    false,
+    });
     if let Some(parent) = ctx.parent {
         entity.insert(ChildNodeOf(parent));
     }
     if let Some(dependency) = ctx.dependency {
         entity.insert(dependency);
     }
+    if let Some(description) = ctx.description {
+        entity.insert(Description(description));
+    }
+    if ctx.advanced {
+        entity.insert(Advanced);
+    }
+    if let Some(deprecated) = ctx.deprecated {
+        entity.insert(deprecated);
+    }
+    if let Some(permission) = ctx.permission {
+        entity.insert(permission);
+    }
+    if let Some(relevance) = ctx.relevance {
+        entity.insert(relevance);
+    }
+    if let Some(in_state) = ctx.in_state {
+        entity.insert(in_state);
+    }
+    if ctx.skip_serde {
+        entity.insert(SkipSerde);
+    }
+    if let Some(rename) = ctx.serde_rename {
+        entity.insert(SerdeRename(rename));
+    }
+}
+
+/// Spawns a scalar field entity storing the default value described by `metadata`
+/// in a [`ScalarData<T>`].
+///
+/// This is used by [`impl_scalar_config_field`] and compact structs
+/// to implement [`ConfigFieldFor::spawn_world`].
+pub fn spawn_scalar_field<M, T>(
+    world: &mut World,
+    ctx: SpawnContext,
+    metadata: T::Metadata,
+) -> Entity
+where
+    M: manager::Supports<T>,
+    T: DefaultFromMetadata + Send + Sync,
+{
+    let manager_comps = world.resource_mut::<manager::Instance<M>>().new_entity::<T>();
+    let mut entity = world.spawn((
+        bevy_ecs::name::Name::new("Scalar config field"),
+        ScalarData::<T>(T::default_from_metadata(&metadata)),
+        ScalarMetadata::<T>(metadata),
+        ScalarConstraint::of::<T>(),
+        ScalarDefault::of::<T>(),
+        ScalarField,
+        manager_comps,
+    ));
+    init_config_node(&mut entity, ctx);
+    entity.id()
+}
+
+/// Tests the value of the scalar field `entity` of type `T` with `when`.
+///
+/// This is used to implement [`RelevancePath::is_entity_relevant`]
+/// for `#[config(relevant_if(...))]` fields.
+/// Returns `false` if `entity` is not a scalar field of type `T`.
+pub fn scalar_relevance<T: Send + Sync + 'static>(
+    entity: EntityRef,
+    when: impl FnOnce(&T) -> bool,
+) -> bool {
+    entity.get::<ScalarData<T>>().is_some_and(|data| when(&data.0))
+}
+
+/// Writes `map` applied to the value of the scalar field `from` into the scalar field `to`,
+/// bumping the generation of `to`.
+///
+/// This is used to implement [`Deprecated::migrate`] for `#[config(deprecated(...))]` fields.
+///
+/// # Errors
+/// Returns an error without modifying `to`
+/// if `from` is not a scalar field of type `From`
+/// or `to` is not a scalar field of type `To`.
+pub fn migrate_scalar<From, To>(
+    world: &mut World,
+    from: Entity,
+    to: Entity,
+    map: impl FnOnce(From) -> To,
+) -> Result<(), MigrationError>
+where
+    From: Clone + Send + Sync + 'static,
+    To: Send + Sync + 'static,
+{
+    let value = world.get::<ScalarData<From>>(from).ok_or(MigrationError::SourceType)?.0.clone();
+    let mut entity = world.entity_mut(to);
+    if !entity.contains::<ScalarData<To>>() {
+        return Err(MigrationError::TargetType);
+    }
+    entity.get_mut::<ScalarData<To>>().expect("checked above").0 = map(value);
+    entity.get_mut::<ConfigNode>().expect("scalar fields are config nodes").bump();
+    Ok(())
+}
+
+/// A function computing the default value of a field at spawn time,
+/// used for `#[config(default_fn = path)]` fields.
+///
+/// This is implemented for functions taking either no arguments or a `&World`.
+/// `Marker` only distinguishes the two implementations and need not be named.
+pub trait DefaultFn<T, Marker> {
+    /// Computes the default value.
+    fn call_default(self, world: &World) -> T;
+}
+
+impl<T, F: FnOnce() -> T> DefaultFn<T, ()> for F {
+    fn call_default(self, _world: &World) -> T { self() }
+}
+
+impl<T, F: FnOnce(&World) -> T> DefaultFn<T, (World,)> for F {
+    fn call_default(self, world: &World) -> T { self(world) }
 }
 
 /// Metadata type for [`ConfigField`] implementors derived from [`Config`].
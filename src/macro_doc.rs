@@ -13,6 +13,25 @@
 /// This may be a [scalar type](crate::impl_scalar_config_field)
 /// or another `#[derive(Config)]` type.
 ///
+/// Structs without fields spawn a [`Section`](crate::Section) node with no children.
+/// Such a struct can be used as a field to label the fields that follow it,
+/// which managers such as the egui editor render as a heading:
+///
+/// ```
+/// # use bevy_mod_config::Config;
+/// #[derive(Config)]
+/// struct AdvancedSection;
+///
+/// #[derive(Config)]
+/// struct Graphics {
+///     #[config(default = 1.0)]
+///     brightness: f32,
+///     advanced:   AdvancedSection,
+///     #[config(default = 4)]
+///     msaa:       u32,
+/// }
+/// ```
+///
 /// [Metadata](crate::ConfigField::Metadata) for each field may be specified
 /// in the form `#[config(path = value_expr, ...)]`:
 ///
@@ -29,7 +48,7 @@
 /// For types that implement [`ConfigField`](crate::ConfigField) directly,
 /// the metadata fields depend on the type.
 /// For example, [`NumericMetadata`](crate::impls::NumericMetadata) exposes
-/// `default`, `min`, `max`, `precision` and `slider`,
+/// `default`, `min`, `max`, `precision`, `slider` and `format`,
 /// so they can be assigned like the following:
 ///
 /// ```
@@ -43,6 +62,32 @@
 /// See the "Implementors" section in [`ConfigField`] documentation
 /// to see the metadata of supported scalar types.
 ///
+/// Defaults that cannot be expressed as an expression in the attribute
+/// may be computed at spawn time with `default_fn = function`,
+/// which assigns the return value of `function` to `metadata.default`.
+/// `function` takes either no arguments or a `&World`
+/// (see [`DefaultFn`](crate::DefaultFn)),
+/// and cannot be combined with `default`.
+///
+/// ```
+/// # use bevy_ecs::resource::Resource;
+/// # use bevy_ecs::world::World;
+/// #[derive(bevy_mod_config::Config)]
+/// struct Settings {
+///     #[config(default_fn = available_threads)]
+///     worker_threads: u32,
+///     #[config(default_fn = |world: &World| world.get_resource::<Display>().map_or(60, |d| d.hz))]
+///     fps_limit:      u32,
+/// }
+///
+/// #[derive(Resource)]
+/// struct Display {
+///     hz: u32,
+/// }
+///
+/// fn available_threads() -> u32 { 4 }
+/// ```
+///
 /// ## `#[derive(Config)]` struct types as fields
 ///
 /// For fields whose type is a `#[derive(Config)]` struct,
@@ -107,7 +152,8 @@
 ///
 /// The `discrim` metadata field of derived enums is an
 /// [`EnumDiscriminantMetadata`](crate::EnumDiscriminantMetadata);
-/// `discrim.default` accesses [`EnuMDiscriminantMetadata::default`] to set the default variant.
+/// `discrim.default` accesses [`EnumDiscriminantMetadata::default`](crate::EnumDiscriminantMetadata::default)
+/// to set the default variant.
 ///
 /// ### Configuring variant fields
 ///
@@ -144,6 +190,142 @@
 /// }
 /// ```
 ///
+/// ## Universal attributes
+///
+/// The following keys apply to fields of any type
+/// and do not assign to the [`Metadata`](crate::ConfigField::Metadata):
+///
+/// - `advanced` (or `advanced = bool_expr`) inserts the [`Advanced`](crate::Advanced) component
+///   into the field node, allowing managers such as the egui editor
+///   to hide the field unless advanced settings are requested.
+///
+/// ```
+/// #[derive(bevy_mod_config::Config)]
+/// struct Settings {
+///     #[config(default = 60)]
+///     fps_limit:      u32,
+///     #[config(advanced, default = 4)]
+///     worker_threads: u32,
+/// }
+/// ```
+///
+/// - `permission = expr` inserts the [`Permission`](crate::Permission) component
+///   into the field node, declaring the role required to edit the field and its descendants.
+///
+/// ```
+/// use bevy_mod_config::Permission;
+///
+/// #[derive(bevy_mod_config::Config)]
+/// struct Server {
+///     #[config(default = 1.0)]
+///     mouse_sensitivity: f32,
+///     #[config(permission = Permission::Admin, default = 8)]
+///     max_players:       u32,
+/// }
+/// ```
+///
+/// - `serde(skip)` inserts the [`SkipSerde`](crate::SkipSerde) component
+///   into the field node and all its descendants,
+///   so that the field is shown in editors but never saved or loaded
+///   by the [`Serde`](crate::manager::Serde) manager.
+///
+/// ```
+/// #[derive(bevy_mod_config::Config)]
+/// struct Audio {
+///     #[config(default = 0.8)]
+///     volume: f32,
+///     #[config(serde(skip), default = 1.0)]
+///     duck:   f32,
+/// }
+/// ```
+///
+/// - `serde(rename = "key")` inserts the [`SerdeRename`](crate::SerdeRename) component
+///   into the field node, replacing the field name with `key` in persisted data
+///   while keeping the field name in editors.
+///   The key is used verbatim regardless of the [`KeyCase`](crate::manager::serde::KeyCase).
+///
+/// ```
+/// #[derive(bevy_mod_config::Config)]
+/// struct Audio {
+///     #[config(serde(rename = "masterVolume"), default = 0.8)]
+///     volume: f32,
+/// }
+/// ```
+///
+/// - `deprecated` (or `deprecated(replaced_by = "path", map = map_fn)`)
+///   inserts the [`Deprecated`](crate::Deprecated) component into the field node.
+///   Persistence managers still load the field from existing data
+///   but omit it when saving.
+///   If `replaced_by` is specified, the loaded value is migrated into the replacement field,
+///   whose `.`-separated path is relative to the containing struct or enum.
+///   `map` converts the old value into the replacement value,
+///   and defaults to the identity function.
+///   Migration only supports scalar fields on both sides.
+///   If the replacement field does not exist or has a different type,
+///   loading reports a [`MigrationError`](crate::MigrationError) for the deprecated field.
+///
+/// ```
+/// #[derive(bevy_mod_config::Config)]
+/// struct Settings {
+///     #[config(deprecated(replaced_by = "vsync_mode", map = vsync_mode_from_bool))]
+///     vsync:      bool,
+///     #[config(default = 1)]
+///     vsync_mode: u8,
+/// }
+///
+/// fn vsync_mode_from_bool(vsync: bool) -> u8 { u8::from(vsync) }
+/// ```
+///
+/// - `relevant_if = "path"` (or `relevant_if(path = "path", when = predicate)`)
+///   inserts a [`RelevancePath`](crate::RelevancePath) into the field node,
+///   making the field irrelevant unless the scalar field at the absolute `path` satisfies `predicate`.
+///   The path starts with the root key, so it may refer to a field in another root.
+///   `predicate` takes a reference to the [`ScalarData`](crate::ScalarData) value of the field,
+///   and defaults to testing whether a `bool` field is true.
+///   This cannot be used on enum variant fields.
+///
+/// ```
+/// #[derive(bevy_mod_config::Config)]
+/// struct DebugOptions {
+///     #[config(relevant_if = "general.developer_mode")]
+///     show_colliders: bool,
+///     #[config(relevant_if(path = "general.log_level", when = |level: &u8| *level >= 3))]
+///     trace_ai:       bool,
+/// }
+/// ```
+///
+/// - `in_state(state, ...)` inserts a [`StateRelevance`](crate::StateRelevance)
+///   into the field node, making the field and its descendants irrelevant
+///   unless the app is in one of the listed `States`.
+///   See the [`state`](crate::state) module for details.
+///   Requires the `bevy_state` feature.
+///
+/// ## Computed fields
+///
+/// A struct field with `#[config(computed = expr)]` does not spawn a config node,
+/// so it is not persisted or shown in editors,
+/// but it is still present in the [`Reader`](crate::ConfigField::Reader)
+/// and the [owned reader](crate::ConfigField::Owned).
+/// `expr` is evaluated on every read with the readers of the other fields in scope
+/// as local variables of the same names.
+/// Computed fields may refer to computed fields declared before them.
+///
+/// The field type can be any [`Copy`] type.
+/// Computed fields cannot have other `#[config]` attributes
+/// and are only supported in structs with named fields.
+///
+/// ```
+/// #[derive(bevy_mod_config::Config)]
+/// struct Resolution {
+///     #[config(default = 1920)]
+///     width:        u32,
+///     #[config(default = 1080)]
+///     height:       u32,
+///     #[config(computed = width as f32 / height as f32)]
+///     aspect_ratio: f32,
+/// }
+/// ```
+///
 /// # Container-level attributes
 /// ## `#[config(expose)]`
 /// `#[derive(Config)]` generates additional types to be used in accessor code.
@@ -162,6 +344,27 @@
 /// The default identifier is `{InputIdent}Read`.
 /// This can be renamed with `#[config(expose(read = NewIdent))]`.
 ///
+/// ### `#[config(expose(owned))]`
+/// Exposes the [`Owned`](crate::ConfigField::Owned) type.
+/// This is the type returned by `to_owned()` on the reader,
+/// which mirrors the input with the `Owned` type of each field type,
+/// e.g. `String` instead of `&str`.
+/// It does not borrow the world, so it can be stored in resources or sent through events.
+///
+/// The default identifier is `{InputIdent}Owned`.
+/// This can be renamed with `#[config(expose(owned = NewIdent))]`.
+///
+/// ### `#[config(expose(write))]`
+/// Exposes the [`Writer`](crate::ConfigField::Writer) type.
+/// This is the type passed to [`WorldExt::update_config`](crate::WorldExt::update_config),
+/// where each field corresponds to the `Writer` type of the field type in the input.
+/// For enums, the `discrim` field writes the active variant,
+/// and each variant with fields has a `v_{VariantIdent}` field
+/// containing the writers of the fields in the variant.
+///
+/// The default identifier is `{InputIdent}Write`.
+/// This can be renamed with `#[config(expose(write = NewIdent))]`.
+///
 /// ### `#[config(expose(changed))]`
 /// Exposes the [`Changed`](crate::ConfigField::Changed) type.
 /// This is the type returned by [`ReadConfig::changed`](crate::ReadConfig::changed).
@@ -178,6 +381,11 @@
 /// The default identifier is `{InputIdent}Discrim`.
 /// This can be renamed with `#[config(expose(discrim = NewIdent))]`.
 ///
+/// This also exposes the type alias `{DiscrimIdent}Metadata`
+/// for the [metadata](crate::EnumDiscriminantMetadata) of the discriminant,
+/// which is stored in the <code>[ScalarMetadata](crate::ScalarMetadata)&lt;{DiscrimIdent}&gt;</code>
+/// component of the discriminant node.
+///
 /// ### `#[config(expose(spawn_handle))]`
 /// Exposes the spawn handle type containing the entity IDs of the config field tree.
 /// Must only be used on enum types.
@@ -194,6 +402,180 @@
 ///
 /// ## `#[config(discrim(...))]`
 /// Specifies the default [metadata](crate::EnumDiscriminantMetadata) for the enum discriminant.
+/// The discriminant type can be referenced in the values even if it is not exposed.
 ///
 /// This can be overridden at usage fields with `#[config(discrim.xxx = value_expr)]` on the field.
+///
+/// ```
+/// # use bevy_mod_config::Config;
+/// #[derive(Config)]
+/// #[config(expose(discrim), discrim(default = QualityDiscrim::Medium))]
+/// enum Quality {
+///     Low,
+///     Medium,
+///     High,
+/// }
+///
+/// let metadata = QualityDiscrimMetadata::default();
+/// # let _ = metadata;
+/// ```
+///
+/// ## `#[config(reader_derive(...))]`
+/// Adds derive macros to the [`Reader`](crate::ConfigField::Reader) type,
+/// e.g. to log the config with `dbg!(settings.read())`
+/// or to dump it in bug reports with serde.
+/// The derived traits must be implemented by the `Reader` types of all fields,
+/// so nested `#[derive(Config)]` types need the same attribute.
+///
+/// ```
+/// # use bevy_mod_config::Config;
+/// #[derive(Config)]
+/// #[config(reader_derive(Debug))]
+/// struct Video {
+///     width:  u32,
+///     window: Window,
+/// }
+///
+/// #[derive(Config)]
+/// #[config(reader_derive(Debug))]
+/// enum Window {
+///     Fullscreen,
+///     Windowed { title: String },
+/// }
+/// ```
+///
+/// ## `#[config(changed_derive(...))]`
+/// Adds derives to the [`Changed`](crate::ConfigField::Changed) type
+/// in addition to `Clone`, `PartialEq`, `Eq` and `Debug`,
+/// e.g. `Hash` to use change values as memoization keys.
+/// The derived traits must be implemented by the `Changed` types of all fields,
+/// so nested `#[derive(Config)]` types need the same attribute.
+/// Only traits supported by `derivative` can be listed.
+///
+/// ```
+/// # use std::collections::HashSet;
+/// # use bevy_mod_config::{Config, ConfigField};
+/// #[derive(Config)]
+/// #[config(changed_derive(Hash))]
+/// struct Video {
+///     width: u32,
+/// }
+///
+/// let seen: HashSet<<Video as ConfigField>::Changed> = HashSet::new();
+/// # let _ = seen;
+/// ```
+///
+/// ## `#[config(in_state(state, ...))]`
+/// Makes every instance of the type only relevant in the listed states,
+/// like the field-level `in_state` attribute.
+/// This also applies when the type is used as a root,
+/// in which case [`ReadConfigChange`](crate::ReadConfigChange) defers changes
+/// until the app enters one of the states.
+/// Since both attributes apply to the same node,
+/// this takes precedence over a field-level `in_state` attribute
+/// on a field of this type.
+/// Requires the `bevy_state` feature.
+///
+/// ## `#[config(reset_variants)]`
+/// Resets the fields of a variant to their defaults when another variant is selected,
+/// so that selecting the variant again shows its defaults instead of the previous values.
+/// Without this attribute, the fields of inactive variants keep their values.
+/// Must only be used on enum types.
+/// See the [`reset`](crate::reset) module for details.
+///
+/// This is equivalent to `#[config(discrim(on_switch = VariantSwitch::Reset))]`,
+/// and can be overridden for a single field with `#[config(discrim.on_switch = ...)]`
+/// using [`VariantSwitch`](crate::VariantSwitch).
+///
+/// ```
+/// # use bevy_mod_config::Config;
+/// # use bevy_mod_config::VariantSwitch;
+/// #[derive(Config)]
+/// #[config(reset_variants)]
+/// enum Color {
+///     Named(String),
+///     Rgb(#[config(default = 255)] u8, #[config(default = 255)] u8, #[config(default = 255)] u8),
+/// }
+///
+/// #[derive(Config)]
+/// struct Theme {
+///     background: Color,
+///     #[config(discrim.on_switch = VariantSwitch::Preserve)]
+///     foreground: Color,
+/// }
+/// ```
+///
+/// ## `#[config(compact)]`
+/// Stores all fields of a struct in a single scalar node
+/// instead of spawning a config node for each field.
+/// This reduces the entity count and per-field overhead of large config trees,
+/// at the cost of treating the struct as one value:
+/// a change to any field changes the whole struct,
+/// and managers see a single node instead of one per field.
+/// Must only be used on non-generic structs,
+/// and all field types must implement [`CompactField`](crate::CompactField),
+/// which includes types implemented with [`impl_scalar_config_field`](crate::impl_scalar_config_field)
+/// and other `#[config(compact)]` structs.
+///
+/// The struct itself becomes the [owned](crate::ConfigField::Owned) value,
+/// so it must implement `Clone`,
+/// as well as any traits required by the managers it is used with,
+/// e.g. `Serialize` and `Deserialize` for [`Serde`](crate::manager::Serde).
+/// Reading it still returns the usual reader struct.
+/// Field-level metadata and `default_fn` work as usual,
+/// but universal attributes that apply to a single node
+/// such as `advanced` and `relevant_if`
+/// and computed fields cannot be used.
+///
+/// ```
+/// # use bevy_mod_config::Config;
+/// #[derive(Config, Clone)]
+/// #[config(compact)]
+/// struct Vertex {
+///     #[config(default = 0.0)]
+///     x:      f32,
+///     #[config(default = 0.0)]
+///     y:      f32,
+///     #[config(default = 1.0, min = 0.0, max = 1.0)]
+///     weight: f32,
+/// }
+///
+/// #[derive(Config)]
+/// struct Mesh {
+///     #[config(y.default = 1.0)]
+///     top:    Vertex,
+///     bottom: Vertex,
+/// }
+/// ```
+///
+/// ## `#[config(validate_struct = validate_fn)]`
+/// Checks an invariant across the fields of a struct whenever any of them changes.
+/// `validate_fn` is a function or non-capturing closure
+/// taking the [`Reader`](crate::ConfigField::Reader) of the struct
+/// and returning `Result<(), String>`.
+/// A named function needs `#[config(expose(read))]` to spell out the reader type.
+///
+/// Violations are reported as [`ValidationFailed`](crate::validate::ValidationFailed) messages
+/// and as warnings in the [`Egui`](crate::manager::Egui) editor.
+/// See the [`validate`](crate::validate) module for details.
+///
+/// ```
+/// # use bevy_mod_config::Config;
+/// #[derive(Config)]
+/// #[config(expose(read), validate_struct = validate_fps)]
+/// struct Fps {
+///     #[config(default = 30)]
+///     min_fps: u32,
+///     #[config(default = 60)]
+///     max_fps: u32,
+/// }
+///
+/// fn validate_fps(fps: FpsRead) -> Result<(), String> {
+///     if fps.min_fps <= fps.max_fps {
+///         Ok(())
+///     } else {
+///         Err(format!("min_fps ({}) exceeds max_fps ({})", fps.min_fps, fps.max_fps))
+///     }
+/// }
+/// ```
 pub use bevy_mod_config_macros::Config;
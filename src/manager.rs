@@ -2,16 +2,55 @@
 //!
 //! See [`Manager`] for more information.
 
+use alloc::vec::Vec;
+use core::any::TypeId;
+use core::marker::PhantomData;
 use core::ops::{Deref, DerefMut};
 
+use bevy_app::App;
 use bevy_ecs::bundle::Bundle;
+use bevy_ecs::change_detection::{DetectChanges, Ref};
+use bevy_ecs::component::Component;
+use bevy_ecs::entity::Entity;
+use bevy_ecs::lifecycle::HookContext;
+use bevy_ecs::query::{Changed, QueryState, With};
 use bevy_ecs::resource::Resource;
+use bevy_ecs::world::{DeferredWorld, Mut, World};
 
-#[cfg(feature = "egui")]
+use crate::{ConfigChanged, ConfigNode, FieldGeneration, ScalarField};
+
+#[cfg(feature = "bevy_diagnostic")]
+pub mod diagnostics;
+#[cfg(feature = "bevy_diagnostic")]
+pub use diagnostics::Diagnostics;
+
+pub mod docs;
+pub use docs::Docs;
+
+#[cfg(feature = "egui_manager")]
 pub mod egui;
-#[cfg(feature = "egui")]
+#[cfg(feature = "egui_manager")]
 pub use egui::Egui;
 
+pub mod layer;
+pub use layer::Layers;
+
+pub mod record;
+pub use record::Recorder;
+
+#[cfg(feature = "bevy_time")]
+pub mod smooth;
+#[cfg(feature = "bevy_time")]
+pub use smooth::Smoothing;
+
+#[cfg(feature = "tracing")]
+pub mod trace;
+#[cfg(feature = "tracing")]
+pub use trace::Tracing;
+
+pub mod validation;
+pub use validation::Validation;
+
 #[cfg(feature = "serde")]
 pub mod serde;
 #[cfg(feature = "serde")]
@@ -21,28 +60,190 @@ pub use serde::Serde;
 ///
 /// A manager is invoked when a scalar config field is spawned in the world,
 /// allowing it to attach behavior to the entity.
+/// It is invoked again when the field entity is despawned,
+/// allowing it to clean up any state associated with the field.
 ///
 /// Tuples of managers are also managers;
 /// each manager would be invoked in order when a new field entity is spawned.
 /// This allows using multiple managers in the same app.
 pub trait Manager: Sized + Send + Sync + 'static {
+    /// Sets up the app for this manager type,
+    /// e.g. by adding the systems and messages that the manager relies on.
+    ///
+    /// Called once, when the first config is initialized in the app
+    /// right after the [`Instance`] resource is inserted.
+    /// For tuples of managers, this is called for each manager in order,
+    /// but only the [`Instance`] of the tuple itself exists in the world.
+    fn build(_app: &mut App) {}
+
+    /// Called when a new root config is initialized,
+    /// after the whole config tree under the root has been spawned.
+    ///
+    /// This allows managers to maintain an index of roots
+    /// without scanning for [`RootNode`](crate::RootNode) entities.
+    fn init_root(&mut self, _root: &RootInfo) {}
+
+    /// Called when the generation of a scalar field has changed,
+    /// e.g. to autosave, audit or synchronize the field.
+    ///
+    /// Changes are collected by [`notify_field_changes`] once per frame in [`Last`](bevy_app::Last),
+    /// so the hook is only called once for a field changed multiple times in the same frame.
+    /// Fields spawned since the last run are reported to
+    /// [`on_field_spawned`](Manager::on_field_spawned) instead.
+    fn on_field_changed(&mut self, _world: &mut World, _entity: Entity) {}
+
+    /// Called once for each scalar field spawned since the last run of [`notify_field_changes`],
+    /// e.g. to check the initial value of a field,
+    /// which is not reported to [`on_field_changed`](Manager::on_field_changed).
+    fn on_field_spawned(&mut self, _world: &mut World, _entity: Entity) {}
+
     /// Returns a component bundle that tracks entity management.
     ///
     /// This is particularly useful for attaching vtable pointers to a component
     /// so that the manager can later traverse the config tree
     /// without knowing the type of each field at compile time.
-    fn new_entity<T>(&mut self) -> impl Bundle
+    fn new_entity<T: 'static>(&mut self) -> impl Bundle
     where
         Self: Supports<T>,
     {
-        self.new_entity_for_type()
+        (self.new_entity_for_type(), Managed::<Self, T>(PhantomData))
     }
+
+    /// Called when a scalar field entity spawned with [`new_entity`](Manager::new_entity)
+    /// is despawned or loses its manager components.
+    ///
+    /// The entity is still accessible in the world when this is called.
+    fn remove_entity<T>(&mut self, entity: Entity)
+    where
+        Self: Supports<T>,
+    {
+        self.remove_entity_for_type(entity);
+    }
+}
+
+/// Describes a root config passed to [`Manager::init_root`].
+#[derive(Debug, Clone, Copy)]
+#[non_exhaustive]
+pub struct RootInfo<'a> {
+    /// The key of the root, as passed to [`init_config`](crate::AppExt::init_config).
+    pub key:       &'a str,
+    /// The [`RootNode`](crate::RootNode) entity of the config tree.
+    pub entity:    Entity,
+    /// The [`TypeId`] of the root config type.
+    pub type_id:   TypeId,
+    /// The type name of the root config type, for diagnostic purposes only.
+    pub type_name: &'static str,
+    /// The world containing the spawned config tree.
+    pub world:     &'a World,
 }
 
 /// Marks that a [`Manager`] type supports handling config fields of scalar type `T`.
+///
+/// Initializing a config with a field type that the manager does not support
+/// fails to compile with an error naming the field type:
+///
+/// ```compile_fail
+/// # use bevy_app::App;
+/// # use bevy_ecs::bundle::Bundle;
+/// use bevy_mod_config::{AppExt, Config, manager};
+///
+/// #[derive(Default)]
+/// struct CountersOnly;
+///
+/// impl manager::Manager for CountersOnly {}
+///
+/// impl manager::Supports<u32> for CountersOnly {
+///     fn new_entity_for_type(&mut self) -> impl Bundle {}
+/// }
+///
+/// #[derive(Config)]
+/// struct Stats {
+///     kills: u32,
+///     name:  String,
+/// }
+///
+/// // error: type `String` is not supported by manager `CountersOnly`
+/// App::new().init_config::<CountersOnly, Stats>("stats");
+/// ```
+#[diagnostic::on_unimplemented(
+    message = "type `{T}` is not supported by manager `{Self}`",
+    label = "`{T}` is used as a config field here",
+    note = "some managers, such as `Egui`, skip fields wrapped in `bevy_mod_config::Lossy`"
+)]
 pub trait Supports<T>: Manager {
     /// Returns a component bundle that tracks entity management for the scalar type `T`.
     fn new_entity_for_type(&mut self) -> impl Bundle;
+
+    /// Cleans up the state associated with a removed field entity of scalar type `T`,
+    /// e.g. caches or indexes populated in [`new_entity_for_type`](Supports::new_entity_for_type).
+    ///
+    /// The default implementation does nothing.
+    fn remove_entity_for_type(&mut self, _entity: Entity) {}
+}
+
+/// Invokes [`Manager::remove_entity`] when removed from a field entity.
+#[derive(Component)]
+#[component(on_remove = remove_managed::<M, T>)]
+struct Managed<M: Supports<T>, T: 'static>(PhantomData<fn() -> (M, T)>);
+
+fn remove_managed<M: Supports<T>, T: 'static>(mut world: DeferredWorld, ctx: HookContext) {
+    // The manager instance may already be gone if the whole world is being dropped.
+    if let Some(mut manager) = world.get_resource_mut::<Instance<M>>() {
+        manager.remove_entity::<T>(ctx.entity);
+    }
+}
+
+/// The generation of a scalar field when it was last seen by [`notify_field_changes`].
+///
+/// Required by [`ScalarField`], so it is inserted into every scalar field automatically.
+#[derive(Component, Default)]
+pub struct NotifiedGeneration(Option<FieldGeneration>);
+
+/// Calls [`Manager::on_field_changed`] for each scalar field
+/// whose generation changed since the last run,
+/// then triggers [`ConfigChanged`] on the field.
+/// Calls [`Manager::on_field_spawned`] instead for each scalar field spawned since the last run.
+///
+/// Mutable access to a [`ConfigNode`] that leaves its generation unchanged,
+/// e.g. a bump deferred by a [`ChangeBatch`](crate::ChangeBatch), is not reported.
+///
+/// Added to [`Last`](bevy_app::Last) by [`AppExt::init_config`](crate::AppExt::init_config).
+#[expect(clippy::type_complexity, reason = "the query state is a system parameter")]
+pub fn notify_field_changes<M: Manager>(
+    world: &mut World,
+    query: &mut QueryState<
+        (Entity, Ref<ConfigNode>, &mut NotifiedGeneration),
+        (Changed<ConfigNode>, With<ScalarField>),
+    >,
+) {
+    let mut spawned = Vec::new();
+    let mut changed = Vec::new();
+    for (entity, node, mut notified) in query.iter_mut(world) {
+        let generation = Some(node.generation);
+        if node.is_added() {
+            notified.0 = generation;
+            spawned.push(entity);
+        } else if notified.0 != generation {
+            notified.0 = generation;
+            changed.push(entity);
+        }
+    }
+    if spawned.is_empty() && changed.is_empty() {
+        return;
+    }
+
+    world.resource_scope(|world, mut manager: Mut<Instance<M>>| {
+        for &entity in &spawned {
+            manager.on_field_spawned(world, entity);
+        }
+        for &entity in &changed {
+            manager.on_field_changed(world, entity);
+        }
+    });
+
+    for entity in changed {
+        world.trigger(ConfigChanged { node: entity, field: entity });
+    }
 }
 
 /// Stores the manager instances from the world.
@@ -69,7 +270,35 @@ macro_rules! impl_manager {
         impl<$($M),*> Manager for ($($M,)*)
         where
             $($M: Manager),*
-        {}
+        {
+            #[allow(unused_variables)]
+            fn build(app: &mut App) {
+                $(
+                    $M::build(app);
+                )*
+            }
+
+            #[allow(unused_variables)]
+            fn init_root(&mut self, root: &RootInfo) {
+                $(
+                    self.$n.init_root(root);
+                )*
+            }
+
+            #[allow(unused_variables)]
+            fn on_field_changed(&mut self, world: &mut World, entity: Entity) {
+                $(
+                    self.$n.on_field_changed(world, entity);
+                )*
+            }
+
+            #[allow(unused_variables)]
+            fn on_field_spawned(&mut self, world: &mut World, entity: Entity) {
+                $(
+                    self.$n.on_field_spawned(world, entity);
+                )*
+            }
+        }
 
         impl<T, $($M: Send + Sync + 'static),*> Supports<T> for ($($M,)*)
         where
@@ -83,6 +312,13 @@ macro_rules! impl_manager {
                     )*
                 )
             }
+
+            #[allow(unused_variables)]
+            fn remove_entity_for_type(&mut self, entity: Entity) {
+                $(
+                    self.$n.remove_entity_for_type(entity);
+                )*
+            }
         }
     };
 }
@@ -0,0 +1,214 @@
+//! Publishes config values to [`bevy_diagnostic`].
+//!
+//! Numeric fields opt in by setting [`NumericMetadata::diagnostic`].
+//! The value of each such field is published every frame
+//! under the path `config/{key}/{field}`, e.g. `config/camera/fov`,
+//! so that performance overlays and log pipelines can correlate behavior with current settings.
+//! In addition, the number of field changes in each frame is published under [`CHANGES`].
+//! Nothing is published unless [`DiagnosticsPlugin`](bevy_diagnostic::DiagnosticsPlugin) is added.
+//!
+//! The [`Diagnostics`] manager publishes fields of all built-in numeric types automatically.
+//! Fields of other [`Measurable`] types are published by adding a [`publish`] system for the type.
+//!
+//! ```
+//! # use bevy_app::App;
+//! # use bevy_mod_config::{AppExt, Config};
+//! use bevy_mod_config::manager::diagnostics::Diagnostics;
+//!
+//! #[derive(Config)]
+//! struct Camera {
+//!     #[config(default = 90.0, diagnostic = true)]
+//!     fov: f32,
+//! }
+//!
+//! let mut app = App::new();
+//! app.add_plugins(bevy_diagnostic::DiagnosticsPlugin);
+//! app.init_config::<Diagnostics, Camera>("camera");
+//! ```
+
+use alloc::string::String;
+use core::time::Duration;
+
+use bevy_app::{App, Update};
+use bevy_diagnostic::{
+    Diagnostic, DiagnosticPath, Diagnostics as BevyDiagnostics, DiagnosticsStore,
+};
+use bevy_ecs::bundle::Bundle;
+use bevy_ecs::component::Component;
+use bevy_ecs::entity::Entity;
+use bevy_ecs::query::{Changed, Has};
+use bevy_ecs::schedule::IntoScheduleConfigs;
+use bevy_ecs::schedule::common_conditions::resource_exists;
+use bevy_ecs::system::{Commands, Local, Query, Res};
+use bevy_ecs::world::World;
+use hashbrown::HashMap;
+
+use crate::impls::NumericMetadata;
+use crate::manager::{self, Manager};
+use crate::{ConfigField, ConfigNode, FieldGeneration, ScalarData, ScalarMetadata};
+
+/// The diagnostic path for the number of config field changes in each frame.
+pub const CHANGES: DiagnosticPath = DiagnosticPath::const_new("config/changes");
+
+/// A [`Manager`] that publishes opted-in numeric fields to [`bevy_diagnostic`].
+///
+/// This manager supports all scalar types,
+/// but only fields whose type implements [`Measurable`]
+/// and whose [`NumericMetadata::diagnostic`] is set are published.
+///
+/// See the [module documentation](self) for usage.
+#[derive(Default)]
+pub struct Diagnostics;
+
+impl Manager for Diagnostics {
+    fn build(app: &mut App) {
+        // `DiagnosticsStore` is initialized by `DiagnosticsPlugin`,
+        // which may be added before or after the config.
+        app.add_systems(
+            Update,
+            (
+                publish_changes,
+                publish::<u8>,
+                publish::<u16>,
+                publish::<u32>,
+                publish::<u64>,
+                publish::<usize>,
+                publish::<i8>,
+                publish::<i16>,
+                publish::<i32>,
+                publish::<i64>,
+                publish::<isize>,
+                publish::<f32>,
+                publish::<f64>,
+                publish::<Duration>,
+            )
+                .run_if(resource_exists::<DiagnosticsStore>),
+        );
+    }
+}
+
+impl<T> manager::Supports<T> for Diagnostics {
+    fn new_entity_for_type(&mut self) -> impl Bundle {}
+}
+
+/// Numeric types that can be published as a diagnostic measurement.
+pub trait Measurable: ConfigField<Metadata = NumericMetadata<Self>> + Copy + Send + Sync {
+    /// Converts the value to a measurement.
+    fn to_measurement(self) -> f64;
+}
+
+macro_rules! impl_measurable_lossless {
+    ($($ty:ty),*) => {
+        $(
+            impl Measurable for $ty {
+                fn to_measurement(self) -> f64 { f64::from(self) }
+            }
+        )*
+    };
+}
+
+impl_measurable_lossless!(u8, u16, u32, i8, i16, i32, f32, f64);
+
+macro_rules! impl_measurable_lossy {
+    ($($ty:ty),*) => {
+        $(
+            impl Measurable for $ty {
+                #[expect(clippy::cast_precision_loss, reason = "measurements are approximate")]
+                fn to_measurement(self) -> f64 { self as f64 }
+            }
+        )*
+    };
+}
+
+impl_measurable_lossy!(u64, usize, i64, isize);
+
+impl Measurable for Duration {
+    fn to_measurement(self) -> f64 { self.as_secs_f64() }
+}
+
+/// Caches the diagnostic path of a published field.
+#[derive(Component)]
+pub struct PublishedField(DiagnosticPath);
+
+/// Publishes the values of all opted-in fields of type `T`.
+///
+/// Fields are only checked for [`NumericMetadata::diagnostic`]
+/// when they are spawned or their metadata changes.
+/// This system panics if [`DiagnosticsStore`] is absent,
+/// so it should run conditionally on [`resource_exists`] like in the [`Diagnostics`] manager.
+#[allow(clippy::needless_pass_by_value, reason = "system parameters are passed by value")]
+#[expect(clippy::type_complexity, reason = "queries are system parameters")]
+pub fn publish<T: Measurable>(
+    mut commands: Commands,
+    store: Res<DiagnosticsStore>,
+    mut diagnostics: BevyDiagnostics,
+    changed_query: Query<
+        (Entity, &ConfigNode, &ScalarMetadata<T>, Has<PublishedField>),
+        Changed<ScalarMetadata<T>>,
+    >,
+    query: Query<(&ScalarData<T>, &PublishedField)>,
+) {
+    for (entity, node, metadata, published) in &changed_query {
+        if metadata.0.diagnostic && !published {
+            let path = DiagnosticPath::new(field_path(&node.path));
+            if store.get(&path).is_none() {
+                register(&mut commands, path.clone());
+            }
+            commands.entity(entity).insert(PublishedField(path));
+        } else if !metadata.0.diagnostic && published {
+            commands.entity(entity).remove::<PublishedField>();
+        }
+    }
+
+    for (&ScalarData(value), PublishedField(path)) in &query {
+        diagnostics.add_measurement(path, || value.to_measurement());
+    }
+}
+
+/// Registers the diagnostic at `path` when the commands are applied.
+///
+/// [`DiagnosticsStore`] cannot be borrowed mutably alongside [`BevyDiagnostics`].
+fn register(commands: &mut Commands, path: DiagnosticPath) {
+    commands.queue(move |world: &mut World| {
+        let mut store = world.resource_mut::<DiagnosticsStore>();
+        if store.get(&path).is_none() {
+            store.add(Diagnostic::new(path));
+        }
+    });
+}
+
+fn field_path(path: &[String]) -> String {
+    let mut output = String::from("config");
+    for component in path {
+        output.push('/');
+        output.push_str(component);
+    }
+    output
+}
+
+/// Publishes the number of config field changes since the last run under [`CHANGES`].
+///
+/// Like [`publish`], this requires [`DiagnosticsStore`].
+#[allow(clippy::needless_pass_by_value, reason = "system parameters are passed by value")]
+pub fn publish_changes(
+    mut commands: Commands,
+    store: Res<DiagnosticsStore>,
+    mut diagnostics: BevyDiagnostics,
+    mut last_generations: Local<HashMap<Entity, FieldGeneration>>,
+    query: Query<(Entity, &ConfigNode)>,
+) {
+    if store.get(&CHANGES).is_none() {
+        register(&mut commands, CHANGES);
+    }
+
+    last_generations.retain(|&entity, _| query.contains(entity));
+    let mut changes = 0u64;
+    for (entity, node) in &query {
+        let last = last_generations.insert(entity, node.generation).unwrap_or_default();
+        changes = changes.saturating_add(node.generation.changes_since(last));
+    }
+
+    #[expect(clippy::cast_precision_loss, reason = "measurements are approximate")]
+    let changes = changes as f64;
+    diagnostics.add_measurement(&CHANGES, || changes);
+}
@@ -0,0 +1,210 @@
+//! Generating reference documentation for config fields.
+//!
+//! The [`Docs`] manager records how to describe each scalar field,
+//! so that [`collect`] can list the path, type, default value, constraints and
+//! [`Description`] of every field in the world,
+//! e.g. to publish the settings of a game server in a wiki.
+//! [`to_markdown`] renders the list as one Markdown table per root.
+//!
+//! ```
+//! # use bevy_app::App;
+//! # use bevy_mod_config::{AppExt, Config};
+//! use bevy_mod_config::manager::docs::{self, Docs};
+//!
+//! #[derive(Config)]
+//! struct Server {
+//!     /// The maximum number of connected players.
+//!     #[config(default = 16, min = 1, max = 64)]
+//!     max_players: u32,
+//! }
+//!
+//! let mut app = App::new();
+//! app.init_config::<Docs, Server>("server");
+//!
+//! let markdown = docs::to_markdown(app.world_mut());
+//! assert!(markdown.contains(
+//!     "| `server.max_players` | `u32` | `16` | 1 ..= 64 | The maximum number of connected \
+//!      players. |"
+//! ));
+//! ```
+
+use alloc::format;
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::any::type_name;
+use core::fmt::{self, Write as _};
+
+use bevy_ecs::bundle::Bundle;
+use bevy_ecs::component::Component;
+use bevy_ecs::entity::Entity;
+use bevy_ecs::query::With;
+use bevy_ecs::world::{EntityRef, World};
+
+use crate::manager::{self, Manager};
+use crate::{
+    ChildNodeList, ConfigNode, DefaultFromMetadata, Description, EnumDiscriminant,
+    EnumDiscriminantMetadata, EnumDiscriminantWrapper, RootNode, ScalarMetadata,
+};
+
+/// A [`Manager`] that allows generating reference documentation for config fields.
+///
+/// This manager supports all scalar types implementing [`DefaultFromMetadata`] and [`Debug`],
+/// as well as enum discriminants.
+///
+/// See the [module documentation](self) for usage.
+#[derive(Default)]
+pub struct Docs;
+
+impl Manager for Docs {}
+
+impl<T> manager::Supports<T> for Docs
+where
+    T: DefaultFromMetadata + fmt::Debug + Send + Sync + 'static,
+{
+    fn new_entity_for_type(&mut self) -> impl Bundle {
+        DocVtable {
+            type_name: short_type_name(type_name::<T>()),
+            describe:  |entity| {
+                let metadata = &entity
+                    .get::<ScalarMetadata<T>>()
+                    .expect("DocVtable must be inserted with the corresponding ScalarMetadata type")
+                    .0;
+                (
+                    format!("{:?}", T::default_from_metadata(metadata)),
+                    T::describe_constraints(metadata),
+                )
+            },
+        }
+    }
+}
+
+impl<T> manager::Supports<EnumDiscriminantWrapper<T>> for Docs
+where
+    T: EnumDiscriminant<Metadata = EnumDiscriminantMetadata<T>>,
+{
+    fn new_entity_for_type(&mut self) -> impl Bundle {
+        DocVtable {
+            type_name: "enum".into(),
+            describe:  |entity| {
+                let metadata = &entity
+                    .get::<ScalarMetadata<T>>()
+                    .expect("DocVtable must be inserted with the corresponding ScalarMetadata type")
+                    .0;
+                let variants: Vec<_> = T::VARIANTS.iter().map(|variant| variant.name()).collect();
+                (metadata.default.name().into(), Some(variants.join(", ")))
+            },
+        }
+    }
+}
+
+/// A type erasure vtable attached to each scalar field to describe it.
+#[derive(Component, Clone)]
+struct DocVtable {
+    type_name: String,
+    /// Returns the default value and the description of the constraints.
+    describe:  fn(EntityRef) -> (String, Option<String>),
+}
+
+/// Strips the module paths from a [type name](type_name),
+/// e.g. `Vec<u8>` for `alloc::vec::Vec<u8>`.
+fn short_type_name(name: &str) -> String {
+    let mut short = String::new();
+    let mut segment_start = 0;
+    for (index, ch) in name.char_indices() {
+        if ch == ':' {
+            segment_start = index + 1;
+        } else if !(ch.is_alphanumeric() || ch == '_') {
+            short.push_str(&name[segment_start..index]);
+            short.push(ch);
+            segment_start = index + ch.len_utf8();
+        }
+    }
+    short.push_str(&name[segment_start..]);
+    short
+}
+
+/// The reference documentation of a scalar config field.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FieldDoc {
+    /// The path of the field.
+    pub path:        Vec<String>,
+    /// The type of the field without module paths, or `enum` for enum discriminants.
+    pub type_name:   String,
+    /// The [`Debug`] representation of the default value,
+    /// or the default variant name for enum discriminants.
+    pub default:     String,
+    /// The constraints on the value, e.g. `0 ..= 100`,
+    /// or the list of variants for enum discriminants.
+    pub constraints: Option<String>,
+    /// The [`Description`] of the field.
+    pub description: Option<&'static str>,
+}
+
+/// Lists the documentation of all scalar fields managed by [`Docs`],
+/// ordered by root key and then by declaration order within each root.
+pub fn collect(world: &mut World) -> Vec<FieldDoc> {
+    let mut roots: Vec<_> = world
+        .query_filtered::<(Entity, &ConfigNode), With<RootNode>>()
+        .iter(world)
+        .map(|(entity, node)| (node.path.clone(), entity))
+        .collect();
+    roots.sort();
+
+    let mut docs = Vec::new();
+    for (_, root) in roots {
+        collect_node(world, root, &mut docs);
+    }
+    docs
+}
+
+fn collect_node(world: &World, entity: Entity, docs: &mut Vec<FieldDoc>) {
+    let entity = world.entity(entity);
+    if let Some(vtable) = entity.get::<DocVtable>() {
+        let node = entity.get::<ConfigNode>().expect("scalar fields are config nodes");
+        let (default, constraints) = (vtable.describe)(entity);
+        docs.push(FieldDoc {
+            path: node.path.clone(),
+            type_name: vtable.type_name.clone(),
+            default,
+            constraints,
+            description: entity.get::<Description>().map(|&Description(description)| description),
+        });
+    } else if let Some(children) = entity.get::<ChildNodeList>() {
+        for &child in children.iter() {
+            collect_node(world, child, docs);
+        }
+    }
+}
+
+/// Renders the documentation of all fields managed by [`Docs`] as Markdown,
+/// with a heading and a table for each root key.
+pub fn to_markdown(world: &mut World) -> String {
+    let mut output = String::new();
+    let mut current_root = None;
+    for doc in collect(world) {
+        let root = doc.path.first().cloned();
+        if current_root != root {
+            if current_root.is_some() {
+                output.push('\n');
+            }
+            let root = root.as_deref().unwrap_or_default();
+            _ = writeln!(output, "## `{root}`\n");
+            output.push_str("| Path | Type | Default | Constraints | Description |\n");
+            output.push_str("| --- | --- | --- | --- | --- |\n");
+            current_root = Some(root.into());
+        }
+        _ = writeln!(
+            output,
+            "| `{}` | `{}` | `{}` | {} | {} |",
+            escape_cell(&doc.path.join(".")),
+            escape_cell(&doc.type_name),
+            escape_cell(&doc.default),
+            escape_cell(doc.constraints.as_deref().unwrap_or_default()),
+            escape_cell(doc.description.unwrap_or_default()),
+        );
+    }
+    output
+}
+
+/// Escapes text so that it fits in a single Markdown table cell.
+fn escape_cell(text: &str) -> String { text.trim().replace('|', "\\|").replace('\n', "<br>") }
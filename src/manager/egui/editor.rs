@@ -0,0 +1,1215 @@
+//! The egui front-end of the [`Egui`] manager.
+
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::any::type_name;
+use core::hash::Hash;
+
+use bevy_ecs::bundle::Bundle;
+use bevy_ecs::component::Component;
+use bevy_ecs::entity::Entity;
+use bevy_ecs::query::{QueryFilter, With, Without};
+use bevy_ecs::resource::IsResource;
+use bevy_ecs::system::{Local, Query, Res, SystemParam};
+use bevy_ecs::world::{EntityMut, EntityRef};
+use bevy_egui::{EguiContext, egui};
+use hashbrown::hash_map::Entry;
+use hashbrown::{HashMap, HashSet};
+
+use super::{DefaultStyle, Egui, LabelPlacement, Style};
+use crate::manager::{self, Manager};
+use crate::validate::ValidationError;
+use crate::{
+    Advanced, ChildNodeList, ConditionalRelevance, ConfigField, ConfigNode, DefaultFromMetadata,
+    Deprecated, EnumDiscriminant, EnumDiscriminantMetadata, EnumDiscriminantWrapper,
+    FieldGeneration, Lossy, OutOfState, Permission, RootNode, RootOrder, ScalarData,
+    ScalarMetadata, Section,
+};
+
+/// A type erasure vtable attached to each scalar field to describe how to draw it in egui.
+#[derive(Component)]
+struct ScalarDraw<S: Style> {
+    draw_fn: fn(&mut egui::Ui, &mut EntityMut<'_>, &S) -> egui::Response,
+}
+
+impl<T, S> manager::Supports<T> for Egui<S>
+where
+    T: Editable<S> + DefaultFromMetadata + Clone + Send + Sync + 'static,
+    T::Metadata: Clone,
+    S: Style,
+{
+    fn new_entity_for_type(&mut self) -> impl Bundle {
+        (
+            ScalarDraw {
+                draw_fn: |ui, entity, style| {
+                    #[derive(Hash)]
+                    struct FieldIdSalt(Entity);
+
+                    let id_salt = FieldIdSalt(entity.id());
+
+                    field_layout(ui, style, |ui, label_width| {
+                        let label_resp = field_label(ui, entity.as_readonly(), label_width);
+                        field_context_menu(&label_resp, entity);
+
+                        let metadata = entity
+                            .get::<ScalarMetadata<T>>()
+                            .expect(
+                                "caller of new_entity must populate the metadata componentwith \
+                                 the corresponding type",
+                            )
+                            .0
+                            .clone();
+
+                        let mut temp_data = entity
+                            .get_mut::<TempData<T::TempData>>()
+                            .expect("inserted with ScalarDraw");
+                        let mut temp_data = temp_data.0.take();
+
+                        let mut field = entity.get_mut::<ScalarData<T>>().expect(
+                            "caller of new_entity must populate entity with the corresponding \
+                             ScalarData type",
+                        );
+
+                        let resp =
+                            T::show(ui, &mut field.0, &metadata, &mut temp_data, id_salt, style);
+
+                        entity
+                            .get_mut::<TempData<T::TempData>>()
+                            .expect("inserted with ScalarDraw")
+                            .0 = temp_data;
+
+                        if resp.changed() {
+                            entity
+                                .get_mut::<ConfigNode>()
+                                .expect("draw_fn must be called with a ConfigNode entity")
+                                .bump();
+                        }
+                        resp
+                    })
+                    .response
+                },
+            },
+            TempData::<T::TempData>(None),
+            deferred::ScalarView::<S>::of::<T>(),
+            ScalarReset {
+                reset_fn: |entity| {
+                    let metadata = &entity
+                        .get::<ScalarMetadata<T>>()
+                        .expect(
+                            "ScalarReset must be inserted with the corresponding ScalarMetadata",
+                        )
+                        .0;
+                    let value = T::default_from_metadata(metadata);
+                    entity
+                        .get_mut::<ScalarData<T>>()
+                        .expect("ScalarReset must be inserted with the corresponding ScalarData")
+                        .0 = value;
+                },
+            },
+        )
+    }
+}
+
+#[derive(Component)]
+struct TempData<T>(Option<T>);
+
+/// A type erasure vtable attached to each scalar field to reset it to its default value.
+#[derive(Component, Clone, Copy)]
+struct ScalarReset {
+    reset_fn: fn(&mut EntityMut<'_>),
+}
+
+impl ScalarReset {
+    /// Resets the field and bumps its generation.
+    fn apply(self, entity: &mut EntityMut<'_>) {
+        (self.reset_fn)(entity);
+        entity.get_mut::<ConfigNode>().expect("scalar fields are config nodes").bump();
+    }
+}
+
+/// Shows a button to reset a field to its default value, returning whether it was clicked.
+fn reset_button(ui: &mut egui::Ui, enabled: bool) -> bool {
+    ui.add_enabled(enabled, egui::Button::new("⟲").small())
+        .on_hover_text("Reset to default")
+        .clicked()
+}
+
+/// Tests whether a node or any of its descendants has a path containing the lowercase `query`.
+fn matches_search<'a>(
+    entity: EntityRef<'a>,
+    get_entity: &impl Fn(Entity) -> EntityRef<'a>,
+    query: &str,
+) -> bool {
+    if let Some(children) = entity.get::<ChildNodeList>() {
+        children.iter().any(|&child| matches_search(get_entity(child), get_entity, query))
+    } else if entity.contains::<Section>() {
+        // Section headings are meaningless without the surrounding fields.
+        false
+    } else {
+        entity
+            .get::<ConfigNode>()
+            .is_some_and(|node| node.path.join(".").to_lowercase().contains(query))
+    }
+}
+
+/// Describes a [`Deprecated`] field for the hover text of its label.
+fn deprecated_hint(deprecated: &Deprecated) -> String {
+    match &deprecated.replaced_by {
+        Some(path) => alloc::format!("Deprecated, replaced by {}", path.join(".")),
+        None => "Deprecated".into(),
+    }
+}
+
+/// Lays out the label and widget of a scalar field as configured by `style`.
+///
+/// `add_contents` is called with the minimum width of the label.
+fn field_layout<R>(
+    ui: &mut egui::Ui,
+    style: &impl Style,
+    add_contents: impl FnOnce(&mut egui::Ui, Option<f32>) -> R,
+) -> egui::InnerResponse<R> {
+    match style.label_placement() {
+        LabelPlacement::Left => ui.horizontal_top(|ui| add_contents(ui, style.label_width())),
+        LabelPlacement::Above => ui.vertical(|ui| add_contents(ui, None)),
+    }
+}
+
+/// Shows the groups of the editor with the indentation configured by `style`.
+fn indented<R>(
+    ui: &mut egui::Ui,
+    style: &impl Style,
+    add_contents: impl FnOnce(&mut egui::Ui) -> R,
+) -> egui::InnerResponse<R> {
+    ui.vertical(|ui| {
+        if let Some(indent) = style.indent() {
+            ui.spacing_mut().indent = indent;
+        }
+        add_contents(ui)
+    })
+}
+
+/// Shows the label of a scalar field, striking through deprecated fields.
+fn field_label(ui: &mut egui::Ui, entity: EntityRef<'_>, min_width: Option<f32>) -> egui::Response {
+    let node = entity.get::<ConfigNode>().expect("draw_fn must be called with a ConfigNode entity");
+    let mut text = egui::RichText::new(node.path.last().expect("node path must be nonempty"));
+    let deprecated = entity.get::<Deprecated>().map(deprecated_hint);
+    if deprecated.is_some() {
+        text = text.strikethrough();
+    }
+    let label = egui::Label::new(text).sense(egui::Sense::click());
+    let resp = match min_width {
+        Some(width) => {
+            ui.scope(|ui| {
+                ui.set_min_width(width);
+                ui.add(label)
+            })
+            .inner
+        }
+        None => ui.add(label),
+    };
+    match deprecated {
+        Some(hint) => resp.on_hover_text(hint),
+        None => resp,
+    }
+}
+
+/// Shows the context menu entry for copying the path of a field.
+fn copy_path_button(ui: &mut egui::Ui, entity: EntityRef<'_>) {
+    if ui.button("Copy path").clicked() {
+        let node =
+            entity.get::<ConfigNode>().expect("draw_fn must be called with a ConfigNode entity");
+        ui.ctx().copy_text(node.path.join("."));
+        ui.close();
+    }
+}
+
+/// Shows the right-click context menu of a scalar field on `resp`.
+fn field_context_menu(resp: &egui::Response, entity: &mut EntityMut<'_>) {
+    resp.context_menu(|ui| {
+        copy_path_button(ui, entity.as_readonly());
+        pinned::pin_button(ui, entity.as_readonly());
+
+        #[cfg(feature = "serde_json")]
+        json_context_menu(ui, entity);
+    });
+}
+
+/// Shows the context menu entries for copying and pasting values as JSON.
+///
+/// These entries are only available if the app uses a [`Serde`](crate::manager::Serde) manager,
+/// which provides the [`ValueVtable`](crate::manager::serde::json::ValueVtable).
+/// Since egui cannot read the system clipboard on demand,
+/// text pasted from the system clipboard (e.g. with Ctrl+V) while the menu is open
+/// is parsed as JSON and written to the field,
+/// while the "Paste value" button uses the value last copied from this menu.
+#[cfg(feature = "serde_json")]
+fn json_context_menu(ui: &mut egui::Ui, entity: &mut EntityMut<'_>) {
+    use alloc::string::ToString as _;
+
+    use crate::manager::serde::json::ValueVtable;
+
+    let Some(&vtable) = entity.get::<ValueVtable>() else { return };
+    let clipboard_id = egui::Id::new("bevy_mod_config::copied_value");
+
+    if ui.button("Copy value (JSON)").clicked() {
+        if let Ok(value) = vtable.to_value(entity.as_readonly()) {
+            ui.ctx().copy_text(value.to_string());
+            ui.ctx().data_mut(|data| data.insert_temp(clipboard_id, value));
+        }
+        ui.close();
+    }
+
+    let pasted = ui.input(|input| {
+        input.events.iter().find_map(|event| match event {
+            egui::Event::Paste(text) => serde_json::from_str::<serde_json::Value>(text).ok(),
+            _ => None,
+        })
+    });
+    // Values of incompatible types are rejected by the deserializer.
+    if let Some(value) = pasted
+        && vtable.set_value(entity, value).is_ok()
+    {
+        ui.close();
+    }
+
+    let copied = ui.ctx().data(|data| data.get_temp::<serde_json::Value>(clipboard_id));
+    let paste = ui
+        .add_enabled(copied.is_some(), egui::Button::new("Paste value"))
+        .on_hover_text("Press Ctrl+V to paste from the system clipboard");
+    if paste.clicked() {
+        if let Some(value) = copied {
+            // Values of incompatible types are rejected by the deserializer.
+            _ = vtable.set_value(entity, value);
+        }
+        ui.close();
+    }
+}
+
+/// A [`SystemParam`] to display config editor UI.
+///
+/// This system requires [full mutable access](EntityMut) to config entities.
+/// This may conflict with other queries in the same system.
+/// If the compiler suggests adding [`Without`] to a query,
+/// you can pass it as the `F` type parameter to this struct,
+/// or use [`DeferredDisplay`] which only requires read-only access:
+///
+/// ```
+/// use bevy_ecs::error::Result;
+/// use bevy_ecs::hierarchy::Children;
+/// use bevy_ecs::query::Without;
+/// use bevy_ecs::system::Query;
+/// use bevy_egui::{EguiContexts, egui};
+/// use bevy_mod_config::manager::egui::Display;
+///
+/// pub fn config_editor_system(
+///     children_query: Query<&Children>,
+///     mut ctxs: EguiContexts,
+///     mut display: Display<Without<Children>>,
+/// ) -> Result {
+///     let ctx = ctxs.ctx_mut()?;
+///     egui::Window::new("Config Editor").show(ctx, |ui| {
+///         println!("We can still use children_query here: {:?}", children_query.iter().count());
+///         display.show(ui);
+///     });
+///     Ok(())
+/// }
+/// ```
+#[derive(SystemParam)]
+pub struct Display<'w, 's, F: QueryFilter + 'static = (), M: Manager = ()> {
+    manager:    Option<Res<'w, manager::Instance<M>>>,
+    node_query: NodeQuery<'w, 's, F>,
+    root_query: Query<'w, 's, Entity, With<RootNode>>,
+    options:    Local<'s, DisplayOptions>,
+    highlights: Local<'s, ChangeHighlights>,
+    /// The keys of the roots whose windows were closed in [`Display::show_windows`].
+    closed:     Local<'s, HashSet<String>>,
+}
+
+/// Options controlling how [`Display`] renders the config tree.
+///
+/// The options are stored per system and persist across frames.
+#[derive(Debug, Clone, Default)]
+#[expect(clippy::struct_excessive_bools, reason = "each option is an independent toggle")]
+pub struct DisplayOptions {
+    /// Whether to show nodes that are irrelevant under the current enum variants.
+    ///
+    /// If `false` (the default), the subtrees of inactive enum variants are hidden.
+    /// If `true`, they are shown disabled,
+    /// so that users can browse the options under other variants before switching.
+    pub show_irrelevant:    bool,
+    /// Whether to show [`Advanced`] nodes.
+    ///
+    /// If `false` (the default), advanced nodes and their descendants are hidden.
+    /// The editor shows a "Show advanced" checkbox to toggle this option
+    /// if the displayed config contains any advanced nodes.
+    pub show_advanced:      bool,
+    /// Whether to show [`Deprecated`] nodes.
+    ///
+    /// If `false` (the default), deprecated nodes and their descendants are hidden.
+    /// If `true`, their labels are struck through
+    /// so that users can review the old values during a transition period.
+    pub show_deprecated:    bool,
+    /// The role of the user viewing the editor.
+    ///
+    /// Nodes that require a more privileged [`Permission`] than this role are shown disabled.
+    /// Defaults to [`Permission::Player`].
+    pub role:               Permission,
+    /// Only shows fields whose dot-separated path contains this text, ignoring case.
+    ///
+    /// If empty (the default), all fields are shown.
+    /// Otherwise, groups containing matching fields are expanded.
+    pub search:             String,
+    /// Only shows the root with this key.
+    ///
+    /// If `None` (the default), all roots are shown.
+    pub root:               Option<String>,
+    /// Whether to show a button next to each field to reset it to its default value.
+    pub show_reset:         bool,
+    /// The number of seconds to highlight fields
+    /// whose values were changed outside the editor,
+    /// e.g. by loading a file, network sync or scripts.
+    ///
+    /// If zero (the default), changes are not highlighted.
+    pub highlight_duration: f32,
+}
+
+/// Tracks the generations of the fields shown by an editor
+/// to highlight fields changed by other sources.
+#[derive(Default)]
+struct ChangeHighlights {
+    fields: HashMap<Entity, FieldHighlight>,
+}
+
+struct FieldHighlight {
+    /// The generation of the field when it was last shown.
+    seen:       FieldGeneration,
+    /// Whether the next change was made by the editor itself.
+    expected:   bool,
+    /// The egui time at which the last external change was observed.
+    changed_at: Option<f64>,
+}
+
+impl ChangeHighlights {
+    /// Records an external change if the generation of the field
+    /// differs from when it was last shown.
+    fn observe(&mut self, id: Entity, generation: FieldGeneration, now: f64) {
+        match self.fields.entry(id) {
+            Entry::Occupied(mut entry) => {
+                let field = entry.get_mut();
+                if field.seen != generation {
+                    if !field.expected {
+                        field.changed_at = Some(now);
+                    }
+                    field.seen = generation;
+                    field.expected = false;
+                }
+            }
+            Entry::Vacant(entry) => {
+                entry.insert(FieldHighlight {
+                    seen:       generation,
+                    expected:   false,
+                    changed_at: None,
+                });
+            }
+        }
+    }
+
+    /// Updates the generation of a field after the editor has changed it immediately.
+    fn acknowledge(&mut self, id: Entity, generation: FieldGeneration) {
+        if let Some(field) = self.fields.get_mut(&id) {
+            field.seen = generation;
+        }
+    }
+
+    /// Marks the next change of a field as made by the editor,
+    /// for changes applied after the editor has been shown.
+    fn expect_change(&mut self, id: Entity) {
+        if let Some(field) = self.fields.get_mut(&id) {
+            field.expected = true;
+        }
+    }
+
+    /// Paints the fading highlight of a recently changed field over `rect`.
+    fn paint(&mut self, ui: &egui::Ui, id: Entity, rect: egui::Rect, duration: f32) {
+        let Some(field) = self.fields.get_mut(&id) else { return };
+        let Some(changed_at) = field.changed_at else { return };
+        let elapsed = ui.input(|input| input.time) - changed_at;
+        if elapsed >= f64::from(duration) {
+            field.changed_at = None;
+            return;
+        }
+
+        #[expect(clippy::cast_possible_truncation, reason = "only used as a color factor")]
+        let alpha = 1.0 - (elapsed / f64::from(duration)) as f32;
+        let color = ui.visuals().selection.bg_fill.gamma_multiply(alpha * 0.5);
+        ui.painter().rect_filled(rect.expand(1.0), 2.0, color);
+        ui.ctx().request_repaint();
+    }
+}
+
+type NodeQuery<'w, 's, F> =
+    Query<'w, 's, EntityMut<'static>, (Without<EguiContext>, Without<IsResource>, F)>;
+
+impl<F, M> Display<'_, '_, F, M>
+where
+    F: QueryFilter + 'static,
+    M: Manager,
+{
+    /// Returns the [`DisplayOptions`] used by this system.
+    pub fn options(&mut self) -> &mut DisplayOptions { &mut self.options }
+
+    /// Resets all fields under the root with key `root`, or all fields if `None`,
+    /// to their default values.
+    ///
+    /// Fields hidden or disabled by the [`DisplayOptions`] are also reset.
+    pub fn reset_all(&mut self, root: Option<&str>) {
+        for mut entity in &mut self.node_query {
+            let Some(&reset) = entity.get::<ScalarReset>() else { continue };
+            let node = entity.get::<ConfigNode>().expect("scalar fields are config nodes");
+            if root.is_none_or(|root| node.path.first().is_some_and(|first| first == root)) {
+                reset.apply(&mut entity);
+            }
+        }
+    }
+
+    /// Shows the config editor UI in `ui`,
+    /// assuming a [`DefaultStyle`] style.
+    ///
+    /// # Panics
+    /// This function panics if the world was not initialized with (a tuple containing)
+    /// an <code>[Egui]&lt;[DefaultStyle]&gt;</code> manager.
+    pub fn show(&mut self, ui: &mut egui::Ui) -> egui::Response {
+        self.show_default::<DefaultStyle>(ui)
+    }
+
+    /// Shows the config editor UI in `ui`
+    /// with a [`Style`] that implements [`Default`].
+    ///
+    /// # Panics
+    /// This function panics if the world was not initialized with (a tuple containing) an [`Egui<S>`] manager.
+    pub fn show_default<S>(&mut self, ui: &mut egui::Ui) -> egui::Response
+    where
+        S: Style + Default,
+    {
+        Self::show_with_style(
+            ui,
+            &mut self.node_query,
+            &self.root_query,
+            &mut self.options,
+            &mut self.highlights,
+            &S::default(),
+        )
+    }
+
+    /// Shows the config editor UI in `ui` for a non-default style.
+    ///
+    /// # Panics
+    /// This function panics if the world was not initialized with manager type `M`.
+    pub fn show_with<S: Style>(
+        &mut self,
+        ui: &mut egui::Ui,
+        get_manager: impl FnOnce(&M) -> &Egui<S>,
+    ) -> egui::Response {
+        let Some(manager) = self.manager.as_ref() else {
+            panic!("World was not initialized with manager type {}", type_name::<M>());
+        };
+        let style = &get_manager(manager).style;
+        Self::show_with_style(
+            ui,
+            &mut self.node_query,
+            &self.root_query,
+            &mut self.options,
+            &mut self.highlights,
+            style,
+        )
+    }
+
+    /// Shows one closable egui window per root in `ctx`,
+    /// titled with the root key and assuming a [`DefaultStyle`] style.
+    ///
+    /// This is a quick way to add a tweak UI to debug builds without any layout code.
+    /// Closed windows stay closed until [`reopen_windows`](Self::reopen_windows) is called.
+    ///
+    /// ```
+    /// use bevy_ecs::error::Result;
+    /// use bevy_egui::EguiContexts;
+    /// use bevy_mod_config::manager::egui::Display;
+    ///
+    /// pub fn config_windows_system(mut ctxs: EguiContexts, mut display: Display) -> Result {
+    ///     display.show_windows(ctxs.ctx_mut()?);
+    ///     Ok(())
+    /// }
+    /// ```
+    ///
+    /// # Panics
+    /// This function panics if the world was not initialized with (a tuple containing)
+    /// an <code>[Egui]&lt;[DefaultStyle]&gt;</code> manager.
+    pub fn show_windows(&mut self, ctx: &egui::Context) {
+        let style = DefaultStyle::default();
+        let node_query = &mut self.node_query;
+        pinned::sync(ctx, node_query);
+        let roots = sorted_roots(&self.root_query, |root| node_query.get(root).ok(), &self.options);
+        self.highlights.fields.retain(|&entity, _| node_query.contains(entity));
+
+        #[cfg(feature = "bevy_input")]
+        update_key_conflicts(ctx, node_query.iter());
+
+        for root in roots {
+            let entity = node_query.get(root).expect("roots are config nodes");
+            let node = entity.get::<ConfigNode>().expect("root node must be a ConfigNode");
+            let key = node.path.join(".");
+            if self.closed.contains(&key) {
+                continue;
+            }
+
+            let mut open = true;
+            egui::Window::new(key.as_str())
+                .id(egui::Id::new(("bevy_mod_config root window", root)))
+                .open(&mut open)
+                .show(ctx, |ui| {
+                    indented(ui, &style, |ui| {
+                        show_node(
+                            ui,
+                            node_query,
+                            root,
+                            &self.options,
+                            &mut self.highlights,
+                            true,
+                            &style,
+                        );
+                    });
+                });
+            if !open {
+                self.closed.insert(key);
+            }
+        }
+    }
+
+    /// Reopens the windows closed in [`show_windows`](Self::show_windows).
+    pub fn reopen_windows(&mut self) { self.closed.clear(); }
+
+    fn show_with_style<S: Style>(
+        ui: &mut egui::Ui,
+        node_query: &mut NodeQuery<F>,
+        root_query: &Query<Entity, With<RootNode>>,
+        options: &mut DisplayOptions,
+        highlights: &mut ChangeHighlights,
+        style: &S,
+    ) -> egui::Response {
+        pinned::sync(ui.ctx(), node_query);
+        advanced_toggle(ui, node_query.iter(), options);
+        let roots = sorted_roots(root_query, |root| node_query.get(root).ok(), options);
+        highlights.fields.retain(|&entity, _| node_query.contains(entity));
+
+        #[cfg(feature = "bevy_input")]
+        update_key_conflicts(ui.ctx(), node_query.iter());
+
+        indented(ui, style, |ui| {
+            for root in roots {
+                show_node(ui, node_query, root, options, highlights, true, style);
+            }
+        })
+        .response
+    }
+}
+
+/// Shows a "Show advanced" checkbox for [`DisplayOptions::show_advanced`]
+/// if any of `entities` under the roots selected by [`DisplayOptions::root`] is [`Advanced`].
+fn advanced_toggle<'a>(
+    ui: &mut egui::Ui,
+    mut entities: impl Iterator<Item = EntityRef<'a>>,
+    options: &mut DisplayOptions,
+) {
+    let has_advanced = entities.any(|entity| {
+        entity.contains::<Advanced>()
+            && entity.get::<ConfigNode>().is_some_and(|node| {
+                options.root.as_ref().is_none_or(|key| node.path.first() == Some(key))
+            })
+    });
+    if has_advanced {
+        ui.checkbox(&mut options.show_advanced, "Show advanced");
+    }
+}
+
+/// Returns the root nodes selected by [`DisplayOptions::root`]
+/// sorted by their [`RootOrder`], then by key.
+///
+/// Sorting keeps the focus order stable across frames and runs,
+/// which is required for non-pointer navigation.
+fn sorted_roots<'a>(
+    root_query: &Query<Entity, With<RootNode>>,
+    get_entity: impl Fn(Entity) -> Option<EntityRef<'a>>,
+    options: &DisplayOptions,
+) -> Vec<Entity> {
+    let mut roots: Vec<_> = root_query
+        .iter()
+        .map(|root| {
+            let entity =
+                get_entity(root).expect("config node must remain in the world once spawned");
+            let node = entity.get::<ConfigNode>().expect("root node must be a ConfigNode");
+            let order = entity.get::<RootOrder>().copied().unwrap_or_default();
+            (order.sort_key(), node.path.clone(), root)
+        })
+        .filter(|(_, path, _)| options.root.as_ref().is_none_or(|key| path.first() == Some(key)))
+        .collect();
+    roots.sort();
+    roots.into_iter().map(|(_, _, root)| root).collect()
+}
+
+/// Stores the conflicts among the key bindings in `entities`
+/// for the warning badges of the key binding editors.
+#[cfg(feature = "bevy_input")]
+fn update_key_conflicts<'a>(ctx: &egui::Context, entities: impl Iterator<Item = EntityRef<'a>>) {
+    types_impl::store_key_conflicts(
+        ctx,
+        crate::types::key_conflicts(entities.filter_map(|entity| {
+            let node = entity.get::<ConfigNode>()?;
+            let binding = entity.get::<ScalarData<crate::types::KeyBinding>>()?;
+            Some((node, binding.0))
+        })),
+    );
+}
+
+/// Resolves whether a node is shown under `options`.
+///
+/// Returns `None` if the node is hidden,
+/// otherwise whether the node is enabled given the `enabled` state of its parent.
+fn node_enabled<'a>(
+    entity: EntityRef<'a>,
+    get_entity: impl Fn(Entity) -> EntityRef<'a>,
+    options: &DisplayOptions,
+    mut enabled: bool,
+) -> Option<bool> {
+    if !options.search.is_empty()
+        && !matches_search(entity, &get_entity, &options.search.to_lowercase())
+    {
+        return None;
+    }
+    if !options.show_advanced && entity.contains::<Advanced>() {
+        return None;
+    }
+    if !options.show_deprecated && entity.contains::<Deprecated>() {
+        return None;
+    }
+    // Permissions of ancestors are propagated through `enabled`.
+    if let Some(&permission) = entity.get::<Permission>()
+        && !options.role.allows(permission)
+    {
+        enabled = false;
+    }
+    if let Some(&ConditionalRelevance { dependency, is_entity_relevant }) = entity.get()
+        && !is_entity_relevant(get_entity(dependency))
+    {
+        if !options.show_irrelevant {
+            // If the dependency is not relevant, skip this node.
+            return None;
+        }
+        enabled = false;
+    }
+    if entity.contains::<OutOfState>() {
+        if !options.show_irrelevant {
+            return None;
+        }
+        enabled = false;
+    }
+    Some(enabled)
+}
+
+/// The collapsing header of a non-scalar node.
+struct GroupHeader {
+    text:  egui::RichText,
+    hint:  Option<String>,
+    error: Option<String>,
+    /// Forces the header open, e.g. to reveal search results.
+    open:  Option<bool>,
+}
+
+impl GroupHeader {
+    fn new(entity: EntityRef, enabled: bool, options: &DisplayOptions) -> Self {
+        let node = entity.get::<ConfigNode>().expect("show_node must provide a ConfigNode");
+        let path = node.path.last().expect("node path must be nonempty").clone();
+        // Keep the header of irrelevant groups interactive
+        // so that their contents can still be expanded for preview.
+        let mut text = egui::RichText::new(path);
+        if !enabled {
+            text = text.weak();
+        }
+        let hint = entity.get::<Deprecated>().map(deprecated_hint);
+        if hint.is_some() {
+            text = text.strikethrough();
+        }
+        let error = entity.get::<ValidationError>().map(|error| error.0.clone());
+        let open = (!options.search.is_empty()).then_some(true);
+        Self { text, hint, error, open }
+    }
+
+    fn show(self, ui: &mut egui::Ui, add_contents: impl FnOnce(&mut egui::Ui)) {
+        let resp = egui::CollapsingHeader::new(self.text).open(self.open).show(ui, add_contents);
+        if let Some(hint) = self.hint {
+            resp.header_response.on_hover_text(hint);
+        }
+        // Shown outside the collapsing body so that it remains visible when collapsed.
+        if let Some(error) = self.error {
+            show_warning(ui, &error);
+        }
+    }
+}
+
+/// Shows a [`Section`] node as a heading above the sibling fields that follow it.
+fn show_section(ui: &mut egui::Ui, entity: EntityRef, enabled: bool) {
+    let node = entity.get::<ConfigNode>().expect("show_node must provide a ConfigNode");
+    let path = node.path.last().expect("node path must be nonempty").clone();
+    let mut text = egui::RichText::new(path).strong();
+    if !enabled {
+        text = text.weak();
+    }
+    ui.separator();
+    ui.label(text);
+}
+
+fn show_node<F: QueryFilter + 'static, S: Style>(
+    ui: &mut egui::Ui,
+    node_query: &mut Query<EntityMut, F>,
+    id: Entity,
+    options: &DisplayOptions,
+    highlights: &mut ChangeHighlights,
+    enabled: bool,
+    style: &S,
+) {
+    let entity = node_query.get(id).expect("config node must remain in the world once spawned");
+    let get_node = |node| match node_query.get(node) {
+        Ok(entity) => entity,
+        Err(err) => {
+            panic!("Config node {id:?} references invalid node {node:?}: {err}")
+        }
+    };
+    let Some(enabled) = node_enabled(entity, get_node, options, enabled) else { return };
+
+    let mut entity =
+        node_query.get_mut(id).expect("config node must remain in the world once spawned");
+    if let Some(&ScalarDraw { draw_fn }) = entity.get() {
+        let node = entity.get::<ConfigNode>().expect("show_node must provide a ConfigNode");
+        highlights.observe(id, node.generation, ui.input(|input| input.time));
+
+        let rect = match entity.get::<ScalarReset>().copied().filter(|_| options.show_reset) {
+            Some(reset) => {
+                ui.horizontal_top(|ui| {
+                    ui.add_enabled_ui(enabled, |ui| draw_fn(ui, &mut entity, style));
+                    if reset_button(ui, enabled) {
+                        reset.apply(&mut entity);
+                    }
+                })
+                .response
+                .rect
+            }
+            None => ui.add_enabled_ui(enabled, |ui| draw_fn(ui, &mut entity, style)).response.rect,
+        };
+
+        let node = entity.get::<ConfigNode>().expect("show_node must provide a ConfigNode");
+        highlights.acknowledge(id, node.generation);
+        highlights.paint(ui, id, rect, options.highlight_duration);
+        show_validation(ui, entity.as_readonly());
+    } else if let Some(children) = entity.get::<ChildNodeList>() {
+        let children: Vec<_> = children.iter().copied().collect();
+        GroupHeader::new(entity.as_readonly(), enabled, options).show(ui, |ui| {
+            for child in children {
+                show_node(ui, node_query, child, options, highlights, enabled, style);
+            }
+        });
+    } else if entity.contains::<Section>() {
+        show_section(ui, entity.as_readonly(), enabled);
+    }
+}
+
+/// Shows a validation error below an editor widget.
+///
+/// [`Editable`] implementations should call this
+/// when the user input cannot be applied to the field,
+/// so that users can tell why their input did not take effect.
+pub fn show_error(ui: &mut egui::Ui, error: &str) -> egui::Response {
+    let color = ui.visuals().error_fg_color;
+    ui.label(egui::RichText::new(error).color(color).small())
+}
+
+/// Shows a warning icon with the [`ValidationError`] of a field, if any.
+fn show_validation(ui: &mut egui::Ui, entity: EntityRef) {
+    if let Some(error) = entity.get::<ValidationError>() {
+        show_warning(ui, &error.0);
+    }
+}
+
+/// Shows a validation warning about a value that was applied but is invalid.
+///
+/// Unlike [`show_error`], this is used for values that have taken effect,
+/// e.g. violations reported by the [`validate`](crate::validate) module.
+pub fn show_warning(ui: &mut egui::Ui, warning: &str) -> egui::Response {
+    let color = ui.visuals().warn_fg_color;
+    ui.label(egui::RichText::new(alloc::format!("⚠ {warning}")).color(color).small())
+}
+
+/// Implements the config editor UI for each scalar config field type.
+///
+/// Note: Since enum discriminants are [wrapped](EnumDiscriminantWrapper) in `ScalarData`,
+/// enum discriminants do not implement this trait directly.
+/// However, all other scalar config field types do implement this trait,
+/// and this is the intended way to extend [`Egui`] support for other types.
+///
+/// Fields of types without an editor cannot be used with [`Egui`]:
+///
+/// ```compile_fail
+/// # use bevy_app::App;
+/// use bevy_mod_config::manager::Egui;
+/// use bevy_mod_config::{AppExt, BareField, Config};
+///
+/// #[derive(Config)]
+/// struct Session {
+///     #[config(default = Some((0, 0)))]
+///     window_pos: BareField<(i32, i32)>,
+/// }
+///
+/// // error: type `BareField<(i32, i32)>` is not Editable for manager `Egui<DefaultStyle>`
+/// App::new().init_config::<Egui, Session>("session");
+/// ```
+///
+/// Wrap such fields in [`Lossy`] to omit them from the editor instead:
+///
+/// ```
+/// # use bevy_app::App;
+/// use bevy_mod_config::manager::Egui;
+/// use bevy_mod_config::{AppExt, BareField, Config, Lossy};
+///
+/// #[derive(Config)]
+/// struct Session {
+///     #[config(default = Some((0, 0)))]
+///     window_pos: Lossy<BareField<(i32, i32)>>,
+/// }
+///
+/// App::new().init_config::<Egui, Session>("session");
+/// ```
+#[diagnostic::on_unimplemented(
+    message = "type `{Self}` is not Editable for manager `Egui<{S}>`",
+    label = "no egui editor for `{Self}`",
+    note = "implement `Editable<{S}>` for `{Self}` to provide an editor widget",
+    note = "or wrap the field type in `bevy_mod_config::Lossy` to omit it from the editor"
+)]
+pub trait Editable<S: Style>: ConfigField {
+    /// Temporary state used by the editor UI.
+    /// See [`Editable::show`] for more information.
+    type TempData: Send + Sync + 'static;
+
+    /// Displays the editor UI for the scalar field in `ui`.
+    ///
+    /// `value` contains the current value of the field,
+    /// and may be modified by the editor if changed through this UI.
+    /// If the field is changed, the returned response must be
+    /// [marked as changed](egui::Response::mark_changed).
+    ///
+    /// `temp` stores temporary state about this UI component in the world,
+    /// and will be passed as-is in the next call to the same field.
+    ///
+    /// `id_salt` provides a unique hash for this field,
+    /// used for the `id_salt` function in many egui widgets.
+    fn show(
+        ui: &mut egui::Ui,
+        value: &mut Self,
+        metadata: &Self::Metadata,
+        temp: &mut Option<Self::TempData>,
+        id_salt: impl Hash,
+        style: &S,
+    ) -> egui::Response;
+}
+
+/// Shows the editor widget of `T` followed by `unit` if specified.
+///
+/// This is used by the [`Editable`] implementations generated by
+/// [`impl_config_newtype`](crate::impl_config_newtype),
+/// and may also be used by custom implementations for types with a unit.
+pub fn show_with_unit<T: Editable<S>, S: Style>(
+    ui: &mut egui::Ui,
+    value: &mut T,
+    metadata: &T::Metadata,
+    temp: &mut Option<T::TempData>,
+    id_salt: impl Hash,
+    style: &S,
+    unit: Option<&str>,
+) -> egui::Response {
+    match unit {
+        Some(unit) => {
+            ui.horizontal(|ui| {
+                let resp = T::show(ui, value, metadata, temp, id_salt, style);
+                ui.label(unit);
+                resp
+            })
+            .inner
+        }
+        None => T::show(ui, value, metadata, temp, id_salt, style),
+    }
+}
+
+pub mod deferred;
+pub use deferred::DeferredDisplay;
+
+mod number_impl;
+pub use number_impl::{NumericLike, evaluate_expression};
+
+#[cfg(feature = "chrono")]
+mod chrono_impl;
+
+mod types_impl;
+#[cfg(feature = "bevy_window")]
+pub use types_impl::detect_resolutions;
+
+pub mod nav;
+
+#[cfg(feature = "serde_json")]
+pub mod diff;
+
+pub mod pinned;
+
+pub mod window;
+pub use window::{ConfigWindow, ConfigWindowPlugin, Placement};
+
+impl Editable<DefaultStyle> for String {
+    type TempData = ();
+
+    fn show(
+        ui: &mut egui::Ui,
+        value: &mut Self,
+        metadata: &Self::Metadata,
+        _: &mut Option<()>,
+        id_salt: impl Hash,
+        _: &DefaultStyle,
+    ) -> egui::Response {
+        let editor = if metadata.multiline {
+            egui::TextEdit::multiline(value)
+        } else {
+            egui::TextEdit::singleline(value)
+        }
+        .char_limit(metadata.max_length.unwrap_or(usize::MAX))
+        .id_salt(id_salt);
+        ui.add(editor)
+    }
+}
+
+impl Editable<DefaultStyle> for bool {
+    type TempData = ();
+
+    fn show(
+        ui: &mut egui::Ui,
+        value: &mut Self,
+        _: &Self::Metadata,
+        _: &mut Option<()>,
+        _: impl Hash,
+        _: &DefaultStyle,
+    ) -> egui::Response {
+        ui.add(egui::Checkbox::without_text(value))
+    }
+}
+
+#[cfg(feature = "url")]
+impl Editable<DefaultStyle> for url::Url {
+    type TempData = String;
+
+    fn show(
+        ui: &mut egui::Ui,
+        value: &mut Self,
+        metadata: &Self::Metadata,
+        temp_data: &mut Option<String>,
+        id_salt: impl Hash,
+        _: &DefaultStyle,
+    ) -> egui::Response {
+        let mut text = temp_data.take().unwrap_or_else(|| value.as_str().into());
+        let inner = ui.vertical(|ui| {
+            let resp = ui.add(egui::TextEdit::singleline(&mut text).id_salt(id_salt));
+            // Invalid input is kept in the editor along with the error
+            // until the user fixes it.
+            let parsed = metadata.parse(&text);
+            match &parsed {
+                Ok(url) if resp.changed() => *value = url.clone(),
+                Ok(_) => {}
+                Err(error) => _ = show_error(ui, error),
+            }
+            (resp, parsed.is_ok())
+        });
+        let (resp, valid) = inner.inner;
+        if !(resp.lost_focus() && valid) {
+            *temp_data = Some(text);
+        }
+        resp
+    }
+}
+
+/// Each element is drawn with a drag handle for reordering and a remove button,
+/// followed by a button that appends a new element with the default value
+/// from [`VecMetadata::element`](crate::impls::VecMetadata::element).
+impl<T> Editable<DefaultStyle> for Vec<T>
+where
+    T: Editable<DefaultStyle> + DefaultFromMetadata + Clone + Send + Sync,
+{
+    type TempData = Vec<Option<T::TempData>>;
+
+    fn show(
+        ui: &mut egui::Ui,
+        value: &mut Self,
+        metadata: &Self::Metadata,
+        temp: &mut Option<Self::TempData>,
+        id_salt: impl Hash,
+        style: &DefaultStyle,
+    ) -> egui::Response {
+        let temp = temp.get_or_insert_with(Vec::new);
+        temp.resize_with(value.len(), || None);
+        let id = egui::Id::new(id_salt);
+
+        let mut changed = false;
+        let mut moved = None;
+        let mut removed = None;
+
+        let mut resp = ui
+            .vertical(|ui| {
+                for (index, (item, item_temp)) in value.iter_mut().zip(&mut *temp).enumerate() {
+                    let row = ui.horizontal(|ui| {
+                        ui.dnd_drag_source(id.with(("handle", index)), index, |ui| {
+                            ui.label("☰");
+                        });
+                        let item_resp =
+                            T::show(ui, item, &metadata.element, item_temp, (id, index), style);
+                        changed |= item_resp.changed();
+                        if ui.small_button("✖").on_hover_text("Remove").clicked() {
+                            removed = Some(index);
+                        }
+                    });
+                    if let Some(from) = row.response.dnd_release_payload::<usize>() {
+                        moved = Some((*from, index));
+                    }
+                }
+
+                let can_add = metadata.max_length.is_none_or(|max| value.len() < max);
+                if ui.add_enabled(can_add, egui::Button::new("+ Add")).clicked() {
+                    value.push(T::default_from_metadata(&metadata.element));
+                    temp.push(None);
+                    changed = true;
+                }
+            })
+            .response;
+
+        if let Some((from, to)) = moved
+            && from != to
+        {
+            let item = value.remove(from);
+            value.insert(to, item);
+            let item_temp = temp.remove(from);
+            temp.insert(to, item_temp);
+            changed = true;
+        }
+        if let Some(index) = removed {
+            value.remove(index);
+            temp.remove(index);
+            changed = true;
+        }
+
+        if changed {
+            resp.mark_changed();
+        }
+        resp
+    }
+}
+
+/// [`Lossy`] fields are not shown in the editor.
+impl<T: Send + Sync + 'static> manager::Supports<Lossy<T>> for Egui<DefaultStyle> {
+    fn new_entity_for_type(&mut self) -> impl Bundle {}
+}
+
+impl<T> manager::Supports<EnumDiscriminantWrapper<T>> for Egui<DefaultStyle>
+where
+    T: EnumDiscriminant<Metadata = EnumDiscriminantMetadata<T>>,
+{
+    fn new_entity_for_type(&mut self) -> impl Bundle {
+        let draw = ScalarDraw::<DefaultStyle> {
+            draw_fn: |ui, entity, _| {
+                #[derive(Hash)]
+                struct FieldIdSalt(Entity);
+
+                let id_salt = FieldIdSalt(entity.id());
+
+                ui.horizontal_top(|ui| {
+                    let mut field =
+                        entity.get_mut::<ScalarData<EnumDiscriminantWrapper<T>>>().expect(
+                            "caller of new_entity must populate entity with the corresponding \
+                             ScalarData type",
+                        );
+
+                    let resp = egui::ComboBox::from_id_salt(id_salt)
+                        .selected_text(field.0.0.name())
+                        .show_ui(ui, |ui| {
+                            for variant in T::VARIANTS {
+                                ui.selectable_value(&mut field.0.0, *variant, variant.name());
+                            }
+                        })
+                        .response;
+                    field_context_menu(&resp, entity);
+
+                    if resp.changed() {
+                        entity
+                            .get_mut::<ConfigNode>()
+                            .expect("draw_fn must be called with a ConfigNode entity")
+                            .bump();
+                    }
+                    resp
+                })
+                .response
+            },
+        };
+        let reset = ScalarReset {
+            reset_fn: |entity| {
+                let default = entity
+                    .get::<ScalarMetadata<T>>()
+                    .expect("ScalarReset must be inserted with the corresponding ScalarMetadata")
+                    .0
+                    .default;
+                entity
+                    .get_mut::<ScalarData<EnumDiscriminantWrapper<T>>>()
+                    .expect("ScalarReset must be inserted with the corresponding ScalarData")
+                    .0 = EnumDiscriminantWrapper(default);
+            },
+        };
+        (draw, reset)
+    }
+}
+
+#[cfg(feature = "bevy_color")]
+impl Editable<DefaultStyle> for bevy_color::Color {
+    type TempData = ();
+    fn show(
+        ui: &mut egui::Ui,
+        value: &mut Self,
+        metadata: &Self::Metadata,
+        _: &mut Option<()>,
+        _: impl Hash,
+        _: &DefaultStyle,
+    ) -> egui::Response {
+        use bevy_color::ColorToPacked;
+        use bevy_egui::egui::color_picker::{self, color_edit_button_srgba};
+
+        let [r, g, b, a] = value.to_srgba().to_u8_array();
+        let mut color32 = egui::Color32::from_rgba_unmultiplied(r, g, b, a);
+        let resp = color_edit_button_srgba(
+            ui,
+            &mut color32,
+            if metadata.alpha_blend {
+                if metadata.alpha_additive {
+                    color_picker::Alpha::BlendOrAdditive
+                } else {
+                    color_picker::Alpha::OnlyBlend
+                }
+            } else {
+                color_picker::Alpha::Opaque
+            },
+        );
+
+        if resp.changed() {
+            let [r, g, b, a] = color32.to_array();
+            *value = bevy_color::Color::srgba_u8(r, g, b, a);
+        }
+        resp
+    }
+}
@@ -0,0 +1,118 @@
+use core::hash::Hash;
+
+use bevy_egui::egui;
+use chrono::{Datelike, NaiveDate, NaiveTime, Timelike};
+
+use super::{DefaultStyle, Editable};
+
+/// Draws a drag value for a two-digit date or time component.
+fn component(ui: &mut egui::Ui, value: &mut u32, max: u32) -> egui::Response {
+    ui.add(
+        egui::DragValue::new(value)
+            .range(0..=max)
+            .custom_formatter(|n, _| alloc::format!("{n:02}"))
+            .speed(0.1),
+    )
+}
+
+fn show_time(ui: &mut egui::Ui, value: &mut NaiveTime) -> egui::Response {
+    let (mut hour, mut minute, mut second) = (value.hour(), value.minute(), value.second());
+    let mut resp = component(ui, &mut hour, 23);
+    ui.label(":");
+    resp |= component(ui, &mut minute, 59);
+    ui.label(":");
+    resp |= component(ui, &mut second, 59);
+
+    if resp.changed()
+        && let Some(time) = NaiveTime::from_hms_opt(hour, minute, second)
+    {
+        *value = time;
+    }
+    resp
+}
+
+fn show_date(ui: &mut egui::Ui, value: &mut NaiveDate) -> egui::Response {
+    let (mut year, mut month, mut day) = (value.year(), value.month(), value.day());
+    let mut resp = ui.add(egui::DragValue::new(&mut year).range(1..=9999).speed(0.1));
+    ui.label("-");
+    resp |= ui.add(
+        egui::DragValue::new(&mut month)
+            .range(1..=12)
+            .custom_formatter(|n, _| alloc::format!("{n:02}"))
+            .speed(0.1),
+    );
+    ui.label("-");
+    resp |= component(ui, &mut day, 31);
+
+    if resp.changed() {
+        // Clamp the day to the length of the selected month,
+        // e.g. when switching from January 31 to February.
+        let date = (1..=day.max(1)).rev().find_map(|day| NaiveDate::from_ymd_opt(year, month, day));
+        if let Some(date) = date {
+            *value = date;
+        }
+    }
+    resp
+}
+
+impl Editable<DefaultStyle> for NaiveTime {
+    type TempData = ();
+
+    fn show(
+        ui: &mut egui::Ui,
+        value: &mut Self,
+        _: &Self::Metadata,
+        _: &mut Option<()>,
+        id_salt: impl Hash,
+        _: &DefaultStyle,
+    ) -> egui::Response {
+        ui.push_id(id_salt, |ui| ui.horizontal(|ui| show_time(ui, value)).inner).inner
+    }
+}
+
+impl Editable<DefaultStyle> for NaiveDate {
+    type TempData = ();
+
+    fn show(
+        ui: &mut egui::Ui,
+        value: &mut Self,
+        _: &Self::Metadata,
+        _: &mut Option<()>,
+        id_salt: impl Hash,
+        _: &DefaultStyle,
+    ) -> egui::Response {
+        ui.push_id(id_salt, |ui| ui.horizontal(|ui| show_date(ui, value)).inner).inner
+    }
+}
+
+impl Editable<DefaultStyle> for chrono::DateTime<chrono::Utc> {
+    type TempData = ();
+
+    fn show(
+        ui: &mut egui::Ui,
+        value: &mut Self,
+        _: &Self::Metadata,
+        _: &mut Option<()>,
+        id_salt: impl Hash,
+        _: &DefaultStyle,
+    ) -> egui::Response {
+        let mut date = value.date_naive();
+        let mut time = value.time();
+        let resp = ui
+            .push_id(id_salt, |ui| {
+                ui.horizontal(|ui| {
+                    let mut resp = show_date(ui, &mut date);
+                    ui.add_space(4.0);
+                    resp |= show_time(ui, &mut time);
+                    ui.label("UTC");
+                    resp
+                })
+                .inner
+            })
+            .inner;
+        if resp.changed() {
+            *value = date.and_time(time).and_utc();
+        }
+        resp
+    }
+}
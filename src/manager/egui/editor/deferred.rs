@@ -0,0 +1,309 @@
+//! A read-only variant of the config editor.
+//!
+//! See [`DeferredDisplay`] for more information.
+
+use alloc::boxed::Box;
+use core::any::{Any, type_name};
+
+use bevy_ecs::component::Component;
+use bevy_ecs::entity::Entity;
+use bevy_ecs::query::{QueryFilter, With, Without};
+use bevy_ecs::resource::IsResource;
+use bevy_ecs::system::{Commands, Local, Query, Res, SystemParam};
+use bevy_ecs::world::{EntityMut, EntityRef, EntityWorldMut};
+use bevy_egui::{EguiContext, egui};
+use hashbrown::HashMap;
+
+use super::{
+    ChangeHighlights, DefaultStyle, DisplayOptions, Editable, Egui, GroupHeader, ScalarReset,
+    Style, advanced_toggle, copy_path_button, field_label, field_layout, indented, node_enabled,
+    reset_button, show_section, show_validation, sorted_roots,
+};
+use crate::manager::{self, Manager};
+use crate::{ChildNodeList, ConfigNode, RootNode, ScalarData, ScalarMetadata, Section};
+
+/// A type erasure vtable attached to each scalar field to describe how to view it in egui
+/// without mutable access to the world.
+#[derive(Component)]
+pub(super) struct ScalarView<S: Style> {
+    view_fn: fn(&mut egui::Ui, EntityRef<'_>, &mut ViewContext<'_, '_, '_>, &S) -> egui::Response,
+}
+
+impl<S: Style> ScalarView<S> {
+    pub(super) fn of<T>() -> Self
+    where
+        T: Editable<S> + Clone + Send + Sync + 'static,
+    {
+        Self { view_fn: view_scalar::<T, S> }
+    }
+}
+
+/// The mutable state passed to [`ScalarView::view_fn`].
+struct ViewContext<'a, 'w, 's> {
+    commands:  &'a mut Commands<'w, 's>,
+    temp_data: &'a mut Option<Box<dyn Any + Send + Sync>>,
+    /// Whether the editor has queued a change to the field.
+    changed:   bool,
+}
+
+fn view_scalar<T, S>(
+    ui: &mut egui::Ui,
+    entity: EntityRef<'_>,
+    ctx: &mut ViewContext<'_, '_, '_>,
+    style: &S,
+) -> egui::Response
+where
+    T: Editable<S> + Clone + Send + Sync + 'static,
+    S: Style,
+{
+    #[derive(Hash)]
+    struct FieldIdSalt(Entity);
+
+    field_layout(ui, style, |ui, label_width| {
+        let label_resp = field_label(ui, entity, label_width);
+        label_resp.context_menu(|ui| copy_path_button(ui, entity));
+
+        let metadata = &entity
+            .get::<ScalarMetadata<T>>()
+            .expect(
+                "caller of new_entity must populate the metadata component with the corresponding \
+                 type",
+            )
+            .0;
+        let mut value = entity
+            .get::<ScalarData<T>>()
+            .expect(
+                "caller of new_entity must populate entity with the corresponding ScalarData type",
+            )
+            .0
+            .clone();
+
+        let mut temp_data = ctx
+            .temp_data
+            .take()
+            .and_then(|temp_data| temp_data.downcast::<T::TempData>().ok())
+            .map(|temp_data| *temp_data);
+
+        let resp =
+            T::show(ui, &mut value, metadata, &mut temp_data, FieldIdSalt(entity.id()), style);
+
+        *ctx.temp_data = temp_data.map(|temp_data| Box::new(temp_data) as Box<_>);
+
+        if resp.changed() {
+            ctx.changed = true;
+            ctx.commands.entity(entity.id()).queue(move |mut entity: EntityWorldMut| {
+                if let Some(mut data) = entity.get_mut::<ScalarData<T>>() {
+                    data.0 = value;
+                }
+                if let Some(mut node) = entity.get_mut::<ConfigNode>() {
+                    node.bump();
+                }
+            });
+        }
+        resp
+    })
+    .response
+}
+
+/// A read-only variant of [`Display`](super::Display).
+///
+/// Unlike [`Display`](super::Display), this system parameter only reads config entities,
+/// so it does not conflict with other read-only queries in the same system,
+/// and may run in parallel with other systems that only read the config.
+/// Edits made through the UI are applied through [`Commands`]
+/// and take effect when the commands are flushed,
+/// i.e. the UI shows the new value from the next frame onwards.
+///
+/// Mutable queries over components of config entities,
+/// such as `Query<&mut ScalarData<T>>`, still conflict with this parameter.
+///
+/// ```
+/// use bevy_ecs::error::Result;
+/// use bevy_ecs::hierarchy::Children;
+/// use bevy_ecs::system::Query;
+/// use bevy_egui::{EguiContexts, egui};
+/// use bevy_mod_config::manager::egui::DeferredDisplay;
+///
+/// pub fn config_editor_system(
+///     children_query: Query<&Children>,
+///     mut ctxs: EguiContexts,
+///     mut display: DeferredDisplay,
+/// ) -> Result {
+///     let ctx = ctxs.ctx_mut()?;
+///     egui::Window::new("Config Editor").show(ctx, |ui| {
+///         println!("We can still use children_query here: {:?}", children_query.iter().count());
+///         display.show(ui);
+///     });
+///     Ok(())
+/// }
+/// ```
+#[derive(SystemParam)]
+pub struct DeferredDisplay<'w, 's, F: QueryFilter + 'static = (), M: Manager = ()> {
+    manager:    Option<Res<'w, manager::Instance<M>>>,
+    node_query: DeferredNodeQuery<'w, 's, F>,
+    root_query: Query<'w, 's, Entity, With<RootNode>>,
+    commands:   Commands<'w, 's>,
+    state:      Local<'s, DeferredState>,
+}
+
+type DeferredNodeQuery<'w, 's, F> = Query<
+    'w,
+    's,
+    EntityRef<'static>,
+    (With<ConfigNode>, Without<EguiContext>, Without<IsResource>, F),
+>;
+
+/// The per-system state of [`DeferredDisplay`].
+#[derive(Default)]
+struct DeferredState {
+    options:    DisplayOptions,
+    /// Stores the [`Editable::TempData`] of each field,
+    /// since the field entities cannot be mutated directly.
+    temp_data:  HashMap<Entity, Box<dyn Any + Send + Sync>>,
+    highlights: ChangeHighlights,
+}
+
+impl<F, M> DeferredDisplay<'_, '_, F, M>
+where
+    F: QueryFilter + 'static,
+    M: Manager,
+{
+    /// Returns the [`DisplayOptions`] used by this system.
+    pub fn options(&mut self) -> &mut DisplayOptions { &mut self.state.options }
+
+    /// Shows the config editor UI in `ui`,
+    /// assuming a [`DefaultStyle`] style.
+    ///
+    /// # Panics
+    /// This function panics if the world was not initialized with (a tuple containing)
+    /// an <code>[Egui]&lt;[DefaultStyle]&gt;</code> manager.
+    pub fn show(&mut self, ui: &mut egui::Ui) -> egui::Response {
+        self.show_default::<DefaultStyle>(ui)
+    }
+
+    /// Shows the config editor UI in `ui`
+    /// with a [`Style`] that implements [`Default`].
+    ///
+    /// # Panics
+    /// This function panics if the world was not initialized with (a tuple containing) an [`Egui<S>`] manager.
+    pub fn show_default<S>(&mut self, ui: &mut egui::Ui) -> egui::Response
+    where
+        S: Style + Default,
+    {
+        self.show_with_style(ui, &S::default())
+    }
+
+    /// Shows the config editor UI in `ui` for a non-default style.
+    ///
+    /// # Panics
+    /// This function panics if the world was not initialized with manager type `M`.
+    pub fn show_with<S: Style>(
+        &mut self,
+        ui: &mut egui::Ui,
+        get_manager: impl FnOnce(&M) -> &Egui<S>,
+    ) -> egui::Response {
+        let Some(manager) = self.manager.take() else {
+            panic!("World was not initialized with manager type {}", type_name::<M>());
+        };
+        let resp = self.show_with_style(ui, &get_manager(&manager).style);
+        self.manager = Some(manager);
+        resp
+    }
+
+    fn show_with_style<S: Style>(&mut self, ui: &mut egui::Ui, style: &S) -> egui::Response {
+        let node_query = &self.node_query;
+        let roots =
+            sorted_roots(&self.root_query, |root| node_query.get(root).ok(), &self.state.options);
+
+        #[cfg(feature = "bevy_input")]
+        super::update_key_conflicts(ui.ctx(), node_query.iter());
+
+        let DeferredState { options, temp_data, highlights } = &mut *self.state;
+        advanced_toggle(ui, node_query.iter(), options);
+        temp_data.retain(|&entity, _| node_query.contains(entity));
+        highlights.fields.retain(|&entity, _| node_query.contains(entity));
+
+        let mut viewer =
+            Viewer { node_query, commands: &mut self.commands, temp_data, highlights, options };
+        indented(ui, style, |ui| {
+            for root in roots {
+                viewer.show_node(ui, root, true, style);
+            }
+        })
+        .response
+    }
+}
+
+/// Borrows the state of a [`DeferredDisplay`] while traversing the config tree.
+struct Viewer<'a, 'w, 's, F: QueryFilter + 'static> {
+    node_query: &'a DeferredNodeQuery<'w, 's, F>,
+    commands:   &'a mut Commands<'w, 's>,
+    temp_data:  &'a mut HashMap<Entity, Box<dyn Any + Send + Sync>>,
+    highlights: &'a mut ChangeHighlights,
+    options:    &'a DisplayOptions,
+}
+
+impl<F: QueryFilter + 'static> Viewer<'_, '_, '_, F> {
+    fn show_node<S: Style>(&mut self, ui: &mut egui::Ui, id: Entity, enabled: bool, style: &S) {
+        let node_query = self.node_query;
+        let entity = node_query.get(id).expect("config node must remain in the world once spawned");
+        let get_node = |node| match node_query.get(node) {
+            Ok(entity) => entity,
+            Err(err) => {
+                panic!("Config node {id:?} references invalid node {node:?}: {err}")
+            }
+        };
+        let Some(enabled) = node_enabled(entity, get_node, self.options, enabled) else {
+            return;
+        };
+
+        if let Some(&ScalarView { view_fn }) = entity.get::<ScalarView<S>>() {
+            let node = entity.get::<ConfigNode>().expect("queried with ConfigNode");
+            self.highlights.observe(id, node.generation, ui.input(|input| input.time));
+
+            let mut temp_data = self.temp_data.remove(&id);
+            let mut view = |ui: &mut egui::Ui, commands: &mut Commands| {
+                ui.add_enabled_ui(enabled, |ui| {
+                    let mut ctx =
+                        ViewContext { commands, temp_data: &mut temp_data, changed: false };
+                    view_fn(ui, entity, &mut ctx, style);
+                    ctx.changed
+                })
+            };
+            let commands = &mut *self.commands;
+            let row = match entity.get::<ScalarReset>().copied().filter(|_| self.options.show_reset)
+            {
+                Some(reset) => ui.horizontal_top(|ui| {
+                    let changed = view(ui, commands).inner;
+                    if reset_button(ui, enabled) {
+                        commands.entity(id).queue(move |mut entity: EntityWorldMut| {
+                            reset.apply(&mut EntityMut::from(&mut entity));
+                        });
+                        return true;
+                    }
+                    changed
+                }),
+                None => view(ui, commands),
+            };
+            if let Some(temp_data) = temp_data {
+                self.temp_data.insert(id, temp_data);
+            }
+
+            // Changes made through the editor are only applied after the system,
+            // so they must not be highlighted when observed in the next frame.
+            if row.inner {
+                self.highlights.expect_change(id);
+            }
+            self.highlights.paint(ui, id, row.response.rect, self.options.highlight_duration);
+            show_validation(ui, entity);
+        } else if let Some(children) = entity.get::<ChildNodeList>() {
+            GroupHeader::new(entity, enabled, self.options).show(ui, |ui| {
+                for &child in children.iter() {
+                    self.show_node(ui, child, enabled, style);
+                }
+            });
+        } else if entity.contains::<Section>() {
+            show_section(ui, entity, enabled);
+        }
+    }
+}
@@ -0,0 +1,115 @@
+//! Shows the fields that differ from a baseline, e.g. the last persisted state.
+//!
+//! A [`DiffBaseline`] is a snapshot of the JSON value of every field
+//! managed by a [`Serde`](crate::manager::Serde) manager.
+//! Capture it right after loading or saving the config to compare against the persisted state,
+//! or right after [`init_config`](crate::AppExt::init_config) to compare against the defaults.
+//! [`Display::show_diff`] then lists the fields that changed since the capture,
+//! each with a button to revert it to the baseline value.
+//!
+//! ```
+//! # use bevy_app::App;
+//! # use bevy_mod_config::{AppExt, Config};
+//! use bevy_mod_config::manager::egui::diff::DiffBaseline;
+//!
+//! # #[derive(Config)]
+//! # struct Settings { fps: u32 }
+//! # type ManagerType = (bevy_mod_config::manager::serde::json::Pretty, bevy_mod_config::manager::Egui);
+//! let mut app = App::new();
+//! app.init_config::<ManagerType, Settings>("video");
+//! let baseline = DiffBaseline::capture(app.world_mut());
+//! app.insert_resource(baseline);
+//! ```
+
+use alloc::string::String;
+use alloc::vec::Vec;
+
+use bevy_ecs::entity::Entity;
+use bevy_ecs::query::{QueryFilter, With};
+use bevy_ecs::resource::Resource;
+use bevy_ecs::world::{EntityRef, World};
+use bevy_egui::egui;
+use hashbrown::HashMap;
+use serde_json::Value;
+
+use super::Display;
+use crate::ConfigNode;
+use crate::manager::Manager;
+use crate::manager::serde::json::ValueVtable;
+
+/// A snapshot of field values to compare the current config against.
+#[derive(Resource, Default, Clone)]
+pub struct DiffBaseline {
+    values: HashMap<Entity, Value>,
+}
+
+impl DiffBaseline {
+    /// Captures the current value of every field with a [`ValueVtable`].
+    ///
+    /// Fields that fail to serialize are omitted from the baseline
+    /// and are never reported as changed.
+    pub fn capture(world: &mut World) -> Self {
+        let mut query = world.query_filtered::<EntityRef, With<ValueVtable>>();
+        let values = query
+            .iter(world)
+            .filter_map(|entity| {
+                let vtable = entity.get::<ValueVtable>().expect("filtered by query");
+                Some((entity.id(), vtable.to_value(entity).ok()?))
+            })
+            .collect();
+        Self { values }
+    }
+
+    /// Returns the baseline value of a field entity, if captured.
+    #[must_use]
+    pub fn get(&self, entity: Entity) -> Option<&Value> { self.values.get(&entity) }
+}
+
+impl<F, M> Display<'_, '_, F, M>
+where
+    F: QueryFilter + 'static,
+    M: Manager,
+{
+    /// Shows the fields whose values differ from `baseline`,
+    /// each with a button to revert it to the baseline value.
+    pub fn show_diff(&mut self, ui: &mut egui::Ui, baseline: &DiffBaseline) -> egui::Response {
+        let mut changed: Vec<(String, Entity, Value, &Value)> = self
+            .node_query
+            .iter()
+            .filter_map(|entity| {
+                let vtable = entity.get::<ValueVtable>()?;
+                let old = baseline.get(entity.id())?;
+                let new = vtable.to_value(entity).ok()?;
+                if &new == old {
+                    return None;
+                }
+                let node = entity.get::<ConfigNode>().expect("scalar fields are config nodes");
+                Some((node.path.join("."), entity.id(), new, old))
+            })
+            .collect();
+        changed.sort_by(|(path1, ..), (path2, ..)| path1.cmp(path2));
+
+        ui.vertical(|ui| {
+            if changed.is_empty() {
+                ui.weak("No changes");
+            }
+            for (path, id, new, old) in changed {
+                ui.horizontal(|ui| {
+                    ui.label(path);
+                    ui.label(alloc::format!("{old} → {new}"));
+                    if ui.small_button("Revert").clicked() {
+                        let mut entity = self
+                            .node_query
+                            .get_mut(id)
+                            .expect("config node must remain in the world once spawned");
+                        let vtable =
+                            *entity.get::<ValueVtable>().expect("checked when computing the diff");
+                        // The baseline was serialized from the same field type.
+                        _ = vtable.set_value(&mut entity, old.clone());
+                    }
+                });
+            }
+        })
+        .response
+    }
+}
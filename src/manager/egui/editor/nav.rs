@@ -0,0 +1,122 @@
+//! Navigating the config editor without a pointer, e.g. with a gamepad.
+//!
+//! [`Display`](super::Display) draws fields in a deterministic order
+//! (roots sorted by [`RootOrder`](crate::RootOrder), then fields in declaration order),
+//! so moving the keyboard focus with [`NavAction::Next`] and [`NavAction::Prev`]
+//! walks through the fields in the same order as they appear on screen.
+//!
+//! The [`gamepad_navigation`] system translates gamepad buttons to [`NavAction`]s
+//! according to the [`NavBindings`] resource.
+//! It must run before the system that shows the [`Display`](super::Display)
+//! so that the injected input is visible to the widgets in the same frame:
+//!
+//! ```
+//! # use bevy_app::App;
+//! # use bevy_ecs::schedule::IntoScheduleConfigs;
+//! # use bevy_egui::EguiPrimaryContextPass;
+//! use bevy_mod_config::manager::egui::nav::{self, NavBindings};
+//!
+//! # fn show_settings() {}
+//! let mut app = App::new();
+//! app.init_resource::<NavBindings>();
+//! app.add_systems(EguiPrimaryContextPass, (nav::gamepad_navigation, show_settings).chain());
+//! ```
+
+use alloc::vec::Vec;
+
+use bevy_ecs::error::Result;
+use bevy_ecs::resource::Resource;
+use bevy_ecs::system::{Query, Res};
+use bevy_egui::{EguiContexts, egui};
+use bevy_input::gamepad::{Gamepad, GamepadButton};
+
+/// An abstract navigation input for the config editor.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum NavAction {
+    /// Moves the focus to the next field.
+    Next,
+    /// Moves the focus to the previous field.
+    Prev,
+    /// Increases the value of the focused numeric field.
+    Increase,
+    /// Decreases the value of the focused numeric field.
+    Decrease,
+    /// Activates the focused widget,
+    /// e.g. toggles a checkbox or expands a collapsed group.
+    Activate,
+}
+
+impl NavAction {
+    /// Applies the action to the egui context.
+    ///
+    /// Focus movement is applied immediately.
+    /// Other actions are injected as key presses,
+    /// which are consumed by the focused widget when the UI is shown later in the frame.
+    pub fn apply(self, ctx: &egui::Context) {
+        let key = match self {
+            NavAction::Next => {
+                ctx.memory_mut(|memory| memory.move_focus(egui::FocusDirection::Next));
+                return;
+            }
+            NavAction::Prev => {
+                ctx.memory_mut(|memory| memory.move_focus(egui::FocusDirection::Previous));
+                return;
+            }
+            NavAction::Increase => egui::Key::ArrowUp,
+            NavAction::Decrease => egui::Key::ArrowDown,
+            NavAction::Activate => egui::Key::Space,
+        };
+        ctx.input_mut(|input| {
+            input.events.push(egui::Event::Key {
+                key,
+                physical_key: None,
+                pressed: true,
+                repeat: false,
+                modifiers: egui::Modifiers::NONE,
+            });
+        });
+    }
+}
+
+/// Maps gamepad buttons to [`NavAction`]s for [`gamepad_navigation`].
+#[derive(Resource, Debug, Clone)]
+pub struct NavBindings {
+    /// Each button triggers the associated action when it is just pressed.
+    pub buttons: Vec<(GamepadButton, NavAction)>,
+}
+
+impl Default for NavBindings {
+    fn default() -> Self {
+        NavBindings {
+            buttons: [
+                (GamepadButton::DPadDown, NavAction::Next),
+                (GamepadButton::DPadUp, NavAction::Prev),
+                (GamepadButton::DPadRight, NavAction::Increase),
+                (GamepadButton::DPadLeft, NavAction::Decrease),
+                (GamepadButton::South, NavAction::Activate),
+            ]
+            .into(),
+        }
+    }
+}
+
+/// Applies the [`NavAction`]s bound to gamepad buttons just pressed on any gamepad.
+///
+/// # Errors
+/// Errors if the primary egui context is unavailable.
+#[allow(clippy::needless_pass_by_value, reason = "system parameters are passed by value")]
+pub fn gamepad_navigation(
+    bindings: Res<NavBindings>,
+    gamepads: Query<&Gamepad>,
+    mut contexts: EguiContexts,
+) -> Result {
+    let ctx = contexts.ctx_mut()?;
+    for gamepad in &gamepads {
+        for &(button, action) in &bindings.buttons {
+            if gamepad.just_pressed(button) {
+                action.apply(ctx);
+            }
+        }
+    }
+    Ok(())
+}
@@ -0,0 +1,485 @@
+use alloc::string::String;
+use core::hash::Hash;
+use core::time::Duration;
+
+use bevy_egui::egui;
+
+use super::{DefaultStyle, Editable};
+use crate::ConfigField;
+use crate::impls::{NumberFormat, NumericMetadata};
+
+/// A trait for types that can be displayed like numbers.
+pub trait NumericLike: ConfigField + PartialOrd + Copy + Sized {
+    /// Parses the value from a string.
+    fn parse_from_str(s: &str) -> Option<Self>;
+
+    /// Converts the value to a string with the [`NumberFormat`] in the metadata.
+    /// Should be roughly the inverse of [`parse_from_str`](NumericLike::parse_from_str)
+    /// after the thousands separators are removed.
+    fn to_string(&self, metadata: &Self::Metadata) -> String;
+
+    /// Adds a `usize` to the value, saturating at the maximum value if overflow occurs.
+    #[must_use]
+    fn saturating_add_usize(self, i: usize) -> Self;
+
+    /// Subtracts a `usize` from the value, saturating at the minimum value if underflow occurs.
+    #[must_use]
+    fn saturating_sub_usize(self, i: usize) -> Self;
+
+    /// Whether the metadata requests the value to be displayed as a slider in the UI.
+    fn metadata_wants_slider(metadata: &Self::Metadata) -> bool;
+
+    /// Returns the lower bound specified by the metadata, if any.
+    fn metadata_min(metadata: &Self::Metadata) -> Option<Self>;
+
+    /// Returns the upper bound specified by the metadata, if any.
+    fn metadata_max(metadata: &Self::Metadata) -> Option<Self>;
+
+    /// Returns the slider precision specified by the metadata, if any.
+    fn metadata_precision(metadata: &Self::Metadata) -> Option<f64>;
+
+    /// Returns the display format specified by the metadata.
+    fn metadata_format(metadata: &Self::Metadata) -> NumberFormat;
+
+    /// Whether the metadata allows entering arithmetic expressions in the text editor.
+    fn metadata_expressions(metadata: &Self::Metadata) -> bool;
+
+    /// Converts the value to a float for slider display.
+    fn as_float(&self) -> f64;
+
+    /// Converts a float from slider response back to the numeric type.
+    fn from_float(f: f64) -> Self;
+}
+
+macro_rules! impl_primitive {
+    (
+        $ty:ty,
+        saturating_add_usize: $self1:ident, $i1:ident => $saturating_add_usize:expr,
+        saturating_sub_usize: $self2:ident, $i2:ident => $saturating_sub_usize:expr,
+        $metadata:ident => $precision:expr,
+        $float:ident => $from_float:expr,
+    ) => {
+        #[allow(clippy::cast_lossless, reason = "u128 to f64 is lossy")]
+        #[allow(clippy::cast_possible_truncation, clippy::cast_precision_loss, clippy::cast_sign_loss)]
+        impl NumericLike for $ty {
+            fn parse_from_str(s: &str) -> Option<Self> {
+                s.parse::<Self>().ok()
+            }
+
+            fn to_string(&self, metadata: &Self::Metadata) -> String {
+                metadata.format.format(*self)
+            }
+
+            fn saturating_add_usize($self1, $i1: usize) -> Self {
+                $saturating_add_usize
+            }
+
+            fn saturating_sub_usize($self2, $i2: usize) -> Self {
+                $saturating_sub_usize
+            }
+
+            fn metadata_wants_slider(metadata: &Self::Metadata) -> bool {
+                metadata.slider
+            }
+
+            fn metadata_min(metadata: &Self::Metadata) -> Option<Self> {
+                Some(metadata.min)
+            }
+
+            fn metadata_max(metadata: &Self::Metadata) -> Option<Self> {
+                Some(metadata.max)
+            }
+
+            fn metadata_precision($metadata: &Self::Metadata) -> Option<f64> {
+                $precision
+            }
+
+            fn metadata_format(metadata: &Self::Metadata) -> NumberFormat {
+                metadata.format
+            }
+
+            fn metadata_expressions(metadata: &Self::Metadata) -> bool {
+                metadata.expressions
+            }
+
+            fn as_float(&self) -> f64 {
+                *self as f64
+            }
+
+            fn from_float($float: f64) -> Self {
+                $from_float
+            }
+        }
+    }
+}
+
+macro_rules! impl_number_signed {
+    ($(($ty:ty, $unsigned:ty),)*) => {
+        $(
+            impl_primitive! {
+                $ty,
+                saturating_add_usize: self, i => {
+                    self.saturating_add_unsigned(<$unsigned>::try_from(i).unwrap_or_else(|_|
+<$unsigned>::MAX,
+                    ))
+                },
+                saturating_sub_usize: self, i => {
+                    self.saturating_sub_unsigned(<$unsigned>::try_from(i).unwrap_or_else(|_| <$unsigned>::MAX))
+                },
+                metadata => { metadata.precision.map(|n| n as f64) },
+                float => { float.round() as $ty },
+            }
+        )*
+    };
+}
+
+impl_number_signed! {
+    (i8, u8),
+    (i16, u16),
+    (i32, u32),
+    (i64, u64),
+    (i128, u128),
+    (isize, usize),
+}
+
+macro_rules! impl_number_unsigned {
+    ($($ty:ty),*) => {
+        $(
+            impl_primitive! {
+                $ty,
+                saturating_add_usize: self, i => {
+                    self.saturating_add(Self::try_from(i).unwrap_or_else(|_| Self::MAX))
+                },
+                saturating_sub_usize: self, i => {
+                    self.saturating_sub(Self::try_from(i).unwrap_or_else(|_| Self::MAX))
+                },
+                metadata => { metadata.precision.map(|n| n as f64) },
+                float => { float.round() as $ty },
+            }
+        )*
+    };
+}
+
+impl_number_unsigned!(u8, u16, u32, u64, u128, usize);
+
+impl_primitive! {
+    f32,
+    saturating_add_usize: self, i =>  self + i as f32 ,
+    saturating_sub_usize: self, i =>  self - i as f32 ,
+    metadata =>  metadata.precision.map(f64::from) ,
+    float =>  float as f32 ,
+}
+impl_primitive! {
+    f64,
+    saturating_add_usize: self, i =>  self + i as f64 ,
+    saturating_sub_usize: self, i =>  self - i as f64 ,
+    metadata =>  metadata.precision ,
+    float => float,
+}
+
+/// Implements the `NumericLike` trait for types that can be converted into a closed interval of
+/// floats, parsed with an optional suffix.
+///
+/// Since this is the innermost bound of the blanket [`Editable`] impl,
+/// the compiler reports this trait for field types without an editor,
+/// so it repeats the diagnostic of [`Editable`].
+#[diagnostic::on_unimplemented(
+    message = "type `{Self}` is not Editable for manager `Egui<DefaultStyle>`",
+    label = "no egui editor for `{Self}`",
+    note = "implement `Editable<DefaultStyle>` for `{Self}` to provide an editor widget",
+    note = "or wrap the field type in `bevy_mod_config::Lossy` to omit it from the editor"
+)]
+pub trait FloatLikeWithSuffix: ConfigField + PartialOrd + Copy + Sized {
+    /// Returns the suffix behind the string representation of the value.
+    fn suffix() -> &'static str;
+    /// Converts the value to a float.
+    fn as_float(&self) -> f64;
+    /// Converts the value from a float.
+    fn from_float(f: f64) -> Self;
+    /// Adds a `usize` to the value.
+    fn saturating_add_usize(&self, i: usize) -> Self;
+    /// Subtracts a `usize` from the value.
+    fn saturating_sub_usize(&self, i: usize) -> Self;
+    /// Converts the metadata to a [`NumericMetadata`] type.
+    fn numeric_metadata(metadata: &Self::Metadata) -> NumericMetadata<Self>;
+}
+
+impl<T: FloatLikeWithSuffix> NumericLike for T {
+    fn parse_from_str(s: &str) -> Option<Self> {
+        let s = s.trim_end();
+        let s = s.strip_suffix(T::suffix()).unwrap_or(s);
+        let s = s.trim_end();
+        s.parse::<f64>().ok().map(T::from_float)
+    }
+    fn to_string(&self, metadata: &Self::Metadata) -> String {
+        let format = T::numeric_metadata(metadata).format;
+        alloc::format!("{}{}", format.format(self.as_float()), T::suffix())
+    }
+
+    fn saturating_add_usize(self, i: usize) -> Self {
+        FloatLikeWithSuffix::saturating_add_usize(&self, i)
+    }
+    fn saturating_sub_usize(self, i: usize) -> Self {
+        FloatLikeWithSuffix::saturating_sub_usize(&self, i)
+    }
+
+    fn metadata_wants_slider(metadata: &Self::Metadata) -> bool {
+        T::numeric_metadata(metadata).slider
+    }
+    fn metadata_min(metadata: &Self::Metadata) -> Option<Self> {
+        Some(T::numeric_metadata(metadata).min)
+    }
+    fn metadata_max(metadata: &Self::Metadata) -> Option<Self> {
+        Some(T::numeric_metadata(metadata).max)
+    }
+    fn metadata_precision(metadata: &Self::Metadata) -> Option<f64> {
+        T::numeric_metadata(metadata).precision.map(|v| v.as_float())
+    }
+    fn metadata_format(metadata: &Self::Metadata) -> NumberFormat {
+        T::numeric_metadata(metadata).format
+    }
+    fn metadata_expressions(metadata: &Self::Metadata) -> bool {
+        T::numeric_metadata(metadata).expressions
+    }
+
+    fn as_float(&self) -> f64 { <T as FloatLikeWithSuffix>::as_float(self) }
+    fn from_float(float: f64) -> Self { <T as FloatLikeWithSuffix>::from_float(float) }
+}
+
+impl FloatLikeWithSuffix for Duration {
+    fn suffix() -> &'static str { "s" }
+    fn as_float(&self) -> f64 { self.as_secs_f64() }
+    fn from_float(f: f64) -> Self { Duration::from_secs_f64(f) }
+    fn saturating_add_usize(&self, i: usize) -> Self {
+        self.saturating_add(Duration::from_secs(i as u64))
+    }
+    fn saturating_sub_usize(&self, i: usize) -> Self {
+        self.saturating_sub(Duration::from_secs(i as u64))
+    }
+    fn numeric_metadata(metadata: &Self::Metadata) -> NumericMetadata<Self> { metadata.clone() }
+}
+
+/// Parses `s` and checks it against the bounds in `metadata`.
+///
+/// Thousands separators are ignored,
+/// and integers may also be entered in scientific notation, e.g. `1.5e3`.
+/// If the metadata allows expressions, `s` may also be an expression
+/// accepted by [`evaluate_expression`].
+fn parse_in_range<T: NumericLike>(s: &str, metadata: &T::Metadata) -> Result<T, String> {
+    let s = T::metadata_format(metadata).strip_separators(s);
+    let parsed = T::parse_from_str(&s)
+        .or_else(|| {
+            let float = s.trim().parse::<f64>().ok()?;
+            (s.contains(['e', 'E']) && float.is_finite() && float.fract() == 0.0)
+                .then(|| T::from_float(float))
+        })
+        .or_else(|| {
+            let float = evaluate_expression(&s).filter(|_| T::metadata_expressions(metadata))?;
+            float.is_finite().then(|| T::from_float(float))
+        })
+        .ok_or_else(|| String::from("Not a valid number"))?;
+    if let Some(min) = T::metadata_min(metadata)
+        && parsed < min
+    {
+        return Err(alloc::format!("Must be at least {}", min.to_string(metadata)));
+    }
+    if let Some(max) = T::metadata_max(metadata)
+        && parsed > max
+    {
+        return Err(alloc::format!("Must be at most {}", max.to_string(metadata)));
+    }
+    Ok(parsed)
+}
+
+/// Evaluates a simple arithmetic expression such as `1920/2` or `(1 + 2) * -0.5`.
+///
+/// Supports decimal numbers (optionally in scientific notation),
+/// `+`, `-`, `*`, `/`, unary minus and parentheses with the usual precedence.
+/// Returns `None` if `s` is not a valid expression.
+///
+/// Used by the numeric text editor for fields with
+/// [`NumericMetadata::expressions`](crate::impls::NumericMetadata::expressions) enabled.
+///
+/// ```
+/// use bevy_mod_config::manager::egui::evaluate_expression;
+///
+/// assert_eq!(evaluate_expression("1920/2"), Some(960.0));
+/// assert_eq!(evaluate_expression("2 * (3 + 4)"), Some(14.0));
+/// assert_eq!(evaluate_expression("2 +"), None);
+/// ```
+#[must_use]
+pub fn evaluate_expression(s: &str) -> Option<f64> {
+    let mut parser = ExprParser { input: s.as_bytes(), pos: 0 };
+    let value = parser.sum()?;
+    parser.skip_whitespace();
+    (parser.pos == parser.input.len()).then_some(value)
+}
+
+/// A recursive descent parser for [`evaluate_expression`].
+struct ExprParser<'a> {
+    input: &'a [u8],
+    pos:   usize,
+}
+
+impl ExprParser<'_> {
+    fn skip_whitespace(&mut self) {
+        while self.input.get(self.pos).is_some_and(u8::is_ascii_whitespace) {
+            self.pos += 1;
+        }
+    }
+
+    /// Consumes `byte` if it is the next non-whitespace byte.
+    fn eat(&mut self, byte: u8) -> bool {
+        self.skip_whitespace();
+        let matched = self.input.get(self.pos) == Some(&byte);
+        if matched {
+            self.pos += 1;
+        }
+        matched
+    }
+
+    /// Parses `product (('+' | '-') product)*`.
+    fn sum(&mut self) -> Option<f64> {
+        let mut value = self.product()?;
+        loop {
+            if self.eat(b'+') {
+                value += self.product()?;
+            } else if self.eat(b'-') {
+                value -= self.product()?;
+            } else {
+                return Some(value);
+            }
+        }
+    }
+
+    /// Parses `unary (('*' | '/') unary)*`.
+    fn product(&mut self) -> Option<f64> {
+        let mut value = self.unary()?;
+        loop {
+            if self.eat(b'*') {
+                value *= self.unary()?;
+            } else if self.eat(b'/') {
+                value /= self.unary()?;
+            } else {
+                return Some(value);
+            }
+        }
+    }
+
+    /// Parses `'-' unary | '(' sum ')' | number`.
+    fn unary(&mut self) -> Option<f64> {
+        if self.eat(b'-') {
+            return self.unary().map(|value| -value);
+        }
+        if self.eat(b'(') {
+            let value = self.sum()?;
+            return self.eat(b')').then_some(value);
+        }
+        self.number()
+    }
+
+    /// Parses a decimal number with an optional exponent.
+    fn number(&mut self) -> Option<f64> {
+        self.skip_whitespace();
+        let start = self.pos;
+        let mut prev = None;
+        while let Some(&byte) = self.input.get(self.pos) {
+            let accepted = byte.is_ascii_digit()
+                || byte == b'.'
+                || matches!(byte, b'e' | b'E')
+                || (matches!(byte, b'+' | b'-') && matches!(prev, Some(b'e' | b'E')));
+            if !accepted {
+                break;
+            }
+            prev = Some(byte);
+            self.pos += 1;
+        }
+        core::str::from_utf8(&self.input[start..self.pos]).ok()?.parse().ok()
+    }
+}
+
+impl<T> Editable<DefaultStyle> for T
+where
+    T: NumericLike,
+{
+    type TempData = String;
+
+    fn show(
+        ui: &mut egui::Ui,
+        value: &mut Self,
+        metadata: &Self::Metadata,
+        temp_data: &mut Option<Self::TempData>,
+        id_salt: impl Hash,
+        _: &DefaultStyle,
+    ) -> egui::Response {
+        if let (true, Some(min), Some(max)) = (
+            T::metadata_wants_slider(metadata),
+            T::metadata_min(metadata),
+            T::metadata_max(metadata),
+        ) {
+            let mut value_float = value.as_float();
+            let min_float = min.as_float();
+            let max_float = max.as_float();
+            let mut slider = egui::Slider::new(&mut value_float, min_float..=max_float)
+                .step_by(T::metadata_precision(metadata).unwrap_or(0.0));
+            let format = T::metadata_format(metadata);
+            if format != NumberFormat::default() {
+                slider = slider
+                    .custom_formatter(move |value, _| format.format(value))
+                    .custom_parser(move |s| format.strip_separators(s).trim().parse().ok());
+            }
+            let resp = ui.add(slider);
+            if resp.changed() {
+                *value = T::from_float(value_float);
+            }
+            resp
+        } else {
+            let mut value_str = temp_data.take().unwrap_or_else(|| value.to_string(metadata));
+            let inner = ui.vertical(|ui| {
+                let edit = egui::TextEdit::singleline(&mut value_str).id_salt(id_salt);
+                let mut resp = ui.add(edit);
+                if resp.changed() {
+                    match parse_in_range::<T>(&value_str, metadata) {
+                        Ok(parsed) => *value = parsed,
+                        // The field keeps its value until the text is valid,
+                        // so the edit must not be reported as a change.
+                        Err(_) => resp.flags.remove(egui::response::Flags::CHANGED),
+                    }
+                } else if resp.has_focus() {
+                    ui.input_mut(|input| {
+                        if let presses @ 1.. =
+                            input.count_and_consume_key(egui::Modifiers::NONE, egui::Key::ArrowUp)
+                        {
+                            *value = value.saturating_add_usize(presses);
+                            value_str = value.to_string(metadata);
+                            resp.mark_changed();
+                        }
+                        if let presses @ 1.. =
+                            input.count_and_consume_key(egui::Modifiers::NONE, egui::Key::ArrowDown)
+                        {
+                            *value = value.saturating_sub_usize(presses);
+                            value_str = value.to_string(metadata);
+                            resp.mark_changed();
+                        }
+                    });
+                }
+
+                // Invalid input is kept in the editor along with the error,
+                // instead of being silently reverted or clamped.
+                let valid = match parse_in_range::<T>(&value_str, metadata) {
+                    Ok(_) => true,
+                    Err(error) => {
+                        super::show_error(ui, &error);
+                        false
+                    }
+                };
+                (resp, valid)
+            });
+            let (resp, valid) = inner.inner;
+            if !(resp.lost_focus() && valid) {
+                *temp_data = Some(value_str);
+            }
+            resp
+        }
+    }
+}
@@ -0,0 +1,138 @@
+//! A compact panel of pinned fields, e.g. as a tuning HUD during development.
+//!
+//! The pinned fields are stored in a [`PinnedFields`] config root,
+//! so they are persisted by the managers of the app like any other config.
+//! Fields are pinned and unpinned from their right-click context menu in [`Display`],
+//! and [`Display::show_pinned`] renders just the pinned fields.
+//!
+//! ```
+//! # use bevy_app::App;
+//! # use bevy_mod_config::AppExt;
+//! use bevy_mod_config::manager::Egui;
+//! use bevy_mod_config::manager::egui::pinned::PinnedFields;
+//!
+//! let mut app = App::new();
+//! app.init_config::<Egui, PinnedFields>("pinned");
+//! ```
+
+use alloc::string::String;
+use alloc::vec::Vec;
+
+use bevy_ecs::entity::Entity;
+use bevy_ecs::query::QueryFilter;
+use bevy_ecs::world::{EntityMut, EntityRef};
+use bevy_egui::egui;
+use hashbrown::HashMap;
+
+use super::{DefaultStyle, Display, NodeQuery, indented, show_node};
+use crate::manager::Manager;
+use crate::{ConfigNode, ScalarData};
+
+/// The dot-separated paths of the fields pinned in the editor.
+///
+/// Initialize it as a root config to enable pinning in [`Display`].
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct PinnedFields(pub Vec<String>);
+
+crate::impl_config_newtype!(PinnedFields => Vec<String>);
+
+/// Stores the pinned paths for the context menus of the current frame.
+fn pinned_id() -> egui::Id { egui::Id::new("bevy_mod_config::pinned") }
+
+/// Stores the paths toggled from the context menus, applied in the next frame.
+fn toggles_id() -> egui::Id { egui::Id::new("bevy_mod_config::pin_toggles") }
+
+/// Applies the pins toggled from the context menus to the [`PinnedFields`] config
+/// and returns the pinned paths.
+///
+/// Returns an empty list if [`PinnedFields`] was not initialized,
+/// in which case the context menus do not offer pinning.
+pub(super) fn sync<F: QueryFilter + 'static>(
+    ctx: &egui::Context,
+    node_query: &mut NodeQuery<F>,
+) -> Vec<String> {
+    let toggles: Vec<String> =
+        ctx.data_mut(|data| data.remove_temp(toggles_id())).unwrap_or_default();
+    let Some(mut entity) =
+        node_query.iter_mut().find(EntityMut::contains::<ScalarData<PinnedFields>>)
+    else {
+        ctx.data_mut(|data| data.remove::<Vec<String>>(pinned_id()));
+        return Vec::new();
+    };
+
+    if !toggles.is_empty() {
+        let mut data = entity.get_mut::<ScalarData<PinnedFields>>().expect("checked in find");
+        for path in toggles {
+            match data.0.0.iter().position(|pinned| *pinned == path) {
+                Some(index) => {
+                    data.0.0.remove(index);
+                }
+                None => data.0.0.push(path),
+            }
+        }
+        entity.get_mut::<ConfigNode>().expect("scalar fields are config nodes").bump();
+    }
+
+    let paths = entity.get::<ScalarData<PinnedFields>>().expect("checked in find").0.0.clone();
+    ctx.data_mut(|data| data.insert_temp(pinned_id(), paths.clone()));
+    paths
+}
+
+/// Shows the context menu entry for pinning or unpinning a field.
+pub(super) fn pin_button(ui: &mut egui::Ui, entity: EntityRef<'_>) {
+    let Some(pinned) = ui.ctx().data(|data| data.get_temp::<Vec<String>>(pinned_id())) else {
+        return;
+    };
+    let node = entity.get::<ConfigNode>().expect("draw_fn must be called with a ConfigNode entity");
+    let path = node.path.join(".");
+    let label = if pinned.contains(&path) { "Unpin" } else { "Pin" };
+    if ui.button(label).clicked() {
+        ui.ctx()
+            .data_mut(|data| data.get_temp_mut_or_default::<Vec<String>>(toggles_id()).push(path));
+        ui.close();
+    }
+}
+
+impl<F, M> Display<'_, '_, F, M>
+where
+    F: QueryFilter + 'static,
+    M: Manager,
+{
+    /// Shows only the fields pinned in [`PinnedFields`],
+    /// in the order they were pinned,
+    /// assuming a [`DefaultStyle`] style.
+    ///
+    /// Pinned paths that do not match any field are skipped.
+    ///
+    /// # Panics
+    /// This function panics if the world was not initialized with (a tuple containing)
+    /// an <code>[Egui](crate::manager::Egui)&lt;[DefaultStyle]&gt;</code> manager.
+    pub fn show_pinned(&mut self, ui: &mut egui::Ui) -> egui::Response {
+        let style = DefaultStyle::default();
+        let pinned = sync(ui.ctx(), &mut self.node_query);
+        let nodes: HashMap<String, Entity> = self
+            .node_query
+            .iter()
+            .filter_map(|entity| Some((entity.get::<ConfigNode>()?.path.join("."), entity.id())))
+            .collect();
+
+        indented(ui, &style, |ui| {
+            if pinned.is_empty() {
+                ui.weak("No pinned fields. Right-click a field to pin it.");
+            }
+            for path in &pinned {
+                let Some(&id) = nodes.get(path) else { continue };
+                show_node(
+                    ui,
+                    &mut self.node_query,
+                    id,
+                    &self.options,
+                    &mut self.highlights,
+                    true,
+                    &style,
+                );
+            }
+        })
+        .response
+    }
+}
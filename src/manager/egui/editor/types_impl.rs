@@ -0,0 +1,404 @@
+use alloc::string::ToString;
+use alloc::vec::Vec;
+use core::hash::Hash;
+
+use bevy_egui::egui;
+#[cfg(feature = "bevy_input")]
+use bevy_input::keyboard::KeyCode;
+
+use super::{DefaultStyle, Editable};
+use crate::types::{Curve, Language, Resolution, Volume, VolumeDisplay};
+#[cfg(feature = "bevy_input")]
+use crate::types::{KeyBinding, KeyConflict};
+
+/// The egui temp data ID storing the resolutions found by [`detect_resolutions`].
+fn detected_id() -> egui::Id { egui::Id::new("bevy_mod_config::detected_resolutions") }
+
+/// Offers the video modes of all connected monitors in the [`Resolution`] dropdown.
+///
+/// Add this system to a schedule before the system that shows the
+/// [`Display`](super::Display), e.g. [`Update`](bevy_app::Update).
+#[cfg(feature = "bevy_window")]
+pub fn detect_resolutions(
+    monitors: bevy_ecs::system::Query<&bevy_window::Monitor>,
+    mut contexts: bevy_egui::EguiContexts,
+) {
+    let Ok(ctx) = contexts.ctx_mut() else { return };
+    let mut resolutions: Vec<_> = monitors
+        .iter()
+        .flat_map(|monitor| &monitor.video_modes)
+        .map(|mode| Resolution::new(mode.physical_size.x, mode.physical_size.y))
+        .collect();
+    resolutions.sort_unstable();
+    resolutions.dedup();
+    ctx.data_mut(|data| data.insert_temp(detected_id(), resolutions));
+}
+
+impl Editable<DefaultStyle> for Resolution {
+    /// Whether the custom entry is selected.
+    type TempData = bool;
+
+    fn show(
+        ui: &mut egui::Ui,
+        value: &mut Self,
+        metadata: &Self::Metadata,
+        temp_data: &mut Option<bool>,
+        id_salt: impl Hash,
+        _: &DefaultStyle,
+    ) -> egui::Response {
+        let detected: Vec<Resolution> =
+            ui.ctx().data(|data| data.get_temp(detected_id())).unwrap_or_default();
+        let mut options: Vec<_> = metadata.presets.iter().copied().chain(detected).collect();
+        options.sort_unstable();
+        options.dedup();
+
+        let custom = temp_data.get_or_insert_with(|| !options.contains(value));
+        let previous = *value;
+
+        ui.vertical(|ui| {
+            let mut resp = egui::ComboBox::from_id_salt(id_salt)
+                .selected_text(if *custom {
+                    alloc::format!("Custom ({value})")
+                } else {
+                    alloc::format!("{value}")
+                })
+                .show_ui(ui, |ui| {
+                    for option in options {
+                        if ui
+                            .selectable_label(!*custom && *value == option, option.to_string())
+                            .clicked()
+                        {
+                            *value = option;
+                            *custom = false;
+                        }
+                    }
+                    if ui.selectable_label(*custom, "Custom").clicked() {
+                        *custom = true;
+                    }
+                })
+                .response;
+
+            if *custom {
+                ui.horizontal(|ui| {
+                    resp |= ui.add(egui::DragValue::new(&mut value.width).range(1..=u32::MAX));
+                    ui.label("x");
+                    resp |= ui.add(egui::DragValue::new(&mut value.height).range(1..=u32::MAX));
+                });
+            }
+            if *value != previous {
+                resp.mark_changed();
+            }
+            resp
+        })
+        .inner
+    }
+}
+
+impl Editable<DefaultStyle> for Language {
+    type TempData = ();
+
+    fn show(
+        ui: &mut egui::Ui,
+        value: &mut Self,
+        metadata: &Self::Metadata,
+        _: &mut Option<()>,
+        id_salt: impl Hash,
+        _: &DefaultStyle,
+    ) -> egui::Response {
+        let selected = metadata
+            .options
+            .iter()
+            .find(|option| option.tag == value.0)
+            .map_or(value.0.as_str(), |option| option.native_name.as_str());
+
+        let mut changed = false;
+        let mut resp = egui::ComboBox::from_id_salt(id_salt)
+            .selected_text(selected)
+            .show_ui(ui, |ui| {
+                for option in &metadata.options {
+                    if ui
+                        .selectable_label(value.0 == option.tag, option.native_name.as_str())
+                        .clicked()
+                        && value.0 != option.tag
+                    {
+                        value.0.clone_from(&option.tag);
+                        changed = true;
+                    }
+                }
+            })
+            .response;
+        if changed {
+            resp.mark_changed();
+        }
+        resp
+    }
+}
+
+impl Editable<DefaultStyle> for Volume {
+    type TempData = ();
+
+    fn show(
+        ui: &mut egui::Ui,
+        value: &mut Self,
+        metadata: &Self::Metadata,
+        _: &mut Option<()>,
+        _: impl Hash,
+        _: &DefaultStyle,
+    ) -> egui::Response {
+        if metadata.display == VolumeDisplay::Decibels {
+            let min = metadata.min_decibels;
+            let mut decibels = value.decibels().max(min);
+            // The lowest slider value stands for silence.
+            let resp = ui.add(egui::Slider::new(&mut decibels, min..=0.0).suffix(" dB"));
+            if resp.changed() {
+                *value =
+                    if decibels <= min { Volume::SILENT } else { Volume::from_decibels(decibels) };
+            }
+            return resp;
+        }
+
+        let mut percent = value.linear() * 100.0;
+        let resp = ui.add(egui::Slider::new(&mut percent, 0.0..=100.0).suffix("%"));
+        if resp.changed() {
+            *value = Volume::new(percent / 100.0);
+        }
+        resp
+    }
+}
+
+/// Control points within this distance in points from the pointer can be grabbed.
+const CURVE_GRAB_RADIUS: f32 = 8.0;
+
+impl Editable<DefaultStyle> for Curve {
+    /// The index of the control point being dragged.
+    type TempData = usize;
+
+    fn show(
+        ui: &mut egui::Ui,
+        value: &mut Self,
+        metadata: &Self::Metadata,
+        dragged: &mut Option<usize>,
+        _: impl Hash,
+        _: &DefaultStyle,
+    ) -> egui::Response {
+        let size = egui::vec2(ui.available_width().clamp(120.0, 240.0), 120.0);
+        let (mut resp, painter) = ui.allocate_painter(size, egui::Sense::click_and_drag());
+        resp = resp.on_hover_text(
+            "Drag to move a point, double-click to add a point, right-click to remove a point",
+        );
+
+        // The curve space has y increasing upwards.
+        let (x_min, x_max) = (*metadata.x_range.start(), *metadata.x_range.end());
+        let (y_min, y_max) = (*metadata.y_range.start(), *metadata.y_range.end());
+        let to_screen = egui::emath::RectTransform::from_to(
+            egui::Rect::from_min_max(egui::pos2(x_min, y_max), egui::pos2(x_max, y_min)),
+            resp.rect,
+        );
+        let from_screen = to_screen.inverse();
+        let to_curve = |pos: egui::Pos2| {
+            let pos = from_screen.transform_pos(pos);
+            [pos.x.clamp(x_min, x_max), pos.y.clamp(y_min, y_max)]
+        };
+        let nearest = |value: &Curve, pos: egui::Pos2| {
+            value
+                .points()
+                .iter()
+                .map(|&[x, y]| to_screen.transform_pos(egui::pos2(x, y)).distance(pos))
+                .enumerate()
+                .filter(|&(_, distance)| distance <= CURVE_GRAB_RADIUS)
+                .min_by(|(_, d1), (_, d2)| d1.total_cmp(d2))
+                .map(|(index, _)| index)
+        };
+
+        let mut changed = false;
+        if let Some(pos) = resp.interact_pointer_pos() {
+            if resp.drag_started() {
+                *dragged = nearest(value, pos);
+            }
+            if let Some(index) = *dragged
+                && resp.dragged()
+                && index < value.points().len()
+            {
+                value.set_point(index, to_curve(pos));
+                changed = true;
+            }
+            if resp.double_clicked() && nearest(value, pos).is_none() {
+                value.insert_point(to_curve(pos));
+                changed = true;
+            }
+            if resp.secondary_clicked()
+                && value.points().len() > 2
+                && let Some(index) = nearest(value, pos)
+            {
+                value.remove_point(index);
+                changed = true;
+            }
+        }
+        if resp.drag_stopped() {
+            *dragged = None;
+        }
+
+        let visuals = ui.visuals();
+        painter.rect_filled(resp.rect, 2.0, visuals.extreme_bg_color);
+        let points: Vec<_> = value
+            .points()
+            .iter()
+            .map(|&[x, y]| to_screen.transform_pos(egui::pos2(x, y)))
+            .collect();
+        let stroke = visuals.widgets.active.fg_stroke;
+        painter.add(egui::Shape::line(points.clone(), stroke));
+        for point in points {
+            painter.circle_filled(point, 4.0, stroke.color);
+        }
+
+        if changed {
+            resp.mark_changed();
+        }
+        resp
+    }
+}
+
+/// Each swatch has its own color picker, and can be reordered and removed like [`Vec`] entries.
+#[cfg(feature = "bevy_color")]
+impl Editable<DefaultStyle> for crate::types::Palette {
+    type TempData = <Vec<bevy_color::Color> as Editable<DefaultStyle>>::TempData;
+
+    fn show(
+        ui: &mut egui::Ui,
+        value: &mut Self,
+        metadata: &Self::Metadata,
+        temp_data: &mut Option<Self::TempData>,
+        id_salt: impl Hash,
+        style: &DefaultStyle,
+    ) -> egui::Response {
+        <Vec<bevy_color::Color> as Editable<DefaultStyle>>::show(
+            ui,
+            &mut value.0,
+            metadata,
+            temp_data,
+            id_salt,
+            style,
+        )
+    }
+}
+
+/// The egui temp data ID storing the conflicts found by [`store_key_conflicts`].
+#[cfg(feature = "bevy_input")]
+fn key_conflicts_id() -> egui::Id { egui::Id::new("bevy_mod_config::key_conflicts") }
+
+/// Stores the key conflicts for the warning badges of the [`KeyBinding`] editors.
+#[cfg(feature = "bevy_input")]
+pub(super) fn store_key_conflicts(ctx: &egui::Context, conflicts: Vec<KeyConflict>) {
+    ctx.data_mut(|data| data.insert_temp(key_conflicts_id(), conflicts));
+}
+
+#[cfg(feature = "bevy_input")]
+impl Editable<DefaultStyle> for KeyBinding {
+    /// Whether the editor is waiting for a key press.
+    type TempData = bool;
+
+    fn show(
+        ui: &mut egui::Ui,
+        value: &mut Self,
+        _: &Self::Metadata,
+        capturing: &mut Option<bool>,
+        _: impl Hash,
+        _: &DefaultStyle,
+    ) -> egui::Response {
+        let capturing = capturing.get_or_insert(false);
+        let previous = *value;
+
+        let mut resp = ui
+            .horizontal(|ui| {
+                let resp = if *capturing {
+                    let resp = ui.add(egui::Button::new("Press a key…").selected(true));
+                    let pressed = ui.input(|input| {
+                        input.events.iter().find_map(|event| match event {
+                            egui::Event::Key { key, physical_key, pressed: true, .. } => {
+                                Some(physical_key.unwrap_or(*key))
+                            }
+                            _ => None,
+                        })
+                    });
+                    if let Some(key) = pressed {
+                        // Escape cancels the capture without changing the binding.
+                        if key != egui::Key::Escape
+                            && let Some(code) = to_key_code(key)
+                        {
+                            *value = KeyBinding(Some(code));
+                        }
+                        *capturing = false;
+                    }
+                    if resp.clicked_elsewhere() {
+                        *capturing = false;
+                    }
+                    resp
+                } else {
+                    let text = match value.0 {
+                        Some(key) => alloc::format!("{key:?}"),
+                        None => "Unbound".into(),
+                    };
+                    let resp = ui.button(text);
+                    if resp.clicked() {
+                        *capturing = true;
+                    }
+                    resp
+                };
+
+                if value.0.is_some() && ui.small_button("✖").on_hover_text("Unbind").clicked() {
+                    *value = KeyBinding(None);
+                }
+
+                let conflicts: Vec<KeyConflict> =
+                    ui.ctx().data(|data| data.get_temp(key_conflicts_id())).unwrap_or_default();
+                if let Some(key) = value.0
+                    && let Some(conflict) = conflicts.iter().find(|conflict| conflict.key == key)
+                {
+                    let paths: Vec<_> = conflict.paths.iter().map(|path| path.join(".")).collect();
+                    let color = ui.visuals().warn_fg_color;
+                    ui.colored_label(color, "⚠").on_hover_text(alloc::format!(
+                        "Bound to multiple actions:\n{}",
+                        paths.join("\n")
+                    ));
+                }
+                resp
+            })
+            .inner;
+
+        if *value != previous {
+            resp.mark_changed();
+        }
+        resp
+    }
+}
+
+/// Converts an egui key to the corresponding Bevy key code.
+#[cfg(feature = "bevy_input")]
+fn to_key_code(key: egui::Key) -> Option<KeyCode> {
+    macro_rules! map_keys {
+        ($($egui:ident => $bevy:ident,)*) => {
+            match key {
+                $(egui::Key::$egui => Some(KeyCode::$bevy),)*
+                _ => None,
+            }
+        };
+    }
+
+    map_keys! {
+        A => KeyA, B => KeyB, C => KeyC, D => KeyD, E => KeyE, F => KeyF, G => KeyG,
+        H => KeyH, I => KeyI, J => KeyJ, K => KeyK, L => KeyL, M => KeyM, N => KeyN,
+        O => KeyO, P => KeyP, Q => KeyQ, R => KeyR, S => KeyS, T => KeyT, U => KeyU,
+        V => KeyV, W => KeyW, X => KeyX, Y => KeyY, Z => KeyZ,
+        Num0 => Digit0, Num1 => Digit1, Num2 => Digit2, Num3 => Digit3, Num4 => Digit4,
+        Num5 => Digit5, Num6 => Digit6, Num7 => Digit7, Num8 => Digit8, Num9 => Digit9,
+        F1 => F1, F2 => F2, F3 => F3, F4 => F4, F5 => F5, F6 => F6,
+        F7 => F7, F8 => F8, F9 => F9, F10 => F10, F11 => F11, F12 => F12,
+        ArrowUp => ArrowUp, ArrowDown => ArrowDown, ArrowLeft => ArrowLeft, ArrowRight => ArrowRight,
+        Space => Space, Enter => Enter, Tab => Tab, Backspace => Backspace,
+        Insert => Insert, Delete => Delete, Home => Home, End => End,
+        PageUp => PageUp, PageDown => PageDown,
+        Minus => Minus, Equals => Equal, Comma => Comma, Period => Period,
+        Slash => Slash, Backslash => Backslash, Semicolon => Semicolon, Quote => Quote,
+        OpenBracket => BracketLeft, CloseBracket => BracketRight, Backtick => Backquote,
+    }
+}
@@ -0,0 +1,263 @@
+//! A ready-made settings window built on [`Display`].
+//!
+//! [`ConfigWindowPlugin`] shows the config editor in an egui window or side panel
+//! with a tab per root, a search box, reset buttons and custom actions
+//! such as saving and loading through a [`Serde`](crate::manager::serde::Serde) manager.
+//! Use [`Display`] directly for custom layouts.
+//!
+//! ```
+//! # use bevy_app::App;
+//! # use bevy_mod_config::{AppExt, Config};
+//! use bevy_mod_config::manager::Egui;
+//! use bevy_mod_config::manager::egui::ConfigWindowPlugin;
+//!
+//! #[derive(Config)]
+//! struct Audio {
+//!     #[config(default = 0.5, min = 0.0, max = 1.0)]
+//!     volume: f32,
+//! }
+//!
+//! let mut app = App::new();
+//! app.init_config::<Egui, Audio>("audio");
+//! app.add_plugins(ConfigWindowPlugin::new("Settings").with_action("Quit", |commands| {
+//!     commands.write_message(bevy_app::AppExit::Success);
+//! }));
+//! ```
+
+#[cfg(feature = "serde_json")]
+extern crate std;
+
+use alloc::string::String;
+use alloc::sync::Arc;
+use alloc::vec::Vec;
+
+use bevy_app::{App, Plugin};
+use bevy_ecs::query::With;
+use bevy_ecs::resource::Resource;
+use bevy_ecs::system::{Commands, Query, ResMut};
+use bevy_egui::{EguiContexts, EguiPrimaryContextPass, egui};
+
+use super::Display;
+use crate::{ConfigNode, RootNode, RootOrder};
+
+/// Where [`ConfigWindowPlugin`] shows the editor.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum Placement {
+    /// A floating window that can be closed.
+    #[default]
+    Window,
+    /// A panel on the left side of the screen.
+    LeftPanel,
+    /// A panel on the right side of the screen.
+    RightPanel,
+}
+
+type ActionFn = Arc<dyn Fn(&mut Commands) + Send + Sync>;
+
+/// A plugin showing a settings screen for all config fields managed by [`Egui`](super::Egui).
+///
+/// See the [module documentation](self) for usage.
+pub struct ConfigWindowPlugin {
+    title:     String,
+    placement: Placement,
+    open:      bool,
+    actions:   Vec<(String, ActionFn)>,
+}
+
+impl ConfigWindowPlugin {
+    /// Creates a plugin showing an open window titled `title`.
+    #[must_use]
+    pub fn new(title: impl Into<String>) -> Self {
+        Self {
+            title:     title.into(),
+            placement: Placement::Window,
+            open:      true,
+            actions:   Vec::new(),
+        }
+    }
+
+    /// Sets where the editor is shown.
+    #[must_use]
+    pub fn with_placement(mut self, placement: Placement) -> Self {
+        self.placement = placement;
+        self
+    }
+
+    /// Sets whether the editor is initially open.
+    ///
+    /// Toggle [`ConfigWindow::open`] to show or hide it later.
+    #[must_use]
+    pub fn with_open(mut self, open: bool) -> Self {
+        self.open = open;
+        self
+    }
+
+    /// Adds a button labelled `label` to the bottom of the editor,
+    /// which runs `action` when clicked.
+    #[must_use]
+    pub fn with_action(
+        mut self,
+        label: impl Into<String>,
+        action: impl Fn(&mut Commands) + Send + Sync + 'static,
+    ) -> Self {
+        self.actions.push((label.into(), Arc::new(action)));
+        self
+    }
+
+    /// Adds "Save" and "Load" buttons that write and read `path`
+    /// through the JSON manager returned by `get_manager`.
+    #[cfg(feature = "serde_json")]
+    #[must_use]
+    pub fn with_json_file<M, F>(
+        self,
+        path: impl Into<std::path::PathBuf>,
+        get_manager: crate::manager::serde::json::GetJsonManager<M, F>,
+    ) -> Self
+    where
+        M: crate::Manager,
+        F: serde_json::ser::Formatter + Send + Sync + 'static,
+    {
+        use crate::manager::serde::json::{LoadConfigCommand, SaveConfigCommand};
+
+        let path = path.into();
+        let load_path = path.clone();
+        self.with_action("Save", move |commands| {
+            commands.queue(SaveConfigCommand::with_manager(path.clone(), get_manager));
+        })
+        .with_action("Load", move |commands| {
+            commands.queue(LoadConfigCommand::with_manager(load_path.clone(), get_manager));
+        })
+    }
+}
+
+impl Plugin for ConfigWindowPlugin {
+    fn build(&self, app: &mut App) {
+        app.insert_resource(ConfigWindow {
+            open:      self.open,
+            title:     self.title.clone(),
+            placement: self.placement,
+            actions:   self.actions.clone(),
+            tab:       None,
+        });
+        app.add_systems(EguiPrimaryContextPass, show_config_window);
+    }
+}
+
+/// The state of the editor shown by [`ConfigWindowPlugin`].
+#[derive(Resource)]
+pub struct ConfigWindow {
+    /// Whether the editor is shown.
+    ///
+    /// Closing the floating window sets this to `false`.
+    pub open:  bool,
+    title:     String,
+    placement: Placement,
+    actions:   Vec<(String, ActionFn)>,
+    /// The key of the root shown in the current tab.
+    tab:       Option<String>,
+}
+
+fn show_config_window(
+    mut contexts: EguiContexts,
+    mut window: ResMut<ConfigWindow>,
+    mut display: Display,
+    root_query: Query<(&ConfigNode, Option<&RootOrder>), With<RootNode>>,
+    mut commands: Commands,
+) {
+    if !window.open {
+        return;
+    }
+    let Ok(ctx) = contexts.ctx_mut() else { return };
+
+    let mut sorted: Vec<_> = root_query
+        .iter()
+        .filter_map(|(node, order)| {
+            Some((order.copied().unwrap_or_default().sort_key(), node.path.first()?.clone()))
+        })
+        .collect();
+    sorted.sort();
+    let mut roots = Vec::<String>::new();
+    for (_, key) in sorted {
+        if !roots.contains(&key) {
+            roots.push(key);
+        }
+    }
+
+    let window = &mut *window;
+    let mut add_contents = |ui: &mut egui::Ui| {
+        show_contents(ui, &mut window.tab, &window.actions, &roots, &mut display, &mut commands);
+    };
+    match window.placement {
+        Placement::Window => {
+            egui::Window::new(window.title.as_str()).open(&mut window.open).show(ctx, add_contents);
+        }
+        Placement::LeftPanel | Placement::RightPanel => {
+            let mut ui = egui::Ui::new(
+                ctx.clone(),
+                "config_window".into(),
+                egui::UiBuilder::new()
+                    .layer_id(egui::LayerId::background())
+                    .max_rect(ctx.viewport_rect()),
+            );
+            let panel = if window.placement == Placement::LeftPanel {
+                egui::Panel::left("config_window")
+            } else {
+                egui::Panel::right("config_window")
+            };
+            panel.show_inside(&mut ui, |ui| {
+                ui.heading(window.title.as_str());
+                add_contents(ui);
+            });
+        }
+    }
+}
+
+fn show_contents(
+    ui: &mut egui::Ui,
+    tab: &mut Option<String>,
+    actions: &[(String, ActionFn)],
+    roots: &[String],
+    display: &mut Display,
+    commands: &mut Commands,
+) {
+    if tab.as_ref().is_none_or(|tab| !roots.contains(tab)) {
+        *tab = roots.first().cloned();
+    }
+
+    ui.horizontal_wrapped(|ui| {
+        for root in roots {
+            if ui.selectable_label(tab.as_ref() == Some(root), root.as_str()).clicked() {
+                *tab = Some(root.clone());
+            }
+        }
+    });
+    ui.separator();
+
+    let options = display.options();
+    options.root.clone_from(tab);
+    options.show_reset = true;
+    if options.highlight_duration <= 0.0 {
+        options.highlight_duration = 1.0;
+    }
+    ui.horizontal(|ui| {
+        ui.label("🔍");
+        ui.text_edit_singleline(&mut options.search);
+    });
+    ui.separator();
+
+    egui::ScrollArea::vertical().max_height(ui.available_height() - 40.0).show(ui, |ui| {
+        display.show(ui);
+    });
+    ui.separator();
+
+    ui.horizontal(|ui| {
+        if ui.button("Reset all").clicked() {
+            display.reset_all(tab.as_deref());
+        }
+        for (label, action) in actions {
+            if ui.button(label.as_str()).clicked() {
+                action(commands);
+            }
+        }
+    });
+}
@@ -0,0 +1,342 @@
+//! Layered configuration sources with provenance tracking.
+//!
+//! Each scalar field managed by [`Layers`] remembers the value provided by every [`Layer`].
+//! The effective value stored in [`ScalarData`] is always the value of the highest layer
+//! that currently provides one, and the [`Provenance`] component records which layer that is.
+//!
+//! Other managers (e.g. [`Serde`](super::Serde) or [`Egui`](super::Egui)) write to
+//! `ScalarData` directly without knowing about layers.
+//! Such writes are attributed to a layer by calling [`capture`] afterwards,
+//! e.g. `capture(world, Layer::File)` after loading a file.
+//! All other changes are attributed to [`Layer::Runtime`]
+//! by the [`capture_runtime`] system, which [`Layers`] adds to [`Last`](bevy_app::Last).
+//! Typed writes can be attributed to a layer directly with [`update_config`].
+//!
+//! ```
+//! # use bevy_app::App;
+//! # use bevy_mod_config::{AppExt, Config};
+//! use bevy_mod_config::manager::layer::{self, Layer, Layers};
+//!
+//! #[derive(Config)]
+//! struct Settings {
+//!     #[config(default = 60)]
+//!     fps: u32,
+//! }
+//!
+//! let mut app = App::new();
+//! app.init_config::<Layers, Settings>("settings");
+//! layer::capture(app.world_mut(), Layer::File);
+//! ```
+
+use alloc::vec::Vec;
+use core::array;
+
+use bevy_app::App;
+use bevy_ecs::bundle::Bundle;
+use bevy_ecs::component::Component;
+use bevy_ecs::entity::Entity;
+use bevy_ecs::lifecycle::HookContext;
+#[cfg(any(feature = "serde_json", feature = "toml"))]
+use bevy_ecs::world::EntityRef;
+use bevy_ecs::world::{DeferredWorld, EntityWorldMut, World};
+
+use crate::manager::{self, Manager};
+use crate::{ConfigField, ConfigNode, FieldGeneration, ScalarData};
+
+/// A source of configuration values.
+///
+/// Layers are ordered by priority:
+/// a value provided by a later layer overrides the values of all earlier layers.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Layer {
+    /// The default value declared in the field metadata.
+    Default,
+    /// Values loaded from a persisted file.
+    File,
+    /// Values fetched from a remote source,
+    /// e.g. live-ops tuning applied by [`RemoteConfigPlugin`](super::serde::remote::RemoteConfigPlugin).
+    ///
+    /// Remote values override the persisted file,
+    /// so that tuning pushed by a server reaches players who already have a config file,
+    /// but are overridden by environment variables and command line arguments,
+    /// so that an override set up locally for a single run still takes effect.
+    Remote,
+    /// Values from environment variables.
+    ///
+    /// Like [`Remote`](Self::Remote) and [`Cli`](Self::Cli) values,
+    /// these values are not saved by [`Serde`](super::Serde) managers;
+    /// the value of the highest layer below is saved instead.
+    Env,
+    /// Values from command line arguments.
+    Cli,
+    /// Values changed while the app is running, e.g. through an editor UI.
+    Runtime,
+}
+
+impl Layer {
+    /// All layers in ascending priority.
+    pub const ALL: [Layer; 6] =
+        [Layer::Default, Layer::File, Layer::Remote, Layer::Env, Layer::Cli, Layer::Runtime];
+
+    fn index(self) -> usize { self as usize }
+
+    /// Whether the values of this layer are saved by [`Serde`](super::Serde) managers.
+    #[cfg(any(feature = "serde_json", feature = "toml"))]
+    fn is_persisted(self) -> bool { matches!(self, Layer::Default | Layer::File | Layer::Runtime) }
+}
+
+/// A [`Manager`] that tracks the value provided by each [`Layer`] for every scalar field.
+///
+/// See the [module documentation](self) for usage.
+#[derive(Default)]
+pub struct Layers;
+
+impl Manager for Layers {
+    fn build(app: &mut App) { app.add_systems(bevy_app::Last, capture_runtime); }
+}
+
+impl<T> manager::Supports<T> for Layers
+where
+    T: Clone + PartialEq + Send + Sync + 'static,
+{
+    fn new_entity_for_type(&mut self) -> impl Bundle {
+        (
+            LayerVtable { capture_fn: capture_typed::<T>, clear_fn: clear_typed::<T> },
+            LayerValues::<T> {
+                values: array::from_fn(|_| None),
+                synced: FieldGeneration::default(),
+            },
+            Provenance(Layer::Default),
+        )
+    }
+}
+
+/// Records the [`Layer`] that provides the current value of a scalar field.
+#[derive(Component, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Provenance(pub Layer);
+
+/// A type erasure vtable attached to each scalar field to operate on its layers.
+#[derive(Component, Clone, Copy)]
+struct LayerVtable {
+    capture_fn: fn(&mut EntityWorldMut, Layer),
+    clear_fn:   fn(&mut EntityWorldMut, Layer),
+}
+
+/// Stores the value provided by each layer.
+#[derive(Component)]
+#[component(on_add = snapshot_default::<T>)]
+struct LayerValues<T: Clone + Send + Sync + 'static> {
+    values: [Option<T>; Layer::ALL.len()],
+    /// The generation of the field when the effective value was last resolved.
+    synced: FieldGeneration,
+}
+
+/// Snapshots the initial scalar value as the [`Layer::Default`] value.
+fn snapshot_default<T: Clone + Send + Sync + 'static>(mut world: DeferredWorld, ctx: HookContext) {
+    let Some(data) = world.get::<ScalarData<T>>(ctx.entity) else { return };
+    let data = data.0.clone();
+    let generation = world.get::<ConfigNode>(ctx.entity).map(|node| node.generation);
+    let mut values =
+        world.get_mut::<LayerValues<T>>(ctx.entity).expect("hook called on the same component");
+    values.values[Layer::Default.index()] = Some(data);
+    if let Some(generation) = generation {
+        values.synced = generation;
+    }
+}
+
+fn capture_typed<T: Clone + PartialEq + Send + Sync + 'static>(
+    entity: &mut EntityWorldMut,
+    layer: Layer,
+) {
+    let generation =
+        entity.get::<ConfigNode>().expect("layered field must be a config node").generation;
+    let values = entity.get::<LayerValues<T>>().expect("inserted with LayerVtable");
+    if values.synced == generation {
+        return;
+    }
+
+    let data = entity
+        .get::<ScalarData<T>>()
+        .expect("LayerVtable must be inserted with the corresponding ScalarData type")
+        .0
+        .clone();
+    entity.get_mut::<LayerValues<T>>().expect("inserted with LayerVtable").values[layer.index()] =
+        Some(data);
+    resolve::<T>(entity, Some(layer));
+}
+
+fn clear_typed<T: Clone + PartialEq + Send + Sync + 'static>(
+    entity: &mut EntityWorldMut,
+    layer: Layer,
+) {
+    if layer == Layer::Default {
+        return;
+    }
+
+    let mut values = entity.get_mut::<LayerValues<T>>().expect("inserted with LayerVtable");
+    if values.values[layer.index()].take().is_some() {
+        resolve::<T>(entity, None);
+    }
+}
+
+fn set_typed<T: Clone + PartialEq + Send + Sync + 'static>(
+    entity: &mut EntityWorldMut,
+    layer: Layer,
+    value: T,
+) {
+    entity
+        .get_mut::<LayerValues<T>>()
+        .expect("field is not managed by the Layers manager with this type")
+        .values[layer.index()] = Some(value);
+    resolve::<T>(entity, None);
+}
+
+/// Recomputes the effective value from the layers.
+///
+/// `written` is the layer whose value is already stored in `ScalarData`, if any.
+fn resolve<T: Clone + PartialEq + Send + Sync + 'static>(
+    entity: &mut EntityWorldMut,
+    written: Option<Layer>,
+) {
+    let values = entity.get::<LayerValues<T>>().expect("inserted with LayerVtable");
+    let Some((layer, value)) = Layer::ALL
+        .into_iter()
+        .rev()
+        .find_map(|layer| Some((layer, values.values[layer.index()].as_ref()?)))
+    else {
+        return;
+    };
+
+    if written != Some(layer)
+        && entity.get::<ScalarData<T>>().expect("checked in capture_typed").0 != *value
+    {
+        let value = value.clone();
+        entity.get_mut::<ScalarData<T>>().expect("checked in capture_typed").0 = value;
+        entity.get_mut::<ConfigNode>().expect("checked in capture_typed").bump();
+    }
+
+    let generation = entity.get::<ConfigNode>().expect("checked in capture_typed").generation;
+    entity.get_mut::<LayerValues<T>>().expect("inserted with LayerVtable").synced = generation;
+    entity.insert(Provenance(layer));
+}
+
+fn layered_entities(world: &mut World) -> Vec<(Entity, LayerVtable)> {
+    world
+        .query::<(Entity, &LayerVtable)>()
+        .iter(world)
+        .map(|(entity, &vtable)| (entity, vtable))
+        .collect()
+}
+
+/// Attributes all changes since the last resolution to `layer`.
+///
+/// Call this after writing to config fields through a layer-unaware path,
+/// e.g. with `Layer::File` after deserializing a file.
+/// If a higher layer provides a value for a changed field,
+/// the field is reverted to the value of that higher layer.
+pub fn capture(world: &mut World, layer: Layer) {
+    for (entity, vtable) in layered_entities(world) {
+        (vtable.capture_fn)(&mut world.entity_mut(entity), layer);
+    }
+}
+
+/// Writes to the root config `C` like [`WorldExt::update_config`](crate::WorldExt::update_config),
+/// attributing the written fields to `layer`.
+///
+/// Changes to other fields remain unattributed.
+///
+/// ```
+/// # use bevy_app::App;
+/// # use bevy_mod_config::{AppExt, Config};
+/// use bevy_mod_config::manager::layer::{self, Layer, Layers};
+///
+/// #[derive(Config)]
+/// struct Settings {
+///     fps: u32,
+/// }
+///
+/// let mut app = App::new();
+/// app.init_config::<Layers, Settings>("settings");
+/// layer::update_config::<Settings>(app.world_mut(), Layer::Cli, |settings| settings.fps.set(144));
+/// ```
+///
+/// # Panics
+/// Panics if `C` was not initialized with [`App::init_config`](crate::AppExt::init_config).
+pub fn update_config<C: ConfigField>(
+    world: &mut World,
+    layer: Layer,
+    update: impl FnOnce(C::Writer<'_>),
+) {
+    let writes = crate::app::collect_writes::<C>(world, update);
+    let fields = writes.fields();
+    writes.apply(world);
+    for field in fields {
+        let mut entity = world.entity_mut(field);
+        if let Some(&vtable) = entity.get::<LayerVtable>() {
+            (vtable.capture_fn)(&mut entity, layer);
+        }
+    }
+}
+
+/// Attributes all unattributed changes to [`Layer::Runtime`].
+///
+/// This is an exclusive system intended to run once per frame,
+/// typically in [`Last`](bevy_app::Last).
+pub fn capture_runtime(world: &mut World) { capture(world, Layer::Runtime); }
+
+/// Removes the values provided by `layer` from all fields,
+/// falling back to the values of lower layers.
+///
+/// Clearing [`Layer::Default`] has no effect.
+pub fn clear(world: &mut World, layer: Layer) {
+    for (entity, vtable) in layered_entities(world) {
+        (vtable.clear_fn)(&mut world.entity_mut(entity), layer);
+    }
+}
+
+/// Sets the value provided by `layer` for the scalar field `entity`.
+///
+/// The effective value is updated if `layer` is the highest layer providing a value.
+///
+/// # Panics
+/// Panics if `entity` is not a scalar field of type `T` managed by [`Layers`].
+pub fn set<T: Clone + PartialEq + Send + Sync + 'static>(
+    world: &mut World,
+    entity: Entity,
+    layer: Layer,
+    value: T,
+) {
+    set_typed(&mut world.entity_mut(entity), layer, value);
+}
+
+/// Returns the value of the highest persisted layer
+/// if the current value of the scalar field `entity` is provided by a layer that is not persisted,
+/// i.e. [`Layer::Remote`], [`Layer::Env`] or [`Layer::Cli`].
+///
+/// These layers only override the values of the running app,
+/// so saving a config file must not write their values into it.
+///
+/// Returns `None` if the current value is provided by a persisted layer
+/// or the field is not managed by [`Layers`] with type `T`.
+#[cfg(any(feature = "serde_json", feature = "toml"))]
+pub(crate) fn persisted_layer_value<T: Clone + Send + Sync + 'static>(
+    entity: EntityRef<'_>,
+) -> Option<&T> {
+    let &Provenance(provenance) = entity.get::<Provenance>()?;
+    if provenance.is_persisted() {
+        return None;
+    }
+    let values = entity.get::<LayerValues<T>>()?;
+    Layer::ALL[..provenance.index()]
+        .iter()
+        .rev()
+        .filter(|layer| layer.is_persisted())
+        .find_map(|layer| values.values[layer.index()].as_ref())
+}
+
+/// Returns the layer that provides the current value of the scalar field `entity`,
+/// or `None` if the field is not managed by [`Layers`].
+#[must_use]
+pub fn provenance(world: &World, entity: Entity) -> Option<Layer> {
+    world.get::<Provenance>(entity).map(|&Provenance(layer)| layer)
+}
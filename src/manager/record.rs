@@ -0,0 +1,304 @@
+//! Recording and replaying changes to config fields.
+//!
+//! The [`Recorder`] manager snapshots the value of every scalar field
+//! whenever [`record`] observes that its generation has changed,
+//! appending a timestamped [`Change`] to the [`Recording`] resource.
+//! A recording can later be replayed against a world with [`Replay`],
+//! e.g. to reproduce a bug report or to demo a sequence of settings changes.
+//!
+//! The first call to [`record`] captures the value of all fields,
+//! so replaying a recording from the start reproduces the complete initial state.
+//!
+//! With the `serde_json` feature, values are stored as JSON through the
+//! [`ValueVtable`](super::serde::json::ValueVtable) of each field,
+//! so a [`Recording`] can be serialized, e.g. to attach it to a bug report,
+//! and only fields of [`SerdeScalar`](super::serde::SerdeScalar) types can be recorded.
+//! Without it, values are stored as type-erased clones and the recording only lives in memory.
+
+use alloc::string::String;
+#[cfg(not(feature = "serde_json"))]
+use alloc::sync::Arc;
+use alloc::vec::Vec;
+#[cfg(not(feature = "serde_json"))]
+use core::any::Any;
+use core::fmt;
+#[cfg(feature = "serde_json")]
+use core::marker::PhantomData;
+use core::time::Duration;
+
+use bevy_ecs::bundle::Bundle;
+use bevy_ecs::component::Component;
+use bevy_ecs::entity::Entity;
+use bevy_ecs::query::With;
+use bevy_ecs::resource::Resource;
+#[cfg(feature = "serde_json")]
+use bevy_ecs::world::EntityMut;
+use bevy_ecs::world::{EntityRef, EntityWorldMut, World};
+use hashbrown::HashMap;
+#[cfg(feature = "serde_json")]
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+#[cfg(feature = "serde_json")]
+use super::serde::SerdeScalar;
+#[cfg(feature = "serde_json")]
+use super::serde::json::{self, ValueVtable as RecordVtable};
+#[cfg(not(feature = "serde_json"))]
+use crate::ScalarData;
+use crate::manager::{self, Manager};
+use crate::{ConfigNode, FieldGeneration};
+
+/// A [`Manager`] that allows recording and replaying changes to config fields.
+///
+/// See the [module documentation](self) for usage.
+#[derive(Default)]
+pub struct Recorder;
+
+impl Manager for Recorder {}
+
+#[cfg(feature = "serde_json")]
+impl<T> manager::Supports<T> for Recorder
+where
+    T: SerdeScalar,
+{
+    fn new_entity_for_type(&mut self) -> impl Bundle {
+        (json::RegisterValueVtable::<Self, T>(PhantomData), LastRecorded(None))
+    }
+}
+
+#[cfg(not(feature = "serde_json"))]
+impl<T> manager::Supports<T> for Recorder
+where
+    T: Clone + Send + Sync + 'static,
+{
+    fn new_entity_for_type(&mut self) -> impl Bundle {
+        (
+            RecordVtable { snapshot_fn: snapshot_typed::<T>, apply_fn: apply_typed::<T> },
+            LastRecorded(None),
+        )
+    }
+}
+
+#[cfg(feature = "serde_json")]
+type Value = serde_json::Value;
+#[cfg(not(feature = "serde_json"))]
+type Value = Arc<dyn Any + Send + Sync>;
+
+/// A type erasure vtable attached to each scalar field to snapshot and restore its value.
+#[cfg(not(feature = "serde_json"))]
+#[derive(Component, Clone, Copy)]
+struct RecordVtable {
+    snapshot_fn: fn(EntityRef) -> Value,
+    apply_fn:    fn(&mut EntityWorldMut, &Value) -> bool,
+}
+
+/// The generation of the field when it was last recorded or replayed,
+/// including any bump deferred by a change batch.
+#[derive(Component)]
+struct LastRecorded(Option<FieldGeneration>);
+
+#[cfg(feature = "serde_json")]
+fn snapshot(vtable: &RecordVtable, entity: EntityRef) -> Option<Value> {
+    vtable.to_value(entity).ok()
+}
+
+#[cfg(not(feature = "serde_json"))]
+#[expect(clippy::unnecessary_wraps, reason = "same signature as the serde_json variant")]
+fn snapshot(vtable: &RecordVtable, entity: EntityRef) -> Option<Value> {
+    Some((vtable.snapshot_fn)(entity))
+}
+
+/// Returns `false` without changing the field if `value` is not valid for the field type.
+fn apply(vtable: &RecordVtable, entity: &mut EntityWorldMut, value: &Value) -> bool {
+    #[cfg(feature = "serde_json")]
+    let applied = vtable.set_value(&mut EntityMut::from(&mut *entity), value.clone()).is_ok();
+    #[cfg(not(feature = "serde_json"))]
+    let applied = (vtable.apply_fn)(entity, value);
+    if !applied {
+        return false;
+    }
+
+    // Inside a change batch, the bump is deferred until the batch ends,
+    // so the replayed value is recorded at the generation it will have after that.
+    let node = entity.get::<ConfigNode>().expect("recorded field must be a config node");
+    let generation = node.settled_generation();
+    entity.get_mut::<LastRecorded>().expect("inserted with RecordVtable").0 = Some(generation);
+    true
+}
+
+#[cfg(not(feature = "serde_json"))]
+fn snapshot_typed<T: Clone + Send + Sync + 'static>(entity: EntityRef) -> Value {
+    let data = entity
+        .get::<ScalarData<T>>()
+        .expect("RecordVtable must be inserted with the corresponding ScalarData type");
+    Arc::new(data.0.clone())
+}
+
+/// Returns `false` without changing the field if `value` is not of type `T`.
+#[cfg(not(feature = "serde_json"))]
+fn apply_typed<T: Clone + Send + Sync + 'static>(
+    entity: &mut EntityWorldMut,
+    value: &Value,
+) -> bool {
+    let Some(value) = value.downcast_ref::<T>() else { return false };
+    let value = value.clone();
+    entity
+        .get_mut::<ScalarData<T>>()
+        .expect("RecordVtable must be inserted with the corresponding ScalarData type")
+        .0 = value;
+    entity.get_mut::<ConfigNode>().expect("recorded field must be a config node").bump();
+    true
+}
+
+/// A timestamped change to a scalar field.
+#[derive(Clone)]
+pub struct Change {
+    /// The time at which the change was recorded.
+    pub timestamp: Duration,
+    /// The path of the changed field.
+    pub path:      Vec<String>,
+    value:         Value,
+}
+
+/// The changes recorded by [`record`].
+///
+/// With the `serde_json` feature, this type implements [`Serialize`] and [`Deserialize`].
+#[derive(Resource, Default, Clone)]
+pub struct Recording {
+    changes: Vec<Change>,
+}
+
+impl Recording {
+    /// Returns the recorded changes in chronological order.
+    #[must_use]
+    pub fn changes(&self) -> &[Change] { &self.changes }
+}
+
+#[cfg(feature = "serde_json")]
+impl Serialize for Recording {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_seq(
+            self.changes.iter().map(|change| (change.timestamp, &change.path, &change.value)),
+        )
+    }
+}
+
+#[cfg(feature = "serde_json")]
+impl<'de> Deserialize<'de> for Recording {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let changes = Vec::<(Duration, Vec<String>, Value)>::deserialize(deserializer)?;
+        Ok(Self {
+            changes: changes
+                .into_iter()
+                .map(|(timestamp, path, value)| Change { timestamp, path, value })
+                .collect(),
+        })
+    }
+}
+
+/// Records all scalar fields that changed since the last call into the [`Recording`] resource.
+///
+/// `timestamp` must not be earlier than the timestamp passed in the previous call.
+pub fn record(world: &mut World, timestamp: Duration) {
+    world.flush();
+    let mut query = world.query::<(EntityRef, &RecordVtable, &ConfigNode, &LastRecorded)>();
+    let changed: Vec<_> = query
+        .iter(world)
+        .filter(|(_, _, node, last)| last.0 != Some(node.settled_generation()))
+        .filter_map(|(entity, vtable, node, _)| {
+            let value = snapshot(vtable, entity)?;
+            Some((
+                entity.id(),
+                node.settled_generation(),
+                Change { timestamp, path: node.path.clone(), value },
+            ))
+        })
+        .collect();
+
+    let mut recorded = Vec::with_capacity(changed.len());
+    for (entity, generation, change) in changed {
+        world.entity_mut(entity).insert(LastRecorded(Some(generation)));
+        recorded.push(change);
+    }
+    recorded.sort_by(|a, b| a.path.cmp(&b.path));
+    world.get_resource_or_init::<Recording>().changes.extend(recorded);
+}
+
+/// Records changes with the elapsed [`Time`](bevy_time::Time) as the timestamp.
+///
+/// This is an exclusive system intended to run once per frame,
+/// typically in [`Last`](bevy_app::Last).
+#[cfg(feature = "bevy_time")]
+pub fn record_system(world: &mut World) {
+    let timestamp = world.resource::<bevy_time::Time>().elapsed();
+    record(world, timestamp);
+}
+
+/// Clears the [`Recording`] resource,
+/// such that the next call to [`record`] captures the value of all fields again.
+pub fn restart(world: &mut World) {
+    world.remove_resource::<Recording>();
+    let entities: Vec<_> =
+        world.query::<(Entity, &LastRecorded)>().iter(world).map(|(entity, _)| entity).collect();
+    for entity in entities {
+        world.entity_mut(entity).insert(LastRecorded(None));
+    }
+}
+
+/// Replays a [`Recording`] against a world.
+pub struct Replay {
+    recording: Recording,
+    cursor:    usize,
+}
+
+impl Replay {
+    /// Creates a replay starting from the first change in `recording`.
+    #[must_use]
+    pub fn new(recording: Recording) -> Self { Self { recording, cursor: 0 } }
+
+    /// Applies all changes with a timestamp up to `until` that were not applied yet.
+    ///
+    /// Changes to paths that do not exist in `world` are skipped.
+    /// Changes whose value is not valid for the type of the field are skipped
+    /// and returned as [`TypeMismatch`] errors.
+    pub fn advance(&mut self, world: &mut World, until: Duration) -> Vec<TypeMismatch> {
+        world.flush();
+        let targets: HashMap<_, _> = world
+            .query_filtered::<(Entity, &RecordVtable, &ConfigNode), With<LastRecorded>>()
+            .iter(world)
+            .map(|(entity, &vtable, node)| (node.path.clone(), (entity, vtable)))
+            .collect();
+
+        let mut errors = Vec::new();
+        while let Some(change) = self.recording.changes.get(self.cursor)
+            && change.timestamp <= until
+        {
+            if let Some(&(entity, vtable)) = targets.get(&change.path)
+                && !apply(&vtable, &mut world.entity_mut(entity), &change.value)
+            {
+                errors.push(TypeMismatch { path: change.path.clone() });
+            }
+            self.cursor += 1;
+        }
+        errors
+    }
+
+    /// Returns whether all changes have been applied.
+    #[must_use]
+    pub fn is_finished(&self) -> bool { self.cursor >= self.recording.changes.len() }
+}
+
+/// A recorded change skipped by [`Replay::advance`]
+/// because the recorded value is not valid for the type of the field at its path.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TypeMismatch {
+    /// The path of the field.
+    pub path: Vec<String>,
+}
+
+impl fmt::Display for TypeMismatch {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "recorded value at {} does not match the field type", self.path.join("."))
+    }
+}
+
+impl core::error::Error for TypeMismatch {}
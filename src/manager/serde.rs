@@ -2,23 +2,38 @@
 //!
 //! See [`Serde`] for more information.
 //! See the [`json`] module for convenience APIs for JSON ser/deserialization.
+//! See the [`toml`] module for comment-preserving TOML support.
+//! See the [`persistent`] module for storing config data through resource persistence crates.
+//! See the [`remote`] module for applying overrides fetched from a URL.
+//! See the [`scripting`] module for dynamic access from scripting layers.
+//! See the [`server`] module for inspecting and editing the config over HTTP.
 
-use alloc::string::String;
+use alloc::string::{String, ToString};
 use alloc::vec::Vec;
 use core::any::TypeId;
 use core::fmt;
 use core::marker::PhantomData;
 
+use bevy_app::App;
 use bevy_ecs::bundle::Bundle;
+use bevy_ecs::component::Component;
 use bevy_ecs::entity::Entity;
-use bevy_ecs::query::With;
+use bevy_ecs::query::{With, Without};
+#[cfg(any(feature = "serde_json", feature = "toml"))]
+use bevy_ecs::world::EntityMut;
 use bevy_ecs::world::{EntityRef, EntityWorldMut, World};
-use hashbrown::HashMap;
+use hashbrown::{HashMap, HashSet};
 use serde::de::{DeserializeOwned, MapAccess};
 use serde::ser::SerializeMap;
 use serde::{Deserialize, Deserializer, Serialize, Serializer};
 
-use crate::{ConfigNode, EnumDiscriminant, EnumDiscriminantWrapper, Manager, ScalarData, manager};
+use crate::{
+    ChildNodeOf, ConditionalRelevance, ConfigNode, Deprecated, DiscriminantField, EnumDiscriminant,
+    EnumDiscriminantWrapper, FieldGeneration, Manager, MigrationError, ScalarData, SerdeRename,
+    SkipSerde, WorldExt, manager,
+};
+#[cfg(any(feature = "serde_json", feature = "toml"))]
+use crate::{ScalarConstraint, temporary};
 
 /// Defines format-specific behavior for a [`Serde`] manager.
 ///
@@ -43,12 +58,31 @@ pub trait Adapter: Send + Sync + 'static {
     type DeInput<'de>: Deserializer<'de>;
     /// The key type used for keys in the deserialized map.
     type DeKey<'de>: fmt::Debug + Deserialize<'de>;
+    /// Converts a deserialized key to the path segments it represents.
+    ///
+    /// The path is relative to the enclosing map,
+    /// which is the root map unless the document uses nested maps.
+    ///
+    /// # Migration
+    /// This method replaces [`index_map_by_de_key`](Self::index_map_by_de_key),
+    /// which looked up the map entry of a key directly.
+    /// Adapters should return the path that they previously used as the map key,
+    /// e.g. `key.split('.').map(String::from).collect()`,
+    /// and [`Serde`] performs the lookup of both flat and nested keys.
+    fn de_key_path(&self, key: Self::DeKey<'_>) -> Vec<String>;
+
     /// Looks up the corresponding map entry upon deserializing a key.
+    ///
+    /// [`Serde`] no longer calls this method.
+    /// The default implementation looks up the path returned by [`de_key_path`](Self::de_key_path).
+    #[deprecated(note = "implement `de_key_path` instead")]
     fn index_map_by_de_key<'map, V>(
         &self,
         map: &'map HashMap<Vec<String>, V>,
         key: Self::DeKey<'_>,
-    ) -> Option<&'map V>;
+    ) -> Option<&'map V> {
+        map.get(&self.de_key_path(key))
+    }
 }
 
 /// Stores the type-specific serialization and deserialization vtable.
@@ -69,6 +103,26 @@ pub trait TypedAdapter: Send + Sync + 'static {
         ser: &mut Self::SerContext<'a>,
     ) -> Result<(), Self::SerError<'a>>;
 
+    /// Writes `variant` as the value of the enum discriminant field `entity`
+    /// when the [`KeyCase`] of the manager converts variant names.
+    ///
+    /// The default implementation writes the unconverted variant name with
+    /// [`serialize_once`](Self::serialize_once).
+    /// Deserialization accepts both forms.
+    ///
+    /// # Errors
+    /// Errors from the serializer.
+    fn serialize_variant_once<'a>(
+        &self,
+        entity: EntityRef,
+        variant: &str,
+        path: &[String],
+        ser: &mut Self::SerContext<'a>,
+    ) -> Result<(), Self::SerError<'a>> {
+        let _ = variant;
+        self.serialize_once(entity, path, ser)
+    }
+
     /// Deserializes the value of the type of interest from a map
     /// and writes it to the entity.
     ///
@@ -77,6 +131,9 @@ pub trait TypedAdapter: Send + Sync + 'static {
     ///
     /// In the current implementation, the error type may be incorrectly wrapped with
     /// `Error::custom` of another deserializer type due to intermediate conversions.
+    ///
+    /// Errors from invalid values should only be returned after the value has been consumed,
+    /// so that [lenient loading](Serde::deserialize_lenient) can continue with the next key.
     fn deserialize_map_value<'de, M: MapAccess<'de>>(
         &self,
         entity: EntityWorldMut,
@@ -87,8 +144,13 @@ pub trait TypedAdapter: Send + Sync + 'static {
 /// A [`Manager`] that serializes config data using Serde.
 #[derive(Clone)]
 pub struct Serde<A: Adapter> {
-    adapter: A,
-    types:   HashMap<TypeId, Typed<A::Typed>>,
+    adapter:           A,
+    types:             HashMap<TypeId, Typed<A::Typed>>,
+    key_case:          KeyCase,
+    inactive_variants: InactiveVariants,
+    /// Fields excluded from persistence due to a duplicate key,
+    /// detected when their root is initialized.
+    duplicate_keys:    Vec<(Entity, DuplicateKey)>,
 }
 
 type ScannedKey = (Vec<String>, Entity);
@@ -96,33 +158,158 @@ type ScannedKey = (Vec<String>, Entity);
 #[derive(Clone)]
 struct Typed<A> {
     adapter:   A,
-    scan_keys: fn(&mut World, &mut Vec<ScannedKey>),
+    scan_keys: fn(&World, &mut Vec<ScannedKey>),
+    /// The number of live field entities of this type.
+    /// The entry is removed when this drops to zero.
+    entities:  usize,
 }
 
 impl<A: Adapter + Default> Default for Serde<A> {
-    fn default() -> Self { Serde { adapter: A::default(), types: HashMap::new() } }
+    fn default() -> Self { Serde::new_with_adapter(A::default()) }
 }
 
 impl<A: Adapter> Serde<A> {
     /// Creates a new [`Serde`] manager with the given adapter.
-    pub fn new_with_adapter(adapter: A) -> Self { Serde { adapter, types: HashMap::new() } }
+    pub fn new_with_adapter(adapter: A) -> Self {
+        Serde {
+            adapter,
+            types: HashMap::new(),
+            key_case: KeyCase::default(),
+            inactive_variants: InactiveVariants::default(),
+            duplicate_keys: Vec::new(),
+        }
+    }
 
-    fn keys_with_types(&self, world: &mut World) -> Vec<(ScannedKey, &Typed<A::Typed>)> {
-        let mut keys_with_types = Vec::new();
-        let types: Vec<_> = self.types.values().collect();
+    /// Sets the naming policy applied to each path segment of persistence keys.
+    #[must_use]
+    pub fn with_key_case(mut self, key_case: KeyCase) -> Self {
+        self.key_case = key_case;
+        self
+    }
+
+    /// Sets whether the fields of inactive enum variants are saved.
+    #[must_use]
+    pub fn with_inactive_variants(mut self, inactive_variants: InactiveVariants) -> Self {
+        self.inactive_variants = inactive_variants;
+        self
+    }
+
+    /// Returns the fields excluded from persistence
+    /// because their persistence key is already used by another field.
+    pub fn duplicate_keys(&self) -> impl Iterator<Item = &DuplicateKey> {
+        self.duplicate_keys.iter().map(|(_, duplicate)| duplicate)
+    }
 
+    /// Scans the keys of all fields, or only the fields under `root` if specified.
+    ///
+    /// `root` is matched against the unconverted root key passed to
+    /// [`init_config`](crate::AppExt::init_config).
+    /// Fields listed in [`duplicate_keys`](Self::duplicate_keys) are skipped.
+    fn keys_with_types(
+        &self,
+        world: &World,
+        root: Option<&str>,
+    ) -> Vec<(ScannedKey, &Typed<A::Typed>)> {
+        let mut keys_with_types = Vec::new();
         let mut keys_buf = Vec::new();
 
-        for typed in types {
+        for typed in self.types.values() {
             (typed.scan_keys)(world, &mut keys_buf);
-            for key in keys_buf.drain(..) {
-                keys_with_types.push((key, typed));
+            for (path, entity) in keys_buf.drain(..) {
+                if root.is_some_and(|root| path.first().is_none_or(|first| first != root))
+                    || self.duplicate_keys.iter().any(|&(excluded, _)| excluded == entity)
+                {
+                    continue;
+                }
+                let persisted = self.key_case.persisted_path(world, path, entity);
+                keys_with_types.push(((persisted, entity), typed));
             }
         }
 
         keys_with_types
     }
 
+    /// Excludes the fields under `root` whose persistence key is already used by another field
+    /// after [key case](KeyCase) conversion and [renaming](SerdeRename).
+    fn detect_duplicate_keys(&mut self, world: &World, root: Entity) {
+        let mut scanned = Vec::new();
+        for typed in self.types.values() {
+            (typed.scan_keys)(world, &mut scanned);
+        }
+        scanned.retain(|(_, entity)| {
+            !self.duplicate_keys.iter().any(|&(excluded, _)| excluded == *entity)
+        });
+        // Fields of earlier roots keep their keys.
+        scanned.sort_by_key(|&(_, entity)| is_descendant(world, entity, root));
+
+        let mut original_paths = HashMap::new();
+        for (path, entity) in scanned {
+            let persisted = self.key_case.persisted_path(world, path.clone(), entity);
+            let original = original_paths.entry(persisted.clone()).or_insert_with(|| path.clone());
+            if *original != path {
+                let duplicate = DuplicateKey {
+                    key:      persisted,
+                    kept:     original.clone(),
+                    excluded: path,
+                };
+                #[cfg(feature = "tracing")]
+                tracing::warn!("{duplicate}");
+                self.duplicate_keys.push((entity, duplicate));
+            }
+        }
+    }
+
+    /// Returns whether any field under `root` has changed
+    /// since the last call to [`mark_clean`](Self::mark_clean) for that root.
+    ///
+    /// Fields that have never been marked clean are always dirty.
+    pub fn is_dirty(&self, world: &mut World, root: &str) -> bool {
+        let mut query =
+            world.query_filtered::<(&ConfigNode, &SavedGeneration<A>), Without<SkipSerde>>();
+        query.iter(world).any(|(node, saved)| {
+            node.path.first().is_some_and(|first| first == root)
+                && saved.generation != Some(node.generation)
+        })
+    }
+
+    /// Marks the fields under `root`, or all fields if `None`, as clean.
+    ///
+    /// This should be called after the data has been persisted or loaded,
+    /// such that [`is_dirty`](Self::is_dirty) only reports subsequent changes.
+    pub fn mark_clean(&self, world: &mut World, root: Option<&str>) {
+        for (node, mut saved) in
+            world.query::<(&ConfigNode, &mut SavedGeneration<A>)>().iter_mut(world)
+        {
+            if root.is_none_or(|root| node.path.first().is_some_and(|first| first == root)) {
+                saved.generation = Some(node.generation);
+            }
+        }
+    }
+
+    /// Returns the current generations of the fields under `root`.
+    #[cfg(all(feature = "serde_json", feature = "bevy_tasks"))]
+    fn generations(world: &mut World, root: &str) -> Vec<(Entity, FieldGeneration)> {
+        world
+            .query_filtered::<(Entity, &ConfigNode), With<SavedGeneration<A>>>()
+            .iter(world)
+            .filter(|(_, node)| node.path.first().is_some_and(|first| first == root))
+            .map(|(entity, node)| (entity, node.generation))
+            .collect()
+    }
+
+    /// Returns the sorted, deduplicated root keys of all fields managed by this manager.
+    pub fn roots(&self, world: &mut World) -> Vec<String> {
+        let mut roots = Vec::new();
+        let mut keys_buf = Vec::new();
+        for typed in self.types.values() {
+            (typed.scan_keys)(world, &mut keys_buf);
+            roots.extend(keys_buf.drain(..).filter_map(|(path, _)| path.into_iter().next()));
+        }
+        roots.sort();
+        roots.dedup();
+        roots
+    }
+
     /// Serializes all config data in the world to a map.
     ///
     /// See adapter-dependent impls for more ergonomic APIs.
@@ -134,18 +321,63 @@ impl<A: Adapter> Serde<A> {
         world: &mut World,
         input: A::SerInput<'a>,
     ) -> Result<<A::SerInput<'a> as Serializer>::Ok, <A::SerInput<'a> as Serializer>::Error> {
-        let mut keys = self.keys_with_types(world);
+        self.serialize_filtered(world, None, input)
+    }
+
+    /// Serializes the config data under a single root key to a map.
+    ///
+    /// This allows storing each root in a separate file.
+    /// Keys in the output are still full paths including the root key.
+    ///
+    /// # Errors
+    /// Errors from the serializer.
+    pub fn serialize_root<'a>(
+        &self,
+        world: &mut World,
+        root: &str,
+        input: A::SerInput<'a>,
+    ) -> Result<<A::SerInput<'a> as Serializer>::Ok, <A::SerInput<'a> as Serializer>::Error> {
+        self.serialize_filtered(world, Some(root), input)
+    }
+
+    fn serialize_filtered<'a>(
+        &self,
+        world: &mut World,
+        root: Option<&str>,
+        input: A::SerInput<'a>,
+    ) -> Result<<A::SerInput<'a> as Serializer>::Ok, <A::SerInput<'a> as Serializer>::Error> {
+        let mut keys = self.keys_with_types(world, root);
+        retain_saved(world, &mut keys, self.inactive_variants);
         keys.sort_by(|((path1, _), _), ((path2, _), _)| path1.cmp(path2));
 
         let mut map_ser = input.serialize_map(Some(keys.len()))?;
         for ((path, entity), typed) in keys {
-            typed.adapter.serialize_once(world.entity(entity), &path, &mut map_ser)?;
+            let entity = world.entity(entity);
+            match self.converted_variant(entity) {
+                Some(variant) => {
+                    typed.adapter.serialize_variant_once(entity, &variant, &path, &mut map_ser)?;
+                }
+                None => typed.adapter.serialize_once(entity, &path, &mut map_ser)?,
+            }
         }
         map_ser.end()
     }
 
+    /// Returns the variant name to persist for the enum discriminant field `entity`,
+    /// or `None` if `entity` is not a discriminant or the key case preserves variant names.
+    fn converted_variant(&self, entity: EntityRef) -> Option<String> {
+        if self.key_case == KeyCase::Preserve {
+            return None;
+        }
+        let discrim = entity.get::<DiscriminantField>()?;
+        Some(self.key_case.convert(discrim.variant_name(entity)))
+    }
+
     /// Deserializes config data from a map and writes them to the config entities in the world.
     ///
+    /// Keys may be flat paths (`"ui.thickness"`), nested maps (`{"ui": {"thickness": 5}}`),
+    /// or any mixture of both within the same document.
+    ///
     /// See adapter-dependent impls for more ergonomic APIs.
     ///
     /// # Errors
@@ -155,21 +387,376 @@ impl<A: Adapter> Serde<A> {
         world: &mut World,
         input: A::DeInput<'de>,
     ) -> Result<(), <A::DeInput<'de> as Deserializer<'de>>::Error> {
-        let keys: HashMap<_, _> = self
-            .keys_with_types(world)
-            .into_iter()
-            .map(|((path, entity), typed)| (path, (entity, typed)))
-            .collect();
+        self.deserialize_filtered(world, None, input, false).map(drop)
+    }
+
+    /// Deserializes config data like [`deserialize`](Self::deserialize),
+    /// but collects the errors of invalid values instead of aborting on the first one.
+    ///
+    /// All valid values are applied, and fields with invalid values are left unchanged.
+    /// Returns the errors of the skipped values,
+    /// so that the app can log them instead of discarding the entire input.
+    ///
+    /// # Errors
+    /// Errors from the deserializer if the input itself is malformed.
+    pub fn deserialize_lenient<'de>(
+        &self,
+        world: &mut World,
+        input: A::DeInput<'de>,
+    ) -> Result<Vec<KeyError>, <A::DeInput<'de> as Deserializer<'de>>::Error> {
+        self.deserialize_filtered(world, None, input, true)
+    }
+
+    /// Deserializes config data for a single root key from a map.
+    ///
+    /// Keys in the input that belong to other roots are ignored,
+    /// so that each root can be loaded independently from its own file.
+    ///
+    /// # Errors
+    /// Errors from the deserializer.
+    pub fn deserialize_root<'de>(
+        &self,
+        world: &mut World,
+        root: &str,
+        input: A::DeInput<'de>,
+    ) -> Result<(), <A::DeInput<'de> as Deserializer<'de>>::Error> {
+        self.deserialize_filtered(world, Some(root), input, false).map(drop)
+    }
+
+    fn deserialize_filtered<'de>(
+        &self,
+        world: &mut World,
+        root: Option<&str>,
+        input: A::DeInput<'de>,
+        lenient: bool,
+    ) -> Result<Vec<KeyError>, <A::DeInput<'de> as Deserializer<'de>>::Error> {
+        world.batch_changes(|world| {
+            let keys = self.key_lookup(world, root);
+            let mut state = LoadState::new(lenient);
+            let visitor = Visitor {
+                adapter: &self.adapter,
+                keys:    &keys,
+                world:   &mut *world,
+                prefix:  Vec::new(),
+                state:   &mut state,
+            };
+            input.deserialize_map(visitor)?;
+            state.finish(world)
+        })
+    }
+
+    fn key_lookup(&self, world: &mut World, root: Option<&str>) -> KeyLookup<'_, A::Typed> {
+        let mut lookup = KeyLookup { fields: HashMap::new(), prefixes: HashSet::new() };
+        for ((path, entity), typed) in self.keys_with_types(world, root) {
+            for len in 1..path.len() {
+                lookup.prefixes.insert(path[..len].to_vec());
+            }
+            lookup.fields.insert(path, (entity, typed));
+        }
+        lookup
+    }
+}
+
+/// Whether a [`Serde`] manager saves the fields of enum variants other than the active one.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum InactiveVariants {
+    /// Save the fields of all variants,
+    /// so that switching back to a variant restores its previous values after reloading.
+    #[default]
+    Save,
+    /// Only save the discriminant and the fields of the active variant.
+    ///
+    /// Since fields missing in the loaded data are left unchanged,
+    /// the fields of inactive variants keep their defaults when loading at startup.
+    Skip,
+}
+
+/// Removes [`Deprecated`] fields,
+/// and the fields of inactive enum variants if they are skipped, from the keys to save.
+fn retain_saved<T>(
+    world: &World,
+    keys: &mut Vec<(ScannedKey, T)>,
+    inactive_variants: InactiveVariants,
+) {
+    keys.retain(|((_, entity), _)| {
+        !world.entity(*entity).contains::<Deprecated>()
+            && (inactive_variants == InactiveVariants::Save || !in_inactive_variant(world, *entity))
+    });
+}
+
+/// Tests whether `entity` is a field of an inactive enum variant or its descendant.
+fn in_inactive_variant(world: &World, entity: Entity) -> bool {
+    let mut current = Some(entity);
+    while let Some(node) = current {
+        let node = world.entity(node);
+        if let Some(&ConditionalRelevance { dependency, is_entity_relevant }) = node.get()
+            && world.entity(dependency).contains::<DiscriminantField>()
+            && !is_entity_relevant(world.entity(dependency))
+        {
+            return true;
+        }
+        current = node.get::<ChildNodeOf>().map(|parent| parent.0);
+    }
+    false
+}
+
+/// Migrates the loaded values of [`Deprecated`] fields into their replacements.
+///
+/// A deprecated field is not migrated if its replacement was also loaded,
+/// so that the value of the replacement field takes precedence.
+///
+/// Returns the paths of the deprecated fields that could not be migrated.
+fn migrate_deprecated(world: &mut World, loaded: &[Entity]) -> Vec<(Vec<String>, MigrationError)> {
+    let mut errors = Vec::new();
+    for &entity in loaded {
+        let Some(deprecated) = world.get::<Deprecated>(entity).cloned() else { continue };
+        let Some(migrate) = deprecated.migrate else { continue };
+        let result = match deprecated.replacement(world) {
+            Some(replacement) if loaded.contains(&replacement) => Ok(()),
+            Some(replacement) => migrate(world, entity, replacement),
+            None => Err(MigrationError::UnresolvedReplacement),
+        };
+        if let Err(err) = result {
+            let node = world.get::<ConfigNode>(entity).expect("loaded fields are config nodes");
+            errors.push((node.path.clone(), err));
+        }
+    }
+    errors
+}
+
+/// Tracks the fields loaded from a single input.
+struct LoadState {
+    /// The field entities loaded so far.
+    loaded: Vec<Entity>,
+    /// The errors of skipped values, or `None` if loading aborts on the first error.
+    errors: Option<Vec<KeyError>>,
+}
+
+impl LoadState {
+    fn new(lenient: bool) -> Self {
+        LoadState { loaded: Vec::new(), errors: lenient.then(Vec::new) }
+    }
+
+    /// Handles an error loading the value at `path`,
+    /// collecting it in lenient mode or propagating it otherwise.
+    fn recover<E: fmt::Display>(&mut self, path: &[String], err: E) -> Result<(), E> {
+        match &mut self.errors {
+            Some(errors) => {
+                errors.push(KeyError { path: path.to_vec(), message: err.to_string() });
+                Ok(())
+            }
+            None => Err(err),
+        }
+    }
+
+    /// Applies post-load migrations and returns the collected errors.
+    ///
+    /// Failed migrations are handled like invalid values at the path of the deprecated field.
+    fn finish<E: serde::de::Error>(mut self, world: &mut World) -> Result<Vec<KeyError>, E> {
+        for (path, err) in migrate_deprecated(world, &self.loaded) {
+            self.recover(&path, E::custom(format_args!("cannot migrate deprecated field: {err}")))?;
+        }
+        Ok(self.errors.unwrap_or_default())
+    }
+}
+
+/// An invalid value skipped during lenient loading.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct KeyError {
+    /// The persisted path of the value.
+    pub path:    Vec<String>,
+    /// The message of the deserialization error.
+    pub message: String,
+}
+
+impl fmt::Display for KeyError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "invalid value at {}: {}", self.path.join("."), self.message)
+    }
+}
+
+impl core::error::Error for KeyError {}
+
+/// A field excluded from persistence by a [`Serde`] manager
+/// because another field has the same persistence key
+/// after [key case](KeyCase) conversion and [renaming](SerdeRename).
+///
+/// Duplicate keys are detected when the root of the excluded field is initialized.
+/// The field of the earlier root keeps the key.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DuplicateKey {
+    /// The persistence key shared by both fields.
+    pub key:      Vec<String>,
+    /// The path of the field that keeps the key.
+    pub kept:     Vec<String>,
+    /// The path of the field that is neither saved nor loaded.
+    pub excluded: Vec<String>,
+}
+
+impl fmt::Display for DuplicateKey {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "config field {} is not persisted because {} has the same persistence key {}",
+            self.excluded.join("."),
+            self.kept.join("."),
+            self.key.join("."),
+        )
+    }
+}
+
+impl core::error::Error for DuplicateKey {}
+
+/// Tests whether `entity` is `root` or one of its descendants.
+fn is_descendant(world: &World, entity: Entity, root: Entity) -> bool {
+    let mut current = Some(entity);
+    while let Some(node) = current {
+        if node == root {
+            return true;
+        }
+        current = world.get::<ChildNodeOf>(node).map(|parent| parent.0);
+    }
+    false
+}
+
+/// Resolves deserialized keys to the fields they refer to.
+struct KeyLookup<'a, A> {
+    /// Maps full field paths to their entity and typed adapter.
+    fields:   HashMap<Vec<String>, (Entity, &'a Typed<A>)>,
+    /// All proper prefixes of field paths, which may appear as nested maps.
+    prefixes: HashSet<Vec<String>>,
+}
+
+/// Naming policy applied to each path segment when generating persistence keys.
+///
+/// Path segments are derived from field identifiers, variant names and root keys.
+/// Words are split at underscores, hyphens and lowercase-to-uppercase boundaries.
+/// The values of enum discriminants are converted in the same way,
+/// and loading accepts variant names in any case.
+///
+/// Fields whose keys become equal after conversion,
+/// e.g. the roots `user_interface` and `userInterface` with [`KeyCase::Camel`],
+/// are reported as [`DuplicateKey`]s when the later root is initialized
+/// and excluded from persistence.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum KeyCase {
+    /// Use path segments as-is.
+    #[default]
+    Preserve,
+    /// Converts segments to `snake_case`.
+    Snake,
+    /// Converts segments to `camelCase`.
+    Camel,
+    /// Converts segments to `kebab-case`.
+    Kebab,
+}
+
+impl KeyCase {
+    /// Converts a single path segment to this case.
+    #[must_use]
+    pub fn convert(self, segment: &str) -> String {
+        let (separator, camel) = match self {
+            KeyCase::Preserve => return segment.into(),
+            KeyCase::Snake => (Some('_'), false),
+            KeyCase::Camel => (None, true),
+            KeyCase::Kebab => (Some('-'), false),
+        };
+
+        let mut output = String::with_capacity(segment.len());
+        for (word_index, word) in split_words(segment).into_iter().enumerate() {
+            if word_index > 0
+                && let Some(separator) = separator
+            {
+                output.push(separator);
+            }
+            for (char_index, ch) in word.chars().enumerate() {
+                if camel && word_index > 0 && char_index == 0 {
+                    output.extend(ch.to_uppercase());
+                } else {
+                    output.extend(ch.to_lowercase());
+                }
+            }
+        }
+        output
+    }
+
+    fn convert_path(self, path: Vec<String>) -> Vec<String> {
+        if self == KeyCase::Preserve {
+            path
+        } else {
+            path.iter().map(|segment| self.convert(segment)).collect()
+        }
+    }
+
+    /// Converts the path of the field `entity` into its persistence key.
+    ///
+    /// Segments of the field and its ancestors with a [`SerdeRename`]
+    /// are replaced verbatim without case conversion.
+    fn persisted_path(self, world: &World, path: Vec<String>, entity: Entity) -> Vec<String> {
+        let mut path = self.convert_path(path);
+        let mut current = Some(entity);
+        while let Some(node) = current {
+            let node = world.entity(node);
+            if let Some(&SerdeRename(rename)) = node.get()
+                && let Some(config_node) = node.get::<ConfigNode>()
+            {
+                path[config_node.path.len() - 1] = String::from(rename);
+            }
+            current = node.get::<ChildNodeOf>().map(|parent| parent.0);
+        }
+        path
+    }
+}
+
+/// Returns whether `a` and `b` consist of the same words regardless of case.
+fn same_words(a: &str, b: &str) -> bool {
+    let words = |segment| split_words(segment).into_iter().map(str::to_lowercase);
+    words(a).eq(words(b))
+}
 
-        let visitor = Visitor { adapter: &self.adapter, keys, world };
-        input.deserialize_map(visitor)
+fn split_words(segment: &str) -> Vec<&str> {
+    let mut words = Vec::new();
+    let mut start = 0;
+    let mut prev = None::<char>;
+    for (index, ch) in segment.char_indices() {
+        if ch == '_' || ch == '-' {
+            if start < index {
+                words.push(&segment[start..index]);
+            }
+            start = index + ch.len_utf8();
+            prev = None;
+            continue;
+        }
+        if ch.is_uppercase() && prev.is_some_and(|prev| prev.is_lowercase() || prev.is_numeric()) {
+            words.push(&segment[start..index]);
+            start = index;
+        }
+        prev = Some(ch);
+    }
+    if start < segment.len() {
+        words.push(&segment[start..]);
     }
+    words
 }
 
 struct Visitor<'a, A: Adapter> {
     adapter: &'a A,
-    keys:    HashMap<Vec<String>, (Entity, &'a Typed<A::Typed>)>,
+    keys:    &'a KeyLookup<'a, A::Typed>,
     world:   &'a mut World,
+    /// The path of the nested map being visited.
+    prefix:  Vec<String>,
+    state:   &'a mut LoadState,
+}
+
+impl<'de, A: Adapter> serde::de::DeserializeSeed<'de> for Visitor<'_, A> {
+    type Value = ();
+
+    fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_map(self)
+    }
 }
 
 impl<'de, A: Adapter> serde::de::Visitor<'de> for Visitor<'_, A> {
@@ -184,9 +771,23 @@ impl<'de, A: Adapter> serde::de::Visitor<'de> for Visitor<'_, A> {
         M: MapAccess<'de>,
     {
         while let Some(key) = map.next_key::<A::DeKey<'de>>()? {
-            if let Some(&(entity_id, typed)) = self.adapter.index_map_by_de_key(&self.keys, key) {
+            let mut path = self.prefix.clone();
+            path.extend(self.adapter.de_key_path(key));
+
+            if let Some(&(entity_id, typed)) = self.keys.fields.get(&path) {
                 let entity = self.world.entity_mut(entity_id);
-                typed.adapter.deserialize_map_value(entity, &mut map)?;
+                match typed.adapter.deserialize_map_value(entity, &mut map) {
+                    Ok(()) => self.state.loaded.push(entity_id),
+                    Err(err) => self.state.recover(&path, err)?,
+                }
+            } else if self.keys.prefixes.contains(&path) {
+                map.next_value_seed(Visitor {
+                    adapter: self.adapter,
+                    keys:    self.keys,
+                    world:   &mut *self.world,
+                    prefix:  path,
+                    state:   &mut *self.state,
+                })?;
             } else {
                 map.next_value::<serde::de::IgnoredAny>()?;
             }
@@ -195,7 +796,28 @@ impl<'de, A: Adapter> serde::de::Visitor<'de> for Visitor<'_, A> {
     }
 }
 
-impl<A: Adapter> Manager for Serde<A> {}
+impl<A: Adapter> Manager for Serde<A> {
+    #[cfg_attr(
+        not(any(feature = "serde_json", feature = "bevy_tasks")),
+        expect(unused_variables, reason = "no adapter installs systems")
+    )]
+    fn build(app: &mut App) {
+        #[cfg(feature = "serde_json")]
+        app.add_message::<json::LoadFailed>();
+
+        // Multiple `Serde` managers in the same app share the pending saves.
+        #[cfg(feature = "bevy_tasks")]
+        if !app.world().contains_resource::<async_save::PendingSaves>() {
+            app.init_resource::<async_save::PendingSaves>();
+            app.add_message::<async_save::SaveCompleted>();
+            app.add_systems(bevy_app::Last, async_save::poll_saves);
+        }
+    }
+
+    fn init_root(&mut self, root: &manager::RootInfo) {
+        self.detect_duplicate_keys(root.world, root.entity);
+    }
+}
 
 impl<A, T> manager::Supports<T> for Serde<A>
 where
@@ -203,16 +825,71 @@ where
     T: SerdeScalar,
 {
     fn new_entity_for_type(&mut self) -> impl Bundle {
-        self.types.entry(TypeId::of::<T>()).or_insert_with(|| Typed {
+        let typed = self.types.entry(TypeId::of::<T>()).or_insert_with(|| Typed {
             adapter:   self.adapter.for_type::<T>(),
-            scan_keys: |world, keys| {
-                let mut query =
-                    world.query_filtered::<(Entity, &ConfigNode), With<ScalarData<T>>>();
-                for (entity, config_data) in query.iter(world) {
-                    keys.push((config_data.path.clone(), entity));
-                }
-            },
+            scan_keys: scan_keys::<T>,
+            entities:  0,
         });
+        typed.entities += 1;
+
+        #[cfg(feature = "serde_json")]
+        let value_vtable = json::RegisterValueVtable::<A, T>(PhantomData);
+        #[cfg(not(feature = "serde_json"))]
+        let value_vtable = ();
+
+        (SavedGeneration::<A> { generation: None, _adapter: PhantomData }, value_vtable)
+    }
+
+    fn remove_entity_for_type(&mut self, entity: Entity) {
+        self.duplicate_keys.retain(|&(excluded, _)| excluded != entity);
+        if let hashbrown::hash_map::Entry::Occupied(mut entry) = self.types.entry(TypeId::of::<T>())
+        {
+            entry.get_mut().entities -= 1;
+            if entry.get().entities == 0 {
+                entry.remove();
+            }
+        }
+    }
+}
+
+/// The generation of a scalar field when it was last saved or loaded.
+///
+/// This is generic over the adapter so that multiple [`Serde`] managers
+/// in the same app track their saves independently.
+#[derive(Component)]
+struct SavedGeneration<A: Adapter> {
+    generation: Option<FieldGeneration>,
+    _adapter:   PhantomData<fn() -> A>,
+}
+
+/// Marks the fields in `saved` as clean at the generations they had when they were serialized,
+/// so that changes made while the data was being written remain dirty.
+///
+/// Fields already marked clean at a newer generation, e.g. by a later synchronous save,
+/// are left unchanged.
+#[cfg(all(feature = "serde_json", feature = "bevy_tasks"))]
+fn mark_saved<A: Adapter>(world: &mut World, saved: &[(Entity, FieldGeneration)]) {
+    for &(entity, generation) in saved {
+        if let Some(mut saved) = world.get_mut::<SavedGeneration<A>>(entity)
+            && saved.generation.is_none_or(|current| current <= generation)
+        {
+            saved.generation = Some(generation);
+        }
+    }
+}
+
+/// Collects the paths of all persisted scalar fields of type `T`,
+/// excluding fields marked with [`SkipSerde`].
+fn scan_keys<T: Send + Sync + 'static>(world: &World, keys: &mut Vec<ScannedKey>) {
+    // `SkipSerde` may not be registered, so it cannot be used as a query filter here.
+    let Some(mut query) = world.try_query_filtered::<(Entity, &ConfigNode), With<ScalarData<T>>>()
+    else {
+        return;
+    };
+    for (entity, config_data) in query.iter(world) {
+        if !world.entity(entity).contains::<SkipSerde>() {
+            keys.push((config_data.path.clone(), entity));
+        }
     }
 }
 
@@ -221,21 +898,61 @@ where
 pub mod json {
     extern crate std;
     use alloc::boxed::Box;
+    use alloc::collections::BTreeMap;
     use alloc::string::String;
     use alloc::vec::Vec;
     use core::any::Any;
+    use core::fmt;
+    use core::marker::PhantomData;
+    use std::fs::File;
     use std::io::{self, BufReader, BufWriter};
-
-    use bevy_ecs::world::{EntityRef, EntityWorldMut, World};
+    use std::path::PathBuf;
+
+    use bevy_ecs::component::Component;
+    use bevy_ecs::entity::Entity;
+    use bevy_ecs::lifecycle::HookContext;
+    use bevy_ecs::message::Message;
+    use bevy_ecs::query::With;
+    use bevy_ecs::system::{Command, Query, SystemParam};
+    use bevy_ecs::world::{DeferredWorld, EntityMut, EntityRef, EntityWorldMut, Mut, World};
+    #[cfg(feature = "bevy_tasks")]
+    use bevy_tasks::{ComputeTaskPool, TaskPool};
     use hashbrown::HashMap;
     use serde::de::{Error as _, MapAccess};
     use serde::ser::SerializeMap as _;
+    use serde::{Deserialize, Serialize};
+    use serde_json::Value;
     use serde_json::ser::{CompactFormatter, Formatter, PrettyFormatter};
     use serde_json::value::RawValue;
 
-    use crate::ScalarData;
+    use super::storage::ConfigStorage;
+    use super::{Adapter as _, OutOfRange, SerdeScalar};
+    use crate::manager::{self, Manager};
+    use crate::{
+        ConfigNode, DiscriminantField, RootNode, ScalarConstraint, ScalarData, WorldExt as _,
+    };
 
     /// A manager that serializes config data to and from [compact](CompactFormatter) JSON.
+    ///
+    /// All field types must be [serializable](SerdeScalar):
+    ///
+    /// ```compile_fail
+    /// # use bevy_app::App;
+    /// use bevy_mod_config::manager::serde::Json;
+    /// use bevy_mod_config::{AppExt, BareField, Config};
+    ///
+    /// #[derive(Clone)]
+    /// struct Uuid(u128);
+    ///
+    /// #[derive(Config)]
+    /// struct Session {
+    ///     #[config(default_with = Some(|| Uuid(42)))]
+    ///     id: BareField<Uuid>,
+    /// }
+    ///
+    /// // error: the trait bound `Uuid: serde::Serialize` is not satisfied
+    /// App::new().init_config_with::<Json, Session>("session", Json::new);
+    /// ```
     pub type Json = super::Serde<JsonAdapter<CompactFormatter>>;
     /// A manager that serializes config data to and from [pretty](PrettyFormatter) JSON.
     pub type Pretty = super::Serde<JsonAdapter<PrettyFormatter<'static>>>;
@@ -243,7 +960,13 @@ pub mod json {
     /// A serde adapter for `serde_json` serializer and deserializer.
     pub struct JsonAdapter<F> {
         /// Builds formatters to pass into `serde_json`.
-        pub formatter: Box<dyn FormatterBuilder<F>>,
+        pub formatter:    Box<dyn FormatterBuilder<F>>,
+        /// Joins the path segments of each field into a JSON key.
+        pub key_syntax:   KeySyntax,
+        /// Represents [`Config`](crate::Config)-derived enums in JSON.
+        pub enum_repr:    EnumRepr,
+        /// Handles loaded values violating the constraints of their field.
+        pub out_of_range: OutOfRange,
     }
 
     impl Json {
@@ -253,37 +976,268 @@ pub mod json {
         /// Creates a new compact JSON manager.
         #[must_use]
         pub fn new() -> Self {
-            Self::new_with_adapter(JsonAdapter { formatter: Box::new(|| CompactFormatter) })
+            Self::new_with_adapter(JsonAdapter {
+                formatter:    Box::new(|| CompactFormatter),
+                key_syntax:   KeySyntax::default(),
+                enum_repr:    EnumRepr::default(),
+                out_of_range: OutOfRange::default(),
+            })
+        }
+    }
+
+    impl<F: Formatter + Send + Sync + 'static> super::Serde<JsonAdapter<F>> {
+        /// Sets the syntax of the JSON keys joined from the path segments of each field.
+        ///
+        /// This must be called before any config is initialized with this manager.
+        ///
+        /// # Panics
+        /// Panics if the separator and the escape character of `key_syntax` are the same.
+        #[must_use]
+        pub fn with_key_syntax(mut self, key_syntax: KeySyntax) -> Self {
+            assert_ne!(
+                key_syntax.separator, key_syntax.escape,
+                "the separator and the escape character must be different"
+            );
+            self.adapter.key_syntax = key_syntax;
+            self
+        }
+
+        /// Sets the representation of [`Config`](crate::Config)-derived enums.
+        #[must_use]
+        pub fn with_enum_repr(mut self, enum_repr: EnumRepr) -> Self {
+            self.adapter.enum_repr = enum_repr;
+            self
+        }
+
+        /// Sets how loaded values violating the constraints of their field are handled.
+        ///
+        /// This must be called before any config is initialized with this manager.
+        #[must_use]
+        pub fn with_out_of_range(mut self, out_of_range: OutOfRange) -> Self {
+            self.adapter.out_of_range = out_of_range;
+            self
         }
     }
 
     impl<F: Default + Send + Sync + 'static> Default for JsonAdapter<F> {
-        fn default() -> Self { JsonAdapter { formatter: Box::new(F::default) } }
+        fn default() -> Self {
+            JsonAdapter {
+                formatter:    Box::new(F::default),
+                key_syntax:   KeySyntax::default(),
+                enum_repr:    EnumRepr::default(),
+                out_of_range: OutOfRange::default(),
+            }
+        }
     }
 
     impl<F: Send + Sync + 'static> Clone for JsonAdapter<F> {
-        fn clone(&self) -> Self { JsonAdapter { formatter: self.formatter.clone() } }
+        fn clone(&self) -> Self {
+            JsonAdapter {
+                formatter:    self.formatter.clone(),
+                key_syntax:   self.key_syntax,
+                enum_repr:    self.enum_repr,
+                out_of_range: self.out_of_range,
+            }
+        }
     }
 
-    /// A dyn-compatible, cloneable trait for constructing a `serde_json` formatter.
-    pub trait FormatterBuilder<F>: Send + Sync + 'static {
-        /// Clones the formatter builder to a new box.
-        fn clone(&self) -> Box<dyn FormatterBuilder<F>>;
-
-        /// Constructs a new formatter.
-        fn call(&self) -> F;
+    /// Selects the subtrees covered by [`content_hash`](super::Serde::content_hash).
+    ///
+    /// Paths are dot-separated field paths such as `"video.resolution"`,
+    /// independent of the [`KeyCase`](super::KeyCase) and [`KeySyntax`] of the manager.
+    /// A field is covered if it is under any included path and not under any excluded path.
+    /// If no paths are included, all fields not under an excluded path are covered.
+    #[derive(Debug, Default, Clone)]
+    pub struct HashScope {
+        include: Vec<Vec<String>>,
+        exclude: Vec<Vec<String>>,
     }
 
-    impl<T, F> FormatterBuilder<F> for T
-    where
-        T: Fn() -> F + Clone + Send + Sync + 'static,
-    {
-        fn clone(&self) -> Box<dyn FormatterBuilder<F>> {
-            Box::new(Clone::clone(self)) as Box<dyn FormatterBuilder<F>>
+    impl HashScope {
+        /// Covers all fields.
+        #[must_use]
+        pub fn all() -> Self { Self::default() }
+
+        /// Covers the fields under `path`.
+        #[must_use]
+        pub fn include(mut self, path: &str) -> Self {
+            self.include.push(path.split('.').map(String::from).collect());
+            self
         }
 
-        fn call(&self) -> F { self() }
-    }
+        /// Excludes the fields under `path`, even if they are under an included path.
+        #[must_use]
+        pub fn exclude(mut self, path: &str) -> Self {
+            self.exclude.push(path.split('.').map(String::from).collect());
+            self
+        }
+
+        fn contains(&self, path: &[String]) -> bool {
+            (self.include.is_empty() || self.include.iter().any(|prefix| path.starts_with(prefix)))
+                && !self.exclude.iter().any(|prefix| path.starts_with(prefix))
+        }
+    }
+
+    /// The 64-bit FNV-1a hash, which is unseeded so that hashes are reproducible across runs.
+    struct Fnv1a(u64);
+
+    impl Default for Fnv1a {
+        fn default() -> Self { Self(0xcbf2_9ce4_8422_2325) }
+    }
+
+    impl Fnv1a {
+        fn write(&mut self, bytes: &[u8]) {
+            for &byte in bytes {
+                self.0 = (self.0 ^ u64::from(byte)).wrapping_mul(0x0100_0000_01b3);
+            }
+        }
+    }
+
+    /// Determines how [`Config`](crate::Config)-derived enums are represented in JSON.
+    ///
+    /// This only applies to the [`Json`] manager.
+    /// The [`Toml`](super::Toml) manager always writes enums in the [`Flat`](Self::Flat)
+    /// representation, where the discriminant and the fields of each variant
+    /// are keys of the table of the enum, e.g. `discrim = "Rgb"` next to a `[color.Rgb]` table.
+    ///
+    /// ```
+    /// # use bevy_mod_config::{AppExt, Config, manager};
+    /// use bevy_mod_config::manager::serde::json::{EnumRepr, Json};
+    ///
+    /// #[derive(Config)]
+    /// enum Color {
+    ///     White,
+    ///     Rgb(f32, f32, f32),
+    /// }
+    ///
+    /// let mut app = bevy_app::App::new();
+    /// app.init_config_with::<Json, Color>("color", || Json::new().with_enum_repr(EnumRepr::Tagged));
+    ///
+    /// let json = app.world().resource::<manager::Instance<Json>>().instance.clone();
+    /// assert_eq!(
+    ///     json.to_string(app.world_mut()).unwrap(),
+    ///     r#"{"color":{"discrim":"White","value":{}}}"#
+    /// );
+    /// ```
+    #[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+    pub enum EnumRepr {
+        /// Writes the discriminant and the fields of all variants as separate keys,
+        /// e.g. `{"color.discrim": "Rgb", "color.Rgb.0": 1.0, ...}`.
+        ///
+        /// This preserves the values of inactive variants across saves.
+        #[default]
+        Flat,
+        /// Writes the enum as a single key holding the discriminant
+        /// and the fields of the active variant only,
+        /// e.g. `{"color": {"discrim": "Rgb", "value": {"0": 1.0, ...}}}`.
+        Tagged,
+        /// Writes the enum as a single key holding the fields of the active variant
+        /// keyed by the variant name, e.g. `{"color": {"Rgb": {"0": 1.0, ...}}}`.
+        ///
+        /// Variants without any persisted fields are written as a string,
+        /// e.g. `{"color": "White"}`.
+        External,
+    }
+
+    /// The persisted path segments under an enum node.
+    struct EnumKeys {
+        /// The last path segment of the discriminant field.
+        discrim: String,
+        /// The path segment of the fields of the active variant.
+        variant: String,
+    }
+
+    /// Maps the persisted path of each enum node to its [`EnumKeys`].
+    type EnumPaths = HashMap<Vec<String>, EnumKeys>;
+
+    /// Describes how the path segments of a field are joined into a single JSON key.
+    ///
+    /// Occurrences of the separator or the escape character within a segment
+    /// are prefixed with the escape character,
+    /// so that segments containing arbitrary strings round-trip safely.
+    /// Keys of segments without either character are unaffected by escaping.
+    ///
+    /// ```
+    /// use bevy_mod_config::manager::serde::json::KeySyntax;
+    ///
+    /// let syntax = KeySyntax::default();
+    /// let path = ["servers".to_string(), "eu.west".to_string(), "port".to_string()];
+    /// let key = syntax.join(&path);
+    /// assert_eq!(key, r"servers.eu\.west.port");
+    /// assert_eq!(syntax.split(&key), path);
+    /// ```
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    pub struct KeySyntax {
+        /// Separates the path segments. Defaults to `.`.
+        pub separator: char,
+        /// Escapes the separator and itself within a segment. Defaults to `\`.
+        pub escape:    char,
+    }
+
+    impl Default for KeySyntax {
+        fn default() -> Self { Self { separator: '.', escape: '\\' } }
+    }
+
+    impl KeySyntax {
+        /// Joins the path segments into a key, escaping each segment.
+        #[must_use]
+        pub fn join(&self, path: &[String]) -> String {
+            let mut key = String::new();
+            for (index, segment) in path.iter().enumerate() {
+                if index > 0 {
+                    key.push(self.separator);
+                }
+                for ch in segment.chars() {
+                    if ch == self.separator || ch == self.escape {
+                        key.push(self.escape);
+                    }
+                    key.push(ch);
+                }
+            }
+            key
+        }
+
+        /// Splits a key into the unescaped path segments.
+        ///
+        /// An escape character at the end of the key is kept as is.
+        #[must_use]
+        pub fn split(&self, key: &str) -> Vec<String> {
+            let mut path = Vec::new();
+            let mut segment = String::new();
+            let mut chars = key.chars();
+            while let Some(ch) = chars.next() {
+                if ch == self.escape {
+                    segment.push(chars.next().unwrap_or(ch));
+                } else if ch == self.separator {
+                    path.push(core::mem::take(&mut segment));
+                } else {
+                    segment.push(ch);
+                }
+            }
+            path.push(segment);
+            path
+        }
+    }
+
+    /// A dyn-compatible, cloneable trait for constructing a `serde_json` formatter.
+    pub trait FormatterBuilder<F>: Send + Sync + 'static {
+        /// Clones the formatter builder to a new box.
+        fn clone(&self) -> Box<dyn FormatterBuilder<F>>;
+
+        /// Constructs a new formatter.
+        fn call(&self) -> F;
+    }
+
+    impl<T, F> FormatterBuilder<F> for T
+    where
+        T: Fn() -> F + Clone + Send + Sync + 'static,
+    {
+        fn clone(&self) -> Box<dyn FormatterBuilder<F>> {
+            Box::new(Clone::clone(self)) as Box<dyn FormatterBuilder<F>>
+        }
+
+        fn call(&self) -> F { self() }
+    }
 
     /// Dyn-compatible trait for [`io::Write`] and [`Any`].
     pub trait AnyWrite: io::Write + Any {}
@@ -296,6 +1250,388 @@ pub mod json {
     type Writer = BufWriter<Box<dyn AnyWrite>>;
     type Reader = serde_json::de::IoRead<BufReader<Box<dyn AnyRead>>>;
 
+    /// Converts a scalar field to and from JSON [`Value`]s.
+    ///
+    /// This component is attached to each scalar field entity by any [`Serde`](super::Serde)
+    /// manager, allowing other tools such as the egui editor to copy and paste values
+    /// without knowing the field type.
+    #[derive(Component, Clone, Copy)]
+    pub struct ValueVtable {
+        type_name: &'static str,
+        to_value:  fn(EntityRef) -> serde_json::Result<Value>,
+        set_value: fn(&mut EntityMut, Value) -> serde_json::Result<()>,
+    }
+
+    fn scalar_value<T: SerdeScalar>(entity: EntityRef) -> serde_json::Result<Value> {
+        let value = entity.get::<ScalarData<T>>().expect("type checked in serde query");
+        serde_json::to_value(value.0.as_serialize())
+    }
+
+    impl ValueVtable {
+        fn of<T: SerdeScalar>() -> Self {
+            ValueVtable {
+                type_name: core::any::type_name::<T>(),
+                to_value:  scalar_value::<T>,
+                set_value: |entity, value| {
+                    let value: T::Deserialize = serde_json::from_value(value)?;
+                    let previous = scalar_value::<T>(entity.as_readonly())?;
+                    entity
+                        .get_mut::<ScalarData<T>>()
+                        .expect("type checked in serde query")
+                        .0
+                        .set_deserialized(value);
+                    if let Some(constraint) = entity.get::<ScalarConstraint>().copied() {
+                        constraint.apply(entity);
+                    }
+                    if scalar_value::<T>(entity.as_readonly())? != previous {
+                        entity
+                            .get_mut::<ConfigNode>()
+                            .expect("scalar fields are config nodes")
+                            .bump();
+                    }
+                    Ok(())
+                },
+            }
+        }
+
+        /// Returns the [type name](core::any::type_name) of the field.
+        #[must_use]
+        pub fn type_name(&self) -> &'static str { self.type_name }
+
+        /// Serializes the value of the field.
+        ///
+        /// # Errors
+        /// Errors from the serializer.
+        pub fn to_value(&self, entity: EntityRef) -> serde_json::Result<Value> {
+            (self.to_value)(entity)
+        }
+
+        /// Deserializes `value` and writes it to the field, bumping its generation.
+        ///
+        /// # Errors
+        /// Errors if `value` is not valid for the field type.
+        pub fn set_value(&self, entity: &mut EntityMut, value: Value) -> serde_json::Result<()> {
+            (self.set_value)(entity, value)
+        }
+    }
+
+    /// Inserts a [`ValueVtable`] for `T` unless another manager has already inserted one.
+    #[derive(Component)]
+    #[component(on_add = insert_value_vtable::<T>)]
+    pub(crate) struct RegisterValueVtable<A: 'static, T: SerdeScalar>(
+        pub(crate) PhantomData<fn() -> (A, T)>,
+    );
+
+    fn insert_value_vtable<T: SerdeScalar>(mut world: DeferredWorld, ctx: HookContext) {
+        world.commands().entity(ctx.entity).insert_if_new(ValueVtable::of::<T>());
+    }
+
+    /// A [`SystemParam`] to inspect the values of all scalar fields
+    /// without knowing their concrete types,
+    /// e.g. for debug overlays or attaching the config to crash reports.
+    ///
+    /// Only fields managed by a [`Serde`](super::Serde) manager are visited,
+    /// since the values are serialized through their [`ValueVtable`].
+    ///
+    /// ```
+    /// use bevy_mod_config::manager::serde::json::DynamicValues;
+    ///
+    /// fn dump_config(values: DynamicValues) {
+    ///     for entry in values.iter() {
+    ///         match entry.value {
+    ///             Ok(value) => println!("{} ({}) = {value}", entry.path.join("."), entry.type_name),
+    ///             Err(err) => println!("{}: {err}", entry.path.join(".")),
+    ///         }
+    ///     }
+    /// }
+    /// ```
+    #[derive(SystemParam)]
+    pub struct DynamicValues<'w, 's> {
+        query: Query<'w, 's, (EntityRef<'static>, &'static ConfigNode, &'static ValueVtable)>,
+    }
+
+    impl DynamicValues<'_, '_> {
+        /// Iterates over all scalar fields in arbitrary order.
+        pub fn iter(&self) -> impl Iterator<Item = DynamicEntry<'_>> {
+            self.query.iter().map(|(entity, node, vtable)| DynamicEntry {
+                path:      &node.path,
+                type_name: vtable.type_name(),
+                value:     vtable.to_value(entity),
+            })
+        }
+    }
+
+    /// A scalar field visited by [`DynamicValues::iter`].
+    #[derive(Debug)]
+    pub struct DynamicEntry<'a> {
+        /// The path of the field.
+        pub path:      &'a [String],
+        /// The [type name](core::any::type_name) of the field.
+        pub type_name: &'static str,
+        /// The serialized value of the field.
+        pub value:     serde_json::Result<Value>,
+    }
+
+    /// A [`Command`] that sets the scalar field at a path from a serialized value,
+    /// bumping its generation.
+    ///
+    /// This allows scripts, cheat menus and remote control tools
+    /// to modify config fields without typed access.
+    /// The field must be managed by a [`Serde`](super::Serde) manager,
+    /// which provides its [`ValueVtable`].
+    ///
+    /// ```
+    /// # use bevy_ecs::system::Commands;
+    /// use bevy_mod_config::manager::serde::json::SetConfigValue;
+    ///
+    /// fn cheat_system(mut commands: Commands) {
+    ///     commands.queue(SetConfigValue::new("player.speed", 20.0).unwrap());
+    /// }
+    /// ```
+    #[derive(Debug, Clone)]
+    pub struct SetConfigValue {
+        path:  Vec<String>,
+        value: Value,
+    }
+
+    impl SetConfigValue {
+        /// Creates a command that sets the field at the dot-separated `path`,
+        /// including the root key, to `value`.
+        ///
+        /// # Errors
+        /// Errors if `value` cannot be serialized.
+        pub fn new(path: &str, value: impl Serialize) -> serde_json::Result<Self> {
+            Ok(Self::from_value(path, serde_json::to_value(value)?))
+        }
+
+        /// Creates a command that sets the field at the dot-separated `path`,
+        /// including the root key, to an already serialized `value`.
+        #[must_use]
+        pub fn from_value(path: &str, value: Value) -> Self {
+            Self { path: path.split('.').map(String::from).collect(), value }
+        }
+    }
+
+    impl Command for SetConfigValue {
+        type Out = Result<(), SetValueError>;
+
+        fn apply(self, world: &mut World) -> Result<(), SetValueError> {
+            let mut query = world.query::<(Entity, &ConfigNode, Option<&ValueVtable>)>();
+            let Some((entity, _, vtable)) =
+                query.iter(world).find(|(_, node, _)| node.path == self.path)
+            else {
+                return Err(SetValueError::NotFound(self.path.join(".")));
+            };
+            let Some(&vtable) = vtable else {
+                return Err(SetValueError::NotScalar(self.path.join(".")));
+            };
+
+            let mut entity = world.entity_mut(entity);
+            vtable
+                .set_value(&mut EntityMut::from(&mut entity), self.value)
+                .map_err(|err| SetValueError::Invalid(self.path.join("."), err))
+        }
+    }
+
+    /// The error returned when a [`SetConfigValue`] command
+    /// or a [`scripting`](super::scripting) access fails.
+    #[derive(Debug)]
+    pub enum SetValueError {
+        /// No config node exists at the path.
+        NotFound(String),
+        /// The node at the path is not a scalar field managed by a [`Serde`](super::Serde) manager.
+        NotScalar(String),
+        /// The value cannot be deserialized as the type of the field,
+        /// or the value of the field cannot be serialized by [`scripting::get`](super::scripting::get).
+        Invalid(String, serde_json::Error),
+    }
+
+    impl fmt::Display for SetValueError {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            match self {
+                Self::NotFound(path) => write!(f, "no config field at {path}"),
+                Self::NotScalar(path) => {
+                    write!(f, "config node {path} is not a serde scalar field")
+                }
+                Self::Invalid(path, err) => {
+                    write!(f, "invalid value for config field {path}: {err}")
+                }
+            }
+        }
+    }
+
+    impl core::error::Error for SetValueError {
+        fn source(&self) -> Option<&(dyn core::error::Error + 'static)> {
+            match self {
+                Self::Invalid(_, err) => Some(err),
+                _ => None,
+            }
+        }
+    }
+
+    /// Returns the JSON [`Serde`](super::Serde) manager from a manager instance `M`,
+    /// e.g. `|managers: &(Json, Egui)| &managers.0` for a tuple of managers.
+    pub type GetJsonManager<M, F> = fn(&M) -> &super::Serde<JsonAdapter<F>>;
+
+    /// A [`Command`] that saves all config data in the world to a JSON file.
+    ///
+    /// The file is replaced [atomically](super::storage::write_atomic),
+    /// and all fields are [marked clean](super::Serde::mark_clean) after a successful write.
+    ///
+    /// ```
+    /// # use bevy_ecs::system::Commands;
+    /// use bevy_mod_config::manager::serde::json::{Json, SaveConfigCommand};
+    ///
+    /// fn save_system(mut commands: Commands) {
+    ///     commands.queue(SaveConfigCommand::<Json, _>::new("settings.json"));
+    /// }
+    /// ```
+    pub struct SaveConfigCommand<M: Manager, F: Formatter + Send + Sync + 'static> {
+        /// The path of the file to write.
+        pub path:    PathBuf,
+        get_manager: GetJsonManager<M, F>,
+    }
+
+    impl<F: Formatter + Send + Sync + 'static> SaveConfigCommand<super::Serde<JsonAdapter<F>>, F> {
+        /// Creates a command that saves to `path`
+        /// for a world initialized with a JSON manager only.
+        pub fn new(path: impl Into<PathBuf>) -> Self { Self::with_manager(path, |manager| manager) }
+    }
+
+    impl<M: Manager, F: Formatter + Send + Sync + 'static> SaveConfigCommand<M, F> {
+        /// Creates a command that saves to `path`
+        /// using the JSON manager returned by `get_manager`.
+        pub fn with_manager(path: impl Into<PathBuf>, get_manager: GetJsonManager<M, F>) -> Self {
+            Self { path: path.into(), get_manager }
+        }
+    }
+
+    impl<M, F> Command for SaveConfigCommand<M, F>
+    where
+        M: Manager,
+        F: Formatter + Send + Sync + 'static,
+    {
+        type Out = Result<(), serde_json::Error>;
+
+        fn apply(self, world: &mut World) -> Result<(), serde_json::Error> {
+            world.resource_scope(|world, managers: Mut<manager::Instance<M>>| {
+                let manager = (self.get_manager)(&managers);
+                let bytes = manager.to_writer(world, Vec::new())?;
+                super::storage::write_atomic(&self.path, &bytes).map_err(serde_json::Error::io)?;
+                manager.mark_clean(world, None);
+                Ok(())
+            })
+        }
+    }
+
+    /// A [`Command`] that loads config data from a JSON file.
+    ///
+    /// Fields missing in the file are left unchanged.
+    /// All fields are [marked clean](super::Serde::mark_clean) after a successful load.
+    ///
+    /// ```
+    /// # use bevy_ecs::system::Commands;
+    /// use bevy_mod_config::manager::serde::json::{Json, LoadConfigCommand};
+    ///
+    /// fn load_system(mut commands: Commands) {
+    ///     commands.queue(LoadConfigCommand::<Json, _>::new("settings.json"));
+    /// }
+    /// ```
+    pub struct LoadConfigCommand<M: Manager, F: Formatter + Send + Sync + 'static> {
+        /// The path of the file to read.
+        pub path:    PathBuf,
+        get_manager: GetJsonManager<M, F>,
+    }
+
+    impl<F: Formatter + Send + Sync + 'static> LoadConfigCommand<super::Serde<JsonAdapter<F>>, F> {
+        /// Creates a command that loads from `path`
+        /// for a world initialized with a JSON manager only.
+        pub fn new(path: impl Into<PathBuf>) -> Self { Self::with_manager(path, |manager| manager) }
+    }
+
+    impl<M: Manager, F: Formatter + Send + Sync + 'static> LoadConfigCommand<M, F> {
+        /// Creates a command that loads from `path`
+        /// using the JSON manager returned by `get_manager`.
+        pub fn with_manager(path: impl Into<PathBuf>, get_manager: GetJsonManager<M, F>) -> Self {
+            Self { path: path.into(), get_manager }
+        }
+    }
+
+    impl<M, F> Command for LoadConfigCommand<M, F>
+    where
+        M: Manager,
+        F: Formatter + Send + Sync + 'static,
+    {
+        type Out = Result<(), serde_json::Error>;
+
+        fn apply(self, world: &mut World) -> Result<(), serde_json::Error> {
+            world.resource_scope(|world, managers: Mut<manager::Instance<M>>| {
+                let manager = (self.get_manager)(&managers);
+                let file = File::open(&self.path).map_err(serde_json::Error::io)?;
+                manager.from_reader(world, file)?;
+                manager.mark_clean(world, None);
+                Ok(())
+            })
+        }
+    }
+
+    /// The storage name of the [`Manifest`] written by
+    /// [`save_split`](super::Serde::save_split).
+    pub const MANIFEST_FILE: &str = "manifest.json";
+
+    fn root_file_name(root: &str) -> String { alloc::format!("{root}.json") }
+
+    /// Returns whether `name` refers to a file directly in the storage,
+    /// such that a manifest cannot point outside of it.
+    fn is_plain_file_name(name: &str) -> bool {
+        !name.is_empty() && !name.contains(['/', '\\']) && !name.contains("..")
+    }
+
+    /// What to do with a file that failed to load in
+    /// [`load_root_or_default`](super::Serde::load_root_or_default).
+    #[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+    pub enum CorruptFile {
+        /// Leave the file in place. It is overwritten on the next save.
+        #[default]
+        Keep,
+        /// Rename the file with a `.corrupt` suffix, e.g. `video.json.corrupt`,
+        /// so that it can be inspected or recovered manually.
+        RenameAside,
+    }
+
+    /// The result of [`load_root_or_default`](super::Serde::load_root_or_default).
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    pub enum LoadOutcome {
+        /// The file was loaded successfully.
+        Loaded,
+        /// The file does not exist, so the fields were left unchanged.
+        Missing,
+        /// The file could not be loaded, so the fields were restored.
+        /// A [`LoadFailed`] message has been written.
+        Corrupted,
+    }
+
+    /// Written when [`load_root_or_default`](super::Serde::load_root_or_default)
+    /// falls back to defaults due to a corrupted file.
+    #[derive(Message, Debug)]
+    pub struct LoadFailed {
+        /// The root key that failed to load.
+        pub root:     String,
+        /// The storage name of the file that failed to load.
+        pub name:     String,
+        /// The error encountered while reading or deserializing the file.
+        pub error:    io::Error,
+        /// The new storage name of the corrupted file if it was renamed aside.
+        pub moved_to: Option<String>,
+    }
+
+    /// Describes the set of files written by [`save_split`](super::Serde::save_split).
+    #[derive(Debug, Default, Clone, PartialEq, Eq)]
+    pub struct Manifest {
+        /// Maps each root key to the storage name of the file storing it.
+        pub files: BTreeMap<String, String>,
+    }
+
     /// The typed adapter for [`JsonAdapter`].
     #[derive(Clone)]
     pub struct TypedVtable<F: Formatter> {
@@ -305,27 +1641,45 @@ pub mod json {
         )]
         ser: fn(
             EntityRef,
-            &[String],
+            &str,
             &mut <&mut serde_json::Serializer<Writer, F> as serde::Serializer>::SerializeMap,
         ) -> serde_json::Result<()>,
-        de:  fn(EntityWorldMut, &RawValue) -> Result<(), serde_json::Error>,
+        key_syntax:   KeySyntax,
+        out_of_range: OutOfRange,
+        de:           fn(EntityWorldMut, &RawValue, OutOfRange) -> Result<(), serde_json::Error>,
+        to_value:     fn(EntityRef) -> serde_json::Result<Value>,
+        from_value:   fn(EntityWorldMut, Value, OutOfRange) -> serde_json::Result<()>,
+        #[cfg(feature = "bevy_tasks")]
+        to_raw:       fn(EntityRef) -> serde_json::Result<Box<RawValue>>,
     }
 
     impl<F: Formatter + Send + Sync + 'static> super::Adapter for JsonAdapter<F> {
         type Typed = TypedVtable<F>;
         fn for_type<T: super::SerdeScalar>(&mut self) -> Self::Typed {
             TypedVtable {
-                ser: |entity, path, ser: &mut <&mut serde_json::Serializer<Writer, F> as serde::Serializer>::SerializeMap| {
-                    let value = entity.get::<ScalarData<T>>().expect("type checked in serde query");
-                    ser.serialize_entry(&path.join("."), value.0.as_serialize())
+                ser: |entity, key, ser: &mut <&mut serde_json::Serializer<Writer, F> as serde::Serializer>::SerializeMap| {
+                    let value = super::persisted_value::<T>(entity).expect("type checked in serde query");
+                    ser.serialize_entry(key, value.as_serialize())
                 },
-                de: |mut entity, value| {
+                key_syntax: self.key_syntax,
+                out_of_range: self.out_of_range,
+                de: |mut entity, value, out_of_range| {
                     let value: T::Deserialize = serde_json::from_str(value.get()).map_err(serde_json::Error::custom)?;
-                    let mut entry = entity.get_mut::<ScalarData::<T>>().expect("type checked in serde query");
-                    entry.0.set_deserialized(value);
-                    Ok(())
+                    super::set_deserialized::<T>(&mut entity, value, out_of_range).map_err(serde_json::Error::custom)
+                },
+                to_value: |entity| {
+                    let value = super::persisted_value::<T>(entity).expect("type checked in serde query");
+                    serde_json::to_value(value.as_serialize())
+                },
+                from_value: |mut entity, value, out_of_range| {
+                    let value: T::Deserialize = serde_json::from_value(value)?;
+                    super::set_deserialized::<T>(&mut entity, value, out_of_range).map_err(serde_json::Error::custom)
+                },
+                #[cfg(feature = "bevy_tasks")]
+                to_raw: |entity| {
+                    let value = super::persisted_value::<T>(entity).expect("type checked in serde query");
+                    serde_json::value::to_raw_value(value.as_serialize())
                 },
-
             }
         }
 
@@ -333,14 +1687,7 @@ pub mod json {
 
         type DeInput<'de> = &'de mut serde_json::Deserializer<Reader>;
         type DeKey<'de> = String;
-        fn index_map_by_de_key<'map, V>(
-            &self,
-            map: &'map HashMap<Vec<String>, V>,
-            key: Self::DeKey<'_>,
-        ) -> Option<&'map V> {
-            let key: Vec<_> = key.split('.').map(String::from).collect();
-            map.get(&key)
-        }
+        fn de_key_path(&self, key: Self::DeKey<'_>) -> Vec<String> { self.key_syntax.split(&key) }
     }
 
     impl<F: Formatter + Send + Sync + 'static> super::TypedAdapter for TypedVtable<F> {
@@ -353,7 +1700,17 @@ pub mod json {
             path: &[String],
             ser: &mut Self::SerContext<'a>,
         ) -> Result<(), Self::SerError<'a>> {
-            (self.ser)(entity, path, ser)
+            (self.ser)(entity, &self.key_syntax.join(path), ser)
+        }
+
+        fn serialize_variant_once<'a>(
+            &self,
+            _entity: EntityRef,
+            variant: &str,
+            path: &[String],
+            ser: &mut Self::SerContext<'a>,
+        ) -> Result<(), Self::SerError<'a>> {
+            ser.serialize_entry(&self.key_syntax.join(path), variant)
         }
 
         fn deserialize_map_value<'de, M: MapAccess<'de>>(
@@ -365,7 +1722,7 @@ pub mod json {
             // so that it can be passed to the vtable without knowing `M` during startup.
             // This is a terrible hack, but it is necessary for type erasure.
             let value: Box<RawValue> = map.next_value()?;
-            (self.de)(entity, &value).map_err(M::Error::custom)
+            (self.de)(entity, &value, self.out_of_range).map_err(M::Error::custom)
         }
     }
 
@@ -387,11 +1744,103 @@ pub mod json {
             &self,
             world: &mut World,
             writer: W,
+        ) -> Result<W, serde_json::Error> {
+            self.write_filtered(world, None, writer)
+        }
+
+        /// Serialize all config data in the world to a [writer](io::Write)
+        /// like [`to_writer`](Self::to_writer),
+        /// but serializes the field values in parallel on the
+        /// [`ComputeTaskPool`](bevy_tasks::ComputeTaskPool).
+        ///
+        /// The sorted keys are split into one contiguous batch per thread,
+        /// and the serialized batches are concatenated in order,
+        /// so the output has the same keys in the same order as `to_writer`.
+        /// Values are serialized without the formatter of this manager,
+        /// so nested values such as lists are written compactly even with [`Pretty`].
+        ///
+        /// This is only worthwhile for trees with thousands of fields.
+        /// Data with [tagged enums](EnumRepr) are serialized sequentially.
+        ///
+        /// # Errors
+        /// Errors from the serializer or the writer.
+        #[cfg(feature = "bevy_tasks")]
+        pub fn serialize_all_par<W: io::Write>(
+            &self,
+            world: &mut World,
+            writer: W,
+        ) -> Result<W, serde_json::Error> {
+            if self.adapter.enum_repr != EnumRepr::Flat {
+                let mut serializer =
+                    serde_json::Serializer::with_formatter(writer, self.adapter.formatter.call());
+                self.value_filtered(world, None)?.serialize(&mut serializer)?;
+                return Ok(serializer.into_inner());
+            }
+
+            let mut keys = self.keys_with_types(world, None);
+            super::retain_saved(world, &mut keys, self.inactive_variants);
+            keys.sort_by(|((path1, _), _), ((path2, _), _)| path1.cmp(path2));
+
+            let pool = ComputeTaskPool::get_or_init(TaskPool::default);
+            let batch_size = keys.len().div_ceil(pool.thread_num().max(1)).max(1);
+            let world = &*world;
+            let batches = pool.scope(|scope| {
+                for batch in keys.chunks(batch_size) {
+                    scope.spawn(async move {
+                        batch
+                            .iter()
+                            .map(|((path, entity), typed)| {
+                                let entity = world.entity(*entity);
+                                let value = match self.converted_variant(entity) {
+                                    Some(variant) => serde_json::value::to_raw_value(&variant)?,
+                                    None => (typed.adapter.to_raw)(entity)?,
+                                };
+                                Ok((typed.adapter.key_syntax.join(path), value))
+                            })
+                            .collect::<serde_json::Result<Vec<_>>>()
+                    });
+                }
+            });
+
+            let mut serializer =
+                serde_json::Serializer::with_formatter(writer, self.adapter.formatter.call());
+            let mut map_ser = serde::Serializer::serialize_map(&mut serializer, Some(keys.len()))?;
+            for batch in batches {
+                for (key, value) in batch? {
+                    map_ser.serialize_entry(&key, &value)?;
+                }
+            }
+            map_ser.end()?;
+            Ok(serializer.into_inner())
+        }
+
+        /// Serialize the config data under a single root key to a [writer](io::Write).
+        ///
+        /// # Errors
+        /// Errors from the serializer or the writer.
+        pub fn root_to_writer<W: Any + io::Write>(
+            &self,
+            world: &mut World,
+            root: &str,
+            writer: W,
+        ) -> Result<W, serde_json::Error> {
+            self.write_filtered(world, Some(root), writer)
+        }
+
+        fn write_filtered<W: Any + io::Write>(
+            &self,
+            world: &mut World,
+            root: Option<&str>,
+            writer: W,
         ) -> Result<W, serde_json::Error> {
             let writer: Writer = BufWriter::new(Box::new(writer) as Box<dyn AnyWrite>);
             let mut serializer =
                 serde_json::ser::Serializer::with_formatter(writer, self.adapter.formatter.call());
-            self.serialize_all(world, &mut serializer)?;
+            if self.adapter.enum_repr == EnumRepr::Flat {
+                self.serialize_filtered(world, root, &mut serializer)?;
+            } else {
+                self.value_filtered(world, root)?.serialize(&mut serializer)?;
+            }
             let boxed = serializer.into_inner().into_inner().map_err(serde_json::Error::custom)?;
             Ok(*Box::<dyn Any>::downcast::<W>(boxed)
                 .expect("Serializer should preserve the underlying type"))
@@ -413,11 +1862,605 @@ pub mod json {
             world: &mut World,
             reader: R,
         ) -> Result<(), serde_json::Error> {
+            self.read_filtered(world, None, reader, false).map(drop)
+        }
+
+        /// Deserialize config data from a JSON [reader](io::Read),
+        /// skipping invalid values instead of aborting on the first one.
+        ///
+        /// Returns the errors of the skipped values, which are left unchanged.
+        /// See [`deserialize_lenient`](Self::deserialize_lenient) for details.
+        ///
+        /// # Errors
+        /// Errors if the input is not valid JSON.
+        pub fn from_reader_lenient<R: Any + io::Read>(
+            &self,
+            world: &mut World,
+            reader: R,
+        ) -> Result<Vec<super::KeyError>, serde_json::Error> {
+            self.read_filtered(world, None, reader, true)
+        }
+
+        /// Deserialize config data for a single root key from a JSON [reader](io::Read).
+        ///
+        /// Keys belonging to other roots are ignored.
+        ///
+        /// # Errors
+        /// Errors from the deserializer.
+        pub fn root_from_reader<R: Any + io::Read>(
+            &self,
+            world: &mut World,
+            root: &str,
+            reader: R,
+        ) -> Result<(), serde_json::Error> {
+            self.read_filtered(world, Some(root), reader, false).map(drop)
+        }
+
+        fn read_filtered<R: Any + io::Read>(
+            &self,
+            world: &mut World,
+            root: Option<&str>,
+            reader: R,
+            lenient: bool,
+        ) -> Result<Vec<super::KeyError>, serde_json::Error> {
             let mut deserializer = serde_json::Deserializer::from_reader(BufReader::new(Box::new(
                 reader,
             )
                 as Box<dyn AnyRead>));
-            self.deserialize(world, &mut deserializer)
+            if self.adapter.enum_repr == EnumRepr::Flat {
+                self.deserialize_filtered(world, root, &mut deserializer, lenient)
+            } else {
+                let value = Value::deserialize(&mut deserializer)?;
+                self.load_value_filtered(world, root, value, lenient)
+            }
+        }
+
+        /// Saves the config data under `root` to its own file in `storage`.
+        ///
+        /// The file is named after the root key, e.g. `video.json`.
+        ///
+        /// # Errors
+        /// Errors from the storage or the serializer.
+        pub fn save_root(
+            &self,
+            world: &mut World,
+            storage: &impl ConfigStorage,
+            root: &str,
+        ) -> io::Result<()> {
+            let bytes = self.root_to_writer(world, root, Vec::new())?;
+            storage.write_bytes(&root_file_name(root), &bytes)?;
+            self.mark_clean(world, Some(root));
+            Ok(())
+        }
+
+        /// Saves the config data under `root` like [`save_root`](Self::save_root),
+        /// but writes the file asynchronously on the [`IoTaskPool`](bevy_tasks::IoTaskPool).
+        ///
+        /// The data is serialized before this function returns,
+        /// so subsequent changes are not included in the written file.
+        /// See the [`async_save`](super::async_save) module for the completion message.
+        ///
+        /// # Errors
+        /// Errors from the serializer.
+        #[cfg(feature = "bevy_tasks")]
+        pub fn save_root_async(
+            &self,
+            world: &mut World,
+            storage: &(impl ConfigStorage + Clone),
+            root: &str,
+        ) -> Result<(), serde_json::Error> {
+            let bytes = self.root_to_writer(world, root, Vec::new())?;
+            let saved = Self::generations(world, root);
+            super::async_save::spawn_write(
+                world,
+                storage.clone(),
+                root.into(),
+                root_file_name(root),
+                bytes,
+                Box::new(move |world| super::mark_saved::<JsonAdapter<F>>(world, &saved)),
+            );
+            Ok(())
+        }
+
+        /// Saves the config data under `root` like [`save_root`](Self::save_root),
+        /// but only if it has changed since it was last saved or loaded.
+        ///
+        /// Returns whether the file was written.
+        /// This is intended for autosave systems running at a fixed interval.
+        ///
+        /// # Errors
+        /// Errors from the storage or the serializer.
+        pub fn save_if_dirty(
+            &self,
+            world: &mut World,
+            storage: &impl ConfigStorage,
+            root: &str,
+        ) -> io::Result<bool> {
+            if !self.is_dirty(world, root) {
+                return Ok(false);
+            }
+            self.save_root(world, storage, root)?;
+            Ok(true)
+        }
+
+        /// Loads the config data under `root` from its own file in `storage`.
+        ///
+        /// This is the inverse of [`save_root`](Self::save_root).
+        ///
+        /// # Errors
+        /// Errors from the storage or the deserializer,
+        /// including [`NotFound`](io::ErrorKind::NotFound) if the file does not exist.
+        pub fn load_root(
+            &self,
+            world: &mut World,
+            storage: &impl ConfigStorage,
+            root: &str,
+        ) -> io::Result<()> {
+            let name = root_file_name(root);
+            let Some(bytes) = storage.read_bytes(&name)? else {
+                return Err(io::Error::new(io::ErrorKind::NotFound, name));
+            };
+            self.root_from_reader(world, root, io::Cursor::new(bytes))?;
+            self.mark_clean(world, Some(root));
+            Ok(())
+        }
+
+        /// Loads the config data under `root` like [`load_root`](Self::load_root),
+        /// but recovers from corrupted files instead of returning an error.
+        ///
+        /// If the file cannot be read or parsed, or contains invalid values,
+        /// all fields under `root` are [reset to their defaults](crate::reset::reset_to_default),
+        /// including fields that are not persisted such as inactive enum variants,
+        /// and a [`LoadFailed`] message is written.
+        /// The fields are left dirty so that the next save replaces the corrupted file.
+        ///
+        /// Files do not carry a checksum,
+        /// so corruption is only detected if it breaks the syntax or the values of the file.
+        pub fn load_root_or_default(
+            &self,
+            world: &mut World,
+            storage: &impl ConfigStorage,
+            root: &str,
+            corrupt: CorruptFile,
+        ) -> LoadOutcome {
+            let name = root_file_name(root);
+            let result = match storage.read_bytes(&name) {
+                Ok(Some(bytes)) => serde_json::from_slice(&bytes)
+                    .and_then(|value| {
+                        self.load_value_filtered(world, Some(root), value, false).map(drop)
+                    })
+                    .map_err(io::Error::from),
+                Ok(None) => return LoadOutcome::Missing,
+                Err(err) => Err(err),
+            };
+
+            match result {
+                Ok(()) => {
+                    self.mark_clean(world, Some(root));
+                    LoadOutcome::Loaded
+                }
+                Err(error) => {
+                    let roots: Vec<_> = world
+                        .query_filtered::<(Entity, &ConfigNode), With<RootNode>>()
+                        .iter(world)
+                        .filter(|(_, node)| node.path.first().is_some_and(|key| key == root))
+                        .map(|(entity, _)| entity)
+                        .collect();
+                    for entity in roots {
+                        crate::reset::reset_to_default(world, entity);
+                    }
+
+                    let moved_to = match corrupt {
+                        CorruptFile::Keep => None,
+                        CorruptFile::RenameAside => {
+                            let aside = alloc::format!("{name}.corrupt");
+                            storage.rename(&name, &aside).ok().map(|()| aside)
+                        }
+                    };
+                    world.write_message(LoadFailed { root: root.into(), name, error, moved_to });
+                    LoadOutcome::Corrupted
+                }
+            }
+        }
+
+        /// Saves each root to its own file in `storage`,
+        /// along with a [`Manifest`] listing the files.
+        ///
+        /// # Errors
+        /// Errors from the storage or the serializer.
+        pub fn save_split(
+            &self,
+            world: &mut World,
+            storage: &impl ConfigStorage,
+        ) -> io::Result<Manifest> {
+            let mut manifest = Manifest::default();
+            for root in self.roots(world) {
+                self.save_root(world, storage, &root)?;
+                let file_name = root_file_name(&root);
+                manifest.files.insert(root, file_name);
+            }
+            let bytes = serde_json::to_vec(&manifest.files)?;
+            storage.write_bytes(MANIFEST_FILE, &bytes)?;
+            Ok(manifest)
+        }
+
+        /// Loads all files listed in the [`Manifest`] in `storage`.
+        ///
+        /// Files missing from `storage` are skipped,
+        /// so that deleting a single file resets only the settings in that root.
+        ///
+        /// # Errors
+        /// Errors from the storage or the deserializer,
+        /// including [`NotFound`](io::ErrorKind::NotFound) if the manifest does not exist.
+        /// Errors with [`InvalidData`](io::ErrorKind::InvalidData) without loading any file
+        /// if the manifest lists a file name containing a path separator or `..`.
+        pub fn load_split(
+            &self,
+            world: &mut World,
+            storage: &impl ConfigStorage,
+        ) -> io::Result<Manifest> {
+            let Some(bytes) = storage.read_bytes(MANIFEST_FILE)? else {
+                return Err(io::Error::new(io::ErrorKind::NotFound, MANIFEST_FILE));
+            };
+            let manifest = Manifest { files: serde_json::from_slice(&bytes)? };
+            if let Some(file_name) = manifest.files.values().find(|name| !is_plain_file_name(name))
+            {
+                return Err(io::Error::new(
+                    io::ErrorKind::InvalidData,
+                    alloc::format!("invalid file name in manifest: {file_name:?}"),
+                ));
+            }
+            for (root, file_name) in &manifest.files {
+                if let Some(bytes) = storage.read_bytes(file_name)? {
+                    self.root_from_reader(world, root, io::Cursor::new(bytes))?;
+                    self.mark_clean(world, Some(root));
+                }
+            }
+            Ok(manifest)
+        }
+
+        /// Serialize all config data in the world to a JSON [`Value`].
+        ///
+        /// The returned value is always an object
+        /// with the same keys as [`to_string`](Self::to_string).
+        ///
+        /// # Errors
+        /// Errors from the serializer.
+        pub fn to_value(&self, world: &mut World) -> Result<Value, serde_json::Error> {
+            self.value_filtered(world, None)
+        }
+
+        fn value_filtered(
+            &self,
+            world: &mut World,
+            root: Option<&str>,
+        ) -> Result<Value, serde_json::Error> {
+            let mut keys = self.keys_with_types(world, root);
+            super::retain_saved(world, &mut keys, self.inactive_variants);
+            let entries = keys
+                .iter()
+                .map(|((path, entity), typed)| {
+                    Ok((path.clone(), self.field_value(world.entity(*entity), typed)?))
+                })
+                .collect::<serde_json::Result<Vec<_>>>()?;
+
+            if self.adapter.enum_repr == EnumRepr::Flat {
+                let map = entries
+                    .into_iter()
+                    .map(|(path, value)| (self.adapter.key_syntax.join(&path), value))
+                    .collect();
+                return Ok(Value::Object(map));
+            }
+
+            let enums = self.enum_paths(world, &keys);
+            Ok(Value::Object(self.tag_enums(entries, 0, &enums, false)))
+        }
+
+        /// Computes a stable hash of the config data in `scope` that this manager would save,
+        /// e.g. to detect whether the settings changed since a replay was recorded.
+        ///
+        /// The hash only depends on the persisted keys and JSON values of the fields,
+        /// so it is stable across runs, platforms and spawn order,
+        /// but changes if the key case, key syntax or any persisted key is changed.
+        /// Fields excluded from saving, such as [`SkipSerde`](crate::SkipSerde) fields,
+        /// do not affect the hash.
+        ///
+        /// # Errors
+        /// Errors from the serializer.
+        pub fn content_hash(
+            &self,
+            world: &mut World,
+            scope: &HashScope,
+        ) -> Result<u64, serde_json::Error> {
+            let mut keys = self.keys_with_types(world, None);
+            super::retain_saved(world, &mut keys, self.inactive_variants);
+            let mut entries = keys
+                .iter()
+                .filter(|((_, entity), _)| {
+                    let node =
+                        world.get::<ConfigNode>(*entity).expect("scanned keys are config nodes");
+                    scope.contains(&node.path)
+                })
+                .map(|((path, entity), typed)| {
+                    let value = self.field_value(world.entity(*entity), typed)?;
+                    Ok((self.adapter.key_syntax.join(path), serde_json::to_string(&value)?))
+                })
+                .collect::<serde_json::Result<Vec<_>>>()?;
+            entries.sort();
+
+            let mut hash = Fnv1a::default();
+            for (key, value) in &entries {
+                hash.write(key.as_bytes());
+                hash.write(&[0]);
+                hash.write(value.as_bytes());
+                hash.write(&[0]);
+            }
+            Ok(hash.0)
+        }
+
+        /// Serializes the value of the field `entity`,
+        /// converting the variant names of enum discriminants by the key case.
+        fn field_value(
+            &self,
+            entity: EntityRef,
+            typed: &super::Typed<TypedVtable<F>>,
+        ) -> serde_json::Result<Value> {
+            match self.converted_variant(entity) {
+                Some(variant) => Ok(Value::String(variant)),
+                None => (typed.adapter.to_value)(entity),
+            }
+        }
+
+        /// Finds the enum nodes among the scanned keys through their discriminant fields.
+        fn enum_paths<T>(&self, world: &World, keys: &[(super::ScannedKey, T)]) -> EnumPaths {
+            keys.iter()
+                .filter_map(|((path, entity), _)| {
+                    let entity = world.entity(*entity);
+                    let discrim = entity.get::<DiscriminantField>()?;
+                    let (last, parent) = path.split_last()?;
+                    let keys = EnumKeys {
+                        discrim: last.clone(),
+                        variant: self.key_case.convert(discrim.variant_name(entity)),
+                    };
+                    Some((parent.to_vec(), keys))
+                })
+                .collect()
+        }
+
+        /// Collects the values of the fields under the path prefix of length `base` into a map,
+        /// replacing the fields of each enum with a single [tagged](EnumRepr) value.
+        ///
+        /// Keys are joined flat paths unless `nested` is true,
+        /// in which case each path segment is a nested object.
+        fn tag_enums(
+            &self,
+            entries: Vec<(Vec<String>, Value)>,
+            base: usize,
+            enums: &EnumPaths,
+            nested: bool,
+        ) -> serde_json::Map<String, Value> {
+            let mut map = serde_json::Map::new();
+            let mut groups: BTreeMap<Vec<String>, Vec<(Vec<String>, Value)>> = BTreeMap::new();
+            for (path, value) in entries {
+                match (base + 1..path.len()).find(|&len| enums.contains_key(&path[..len])) {
+                    Some(len) => {
+                        groups.entry(path[..len].to_vec()).or_default().push((path, value));
+                    }
+                    None => self.insert_entry(&mut map, &path[base..], value, nested),
+                }
+            }
+
+            for (enum_path, entries) in groups {
+                let keys = &enums[&enum_path];
+                let mut discrim = None;
+                let mut active = Vec::new();
+                for (path, value) in entries {
+                    let segment = &path[enum_path.len()];
+                    if path.len() == enum_path.len() + 1 && *segment == keys.discrim {
+                        discrim = Some(value);
+                    } else if *segment == keys.variant {
+                        active.push((path, value));
+                    }
+                }
+                let fields = self.tag_enums(active, enum_path.len() + 1, enums, true);
+
+                let tagged = match (self.adapter.enum_repr, discrim) {
+                    (EnumRepr::Tagged, discrim) => {
+                        let mut tagged = serde_json::Map::new();
+                        if let Some(discrim) = discrim {
+                            tagged.insert(String::from("discrim"), discrim);
+                        }
+                        tagged.insert(String::from("value"), Value::Object(fields));
+                        Value::Object(tagged)
+                    }
+                    (_, Some(Value::String(variant))) if fields.is_empty() => {
+                        Value::String(variant)
+                    }
+                    (_, Some(Value::String(variant))) => {
+                        Value::Object([(variant, Value::Object(fields))].into_iter().collect())
+                    }
+                    // The discriminant is not persisted, so the variant cannot be named.
+                    (_, _) => Value::Object(fields),
+                };
+                self.insert_entry(&mut map, &enum_path[base..], tagged, nested);
+            }
+            map
+        }
+
+        fn insert_entry(
+            &self,
+            map: &mut serde_json::Map<String, Value>,
+            path: &[String],
+            value: Value,
+            nested: bool,
+        ) {
+            if !nested {
+                map.insert(self.adapter.key_syntax.join(path), value);
+                return;
+            }
+
+            let (last, parents) = path.split_last().expect("field path must be nonempty");
+            let escape =
+                |segment: &String| self.adapter.key_syntax.join(core::slice::from_ref(segment));
+            let mut map = map;
+            for segment in parents {
+                map = map
+                    .entry(escape(segment))
+                    .or_insert_with(|| Value::Object(serde_json::Map::new()))
+                    .as_object_mut()
+                    .expect("config nodes cannot be both scalar and composite");
+            }
+            map.insert(escape(last), value);
+        }
+
+        /// Deserialize config data from a JSON [`Value`].
+        ///
+        /// `value` must be an object with the same structure as accepted by
+        /// [`from_reader`](Self::from_reader),
+        /// i.e. flat keys, nested objects or a mixture of both.
+        ///
+        /// # Errors
+        /// Errors if `value` is not an object or contains invalid values.
+        pub fn from_value(&self, world: &mut World, value: Value) -> Result<(), serde_json::Error> {
+            self.load_value_filtered(world, None, value, false).map(drop)
+        }
+
+        fn load_value_filtered(
+            &self,
+            world: &mut World,
+            root: Option<&str>,
+            value: Value,
+            lenient: bool,
+        ) -> Result<Vec<super::KeyError>, serde_json::Error> {
+            let Value::Object(map) = value else {
+                return Err(serde_json::Error::custom("expected a JSON object"));
+            };
+
+            world.batch_changes(|world| {
+                let keys = self.key_lookup(world, root);
+                let enums = if self.adapter.enum_repr == EnumRepr::Flat {
+                    EnumPaths::new()
+                } else {
+                    let scanned = self.keys_with_types(world, root);
+                    self.enum_paths(world, &scanned)
+                };
+                let mut state = super::LoadState::new(lenient);
+                self.load_object(world, &keys, &enums, &[], map, &mut state)?;
+                state.finish(world)
+            })
+        }
+
+        fn load_object(
+            &self,
+            world: &mut World,
+            keys: &super::KeyLookup<'_, TypedVtable<F>>,
+            enums: &EnumPaths,
+            prefix: &[String],
+            map: serde_json::Map<String, Value>,
+            state: &mut super::LoadState,
+        ) -> Result<(), serde_json::Error> {
+            for (key, value) in map {
+                let mut path = prefix.to_vec();
+                path.extend(self.adapter.de_key_path(key));
+
+                if let Some(enum_keys) = enums.get(&path) {
+                    self.load_tagged(world, keys, enums, path, enum_keys, value, state)?;
+                } else if let Some(&(entity, typed)) = keys.fields.get(&path) {
+                    match (typed.adapter.from_value)(
+                        world.entity_mut(entity),
+                        value,
+                        typed.adapter.out_of_range,
+                    ) {
+                        Ok(()) => state.loaded.push(entity),
+                        Err(err) => state.recover(&path, err)?,
+                    }
+                } else if keys.prefixes.contains(&path)
+                    && let Value::Object(nested) = value
+                {
+                    self.load_object(world, keys, enums, &path, nested, state)?;
+                }
+            }
+            Ok(())
+        }
+
+        /// Loads an enum written with a [tagged](EnumRepr) representation at `path`.
+        #[expect(clippy::too_many_arguments, reason = "recursion state of load_object")]
+        fn load_tagged(
+            &self,
+            world: &mut World,
+            keys: &super::KeyLookup<'_, TypedVtable<F>>,
+            enums: &EnumPaths,
+            mut path: Vec<String>,
+            enum_keys: &EnumKeys,
+            value: Value,
+            state: &mut super::LoadState,
+        ) -> Result<(), serde_json::Error> {
+            let (discrim, fields) = match (self.adapter.enum_repr, value) {
+                (EnumRepr::Tagged, Value::Object(mut tagged)) => {
+                    (tagged.remove("discrim"), tagged.remove("value"))
+                }
+                (EnumRepr::External, Value::String(variant)) => {
+                    (Some(Value::String(variant)), None)
+                }
+                (EnumRepr::External, Value::Object(external)) if external.len() == 1 => {
+                    let (variant, fields) =
+                        external.into_iter().next().expect("object has exactly one entry");
+                    (Some(Value::String(variant)), Some(fields))
+                }
+                _ => {
+                    let err = serde_json::Error::custom(format_args!(
+                        "expected a tagged enum at {}",
+                        path.join(".")
+                    ));
+                    return state.recover(&path, err);
+                }
+            };
+            // Without a discriminant, the variant of the fields is unknown.
+            let Some(discrim) = discrim else { return Ok(()) };
+            let variant = discrim.as_str().map(|variant| self.key_case.convert(variant));
+
+            path.push(enum_keys.discrim.clone());
+            if let Some(&(entity, typed)) = keys.fields.get(&path) {
+                match (typed.adapter.from_value)(
+                    world.entity_mut(entity),
+                    discrim,
+                    typed.adapter.out_of_range,
+                ) {
+                    Ok(()) => state.loaded.push(entity),
+                    Err(err) => {
+                        state.recover(&path, err)?;
+                        // The variant of the fields is unknown without a valid discriminant.
+                        return Ok(());
+                    }
+                }
+            }
+            path.pop();
+
+            if let (Some(variant), Some(Value::Object(fields))) = (variant, fields) {
+                path.push(variant);
+                self.load_object(world, keys, enums, &path, fields, state)?;
+            }
+            Ok(())
+        }
+
+        /// Deserialize config data from a [JSON5](https://json5.org) string.
+        ///
+        /// JSON5 is a superset of JSON that allows comments, trailing commas and unquoted keys,
+        /// which is convenient for settings files edited by hand.
+        /// Only reading is supported;
+        /// [`to_string`](Self::to_string) and [`to_writer`](Self::to_writer) still write
+        /// standard JSON, which is also valid JSON5.
+        ///
+        /// # Errors
+        /// Errors if `input` is not valid JSON5 or contains invalid values.
+        #[cfg(feature = "json5")]
+        pub fn from_json5_str(
+            &self,
+            world: &mut World,
+            input: &str,
+        ) -> Result<(), serde_json::Error> {
+            let value: Value = json5::from_str(input).map_err(serde_json::Error::custom)?;
+            self.from_value(world, value)
         }
     }
 }
@@ -425,8 +2468,120 @@ pub mod json {
 #[cfg(feature = "serde_json")]
 pub use json::Json;
 
-/// Generalizes all `Serialize + DeserializeOwned` types, as well as enum discriminants.
-pub trait SerdeScalar: Send + Sync + 'static {
+#[cfg(feature = "bevy_tasks")]
+pub mod async_save;
+
+#[cfg(feature = "serde_json")]
+pub mod persistent;
+
+#[cfg(all(feature = "serde_json", feature = "bevy_tasks"))]
+pub mod remote;
+
+#[cfg(feature = "serde_json")]
+pub mod scripting;
+
+#[cfg(feature = "debug_server")]
+pub mod server;
+
+#[cfg(feature = "std")]
+pub mod storage;
+#[cfg(feature = "std")]
+pub use storage::ConfigStorage;
+
+#[cfg(feature = "toml")]
+pub mod toml;
+#[cfg(feature = "toml")]
+pub use toml::Toml;
+
+/// Returns the value of the scalar field `entity` to be persisted.
+///
+/// This is the [original value](temporary::persisted_value) of a field holding a temporary value.
+/// Values provided by the [`Remote`](manager::layer::Layer::Remote),
+/// [`Env`](manager::layer::Layer::Env) and [`Cli`](manager::layer::Layer::Cli) layers
+/// are replaced with the value of the highest persisted layer below,
+/// so that overrides for the running app are not saved.
+#[cfg(any(feature = "serde_json", feature = "toml"))]
+fn persisted_value<T: SerdeScalar>(entity: EntityRef<'_>) -> Option<&T> {
+    manager::layer::persisted_layer_value(entity).or_else(|| temporary::persisted_value(entity))
+}
+
+/// Writes a deserialized value to the scalar field and bumps its generation if the value changed.
+///
+/// The value is adjusted by the [`ScalarConstraint`] of the field, if any,
+/// so that loaded data cannot violate the metadata of the field.
+///
+/// # Errors
+/// Returns an error message if the value would be adjusted
+/// and `out_of_range` is [`OutOfRange::Reject`],
+/// in which case the previous value of the field is restored.
+#[cfg(any(feature = "serde_json", feature = "toml"))]
+fn set_deserialized<T: SerdeScalar>(
+    entity: &mut EntityWorldMut,
+    value: T::Deserialize,
+    out_of_range: OutOfRange,
+) -> Result<(), &'static str> {
+    let mut data = entity.get_mut::<ScalarData<T>>().expect("type checked in serde query");
+    let previous = data.0.clone();
+    data.0.set_deserialized(value);
+    let adjusted = entity
+        .get::<ScalarConstraint>()
+        .copied()
+        .is_some_and(|constraint| constraint.apply(&mut EntityMut::from(&mut *entity)));
+    let mut data = entity.get_mut::<ScalarData<T>>().expect("type checked in serde query");
+    if adjusted && out_of_range == OutOfRange::Reject {
+        data.0 = previous;
+        return Err("value violates the constraints of the field");
+    }
+    if !same_serialized(&data.0, &previous) {
+        entity.get_mut::<ConfigNode>().expect("scanned entities are config nodes").bump();
+    }
+    Ok(())
+}
+
+/// Whether two scalar values serialize to the same data,
+/// so that loading a value equal to the current one does not bump the generation.
+///
+/// Values that fail to serialize are treated as different.
+#[cfg(any(feature = "serde_json", feature = "toml"))]
+fn same_serialized<T: SerdeScalar>(a: &T, b: &T) -> bool {
+    #[cfg(feature = "serde_json")]
+    let (a, b) = (serde_json::to_value(a.as_serialize()), serde_json::to_value(b.as_serialize()));
+    #[cfg(not(feature = "serde_json"))]
+    let (a, b) = {
+        let to_string = |value: &T| {
+            value
+                .as_serialize()
+                .serialize(toml_edit::ser::ValueSerializer::new())
+                .map(|value| value.to_string())
+        };
+        (to_string(a), to_string(b))
+    };
+    matches!((a, b), (Ok(a), Ok(b)) if a == b)
+}
+
+/// Determines how loaded values violating the metadata constraints of their field are handled,
+/// e.g. numbers outside the `min..=max` range.
+///
+/// Such values are never stored verbatim;
+/// they are either adjusted the same way as the editor UI would, e.g. clamped into the range,
+/// or rejected.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum OutOfRange {
+    /// Silently adjust the value.
+    #[default]
+    Clamp,
+    /// Keep the previous value of the field and report an error for the field.
+    ///
+    /// Combine with lenient loading to collect the errors without aborting the load.
+    Reject,
+}
+
+/// Generalizes all `Clone + Serialize + DeserializeOwned` types, as well as enum discriminants.
+#[diagnostic::on_unimplemented(
+    message = "type `{Self}` cannot be persisted by the `Serde` manager",
+    label = "`{Self}` does not implement `Clone`, `Serialize` and `DeserializeOwned`"
+)]
+pub trait SerdeScalar: Clone + Send + Sync + 'static {
     /// Expresses the scalar as a serializable type.
     ///
     /// [`Serde`] uses this value to serialize the scalar data into its output.
@@ -438,7 +2593,7 @@ pub trait SerdeScalar: Send + Sync + 'static {
     fn set_deserialized(&mut self, value: Self::Deserialize);
 }
 
-impl<T: Serialize + DeserializeOwned + Send + Sync + 'static> SerdeScalar for T {
+impl<T: Clone + Serialize + DeserializeOwned + Send + Sync + 'static> SerdeScalar for T {
     fn as_serialize(&self) -> &(impl Serialize + ?Sized) { self }
 
     type Deserialize = Self;
@@ -476,7 +2631,14 @@ const _: () = {
                 where
                     E: serde::de::Error,
                 {
+                    // Variant names may have been converted by a `KeyCase`.
                     T::from_name(value)
+                        .or_else(|| {
+                            T::VARIANTS
+                                .iter()
+                                .copied()
+                                .find(|variant| same_words(variant.name(), value))
+                        })
                         .ok_or_else(|| E::custom(format_args!("unknown enum variant: {value}")))
                 }
             }
@@ -0,0 +1,138 @@
+//! Non-blocking saves that perform file IO on the [`IoTaskPool`].
+//!
+//! Config data is serialized into a buffer on the calling thread,
+//! which is cheap compared to disk writes,
+//! and the buffer is written to the [`ConfigStorage`] on the IO task pool.
+//! A [`SaveCompleted`] message is written by [`poll_saves`] when the write finishes.
+//! The saved fields are only marked clean once the write has succeeded,
+//! so a failed write leaves them dirty for the next save.
+//!
+//! [`Serde`](super::Serde) managers register the message and add [`poll_saves`]
+//! to [`Last`](bevy_app::Last) automatically.
+
+extern crate std;
+
+use alloc::boxed::Box;
+use alloc::string::String;
+use alloc::vec::Vec;
+use std::io;
+
+use bevy_ecs::message::Message;
+use bevy_ecs::resource::Resource;
+use bevy_ecs::world::World;
+#[cfg(feature = "serde_json")]
+use bevy_tasks::IoTaskPool;
+use bevy_tasks::Task;
+use bevy_tasks::futures::check_ready;
+
+#[cfg(feature = "serde_json")]
+use super::ConfigStorage;
+
+/// Written by [`poll_saves`] when an asynchronous save has finished.
+#[derive(Message, Debug)]
+pub struct SaveCompleted {
+    /// The root key that was saved.
+    pub root:   String,
+    /// The storage name of the file that was written.
+    pub name:   String,
+    /// The result of the file IO.
+    pub result: io::Result<()>,
+}
+
+/// Called with the world after a save has been written successfully.
+pub(super) type OnSaved = Box<dyn FnOnce(&mut World) + Send + Sync>;
+
+/// Starts writing a save on the IO task pool.
+type StartWrite = Box<dyn FnOnce() -> Task<io::Result<()>> + Send + Sync>;
+
+struct PendingSave {
+    root:     String,
+    name:     String,
+    on_saved: OnSaved,
+}
+
+/// The saves of a single storage name.
+struct SaveQueue {
+    /// The save currently being written.
+    running: (PendingSave, Task<io::Result<()>>),
+    /// The newest save requested while `running` was being written.
+    queued:  Option<(PendingSave, StartWrite)>,
+}
+
+/// Saves that are still being written on the IO task pool.
+///
+/// Only one write per storage name is in progress at a time,
+/// so that an older save can never overwrite the file after a newer one.
+/// A save requested while another save of the same name is being written
+/// waits for that write to finish,
+/// replacing any other save that is still waiting, since it contains newer data.
+/// Replaced saves are dropped without a [`SaveCompleted`] message.
+#[derive(Resource, Default)]
+pub struct PendingSaves {
+    queues: Vec<SaveQueue>,
+}
+
+impl PendingSaves {
+    /// Returns whether any save is still in progress.
+    #[must_use]
+    pub fn is_empty(&self) -> bool { self.queues.is_empty() }
+}
+
+/// Spawns a task writing `bytes` to `storage` on the IO task pool,
+/// or queues it behind the save of the same `name` that is currently being written.
+///
+/// `on_saved` is called by [`poll_saves`] if the write succeeds.
+#[cfg(feature = "serde_json")]
+pub(super) fn spawn_write(
+    world: &mut World,
+    storage: impl ConfigStorage,
+    root: String,
+    name: String,
+    bytes: Vec<u8>,
+    on_saved: OnSaved,
+) {
+    let task_name = name.clone();
+    let start: StartWrite = Box::new(move || {
+        IoTaskPool::get().spawn(async move { storage.write_bytes(&task_name, &bytes) })
+    });
+    let save = PendingSave { root, name, on_saved };
+
+    let mut pending = world.get_resource_or_init::<PendingSaves>();
+    match pending.queues.iter_mut().find(|queue| queue.running.0.name == save.name) {
+        Some(queue) => queue.queued = Some((save, start)),
+        None => pending.queues.push(SaveQueue { running: (save, start()), queued: None }),
+    }
+}
+
+/// Polls pending saves and writes a [`SaveCompleted`] message for each finished save.
+///
+/// The fields of successfully written saves are marked clean,
+/// and the next queued save of the same storage name is started.
+/// This is an exclusive system.
+pub fn poll_saves(world: &mut World) {
+    let Some(mut pending) = world.get_resource_mut::<PendingSaves>() else { return };
+    let mut finished = Vec::new();
+    let mut index = 0;
+    while index < pending.queues.len() {
+        let queue = &mut pending.queues[index];
+        let Some(result) = check_ready(&mut queue.running.1) else {
+            index += 1;
+            continue;
+        };
+        match queue.queued.take() {
+            Some((save, start)) => {
+                let (done, _) = core::mem::replace(&mut queue.running, (save, start()));
+                finished.push((done, result));
+                index += 1;
+            }
+            None => finished.push((pending.queues.remove(index).running.0, result)),
+        }
+    }
+
+    for (save, result) in finished {
+        if result.is_ok() {
+            (save.on_saved)(world);
+        }
+        world.write_message(SaveCompleted { root: save.root, name: save.name, result });
+    }
+}
@@ -0,0 +1,181 @@
+//! Interoperability with resource persistence crates such as [`bevy-persistent`].
+//!
+//! Such crates persist a single serializable [`Resource`] through their own storage backends.
+//! [`ConfigDocument`] is that resource for the config tree:
+//! [`export_document`] returns a system that copies the config data
+//! saved by a JSON [`Serde`](super::Serde) manager into it whenever a field changes,
+//! and [`import_document`] returns a system that applies its contents back to the config fields.
+//! Both take a [`GetJsonManager`] projection, so they also work with tuples of managers.
+//!
+//! With `bevy-persistent`, wrap the document in a `Persistent` resource
+//! and bridge the two resources with a pair of systems:
+//!
+//! ```no_run
+//! # use bevy_app::{App, Last, Startup};
+//! # use bevy_ecs::prelude::*;
+//! # use bevy_mod_config::{AppExt, Config};
+//! # use bevy_mod_config::manager::serde::Json;
+//! use bevy_mod_config::manager::serde::persistent::{self, ConfigDocument};
+//! use bevy_persistent::{Persistent, StorageFormat};
+//! # #[derive(Config)]
+//! # struct Settings {
+//! #     #[config(default = 60)]
+//! #     fps: u32,
+//! # }
+//! # fn main() -> Result<(), bevy_persistent::PersistenceError> {
+//! # let mut app = App::new();
+//! # let config_dir = std::env::temp_dir();
+//!
+//! app.init_config::<Json, Settings>("settings");
+//! app.insert_resource(
+//!     Persistent::<ConfigDocument>::builder()
+//!         .name("settings")
+//!         .format(StorageFormat::Toml)
+//!         .path(config_dir.join("settings.toml"))
+//!         .default(ConfigDocument::default())
+//!         .build()?,
+//! );
+//! app.add_systems(
+//!     Startup,
+//!     (load_document, persistent::import_document::<Json, _>(|manager| manager)).chain(),
+//! );
+//! app.add_systems(
+//!     Last,
+//!     (persistent::export_document::<Json, _>(|manager| manager), save_document).chain(),
+//! );
+//! # Ok(())
+//! # }
+//!
+//! fn load_document(mut commands: Commands, persistent: Res<Persistent<ConfigDocument>>) {
+//!     commands.insert_resource(persistent.get().clone());
+//! }
+//!
+//! fn save_document(
+//!     document: Res<ConfigDocument>,
+//!     mut persistent: ResMut<Persistent<ConfigDocument>>,
+//! ) {
+//!     if document.is_changed() && !document.is_added() {
+//!         persistent.set(document.clone()).expect("failed to persist settings");
+//!     }
+//! }
+//! ```
+//!
+//! [`bevy-persistent`]: https://docs.rs/bevy-persistent
+
+use alloc::vec::Vec;
+
+use bevy_ecs::entity::Entity;
+use bevy_ecs::error::Result;
+use bevy_ecs::query::Without;
+use bevy_ecs::resource::Resource;
+use bevy_ecs::world::{Mut, World};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use serde_json::Value;
+use serde_json::ser::Formatter;
+
+use super::json::GetJsonManager;
+use crate::manager::{self, Manager};
+use crate::{ConfigNode, FieldGeneration, SkipSerde};
+
+/// A serializable copy of the config data saved by a JSON [`Serde`](super::Serde) manager.
+///
+/// The document has the same structure as [`Serde::to_value`](super::Serde::to_value),
+/// so it can be stored in any format supported by serde, not only JSON.
+#[derive(Resource, Debug, Clone, PartialEq)]
+pub struct ConfigDocument(pub Value);
+
+impl Default for ConfigDocument {
+    fn default() -> Self { Self(Value::Object(serde_json::Map::new())) }
+}
+
+impl Serialize for ConfigDocument {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        self.0.serialize(serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for ConfigDocument {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        Value::deserialize(deserializer).map(Self)
+    }
+}
+
+/// The generations of the config fields when [`ConfigDocument`] was last exported or imported.
+#[derive(Resource)]
+struct DocumentGenerations(Vec<(Entity, FieldGeneration)>);
+
+impl DocumentGenerations {
+    fn of(world: &mut World) -> Self {
+        let mut generations: Vec<_> = world
+            .query_filtered::<(Entity, &ConfigNode), Without<SkipSerde>>()
+            .iter(world)
+            .map(|(entity, node)| (entity, node.generation))
+            .collect();
+        generations.sort_unstable();
+        Self(generations)
+    }
+}
+
+/// Returns an exclusive system that replaces the [`ConfigDocument`] resource
+/// with the current config data
+/// if any field has changed since the last export or import.
+///
+/// `get_manager` returns the JSON [`Serde`](super::Serde) manager from the manager instance `M`,
+/// e.g. `|manager| manager` for [`Json`](super::Json).
+/// The system is intended to run once per frame, typically in [`Last`](bevy_app::Last).
+/// This does not [mark the fields clean](super::Serde::mark_clean),
+/// since the document is persisted independently of the files saved by the manager.
+///
+/// # Errors
+/// The system fails if the config data cannot be serialized.
+pub fn export_document<M, F>(
+    get_manager: GetJsonManager<M, F>,
+) -> impl FnMut(&mut World) -> Result + Send + Sync + 'static
+where
+    M: Manager,
+    F: Formatter + Send + Sync + 'static,
+{
+    move |world: &mut World| {
+        let generations = DocumentGenerations::of(world);
+        if world.contains_resource::<ConfigDocument>()
+            && world
+                .get_resource::<DocumentGenerations>()
+                .is_some_and(|last| last.0 == generations.0)
+        {
+            return Ok(());
+        }
+        let value = world.resource_scope(|world, managers: Mut<manager::Instance<M>>| {
+            get_manager(&managers).to_value(world)
+        })?;
+        world.insert_resource(ConfigDocument(value));
+        world.insert_resource(generations);
+        Ok(())
+    }
+}
+
+/// Returns an exclusive system that applies the [`ConfigDocument`] resource
+/// to the config fields, if it exists.
+///
+/// `get_manager` is the same as in [`export_document`].
+/// Fields missing in the document keep their current values.
+///
+/// # Errors
+/// The system fails if the document contains invalid values.
+pub fn import_document<M, F>(
+    get_manager: GetJsonManager<M, F>,
+) -> impl FnMut(&mut World) -> Result + Send + Sync + 'static
+where
+    M: Manager,
+    F: Formatter + Send + Sync + 'static,
+{
+    move |world: &mut World| {
+        let Some(document) = world.get_resource::<ConfigDocument>() else { return Ok(()) };
+        let value = document.0.clone();
+        world.resource_scope(|world, managers: Mut<manager::Instance<M>>| {
+            get_manager(&managers).from_value(world, value)
+        })?;
+        let generations = DocumentGenerations::of(world);
+        world.insert_resource(generations);
+        Ok(())
+    }
+}
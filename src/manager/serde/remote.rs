@@ -0,0 +1,263 @@
+//! Fetching config overrides from a remote source, e.g. for live-ops tuning.
+//!
+//! [`RemoteConfigPlugin`] fetches a JSON payload with the same structure as
+//! [`Serde::to_value`](super::Serde::to_value) at startup,
+//! and optionally again on an interval,
+//! then applies it to the config fields through a JSON [`Serde`](super::Serde) manager.
+//! If the fields are also managed by [`Layers`](crate::manager::Layers),
+//! the fetched values are attributed to [`Layer::Remote`],
+//! so they override values loaded from files
+//! and are removed again when a later payload no longer provides them.
+//!
+//! This crate does not depend on an HTTP client.
+//! The plugin is given a blocking [fetch function](FetchFn)
+//! that downloads the payload from a URL, e.g. with `ureq`,
+//! which is run on the [`IoTaskPool`].
+//! A [`RemoteConfigFetched`] message is written after each fetch.
+//!
+//! The fetched values become the effective values of the fields,
+//! but [`Serde`](super::Serde) managers saving the config afterwards
+//! persist the values of the layers below `Layer::Remote` instead,
+//! so that remote overrides are not written to local files.
+//! Without [`Layers`](crate::manager::Layers), the fetched values cannot be told apart
+//! from other values and are persisted as well.
+//!
+//! ```
+//! # use bevy_app::App;
+//! # use bevy_mod_config::{AppExt, Config};
+//! use bevy_mod_config::manager::Layers;
+//! use bevy_mod_config::manager::serde::Json;
+//! use bevy_mod_config::manager::serde::remote::RemoteConfigPlugin;
+//!
+//! #[derive(Config)]
+//! struct Tuning {
+//!     #[config(default = 1.0)]
+//!     xp_multiplier: f32,
+//! }
+//!
+//! let mut app = App::new();
+//! app.init_config_with::<(Json, Layers), Tuning>("tuning", || (Json::new(), Layers));
+//! app.add_plugins(RemoteConfigPlugin::with_manager(
+//!     "https://example.com/tuning.json",
+//!     |_url| {
+//!         // e.g. ureq::get(url).call()?.body_mut().read_to_vec()
+//!         Ok(br#"{"tuning.xp_multiplier": 2.0}"#.to_vec())
+//!     },
+//!     |managers: &(Json, Layers)| &managers.0,
+//! ));
+//! ```
+
+use alloc::string::String;
+use alloc::sync::Arc;
+use alloc::vec::Vec;
+use core::fmt;
+#[cfg(feature = "bevy_time")]
+use core::time::Duration;
+
+use bevy_app::{App, Plugin, PreUpdate};
+use bevy_ecs::message::Message;
+use bevy_ecs::resource::Resource;
+use bevy_ecs::world::{Mut, World};
+use bevy_tasks::futures::check_ready;
+use bevy_tasks::{IoTaskPool, Task};
+use serde_json::Value;
+use serde_json::ser::Formatter;
+
+use super::Serde;
+use super::json::{GetJsonManager, JsonAdapter};
+use crate::manager::layer::{self, Layer};
+use crate::manager::{self, Manager};
+
+/// A blocking function that downloads the payload at a URL.
+///
+/// The error is reported as [`RemoteError::Fetch`].
+pub type FetchFn = Arc<dyn Fn(&str) -> Result<Vec<u8>, String> + Send + Sync>;
+
+/// A plugin that applies config overrides fetched from a URL.
+///
+/// See the [module documentation](self) for usage.
+pub struct RemoteConfigPlugin<M: Manager, F: Formatter + Send + Sync + 'static> {
+    url:         String,
+    fetch:       FetchFn,
+    #[cfg(feature = "bevy_time")]
+    interval:    Option<Duration>,
+    get_manager: GetJsonManager<M, F>,
+}
+
+impl<F: Formatter + Send + Sync + 'static> RemoteConfigPlugin<Serde<JsonAdapter<F>>, F> {
+    /// Creates a plugin fetching from `url`
+    /// for a world initialized with a JSON manager only.
+    pub fn new(
+        url: impl Into<String>,
+        fetch: impl Fn(&str) -> Result<Vec<u8>, String> + Send + Sync + 'static,
+    ) -> Self {
+        Self::with_manager(url, fetch, |manager| manager)
+    }
+}
+
+impl<M: Manager, F: Formatter + Send + Sync + 'static> RemoteConfigPlugin<M, F> {
+    /// Creates a plugin fetching from `url`
+    /// using the JSON manager returned by `get_manager`.
+    pub fn with_manager(
+        url: impl Into<String>,
+        fetch: impl Fn(&str) -> Result<Vec<u8>, String> + Send + Sync + 'static,
+        get_manager: GetJsonManager<M, F>,
+    ) -> Self {
+        Self {
+            url: url.into(),
+            fetch: Arc::new(fetch),
+            #[cfg(feature = "bevy_time")]
+            interval: None,
+            get_manager,
+        }
+    }
+
+    /// Fetches the payload again after `interval` has elapsed since the previous fetch finished,
+    /// measured by the [`Time`](bevy_time::Time) resource.
+    #[cfg(feature = "bevy_time")]
+    #[must_use]
+    pub fn with_interval(mut self, interval: Duration) -> Self {
+        self.interval = Some(interval);
+        self
+    }
+}
+
+impl<M, F> Plugin for RemoteConfigPlugin<M, F>
+where
+    M: Manager,
+    F: Formatter + Send + Sync + 'static,
+{
+    fn build(&self, app: &mut App) {
+        app.add_message::<RemoteConfigFetched>();
+        let url = self.url.clone();
+        let fetch = Arc::clone(&self.fetch);
+        app.insert_resource(RemoteSource::<M, F> {
+            url,
+            fetch,
+            #[cfg(feature = "bevy_time")]
+            interval: self.interval,
+            #[cfg(feature = "bevy_time")]
+            next_fetch: None,
+            due: true,
+            task: None,
+            get_manager: self.get_manager,
+        });
+        app.add_systems(PreUpdate, poll_remote::<M, F>);
+    }
+}
+
+/// The state of a [`RemoteConfigPlugin`].
+#[derive(Resource)]
+struct RemoteSource<M: Manager, F: Formatter + Send + Sync + 'static> {
+    url:         String,
+    fetch:       FetchFn,
+    #[cfg(feature = "bevy_time")]
+    interval:    Option<Duration>,
+    /// The elapsed time at which the payload should be fetched again.
+    #[cfg(feature = "bevy_time")]
+    next_fetch:  Option<Duration>,
+    /// Whether a fetch should be started in the next poll.
+    due:         bool,
+    task:        Option<Task<Result<Vec<u8>, String>>>,
+    get_manager: GetJsonManager<M, F>,
+}
+
+/// Written after each fetch started by [`RemoteConfigPlugin`] has finished.
+#[derive(Message, Debug)]
+pub struct RemoteConfigFetched {
+    /// The URL that was fetched.
+    pub url:    String,
+    /// Whether the payload was fetched and applied successfully.
+    pub result: Result<(), RemoteError>,
+}
+
+/// The error reported in [`RemoteConfigFetched`].
+#[derive(Debug)]
+pub enum RemoteError {
+    /// The [fetch function](FetchFn) returned an error.
+    Fetch(String),
+    /// The payload is not valid config data.
+    ///
+    /// Fields before the invalid value may have been applied.
+    Invalid(serde_json::Error),
+}
+
+impl fmt::Display for RemoteError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Fetch(err) => write!(f, "failed to fetch remote config: {err}"),
+            Self::Invalid(err) => write!(f, "invalid remote config: {err}"),
+        }
+    }
+}
+
+impl core::error::Error for RemoteError {
+    fn source(&self) -> Option<&(dyn core::error::Error + 'static)> {
+        match self {
+            Self::Invalid(err) => Some(err),
+            Self::Fetch(_) => None,
+        }
+    }
+}
+
+/// Applies finished fetches and starts new ones when due.
+fn poll_remote<M, F>(world: &mut World)
+where
+    M: Manager,
+    F: Formatter + Send + Sync + 'static,
+{
+    world.resource_scope(|world, mut source: Mut<RemoteSource<M, F>>| {
+        if let Some(task) = &mut source.task {
+            let Some(payload) = check_ready(task) else { return };
+            source.task = None;
+
+            let get_manager = source.get_manager;
+            let result = payload
+                .map_err(RemoteError::Fetch)
+                .and_then(|bytes| serde_json::from_slice(&bytes).map_err(RemoteError::Invalid))
+                .and_then(|value| {
+                    world.resource_scope(|world, managers: Mut<manager::Instance<M>>| {
+                        apply(world, get_manager(&managers), value)
+                    })
+                });
+            world.write_message(RemoteConfigFetched { url: source.url.clone(), result });
+
+            #[cfg(feature = "bevy_time")]
+            if let (Some(interval), Some(time)) =
+                (source.interval, world.get_resource::<bevy_time::Time>())
+            {
+                source.next_fetch = Some(time.elapsed() + interval);
+            }
+        }
+
+        #[cfg(feature = "bevy_time")]
+        if let (Some(next_fetch), Some(time)) =
+            (source.next_fetch, world.get_resource::<bevy_time::Time>())
+            && time.elapsed() >= next_fetch
+        {
+            source.next_fetch = None;
+            source.due = true;
+        }
+
+        if source.due {
+            source.due = false;
+            let fetch = Arc::clone(&source.fetch);
+            let url = source.url.clone();
+            source.task = Some(IoTaskPool::get().spawn(async move { fetch(&url) }));
+        }
+    });
+}
+
+/// Replaces the [`Layer::Remote`] values with the fields in `value`.
+fn apply<F: Formatter + Send + Sync + 'static>(
+    world: &mut World,
+    manager: &Serde<JsonAdapter<F>>,
+    value: Value,
+) -> Result<(), RemoteError> {
+    // Attribute pending changes to the runtime layer before they are mixed with the payload.
+    layer::capture(world, Layer::Runtime);
+    layer::clear(world, Layer::Remote);
+    let result = manager.from_value(world, value).map_err(RemoteError::Invalid);
+    layer::capture(world, Layer::Remote);
+    result
+}
@@ -0,0 +1,187 @@
+//! A minimal dynamic API for scripting layers.
+//!
+//! Scripting integrations such as `bevy_mod_scripting` cannot name the Rust config types,
+//! so this module exposes the config fields by their dot-separated paths
+//! with JSON [`Value`]s, which map naturally to Lua tables or Rhai maps:
+//!
+//! - [`paths`] lists all fields.
+//! - [`get`] and [`set`] read and write a field.
+//! - [`subscribe`] starts recording changes under a path prefix,
+//!   which the script collects with [`poll`] each frame.
+//!
+//! Only fields managed by a [`Serde`](super::Serde) manager are accessible,
+//! since their values are converted through the [`ValueVtable`].
+//!
+//! ```
+//! # use bevy_app::App;
+//! # use bevy_mod_config::{AppExt, Config};
+//! use bevy_mod_config::manager::serde::{Json, scripting};
+//! use serde_json::json;
+//!
+//! #[derive(Config)]
+//! struct Player {
+//!     #[config(default = 5.0)]
+//!     speed: f32,
+//! }
+//!
+//! let mut app = App::new();
+//! app.init_config_with::<Json, Player>("player", Json::new);
+//! app.update();
+//!
+//! let world = app.world_mut();
+//! assert_eq!(scripting::paths(world), ["player.speed"]);
+//! let subscription = scripting::subscribe(world, "player");
+//!
+//! scripting::set(world, "player.speed", json!(8.0)).unwrap();
+//! assert_eq!(scripting::get(world, "player.speed").unwrap(), json!(8.0));
+//!
+//! app.update();
+//! let changes = scripting::poll(app.world_mut(), subscription);
+//! assert_eq!(changes[0].path, "player.speed");
+//! assert_eq!(changes[0].value, json!(8.0));
+//! ```
+
+use alloc::string::String;
+use alloc::vec::Vec;
+
+use bevy_ecs::entity::Entity;
+use bevy_ecs::observer::On;
+use bevy_ecs::resource::Resource;
+use bevy_ecs::system::{Command, Query, ResMut};
+use bevy_ecs::world::World;
+use hashbrown::HashMap;
+use serde_json::Value;
+
+use super::json::{SetConfigValue, SetValueError, ValueVtable};
+use crate::{ConfigChanged, ConfigNode};
+
+/// Lists the dot-separated paths of all accessible fields in lexicographic order.
+pub fn paths(world: &mut World) -> Vec<String> {
+    let mut query = world.query::<(&ConfigNode, &ValueVtable)>();
+    let mut paths: Vec<_> = query.iter(world).map(|(node, _)| node.path.join(".")).collect();
+    paths.sort();
+    paths
+}
+
+/// Serializes the value of the field at the dot-separated `path`.
+///
+/// # Errors
+/// Errors if the path is not an accessible field
+/// or the value cannot be serialized.
+pub fn get(world: &mut World, path: &str) -> Result<Value, SetValueError> {
+    let path: Vec<_> = path.split('.').map(String::from).collect();
+    let mut query = world.query::<(Entity, &ConfigNode, Option<&ValueVtable>)>();
+    let Some((entity, _, vtable)) = query.iter(world).find(|(_, node, _)| node.path == path) else {
+        return Err(SetValueError::NotFound(path.join(".")));
+    };
+    let Some(vtable) = vtable else {
+        return Err(SetValueError::NotScalar(path.join(".")));
+    };
+    vtable.to_value(world.entity(entity)).map_err(|err| SetValueError::Invalid(path.join("."), err))
+}
+
+/// Writes `value` to the field at the dot-separated `path`, bumping its generation.
+///
+/// This is equivalent to applying a [`SetConfigValue`] command immediately.
+///
+/// # Errors
+/// Errors if the path is not an accessible field
+/// or the value is not valid for the field type.
+pub fn set(world: &mut World, path: &str, value: Value) -> Result<(), SetValueError> {
+    SetConfigValue::from_value(path, value).apply(world)
+}
+
+/// Identifies a subscription created by [`subscribe`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct SubscriptionId(u64);
+
+/// A field change collected by [`poll`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct FieldChange {
+    /// The dot-separated path of the changed field.
+    pub path:  String,
+    /// The value of the field when polled.
+    pub value: Value,
+}
+
+#[derive(Resource, Default)]
+struct Subscriptions {
+    next_id:       u64,
+    subscriptions: HashMap<SubscriptionId, Subscription>,
+}
+
+struct Subscription {
+    prefix:  Vec<String>,
+    pending: Vec<Entity>,
+}
+
+/// Starts recording changes of the fields under the dot-separated `prefix`.
+///
+/// An empty `prefix` subscribes to all fields.
+/// Changes are recorded when [`ConfigChanged`] is triggered,
+/// i.e. once per frame in [`Last`](bevy_app::Last),
+/// until the subscription is removed with [`unsubscribe`].
+pub fn subscribe(world: &mut World, prefix: &str) -> SubscriptionId {
+    if !world.contains_resource::<Subscriptions>() {
+        world.init_resource::<Subscriptions>();
+        world.add_observer(record_change);
+    }
+
+    let prefix =
+        if prefix.is_empty() { Vec::new() } else { prefix.split('.').map(String::from).collect() };
+    let mut subscriptions = world.resource_mut::<Subscriptions>();
+    let id = SubscriptionId(subscriptions.next_id);
+    subscriptions.next_id += 1;
+    subscriptions.subscriptions.insert(id, Subscription { prefix, pending: Vec::new() });
+    id
+}
+
+/// Stops recording changes for the subscription.
+pub fn unsubscribe(world: &mut World, id: SubscriptionId) {
+    if let Some(mut subscriptions) = world.get_resource_mut::<Subscriptions>() {
+        subscriptions.subscriptions.remove(&id);
+    }
+}
+
+/// Takes the changes recorded for the subscription since the last poll.
+///
+/// Each changed field is reported once with its current value,
+/// in the order of its first change.
+/// Fields that have been despawned or can no longer be serialized are skipped.
+pub fn poll(world: &mut World, id: SubscriptionId) -> Vec<FieldChange> {
+    let Some(mut subscriptions) = world.get_resource_mut::<Subscriptions>() else {
+        return Vec::new();
+    };
+    let Some(subscription) = subscriptions.subscriptions.get_mut(&id) else { return Vec::new() };
+    let pending = core::mem::take(&mut subscription.pending);
+
+    pending
+        .into_iter()
+        .filter_map(|entity| {
+            let entity = world.get_entity(entity).ok()?;
+            let node = entity.get::<ConfigNode>()?;
+            let value = entity.get::<ValueVtable>()?.to_value(entity).ok()?;
+            Some(FieldChange { path: node.path.join("."), value })
+        })
+        .collect()
+}
+
+#[allow(clippy::needless_pass_by_value, reason = "system parameters are passed by value")]
+fn record_change(
+    event: On<ConfigChanged>,
+    nodes: Query<&ConfigNode>,
+    mut subscriptions: ResMut<Subscriptions>,
+) {
+    // Only handle the event at the changed field itself, not during propagation.
+    if event.node != event.field {
+        return;
+    }
+    let Ok(node) = nodes.get(event.field) else { return };
+    for subscription in subscriptions.subscriptions.values_mut() {
+        if node.path.starts_with(&subscription.prefix)
+            && !subscription.pending.contains(&event.field)
+        {
+            subscription.pending.push(event.field);
+        }
+    }
+}
@@ -0,0 +1,396 @@
+//! A debug HTTP server to inspect and edit the config of a running app from a browser.
+//!
+//! [`ConfigServerPlugin`] listens on a TCP address and serves the fields accessible through the
+//! [`scripting`](super::scripting) API:
+//!
+//! - `GET /config` returns a JSON object mapping each dot-separated path to its value.
+//! - `GET /config/{path}` returns the value of a single field.
+//! - `PUT /config/{path}` sets a field from the JSON request body,
+//!   only if the server is [writable](ConfigServerPlugin::writable)
+//!   and its [role](ConfigServerPlugin::role) has the [`Permission`] required by the field.
+//!
+//! Paths may be percent-encoded.
+//! Connections are polled without blocking in [`PreUpdate`],
+//! so a slow client never stalls the frame; each connection serves one request without keep-alive.
+//! The server is intended for local debugging only:
+//! it performs no authentication and should not be exposed to untrusted networks.
+//!
+//! ```no_run
+//! # use bevy_app::App;
+//! # use bevy_mod_config::{AppExt, Config};
+//! use bevy_mod_config::manager::serde::Json;
+//! use bevy_mod_config::manager::serde::server::ConfigServerPlugin;
+//!
+//! #[derive(Config)]
+//! struct Player {
+//!     #[config(default = 5.0)]
+//!     speed: f32,
+//! }
+//!
+//! let mut app = App::new();
+//! app.init_config_with::<Json, Player>("player", Json::new);
+//! app.add_plugins(ConfigServerPlugin::new("127.0.0.1:8765").writable());
+//! // curl -X PUT localhost:8765/config/player.speed -d 8.0
+//! ```
+
+extern crate std;
+
+use alloc::format;
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+use std::io::{self, Read, Write};
+use std::net::{SocketAddr, TcpListener, TcpStream};
+use std::time::{Duration, Instant};
+
+use bevy_app::{App, Plugin, PreUpdate};
+use bevy_ecs::entity::Entity;
+use bevy_ecs::resource::Resource;
+use bevy_ecs::world::{EntityMut, Mut, World};
+use serde_json::{Map, Value};
+
+use super::json::{SetValueError, ValueVtable};
+use super::scripting;
+use crate::{ConfigNode, Permission};
+
+/// The maximum size of a request, including headers.
+const MAX_REQUEST_LEN: usize = 1 << 20;
+
+/// The time after which an accepted connection is dropped
+/// if it has not sent a complete request and received the response.
+const CONNECTION_TIMEOUT: Duration = Duration::from_secs(5);
+
+/// A plugin that serves the config tree over HTTP.
+///
+/// If the address cannot be bound, the failure is logged
+/// and the server is disabled without inserting the [`ConfigServer`] resource.
+///
+/// See the [module documentation](self) for usage.
+#[derive(Debug, Clone)]
+pub struct ConfigServerPlugin {
+    addr:     String,
+    writable: bool,
+    role:     Permission,
+}
+
+impl ConfigServerPlugin {
+    /// Creates a read-only server listening on `addr`, e.g. `"127.0.0.1:8765"`.
+    #[must_use]
+    pub fn new(addr: impl Into<String>) -> Self {
+        Self { addr: addr.into(), writable: false, role: Permission::Player }
+    }
+
+    /// Allows setting fields with `PUT` requests.
+    #[must_use]
+    pub fn writable(mut self) -> Self {
+        self.writable = true;
+        self
+    }
+
+    /// Sets the role of clients when setting fields.
+    ///
+    /// Fields that require a more privileged [`Permission`] cannot be set.
+    /// Defaults to [`Permission::Player`].
+    #[must_use]
+    pub fn role(mut self, role: Permission) -> Self {
+        self.role = role;
+        self
+    }
+}
+
+impl Plugin for ConfigServerPlugin {
+    fn build(&self, app: &mut App) {
+        let listener = match TcpListener::bind(&self.addr)
+            .and_then(|listener| listener.set_nonblocking(true).map(|()| listener))
+        {
+            Ok(listener) => listener,
+            Err(err) => {
+                #[cfg(feature = "tracing")]
+                tracing::error!(addr = %self.addr, "cannot start config server: {err}");
+                #[cfg(not(feature = "tracing"))]
+                let _ = err;
+                return;
+            }
+        };
+        app.insert_resource(ConfigServer {
+            listener,
+            writable: self.writable,
+            role: self.role,
+            connections: Vec::new(),
+        });
+        app.add_systems(PreUpdate, serve_requests);
+    }
+}
+
+/// The listener of a [`ConfigServerPlugin`].
+#[derive(Resource)]
+pub struct ConfigServer {
+    listener:    TcpListener,
+    writable:    bool,
+    role:        Permission,
+    connections: Vec<Connection>,
+}
+
+impl ConfigServer {
+    /// Returns the address the server is listening on,
+    /// e.g. to find the port assigned for the address `127.0.0.1:0`.
+    ///
+    /// # Errors
+    /// Errors from the underlying socket.
+    pub fn local_addr(&self) -> io::Result<SocketAddr> { self.listener.local_addr() }
+}
+
+/// Accepts pending connections and advances all open connections without blocking.
+///
+/// Added to [`PreUpdate`] by [`ConfigServerPlugin`].
+pub fn serve_requests(world: &mut World) {
+    world.resource_scope(|world, mut server: Mut<ConfigServer>| {
+        let server = &mut *server;
+        let now = Instant::now();
+        // Other accept errors are retried in the next frame.
+        while let Ok((stream, _)) = server.listener.accept() {
+            // A failed connection only affects the client that made it.
+            if stream.set_nonblocking(true).is_ok() {
+                server.connections.push(Connection {
+                    stream,
+                    state: ConnectionState::Reading(Vec::new()),
+                    deadline: now + CONNECTION_TIMEOUT,
+                });
+            }
+        }
+
+        let (writable, role) = (server.writable, server.role);
+        server.connections.retain_mut(|connection| {
+            now < connection.deadline && connection.poll(world, writable, role)
+        });
+    });
+}
+
+struct Connection {
+    stream:   TcpStream,
+    state:    ConnectionState,
+    deadline: Instant,
+}
+
+enum ConnectionState {
+    /// The bytes of the request received so far.
+    Reading(Vec<u8>),
+    /// The encoded response and the number of bytes already sent.
+    Writing(Vec<u8>, usize),
+}
+
+impl Connection {
+    /// Reads and writes as much as possible without blocking.
+    ///
+    /// Returns `false` if the connection should be closed.
+    fn poll(&mut self, world: &mut World, writable: bool, role: Permission) -> bool {
+        if let ConnectionState::Reading(buf) = &mut self.state {
+            let Ok(eof) = read_available(&mut self.stream, buf) else { return false };
+            let response = match parse_request(buf) {
+                ParsedRequest::Incomplete if !eof => return true,
+                ParsedRequest::Incomplete | ParsedRequest::Malformed => {
+                    Response::error("400 Bad Request", "malformed request")
+                }
+                ParsedRequest::Complete { method, target, body } => {
+                    route(world, method, target, body, writable, role)
+                }
+            };
+            self.state = ConnectionState::Writing(response.encode(), 0);
+        }
+
+        let ConnectionState::Writing(data, written) = &mut self.state else { return true };
+        while *written < data.len() {
+            match self.stream.write(&data[*written..]) {
+                Ok(0) => return false,
+                Ok(len) => *written += len,
+                Err(err) if err.kind() == io::ErrorKind::WouldBlock => return true,
+                Err(err) if err.kind() == io::ErrorKind::Interrupted => {}
+                Err(_) => return false,
+            }
+        }
+        false
+    }
+}
+
+/// Appends all bytes available on `stream` to `buf`.
+///
+/// Returns whether the client has closed its side of the connection.
+fn read_available(stream: &mut TcpStream, buf: &mut Vec<u8>) -> io::Result<bool> {
+    let mut chunk = [0; 4096];
+    while buf.len() <= MAX_REQUEST_LEN {
+        match stream.read(&mut chunk) {
+            Ok(0) => return Ok(true),
+            Ok(len) => buf.extend_from_slice(&chunk[..len]),
+            Err(err) if err.kind() == io::ErrorKind::WouldBlock => break,
+            Err(err) if err.kind() == io::ErrorKind::Interrupted => {}
+            Err(err) => return Err(err),
+        }
+    }
+    Ok(false)
+}
+
+enum ParsedRequest<'a> {
+    /// More bytes are required to parse the request.
+    Incomplete,
+    /// The request is malformed or too large.
+    Malformed,
+    Complete {
+        method: &'a str,
+        target: &'a str,
+        body:   &'a [u8],
+    },
+}
+
+/// Parses the method, request target and body of a request.
+fn parse_request(buf: &[u8]) -> ParsedRequest<'_> {
+    let Some(header_end) = buf.windows(4).position(|window| window == b"\r\n\r\n") else {
+        return if buf.len() > MAX_REQUEST_LEN {
+            ParsedRequest::Malformed
+        } else {
+            ParsedRequest::Incomplete
+        };
+    };
+
+    let Ok(head) = core::str::from_utf8(&buf[..header_end]) else {
+        return ParsedRequest::Malformed;
+    };
+    let mut lines = head.split("\r\n");
+    let mut request_line = lines.next().unwrap_or_default().split(' ');
+    let (Some(method), Some(target)) = (request_line.next(), request_line.next()) else {
+        return ParsedRequest::Malformed;
+    };
+
+    let content_length = lines
+        .filter_map(|line| line.split_once(':'))
+        .find(|(name, _)| name.trim().eq_ignore_ascii_case("content-length"))
+        .map_or(Ok(0), |(_, value)| value.trim().parse::<usize>());
+    let Ok(content_length) = content_length else { return ParsedRequest::Malformed };
+    if content_length > MAX_REQUEST_LEN {
+        return ParsedRequest::Malformed;
+    }
+
+    let body = &buf[header_end + 4..];
+    if body.len() < content_length {
+        return ParsedRequest::Incomplete;
+    }
+    ParsedRequest::Complete { method, target, body: &body[..content_length] }
+}
+
+struct Response {
+    status: &'static str,
+    body:   Option<Value>,
+}
+
+impl Response {
+    fn ok(body: Value) -> Self { Self { status: "200 OK", body: Some(body) } }
+
+    fn error(status: &'static str, message: impl Into<String>) -> Self {
+        Self { status, body: Some(Value::String(message.into())) }
+    }
+
+    fn encode(self) -> Vec<u8> {
+        let body = self.body.map(|body| body.to_string()).unwrap_or_default();
+        let content_type = if body.is_empty() { "" } else { "Content-Type: application/json\r\n" };
+        format!(
+            "HTTP/1.1 {}\r\n{content_type}Content-Length: {}\r\nConnection: close\r\n\r\n{body}",
+            self.status,
+            body.len(),
+        )
+        .into_bytes()
+    }
+}
+
+fn route(
+    world: &mut World,
+    method: &str,
+    target: &str,
+    body: &[u8],
+    writable: bool,
+    role: Permission,
+) -> Response {
+    let path = target.split('?').next().unwrap_or_default().trim_end_matches('/');
+    let Some(path) = percent_decode(path) else {
+        return Response::error("400 Bad Request", "malformed percent-encoding in path");
+    };
+    if path == "/config" {
+        if method != "GET" {
+            return Response::error("405 Method Not Allowed", "use GET");
+        }
+        let mut values = Map::new();
+        for path in scripting::paths(world) {
+            if let Ok(value) = scripting::get(world, &path) {
+                values.insert(path, value);
+            }
+        }
+        return Response::ok(Value::Object(values));
+    }
+
+    let Some(field) = path.strip_prefix("/config/") else {
+        return Response::error("404 Not Found", "not found");
+    };
+    match method {
+        "GET" => match scripting::get(world, field) {
+            Ok(value) => Response::ok(value),
+            Err(err) => error_response(&err),
+        },
+        "PUT" => {
+            if !writable {
+                return Response::error("403 Forbidden", "the config server is read-only");
+            }
+            let Some(entity) = find_node(world, field) else {
+                return error_response(&SetValueError::NotFound(field.into()));
+            };
+            if !role.allows(Permission::required_for(world, entity)) {
+                return Response::error(
+                    "403 Forbidden",
+                    format!("{field} requires a more privileged role"),
+                );
+            }
+            let Some(&vtable) = world.get::<ValueVtable>(entity) else {
+                return error_response(&SetValueError::NotScalar(field.into()));
+            };
+            let value = match serde_json::from_slice(body) {
+                Ok(value) => value,
+                Err(err) => return Response::error("400 Bad Request", format!("{err}")),
+            };
+            match vtable.set_value(&mut EntityMut::from(world.entity_mut(entity)), value) {
+                Ok(()) => Response { status: "204 No Content", body: None },
+                Err(err) => error_response(&SetValueError::Invalid(field.into(), err)),
+            }
+        }
+        _ => Response::error("405 Method Not Allowed", "use GET or PUT"),
+    }
+}
+
+fn find_node(world: &mut World, path: &str) -> Option<Entity> {
+    world
+        .query::<(Entity, &ConfigNode)>()
+        .iter(world)
+        .find(|(_, node)| node.path.iter().map(String::as_str).eq(path.split('.')))
+        .map(|(entity, _)| entity)
+}
+
+/// Decodes `%XX` escapes in a request path.
+///
+/// Returns `None` if an escape is malformed or the decoded path is not UTF-8.
+fn percent_decode(path: &str) -> Option<String> {
+    let mut bytes = Vec::with_capacity(path.len());
+    let mut iter = path.bytes();
+    while let Some(byte) = iter.next() {
+        if byte == b'%' {
+            let mut digit = || char::from(iter.next()?).to_digit(16);
+            let (high, low) = (digit()?, digit()?);
+            bytes.push(u8::try_from(high << 4 | low).ok()?);
+        } else {
+            bytes.push(byte);
+        }
+    }
+    String::from_utf8(bytes).ok()
+}
+
+fn error_response(err: &SetValueError) -> Response {
+    let status = match err {
+        SetValueError::NotFound(_) | SetValueError::NotScalar(_) => "404 Not Found",
+        SetValueError::Invalid(..) => "400 Bad Request",
+    };
+    Response::error(status, format!("{err}"))
+}
@@ -0,0 +1,182 @@
+//! Storage backends for persisted config data.
+//!
+//! The persistence helpers (e.g. [`Serde::save_root`](super::Serde::save_root))
+//! only produce and consume bytes,
+//! and delegate the actual storage to a [`ConfigStorage`] implementation.
+//! This allows plugging in platform-specific storage such as cloud saves,
+//! console save systems or a database without touching the serde layer.
+
+extern crate std;
+
+use alloc::borrow::ToOwned as _;
+#[cfg(all(feature = "web", target_arch = "wasm32"))]
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::sync::atomic::{AtomicU64, Ordering};
+use std::io::{self, Write as _};
+use std::path::PathBuf;
+
+/// A backend that stores named byte blobs, e.g. files in a directory.
+///
+/// Names are relative identifiers like `video.json` chosen by the persistence helpers.
+pub trait ConfigStorage: Send + Sync + 'static {
+    /// Reads the bytes stored under `name`.
+    ///
+    /// Returns `Ok(None)` if nothing is stored under `name`.
+    ///
+    /// # Errors
+    /// Errors from the underlying storage.
+    fn read_bytes(&self, name: &str) -> io::Result<Option<Vec<u8>>>;
+
+    /// Replaces the bytes stored under `name`.
+    ///
+    /// Implementations should make the replacement atomic where possible,
+    /// such that a crash during the write never leaves partially written data.
+    ///
+    /// # Errors
+    /// Errors from the underlying storage.
+    fn write_bytes(&self, name: &str, bytes: &[u8]) -> io::Result<()>;
+
+    /// Moves the bytes stored under `from` to `to`, replacing any existing data under `to`.
+    ///
+    /// # Errors
+    /// Errors from the underlying storage.
+    fn rename(&self, from: &str, to: &str) -> io::Result<()>;
+}
+
+/// Stores each name as a file in a directory.
+#[derive(Debug, Clone)]
+pub struct FileStorage {
+    /// The directory containing the files.
+    pub dir: PathBuf,
+}
+
+impl FileStorage {
+    /// Creates a storage backend for files in `dir`.
+    ///
+    /// The directory is not created automatically.
+    #[must_use]
+    pub fn new(dir: impl Into<PathBuf>) -> Self { Self { dir: dir.into() } }
+}
+
+impl ConfigStorage for FileStorage {
+    fn read_bytes(&self, name: &str) -> io::Result<Option<Vec<u8>>> {
+        match std::fs::read(self.dir.join(name)) {
+            Ok(bytes) => Ok(Some(bytes)),
+            Err(err) if err.kind() == io::ErrorKind::NotFound => Ok(None),
+            Err(err) => Err(err),
+        }
+    }
+
+    fn write_bytes(&self, name: &str, bytes: &[u8]) -> io::Result<()> {
+        write_atomic(&self.dir.join(name), bytes)
+    }
+
+    fn rename(&self, from: &str, to: &str) -> io::Result<()> {
+        std::fs::rename(self.dir.join(from), self.dir.join(to))
+    }
+}
+
+/// Replaces the file at `path` with `bytes` atomically.
+///
+/// The data is written to a temporary file next to `path`
+/// (e.g. `config.json.1234.0.tmp` with the process ID and a per-process counter),
+/// flushed to disk and then renamed over `path`,
+/// so readers observe either the old or the new contents even if the process crashes mid-write.
+/// On Unix, the parent directory is also synced after the rename,
+/// so that the rename itself survives a power loss.
+///
+/// Concurrent writes to the same `path` use distinct temporary files and never interleave,
+/// but `path` ends up with the contents of whichever write *renames* last,
+/// which is not necessarily the one that started last.
+/// Callers that need the newest data to win must not write the same `path` concurrently.
+///
+/// # Errors
+/// Errors from the file system.
+/// The temporary file is removed if writing fails.
+pub fn write_atomic(path: &std::path::Path, bytes: &[u8]) -> io::Result<()> {
+    static COUNTER: AtomicU64 = AtomicU64::new(0);
+
+    let mut temp_path = path.as_os_str().to_owned();
+    temp_path.push(alloc::format!(
+        ".{}.{}.tmp",
+        std::process::id(),
+        COUNTER.fetch_add(1, Ordering::Relaxed)
+    ));
+    let temp_path = PathBuf::from(temp_path);
+
+    let result = (|| {
+        let mut file = std::fs::OpenOptions::new().write(true).create_new(true).open(&temp_path)?;
+        file.write_all(bytes)?;
+        file.sync_all()?;
+        drop(file);
+        std::fs::rename(&temp_path, path)
+    })();
+    if result.is_err() {
+        _ = std::fs::remove_file(&temp_path);
+    }
+    result?;
+
+    #[cfg(unix)]
+    {
+        let parent = path.parent().filter(|parent| !parent.as_os_str().is_empty());
+        std::fs::File::open(parent.unwrap_or(std::path::Path::new(".")))?.sync_all()?;
+    }
+    Ok(())
+}
+
+/// Stores each name as an entry in the browser's `localStorage`.
+///
+/// Since `localStorage` only stores strings, the stored bytes must be valid UTF-8,
+/// which is the case for text formats such as JSON.
+#[cfg(all(feature = "web", target_arch = "wasm32"))]
+#[derive(Debug, Clone, Default)]
+pub struct LocalStorage {
+    /// Prepended to each name to form the `localStorage` key,
+    /// which avoids collisions with other data on the same origin.
+    pub prefix: String,
+}
+
+#[cfg(all(feature = "web", target_arch = "wasm32"))]
+impl LocalStorage {
+    /// Creates a storage backend with keys prefixed by `prefix`.
+    #[must_use]
+    pub fn new(prefix: impl Into<String>) -> Self { Self { prefix: prefix.into() } }
+
+    fn storage() -> io::Result<web_sys::Storage> {
+        web_sys::window().and_then(|window| window.local_storage().ok().flatten()).ok_or_else(
+            || io::Error::new(io::ErrorKind::Unsupported, "localStorage is unavailable"),
+        )
+    }
+
+    fn key(&self, name: &str) -> String { alloc::format!("{}{name}", self.prefix) }
+}
+
+#[cfg(all(feature = "web", target_arch = "wasm32"))]
+fn js_error(err: web_sys::wasm_bindgen::JsValue) -> io::Error {
+    io::Error::other(alloc::format!("{err:?}"))
+}
+
+#[cfg(all(feature = "web", target_arch = "wasm32"))]
+impl ConfigStorage for LocalStorage {
+    fn read_bytes(&self, name: &str) -> io::Result<Option<Vec<u8>>> {
+        let value = Self::storage()?.get_item(&self.key(name)).map_err(js_error)?;
+        Ok(value.map(String::into_bytes))
+    }
+
+    fn write_bytes(&self, name: &str, bytes: &[u8]) -> io::Result<()> {
+        let value = core::str::from_utf8(bytes)
+            .map_err(|err| io::Error::new(io::ErrorKind::InvalidData, err))?;
+        Self::storage()?.set_item(&self.key(name), value).map_err(js_error)
+    }
+
+    fn rename(&self, from: &str, to: &str) -> io::Result<()> {
+        let storage = Self::storage()?;
+        let from = self.key(from);
+        if let Some(value) = storage.get_item(&from).map_err(js_error)? {
+            storage.set_item(&self.key(to), &value).map_err(js_error)?;
+            storage.remove_item(&from).map_err(js_error)?;
+        }
+        Ok(())
+    }
+}
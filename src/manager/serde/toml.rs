@@ -0,0 +1,252 @@
+//! TOML support through [`toml_edit`].
+//!
+//! Unlike the [`json`](super::json) module which always writes a new document,
+//! [`Toml::update_document`] writes the config data into an existing [`DocumentMut`],
+//! preserving the comments and formatting of keys that the user has edited by hand.
+//! Keys missing from the document are appended
+//! with the [`Description`] of the field as a comment.
+//!
+//! Enums are stored as a table containing the `discrim` key
+//! and a nested table for the fields of each variant.
+//! The tagged representations of [`json::EnumRepr`](super::json::EnumRepr)
+//! are not supported for TOML.
+//!
+//! ```
+//! # use bevy_app::App;
+//! # use bevy_mod_config::{AppExt, Config, manager};
+//! use bevy_mod_config::manager::serde::Toml;
+//!
+//! #[derive(Config)]
+//! struct Settings {
+//!     /// Maximum frames per second.
+//!     #[config(default = 60)]
+//!     fps: u32,
+//! }
+//!
+//! let mut app = App::new();
+//! app.init_config::<Toml, Settings>("video");
+//!
+//! let toml = app.world().resource::<manager::Instance<Toml>>().instance.clone();
+//! let mut document =
+//!     toml.from_str(app.world_mut(), "[video]\nfps = 30 # user comment\n").unwrap();
+//! toml.update_document(app.world_mut(), &mut document).unwrap();
+//! assert_eq!(document.to_string(), "[video]\nfps = 30 # user comment\n");
+//! ```
+
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::any::TypeId;
+use core::fmt::Write as _;
+
+use bevy_ecs::bundle::Bundle;
+use bevy_ecs::world::{EntityRef, EntityWorldMut, World};
+use hashbrown::HashMap;
+use serde::de::IntoDeserializer;
+use serde::{Deserialize, Serialize};
+use toml_edit::{DocumentMut, Item, Table, TableLike, Value, de, ser};
+
+use super::{KeyCase, ScannedKey, SerdeScalar};
+use crate::manager::{self, Manager};
+use crate::{Deprecated, Description, WorldExt};
+
+/// A [`Manager`] that reads and writes config data as TOML documents.
+///
+/// Each path segment of a config field corresponds to a nested table,
+/// e.g. the field `video.fps` is stored as the key `fps` in the table `[video]`.
+#[derive(Clone, Default)]
+pub struct Toml {
+    types:    HashMap<TypeId, TypedVtable>,
+    key_case: KeyCase,
+}
+
+/// The type-specific conversion vtable for [`Toml`].
+#[derive(Clone, Copy)]
+struct TypedVtable {
+    scan_keys:  fn(&World, &mut Vec<ScannedKey>),
+    to_value:   fn(EntityRef) -> Result<Value, ser::Error>,
+    from_value: fn(EntityWorldMut, Value) -> Result<(), de::Error>,
+}
+
+impl Manager for Toml {}
+
+impl<T: SerdeScalar> manager::Supports<T> for Toml {
+    fn new_entity_for_type(&mut self) -> impl Bundle {
+        self.types.entry(TypeId::of::<T>()).or_insert_with(|| TypedVtable {
+            scan_keys:  super::scan_keys::<T>,
+            to_value:   |entity| {
+                let value = super::persisted_value::<T>(entity).expect("type checked in scan_keys");
+                value.as_serialize().serialize(ser::ValueSerializer::new())
+            },
+            from_value: |mut entity, value| {
+                let value = T::Deserialize::deserialize(value.into_deserializer())?;
+                super::set_deserialized::<T>(&mut entity, value, super::OutOfRange::Clamp)
+                    .map_err(<de::Error as serde::de::Error>::custom)
+            },
+        });
+    }
+}
+
+impl Toml {
+    /// Sets the naming policy applied to each path segment of persistence keys.
+    #[must_use]
+    pub fn with_key_case(mut self, key_case: KeyCase) -> Self {
+        self.key_case = key_case;
+        self
+    }
+
+    fn keys_with_types(&self, world: &mut World) -> Vec<(ScannedKey, TypedVtable)> {
+        let mut keys_with_types = Vec::new();
+        let mut keys_buf = Vec::new();
+        for &typed in self.types.values() {
+            (typed.scan_keys)(world, &mut keys_buf);
+            for (path, entity) in keys_buf.drain(..) {
+                let path = self.key_case.persisted_path(world, path, entity);
+                keys_with_types.push(((path, entity), typed));
+            }
+        }
+        keys_with_types.sort_by(|((path1, _), _), ((path2, _), _)| path1.cmp(path2));
+        keys_with_types
+    }
+
+    /// Serializes all config data in the world to a new TOML document.
+    ///
+    /// # Errors
+    /// Errors from the serializer.
+    pub fn to_document(&self, world: &mut World) -> Result<DocumentMut, ser::Error> {
+        let mut document = DocumentMut::new();
+        self.update_document(world, &mut document)?;
+        Ok(document)
+    }
+
+    /// Writes all config data in the world into an existing TOML document.
+    ///
+    /// Existing values are replaced in place, retaining their comments and formatting.
+    /// New keys are appended to their tables,
+    /// with the [`Description`] of the field written as a comment above the key.
+    /// Keys in the document that do not correspond to any config field are left untouched,
+    /// while the keys of [`Deprecated`] fields are removed.
+    ///
+    /// # Errors
+    /// Errors from the serializer,
+    /// or if the document contains a non-table value where a table is expected.
+    pub fn update_document(
+        &self,
+        world: &mut World,
+        document: &mut DocumentMut,
+    ) -> Result<(), ser::Error> {
+        for ((path, entity), typed) in self.keys_with_types(world) {
+            let entity = world.entity(entity);
+            if entity.contains::<Deprecated>() {
+                remove_key(document, &path);
+                continue;
+            }
+            let mut value = (typed.to_value)(entity)?;
+            let description =
+                entity.get::<Description>().map(|&Description(description)| description);
+
+            let (key, parents) = path.split_last().expect("node path must be nonempty");
+            let mut table: &mut dyn TableLike = document.as_table_mut();
+            for segment in parents {
+                table = table
+                    .entry(segment)
+                    .or_insert_with(|| {
+                        let mut table = Table::new();
+                        table.set_implicit(true);
+                        Item::Table(table)
+                    })
+                    .as_table_like_mut()
+                    .ok_or_else(|| {
+                        <ser::Error as serde::ser::Error>::custom(format_args!(
+                            "expected {segment:?} to be a table in {}",
+                            path.join(".")
+                        ))
+                    })?;
+            }
+
+            if let Some(Item::Value(existing)) = table.get_mut(key) {
+                *value.decor_mut() = existing.decor().clone();
+                *existing = value;
+            } else {
+                table.insert(key, Item::Value(value));
+                if let Some(description) = description
+                    && let Some(mut key) = table.key_mut(key)
+                {
+                    let comment = description.lines().fold(String::new(), |mut comment, line| {
+                        _ = writeln!(comment, "# {line}");
+                        comment
+                    });
+                    key.leaf_decor_mut().set_prefix(comment);
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Deserializes config data from a TOML document
+    /// and writes them to the config entities in the world.
+    ///
+    /// Fields missing from the document are left unchanged.
+    ///
+    /// # Errors
+    /// Errors from the deserializer.
+    pub fn from_document(
+        &self,
+        world: &mut World,
+        document: &DocumentMut,
+    ) -> Result<(), de::Error> {
+        world.batch_changes(|world| {
+            let mut loaded = Vec::new();
+            'keys: for ((path, entity), typed) in self.keys_with_types(world) {
+                let mut item = document.as_item();
+                for segment in &path {
+                    match item.get(segment) {
+                        Some(child) => item = child,
+                        None => continue 'keys,
+                    }
+                }
+                let Ok(value) = item.clone().into_value() else {
+                    return Err(<de::Error as serde::de::Error>::custom(format_args!(
+                        "expected a value at {}",
+                        path.join(".")
+                    )));
+                };
+                (typed.from_value)(world.entity_mut(entity), value)?;
+                loaded.push(entity);
+            }
+            if let Some((path, err)) = super::migrate_deprecated(world, &loaded).into_iter().next()
+            {
+                return Err(<de::Error as serde::de::Error>::custom(format_args!(
+                    "cannot migrate deprecated field {}: {err}",
+                    path.join(".")
+                )));
+            }
+            Ok(())
+        })
+    }
+
+    /// Parses a TOML string and deserializes config data from it.
+    ///
+    /// The parsed document is returned so that it can be passed to
+    /// [`update_document`](Self::update_document) when saving,
+    /// which preserves the comments and formatting of the original string.
+    ///
+    /// # Errors
+    /// Errors from the parser or the deserializer.
+    pub fn from_str(&self, world: &mut World, input: &str) -> Result<DocumentMut, de::Error> {
+        let document: DocumentMut =
+            input.parse().map_err(<de::Error as serde::de::Error>::custom)?;
+        self.from_document(world, &document)?;
+        Ok(document)
+    }
+}
+
+/// Removes the key at `path` from the document if it exists.
+fn remove_key(document: &mut DocumentMut, path: &[String]) {
+    let (key, parents) = path.split_last().expect("node path must be nonempty");
+    let mut table: &mut dyn TableLike = document.as_table_mut();
+    for segment in parents {
+        let Some(child) = table.get_mut(segment).and_then(Item::as_table_like_mut) else { return };
+        table = child;
+    }
+    table.remove(key);
+}
@@ -0,0 +1,176 @@
+//! Smoothed transitions for numeric fields.
+//!
+//! Fields opt in by setting [`NumericMetadata::smoothing`].
+//! When the raw value of such a field changes,
+//! [`ReadSmoothed`] yields a value that interpolates from the previous value
+//! to the new value over the configured duration,
+//! which avoids visible pops for settings like camera FOV or audio volume.
+//!
+//! The interpolation is advanced by the [`update`] system,
+//! which must be added for each smoothed type:
+//!
+//! ```
+//! # use core::time::Duration;
+//! # use bevy_app::App;
+//! # use bevy_ecs::entity::Entity;
+//! # use bevy_ecs::schedule::IntoScheduleConfigs;
+//! # use bevy_ecs::system::Local;
+//! # use bevy_mod_config::{AppExt, Config};
+//! use bevy_mod_config::manager::smooth::{self, ReadSmoothed, Smoothing};
+//!
+//! #[derive(Config)]
+//! struct Camera {
+//!     #[config(default = 90.0, smoothing = Some(Duration::from_millis(300)))]
+//!     fov: f32,
+//! }
+//!
+//! fn apply_fov(fov: ReadSmoothed<f32>, mut field: Local<Option<Entity>>) {
+//!     let field = *field.get_or_insert_with(|| fov.entity(&["camera", "fov"]).unwrap());
+//!     let _fov = fov.get(field);
+//! }
+//!
+//! let mut app = App::new();
+//! app.init_config::<Smoothing, Camera>("camera");
+//! app.add_systems(bevy_app::Update, (smooth::update::<f32>, apply_fov).chain());
+//! ```
+
+use core::time::Duration;
+
+use bevy_ecs::bundle::Bundle;
+use bevy_ecs::change_detection::DetectChangesMut;
+use bevy_ecs::component::Component;
+use bevy_ecs::entity::Entity;
+use bevy_ecs::system::{Query, Res, SystemParam};
+use bevy_time::Time;
+
+use crate::impls::NumericMetadata;
+use crate::manager::{self, Manager};
+use crate::{ConfigField, ConfigNode, ScalarData, ScalarMetadata};
+
+/// A [`Manager`] that maintains smoothed values for opted-in numeric fields.
+///
+/// This manager supports all scalar types,
+/// but only fields whose type implements [`Lerp`]
+/// and whose [`NumericMetadata::smoothing`] is set are smoothed.
+///
+/// See the [module documentation](self) for usage.
+#[derive(Default)]
+pub struct Smoothing;
+
+impl Manager for Smoothing {}
+
+impl<T: Send + Sync + 'static> manager::Supports<T> for Smoothing {
+    fn new_entity_for_type(&mut self) -> impl Bundle { Smoothed::<T> { state: None } }
+}
+
+/// Numeric types that can be linearly interpolated.
+pub trait Lerp:
+    ConfigField<Metadata = NumericMetadata<Self>> + PartialEq + Copy + Send + Sync
+{
+    /// Interpolates between `from` and `to`, where `t` is in the range `0.0..=1.0`.
+    #[must_use]
+    fn lerp(from: Self, to: Self, t: f32) -> Self;
+}
+
+impl Lerp for f32 {
+    fn lerp(from: Self, to: Self, t: f32) -> Self { from + (to - from) * t }
+}
+
+impl Lerp for f64 {
+    fn lerp(from: Self, to: Self, t: f32) -> Self { from + (to - from) * f64::from(t) }
+}
+
+/// Stores the smoothing state of a scalar field.
+///
+/// Inserted on every field managed by [`Smoothing`].
+/// The state is populated by [`update`] for fields with [`NumericMetadata::smoothing`] set.
+#[derive(Component)]
+pub struct Smoothed<T> {
+    state: Option<SmoothState<T>>,
+}
+
+struct SmoothState<T> {
+    value:   T,
+    from:    T,
+    target:  T,
+    elapsed: Duration,
+}
+
+impl<T: Copy> Smoothed<T> {
+    /// Returns the current smoothed value,
+    /// or `None` if the field is not smoothed or [`update`] has not run yet.
+    #[must_use]
+    pub fn value(&self) -> Option<T> { self.state.as_ref().map(|state| state.value) }
+}
+
+/// Advances the smoothed values of all opted-in fields of type `T`.
+#[allow(clippy::needless_pass_by_value, reason = "system parameters are passed by value")]
+pub fn update<T: Lerp>(
+    time: Res<Time>,
+    mut query: Query<(&ScalarData<T>, &ScalarMetadata<T>, &mut Smoothed<T>)>,
+) {
+    for (&ScalarData(raw), metadata, mut smoothed) in &mut query {
+        let Some(duration) = metadata.0.smoothing else {
+            if smoothed.state.is_some() {
+                smoothed.state = None;
+            }
+            continue;
+        };
+
+        let state = smoothed.bypass_change_detection().state.get_or_insert(SmoothState {
+            value:   raw,
+            from:    raw,
+            target:  raw,
+            elapsed: Duration::ZERO,
+        });
+        if raw != state.target {
+            state.from = state.value;
+            state.target = raw;
+            state.elapsed = Duration::ZERO;
+        }
+        if state.value == state.target {
+            continue;
+        }
+
+        state.elapsed += time.delta();
+        state.value = if state.elapsed >= duration {
+            state.target
+        } else {
+            T::lerp(state.from, state.target, state.elapsed.as_secs_f32() / duration.as_secs_f32())
+        };
+        smoothed.set_changed();
+    }
+}
+
+/// Reads the smoothed values of numeric fields of type `T`.
+#[derive(SystemParam)]
+pub struct ReadSmoothed<'w, 's, T: Lerp> {
+    query: Query<'w, 's, (&'static ScalarData<T>, Option<&'static Smoothed<T>>)>,
+    nodes: Query<'w, 's, (Entity, &'static ConfigNode)>,
+}
+
+impl<T: Lerp> ReadSmoothed<'_, '_, T> {
+    /// Returns the smoothed value of the field `entity`,
+    /// or the raw value if the field is not smoothed.
+    ///
+    /// Returns `None` if `entity` is not a field of type `T`.
+    #[must_use]
+    pub fn get(&self, entity: Entity) -> Option<T> {
+        let (&ScalarData(raw), smoothed) = self.query.get(entity).ok()?;
+        Some(smoothed.and_then(Smoothed::value).unwrap_or(raw))
+    }
+
+    /// Finds the entity of the field at `path`.
+    ///
+    /// This scans all config nodes,
+    /// so the result should be stored instead of looked up every frame.
+    #[must_use]
+    pub fn entity(&self, path: &[&str]) -> Option<Entity> {
+        self.nodes
+            .iter()
+            .find(|(entity, node)| {
+                node.path.iter().eq(path.iter().copied()) && self.query.contains(*entity)
+            })
+            .map(|(entity, _)| entity)
+    }
+}
@@ -0,0 +1,116 @@
+//! Logging changes to config fields with [`tracing`].
+//!
+//! The [`Tracing`] manager emits a [`DEBUG`](tracing::Level::DEBUG) event
+//! with the path, old value and new value of every scalar field
+//! whose generation changed since the previous frame,
+//! which helps diagnosing settings-related bugs in the field.
+//!
+//! The events are emitted under the [`TARGET`] target,
+//! so they can be enabled independently of other debug logs,
+//! e.g. with `RUST_LOG=bevy_mod_config::changes=debug`.
+//!
+//! ```
+//! # use bevy_app::App;
+//! # use bevy_mod_config::{AppExt, Config};
+//! use bevy_mod_config::manager::Tracing;
+//!
+//! #[derive(Config)]
+//! struct Camera {
+//!     #[config(default = 90.0)]
+//!     fov: f32,
+//! }
+//!
+//! let mut app = App::new();
+//! app.init_config::<Tracing, Camera>("camera");
+//! ```
+
+use alloc::format;
+use alloc::string::String;
+use core::fmt;
+
+use bevy_app::{App, Last};
+use bevy_ecs::bundle::Bundle;
+use bevy_ecs::component::Component;
+use bevy_ecs::entity::Entity;
+use bevy_ecs::system::{Local, Query};
+use bevy_ecs::world::EntityRef;
+use hashbrown::HashMap;
+
+use crate::manager::{self, Manager};
+use crate::{ConfigNode, FieldGeneration, ScalarData};
+
+/// The target of the events emitted by [`trace_changes`].
+pub const TARGET: &str = "bevy_mod_config::changes";
+
+/// A [`Manager`] that logs every change to scalar config fields.
+///
+/// See the [module documentation](self) for usage.
+#[derive(Default)]
+pub struct Tracing;
+
+impl Manager for Tracing {
+    fn build(app: &mut App) { app.add_systems(Last, trace_changes); }
+}
+
+impl<T> manager::Supports<T> for Tracing
+where
+    T: fmt::Debug + Send + Sync + 'static,
+{
+    fn new_entity_for_type(&mut self) -> impl Bundle {
+        TraceVtable { format_fn: format_typed::<T> }
+    }
+}
+
+/// A type erasure vtable attached to each scalar field to format its value.
+///
+/// Inserted on every field managed by [`Tracing`].
+#[derive(Component, Clone, Copy)]
+pub struct TraceVtable {
+    format_fn: fn(EntityRef) -> String,
+}
+
+fn format_typed<T: fmt::Debug + Send + Sync + 'static>(entity: EntityRef) -> String {
+    let data = entity
+        .get::<ScalarData<T>>()
+        .expect("TraceVtable must be inserted with the corresponding ScalarData type");
+    format!("{:?}", data.0)
+}
+
+/// Emits an event for each scalar field whose generation changed since the last run.
+///
+/// Added to [`Last`] by the [`Tracing`] manager.
+/// Fields seen for the first time are not logged.
+///
+/// Values are only formatted while events under [`TARGET`] are enabled at the debug level.
+/// When the events are enabled again after being disabled,
+/// all fields are treated as seen for the first time.
+pub fn trace_changes(
+    query: Query<(EntityRef, &ConfigNode, &TraceVtable)>,
+    mut last_values: Local<HashMap<Entity, (FieldGeneration, String)>>,
+) {
+    if !tracing::enabled!(target: TARGET, tracing::Level::DEBUG) {
+        last_values.clear();
+        return;
+    }
+
+    last_values.retain(|&entity, _| query.contains(entity));
+
+    for (entity, node, vtable) in &query {
+        let last = last_values.get(&entity.id());
+        if last.is_some_and(|&(generation, _)| generation == node.generation) {
+            continue;
+        }
+
+        let value = (vtable.format_fn)(entity);
+        if let Some((_, old)) = last {
+            tracing::debug!(
+                target: TARGET,
+                path = %node.path.join("."),
+                old = %old,
+                new = %value,
+                "config field changed",
+            );
+        }
+        last_values.insert(entity.id(), (node.generation, value));
+    }
+}
@@ -0,0 +1,127 @@
+//! Validation of individual config fields.
+//!
+//! The [`Validation`] manager runs the validators registered for each field path
+//! when the field is spawned and whenever it changes.
+//! Like the struct invariants declared with `#[config(validate_struct)]`
+//! (see the [`validate`](crate::validate) module),
+//! the latest error is stored in the [`ValidationError`](crate::validate::ValidationError)
+//! component of the field entity
+//! and reported with a [`ValidationFailed`](crate::validate::ValidationFailed) message,
+//! so that the [`Egui`](crate::manager::Egui) editor shows a warning next to the field.
+//!
+//! As with struct validation, invalid values are not rejected or reverted.
+//! Use field metadata such as `min` and `max` to constrain values instead.
+//!
+//! ```
+//! # use bevy_app::App;
+//! # use bevy_mod_config::{AppExt, Config, WorldExt};
+//! use bevy_mod_config::manager::Validation;
+//! use bevy_mod_config::validate::ValidationFailed;
+//!
+//! #[derive(Config)]
+//! struct Profile {
+//!     name: String,
+//! }
+//!
+//! let mut app = App::new();
+//! app.init_config_with::<Validation, Profile>("profile", || {
+//!     Validation::new().with_validator("profile.name", |name: &String| {
+//!         if name.is_empty() { Err("name must not be empty".into()) } else { Ok(()) }
+//!     })
+//! });
+//!
+//! app.world_mut().update_config::<Profile>(|profile| profile.name.set(String::new()));
+//! app.update();
+//!
+//! let messages = app.world().resource::<bevy_ecs::message::Messages<ValidationFailed>>();
+//! assert_eq!(
+//!     messages.iter_current_update_messages().next().unwrap().error,
+//!     "name must not be empty"
+//! );
+//! ```
+
+use alloc::boxed::Box;
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+
+use bevy_ecs::bundle::Bundle;
+use bevy_ecs::entity::Entity;
+use bevy_ecs::world::{EntityRef, World};
+use hashbrown::HashMap;
+
+use crate::manager::{self, Manager};
+use crate::{ConfigNode, ScalarData, validate};
+
+type ValidatorFn = Box<dyn Fn(EntityRef) -> Result<(), String> + Send + Sync>;
+
+/// A [`Manager`] that validates scalar fields after each change.
+///
+/// See the [module documentation](self) for usage.
+#[derive(Default)]
+pub struct Validation {
+    validators: HashMap<Vec<String>, Vec<ValidatorFn>>,
+}
+
+impl Validation {
+    /// Creates a manager without any validators.
+    #[must_use]
+    pub fn new() -> Self { Self::default() }
+
+    /// Registers a validator for the field at the dot-separated `path`,
+    /// e.g. `"video.max_fps"`.
+    ///
+    /// `T` must be the scalar type of the field.
+    /// A validator registered with a different type is skipped,
+    /// logging a warning with the `tracing` feature.
+    /// Multiple validators of the same field are run in registration order,
+    /// and the first error is reported.
+    #[must_use]
+    pub fn with_validator<T: Send + Sync + 'static>(
+        mut self,
+        path: &str,
+        validate: impl Fn(&T) -> Result<(), String> + Send + Sync + 'static,
+    ) -> Self {
+        let path: Vec<String> = path.split('.').map(ToString::to_string).collect();
+        self.validators.entry(path).or_default().push(Box::new(move |entity| {
+            let Some(data) = entity.get::<ScalarData<T>>() else {
+                #[cfg(feature = "tracing")]
+                tracing::warn!(
+                    field = ?entity.get::<ConfigNode>().map(|node| node.path.join(".")),
+                    ty = core::any::type_name::<T>(),
+                    "validator type does not match the scalar type of the config field",
+                );
+                return Ok(());
+            };
+            validate(&data.0)
+        }));
+        self
+    }
+
+    /// Runs the validators of the field `entity`
+    /// and updates its [`ValidationError`](validate::ValidationError) with the result.
+    ///
+    /// Fields are validated automatically when they are spawned and when they change,
+    /// but this can be called to validate a field again,
+    /// e.g. after the data that a validator depends on has changed.
+    pub fn validate(&self, world: &mut World, entity: Entity) {
+        let Some(node) = world.get::<ConfigNode>(entity) else { return };
+        let Some(validators) = self.validators.get(&node.path) else { return };
+        let entity_ref = world.entity(entity);
+        let result = validators.iter().try_for_each(|validator| validator(entity_ref));
+        validate::report(world, entity, result);
+    }
+}
+
+impl Manager for Validation {
+    fn on_field_changed(&mut self, world: &mut World, entity: Entity) {
+        self.validate(world, entity);
+    }
+
+    fn on_field_spawned(&mut self, world: &mut World, entity: Entity) {
+        self.validate(world, entity);
+    }
+}
+
+impl<T: Send + Sync + 'static> manager::Supports<T> for Validation {
+    fn new_entity_for_type(&mut self) -> impl Bundle {}
+}
@@ -0,0 +1,74 @@
+//! Access to config values from worlds other than the main world.
+//!
+//! The config trees and the [`Instance`](crate::manager::Instance) of the manager
+//! only exist in the main world.
+//! Sub-apps such as the render app can consume config values in two ways:
+//!
+//! - [`ReadConfig`] and [`ReadConfigChange`](crate::ReadConfigChange) are read-only system params,
+//!   so they can be wrapped in `Extract` in the extract schedule of the render app.
+//! - [`AppExt::mirror_config`](crate::AppExt::mirror_config) copies a value derived from the config
+//!   into a [`Mirrored`] resource of a sub-app whenever the config changes,
+//!   which works with any sub-app and allows the sub-app systems to use
+//!   [change detection](bevy_ecs::change_detection::DetectChanges) on `Res<Mirrored<T>>`.
+
+use core::ops::Deref;
+
+use bevy_ecs::resource::Resource;
+use bevy_ecs::system::SystemState;
+use bevy_ecs::world::World;
+
+use crate::{ConfigField, ReadConfig};
+
+/// A value derived from a config in another world, mirrored by [`ConfigMirror`].
+///
+/// The resource is only written when the config changes,
+/// so `Res<Mirrored<T>>::is_changed` reflects changes of the config.
+#[derive(Resource, Debug, Clone)]
+pub struct Mirrored<T: Send + Sync + 'static>(pub T);
+
+impl<T: Send + Sync + 'static> Deref for Mirrored<T> {
+    type Target = T;
+
+    fn deref(&self) -> &T { &self.0 }
+}
+
+/// Copies a value derived from the config `C` in a source world
+/// into a [`Mirrored<T>`] resource of a target world.
+///
+/// [`AppExt::mirror_config`](crate::AppExt::mirror_config) stores this in a sub-app
+/// and calls [`sync`](Self::sync) during extraction.
+/// Use this type directly to mirror into worlds not managed by a sub-app.
+#[derive(Resource)]
+pub struct ConfigMirror<C: ConfigField, T: Send + Sync + 'static> {
+    state:        SystemState<ReadConfig<'static, 'static, C>>,
+    last_changed: Option<C::Changed>,
+    map:          for<'a> fn(C::Reader<'a>) -> T,
+}
+
+impl<C: ConfigField, T: Send + Sync + 'static> ConfigMirror<C, T> {
+    /// Creates a mirror reading the config `C` from `source`.
+    ///
+    /// `C` must have been initialized in `source` with
+    /// [`init_config`](crate::AppExt::init_config).
+    /// [`sync`](Self::sync) must always be called with the same source world.
+    pub fn new(source: &mut World, map: for<'a> fn(C::Reader<'a>) -> T) -> Self {
+        Self { state: SystemState::new(source), last_changed: None, map }
+    }
+
+    /// Writes the mapped config value into `target`
+    /// if the config has changed since the last call.
+    ///
+    /// Returns whether the value was written.
+    pub fn sync(&mut self, source: &World, target: &mut World) -> bool {
+        let config =
+            self.state.get(source).expect("config must be initialized in the source world");
+        let changed = config.changed();
+        if self.last_changed.as_ref() == Some(&changed) {
+            return false;
+        }
+
+        target.insert_resource(Mirrored((self.map)(config.read())));
+        self.last_changed = Some(changed);
+        true
+    }
+}
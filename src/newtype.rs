@@ -0,0 +1,180 @@
+//! Config fields for newtypes wrapping a scalar type.
+//!
+//! See [`impl_config_newtype`](crate::impl_config_newtype) for usage.
+
+use core::ops::{Deref, DerefMut};
+
+use crate::ConfigField;
+
+/// Metadata for newtype fields declared with [`impl_config_newtype`](crate::impl_config_newtype).
+///
+/// Dereferences to the metadata of the inner type,
+/// so `#[config(...)]` attributes such as `default` or `min` apply to the inner value.
+pub struct NewtypeMetadata<T: ConfigField> {
+    /// The metadata of the inner scalar type.
+    pub inner: T::Metadata,
+    /// The unit displayed after the value in the UI, e.g. `"m"`.
+    ///
+    /// Overrides the unit passed to [`impl_config_newtype`](crate::impl_config_newtype).
+    pub unit:  Option<&'static str>,
+}
+
+impl<T: ConfigField> Default for NewtypeMetadata<T> {
+    fn default() -> Self { Self { inner: T::Metadata::default(), unit: None } }
+}
+
+impl<T: ConfigField<Metadata: Clone>> Clone for NewtypeMetadata<T> {
+    fn clone(&self) -> Self { Self { inner: self.inner.clone(), unit: self.unit } }
+}
+
+impl<T: ConfigField> Deref for NewtypeMetadata<T> {
+    type Target = T::Metadata;
+
+    fn deref(&self) -> &T::Metadata { &self.inner }
+}
+
+impl<T: ConfigField> DerefMut for NewtypeMetadata<T> {
+    fn deref_mut(&mut self) -> &mut T::Metadata { &mut self.inner }
+}
+
+/// Implements [`ConfigField`] for a tuple struct wrapping a scalar config field type,
+/// delegating to the inner type.
+///
+/// Newtypes are the idiomatic way to model domain values such as distances or currencies.
+/// This macro makes them usable as config fields
+/// with the same metadata, serialization and editor widget as the inner type:
+///
+/// - The metadata type is <code>[NewtypeMetadata]&lt;$inner&gt;</code>,
+///   which accepts the same `#[config(...)]` attributes as the inner type.
+/// - With the `serde` feature, `Serialize` and `Deserialize` are implemented
+///   transparently as the inner value,
+///   so the newtype must not implement them itself.
+/// - With the `egui` feature, the newtype is edited with the widget of the inner type,
+///   followed by the unit if specified with `unit = "..."`
+///   or with the [`unit`](NewtypeMetadata::unit) metadata.
+///
+/// Fields are read as a reference to the newtype.
+/// The newtype must implement [`Clone`],
+/// and its field must be accessible from where the macro is invoked.
+///
+/// ```
+/// # use bevy_app::App;
+/// # use bevy_mod_config::{AppExt, Config, ReadConfig};
+/// #[derive(Debug, Clone, Copy, PartialEq)]
+/// struct Meters(f32);
+///
+/// bevy_mod_config::impl_config_newtype!(Meters => f32, unit = "m");
+///
+/// #[derive(Config)]
+/// struct Camera {
+///     #[config(default = 10.0, min = 0.0, max = 100.0)]
+///     distance: Meters,
+/// }
+/// # let mut app = App::new();
+/// # app.init_config::<(), Camera>("camera");
+/// ```
+#[macro_export]
+macro_rules! impl_config_newtype {
+    ($ty:ident => $inner:ty $(, unit = $unit:expr)? $(,)?) => {
+        $crate::impl_scalar_config_field!(
+            $ty,
+            $crate::NewtypeMetadata<$inner>,
+            |metadata: &$crate::NewtypeMetadata<$inner>| {
+                $ty(<$inner as $crate::DefaultFromMetadata>::default_from_metadata(&metadata.inner))
+            },
+            'a => &'a $ty,
+            $crate::__import::identity::<&$ty>,
+            to_owned = $crate::__import::Clone::clone,
+            constrain = |value: &$ty, metadata: &$crate::NewtypeMetadata<$inner>| {
+                $crate::__import::Option::map(
+                    <$inner as $crate::DefaultFromMetadata>::constrain(&value.0, &metadata.inner),
+                    $ty,
+                )
+            },
+            describe = |metadata: &$crate::NewtypeMetadata<$inner>| {
+                <$inner as $crate::DefaultFromMetadata>::describe_constraints(&metadata.inner)
+            },
+        );
+        $crate::__impl_newtype_serde!($ty, $inner);
+        $crate::__impl_newtype_egui!(
+            $ty,
+            $inner,
+            $crate::impl_scalar_config_field!(
+                @or [$($crate::__import::Some($unit))?] [$crate::__import::None]
+            ),
+        );
+    };
+}
+
+#[cfg(feature = "serde")]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __impl_newtype_serde {
+    ($ty:ident, $inner:ty) => {
+        impl $crate::__import::serde::Serialize for $ty {
+            fn serialize<S: $crate::__import::serde::Serializer>(
+                &self,
+                serializer: S,
+            ) -> $crate::__import::Result<S::Ok, S::Error> {
+                <$inner as $crate::__import::serde::Serialize>::serialize(&self.0, serializer)
+            }
+        }
+
+        impl<'de> $crate::__import::serde::Deserialize<'de> for $ty {
+            fn deserialize<D: $crate::__import::serde::Deserializer<'de>>(
+                deserializer: D,
+            ) -> $crate::__import::Result<Self, D::Error> {
+                <$inner as $crate::__import::serde::Deserialize<'de>>::deserialize(deserializer)
+                    .map($ty)
+            }
+        }
+    };
+}
+
+#[cfg(not(feature = "serde"))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __impl_newtype_serde {
+    ($($tt:tt)*) => {};
+}
+
+#[cfg(feature = "egui")]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __impl_newtype_egui {
+    ($ty:ident, $inner:ty, $unit:expr $(,)?) => {
+        impl<S> $crate::manager::egui::Editable<S> for $ty
+        where
+            S: $crate::manager::egui::Style,
+            $inner: $crate::manager::egui::Editable<S>,
+        {
+            type TempData = <$inner as $crate::manager::egui::Editable<S>>::TempData;
+
+            fn show(
+                ui: &mut $crate::__import::egui::Ui,
+                value: &mut Self,
+                metadata: &Self::Metadata,
+                temp: &mut $crate::__import::Option<Self::TempData>,
+                id_salt: impl $crate::__import::Hash,
+                style: &S,
+            ) -> $crate::__import::egui::Response {
+                $crate::manager::egui::show_with_unit::<$inner, S>(
+                    ui,
+                    &mut value.0,
+                    &metadata.inner,
+                    temp,
+                    id_salt,
+                    style,
+                    metadata.unit.or($unit),
+                )
+            }
+        }
+    };
+}
+
+#[cfg(not(feature = "egui"))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __impl_newtype_egui {
+    ($($tt:tt)*) => {};
+}
@@ -0,0 +1,103 @@
+//! Resetting config fields to their default values.
+//!
+//! [`reset_to_default`] resets a whole subtree of config fields,
+//! e.g. a settings page or a single field.
+//!
+//! By default, enum variants keep the values of their fields while another variant is active,
+//! so switching back to a variant restores its previous values.
+//! With [`VariantSwitch::Reset`] in the [discriminant metadata](crate::EnumDiscriminantMetadata),
+//! [`reset_switched_variants`] instead resets the fields of a variant when it is deactivated,
+//! so that the variant shows its defaults when it is selected again.
+//! Use `#[config(reset_variants)]` on a `#[derive(Config)]` enum to enable this for all its fields.
+
+use alloc::vec;
+use alloc::vec::Vec;
+
+use bevy_ecs::component::Component;
+use bevy_ecs::entity::Entity;
+use bevy_ecs::query::With;
+use bevy_ecs::world::{EntityMut, EntityRef, World};
+
+use crate::{
+    ChildNodeList, ChildNodeOf, ConfigNode, DiscriminantField, ScalarDefault, VariantSwitch,
+};
+
+/// Resets all scalar fields in the subtree of the config node `node`
+/// to the default values described by their metadata,
+/// bumping their generations.
+///
+/// This includes enum discriminants, which are reset to their default variants.
+pub fn reset_to_default(world: &mut World, node: Entity) {
+    let mut stack = vec![node];
+    while let Some(entity) = stack.pop() {
+        let Ok(mut entity) = world.get_entity_mut(entity) else { continue };
+        if let Some(children) = entity.get::<ChildNodeList>() {
+            stack.extend(children.iter().copied());
+        }
+        if let Some(&default) = entity.get::<ScalarDefault>() {
+            let mut entity = EntityMut::from(&mut entity);
+            default.apply(&mut entity);
+            entity.get_mut::<ConfigNode>().expect("scalar fields are config nodes").bump();
+        }
+    }
+}
+
+/// Tracks the active variant of an enum to detect variant switches.
+///
+/// Attached to the discriminant field of each `#[derive(Config)]` enum.
+#[derive(Component, Default)]
+pub struct ActiveVariant {
+    /// The variant active in the previous run of [`reset_switched_variants`].
+    active: Option<&'static str>,
+}
+
+/// Resets the fields of the variants deactivated since the previous run
+/// for enums with [`VariantSwitch::Reset`].
+///
+/// Added to [`Last`](bevy_app::Last) by [`init_config`](crate::AppExt::init_config).
+///
+/// Only the deactivated variant is reset,
+/// so values loaded for the newly active variant together with the discriminant are kept.
+pub fn reset_switched_variants(world: &mut World) {
+    let mut query = world.query_filtered::<(EntityRef, &DiscriminantField), With<ActiveVariant>>();
+    let variants: Vec<_> = query
+        .iter(world)
+        .map(|(entity, discrim)| {
+            (entity.id(), discrim.variant_name(entity), discrim.on_switch(entity))
+        })
+        .collect();
+
+    for (discrim, variant, on_switch) in variants {
+        let previous = world
+            .get_mut::<ActiveVariant>(discrim)
+            .expect("queried with the component")
+            .active
+            .replace(variant);
+        if on_switch == VariantSwitch::Reset
+            && let Some(previous) = previous
+            && previous != variant
+        {
+            for node in variant_nodes(world, discrim, previous) {
+                reset_to_default(world, node);
+            }
+        }
+    }
+}
+
+/// Returns the top-level nodes of the fields of `variant`
+/// in the enum with the discriminant field `discrim`.
+fn variant_nodes(world: &World, discrim: Entity, variant: &str) -> Vec<Entity> {
+    let discrim = world.entity(discrim);
+    let Some(&ChildNodeOf(parent)) = discrim.get::<ChildNodeOf>() else { return Vec::new() };
+    let depth = discrim.get::<ConfigNode>().expect("discriminant is a config node").path.len() - 1;
+    let Some(children) = world.get::<ChildNodeList>(parent) else { return Vec::new() };
+    children
+        .iter()
+        .copied()
+        .filter(|&child| {
+            world
+                .get::<ConfigNode>(child)
+                .is_some_and(|node| node.path.get(depth).is_some_and(|key| key == variant))
+        })
+        .collect()
+}
@@ -0,0 +1,70 @@
+//! Immutable copies of config values that can be sent to other threads.
+//!
+//! See [`ConfigSnapshot`] for more information.
+
+use alloc::sync::Arc;
+use core::fmt;
+use core::marker::PhantomData;
+use core::ops::Deref;
+
+use crate::{ConfigField, ReadConfig};
+
+/// A value derived from the config `C`, captured at a specific generation.
+///
+/// Snapshots are created with [`ReadConfig::snapshot`].
+/// They are cheap to clone and are [`Send`] if `T` is [`Send`] and [`Sync`],
+/// so async tasks can read a consistent view of the config
+/// without accessing the world.
+///
+/// When the task result comes back to the main thread,
+/// use [`is_stale`](Self::is_stale) to check whether the config has changed
+/// since the snapshot was taken, e.g. to discard results computed from outdated values.
+pub struct ConfigSnapshot<C: ConfigField, T> {
+    value:      Arc<T>,
+    generation: C::Changed,
+    _ph:        PhantomData<fn() -> C>,
+}
+
+impl<C: ConfigField, T> ConfigSnapshot<C, T> {
+    /// Creates a snapshot of `value` captured at `generation`,
+    /// which should be the value of [`ReadConfig::changed`] when `value` was read.
+    pub fn new(value: T, generation: C::Changed) -> Self {
+        Self { value: Arc::new(value), generation, _ph: PhantomData }
+    }
+
+    /// Returns the captured value.
+    #[must_use]
+    pub fn get(&self) -> &T { &self.value }
+
+    /// Returns the generation of the config when the snapshot was taken.
+    ///
+    /// See [`ConfigField::Changed`] for details.
+    #[must_use]
+    pub fn generation(&self) -> &C::Changed { &self.generation }
+
+    /// Returns whether the config has changed since the snapshot was taken.
+    #[must_use]
+    pub fn is_stale(&self, config: &ReadConfig<C>) -> bool { config.changed() != self.generation }
+}
+
+impl<C: ConfigField, T> Clone for ConfigSnapshot<C, T> {
+    fn clone(&self) -> Self {
+        Self {
+            value:      Arc::clone(&self.value),
+            generation: self.generation.clone(),
+            _ph:        PhantomData,
+        }
+    }
+}
+
+impl<C: ConfigField, T> Deref for ConfigSnapshot<C, T> {
+    type Target = T;
+
+    fn deref(&self) -> &T { &self.value }
+}
+
+impl<C: ConfigField, T: fmt::Debug> fmt::Debug for ConfigSnapshot<C, T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ConfigSnapshot").field("value", &self.value).finish_non_exhaustive()
+    }
+}
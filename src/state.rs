@@ -0,0 +1,82 @@
+//! Relevance of config fields depending on [`States`].
+//!
+//! Fields and whole subtrees can be declared relevant only in certain states
+//! with <code>#[config(in_state(<i>states...</i>))]</code>, e.g. editor-only settings.
+//! While the app is in none of the listed states,
+//! [`update_state_relevance`] marks the node as [`OutOfState`], which has the following effects:
+//!
+//! - The [`Egui`](crate::manager::Egui) editor treats the node as irrelevant,
+//!   hiding it together with its descendants.
+//! - [`ReadConfigChange::consume_change`](crate::ReadConfigChange::consume_change)
+//!   does not report changes of a node marked as `OutOfState` or its descendants;
+//!   the changes are reported after the node becomes relevant again.
+//!
+//! If a field of a type declared with the type-level `in_state` attribute
+//! also has the field-level `in_state` attribute,
+//! the type-level states take precedence, since both apply to the same node.
+//!
+//! ```
+//! # use bevy_app::App;
+//! # use bevy_mod_config::{AppExt, Config};
+//! use bevy_state::app::{AppExtStates, StatesPlugin};
+//! use bevy_state::state::States;
+//!
+//! #[derive(States, Default, Debug, Clone, PartialEq, Eq, Hash)]
+//! enum AppState {
+//!     #[default]
+//!     Game,
+//!     Editor,
+//! }
+//!
+//! #[derive(Config)]
+//! struct Settings {
+//!     #[config(default = 1.0)]
+//!     volume:    f32,
+//!     #[config(in_state(AppState::Editor))]
+//!     show_grid: bool,
+//! }
+//!
+//! let mut app = App::new();
+//! app.add_plugins(StatesPlugin).init_state::<AppState>();
+//! app.init_config::<(), Settings>("settings");
+//! ```
+
+use alloc::vec::Vec;
+
+use bevy_ecs::entity::Entity;
+use bevy_ecs::world::World;
+use bevy_state::state::{State, States};
+
+use crate::{OutOfState, StateRelevance};
+
+/// Returns whether the current state of `S` is one of `states`.
+///
+/// Returns `false` if the state `S` has not been initialized.
+pub fn in_states<S: States>(world: &World, states: &[S]) -> bool {
+    world.get_resource::<State<S>>().is_some_and(|state| states.contains(state.get()))
+}
+
+/// Inserts or removes [`OutOfState`] on each node with [`StateRelevance`].
+///
+/// Added to [`RunFixedMainLoop`](bevy_app::RunFixedMainLoop)
+/// by [`AppExt::init_config`](crate::AppExt::init_config),
+/// which runs after state transitions are applied and before [`Update`](bevy_app::Update).
+pub fn update_state_relevance(world: &mut World) {
+    let mut query = world.query::<(Entity, &StateRelevance, Option<&OutOfState>)>();
+    let updates: Vec<_> = query
+        .iter(world)
+        .filter_map(|(entity, relevance, out_of_state)| {
+            let relevant = (relevance.is_relevant)(world);
+            (relevant == out_of_state.is_some()).then_some((entity, relevant))
+        })
+        .collect();
+
+    for (entity, relevant) in updates {
+        let mut entity = world.entity_mut(entity);
+        if relevant {
+            entity.remove::<OutOfState>();
+        } else {
+            entity.insert(OutOfState);
+        }
+    }
+}
@@ -0,0 +1,242 @@
+//! Temporary values of scalar fields that are reverted automatically.
+//!
+//! A temporary value overrides the current value of a field
+//! until a revert condition is met, e.g. a benchmark mode that forces low settings
+//! and restores the user's choices afterwards:
+//!
+//! - [`set`] reverts when the returned [`TemporaryGuard`] is dropped.
+//! - [`set_until`] reverts when a [`RevertWhen`] condition is met,
+//!   e.g. after a duration or when a state is exited.
+//!
+//! Reverts are applied by [`revert_expired`],
+//! which is added to [`Last`](bevy_app::Last) by [`AppExt::init_config`](crate::AppExt::init_config),
+//! or immediately by [`revert`].
+//! Reverting restores the value before the first temporary value of the field,
+//! discarding any other changes made in the meantime.
+//!
+//! While a field holds a temporary value,
+//! the [`Serde`](crate::manager::Serde) managers persist its original value instead
+//! (see [`persisted_value`]),
+//! so that saving during e.g. a benchmark does not store the forced settings.
+//!
+//! ```
+//! # use bevy_app::App;
+//! # use bevy_ecs::system::RunSystemOnce;
+//! # use bevy_mod_config::{AppExt, Config, ConfigNode, ReadConfig};
+//! use bevy_mod_config::temporary;
+//!
+//! #[derive(Config)]
+//! struct Graphics {
+//!     #[config(default = 4)]
+//!     shadow_quality: u32,
+//! }
+//!
+//! let mut app = App::new();
+//! app.init_config::<(), Graphics>("graphics");
+//! let field = app
+//!     .world_mut()
+//!     .query::<(bevy_ecs::entity::Entity, &ConfigNode)>()
+//!     .iter(app.world())
+//!     .find(|(_, node)| node.path == ["graphics", "shadow_quality"])
+//!     .unwrap()
+//!     .0;
+//!
+//! let guard = temporary::set(app.world_mut(), field, 0u32);
+//! // ... run the benchmark ...
+//! drop(guard);
+//! app.update();
+//!
+//! let quality = app
+//!     .world_mut()
+//!     .run_system_once(|graphics: ReadConfig<Graphics>| graphics.read().shadow_quality)
+//!     .unwrap();
+//! assert_eq!(quality, 4);
+//! ```
+
+use alloc::boxed::Box;
+use alloc::sync::Arc;
+use alloc::vec::Vec;
+use core::any::Any;
+use core::sync::atomic::{AtomicBool, Ordering};
+#[cfg(feature = "bevy_time")]
+use core::time::Duration;
+
+use bevy_ecs::component::Component;
+use bevy_ecs::entity::Entity;
+use bevy_ecs::world::{EntityRef, EntityWorldMut, World};
+
+use crate::{ConfigNode, ScalarData};
+
+type Value = Box<dyn Any + Send + Sync>;
+
+/// A condition under which a temporary value set by [`set_until`] is reverted.
+pub enum RevertWhen {
+    /// Revert when the [elapsed time](bevy_time::Time::elapsed) of the [`Time`](bevy_time::Time)
+    /// resource has advanced by this duration.
+    #[cfg(feature = "bevy_time")]
+    Elapsed(Duration),
+    /// Revert when the function returns `true`.
+    Condition(Box<dyn Fn(&World) -> bool + Send + Sync>),
+}
+
+impl RevertWhen {
+    /// Revert when the app is no longer in `state`.
+    #[cfg(feature = "bevy_state")]
+    #[must_use]
+    pub fn state_exited<S: bevy_state::state::States>(state: S) -> Self {
+        Self::Condition(Box::new(move |world| {
+            !crate::state::in_states(world, core::slice::from_ref(&state))
+        }))
+    }
+}
+
+enum Condition {
+    Guard(Arc<AtomicBool>),
+    #[cfg(feature = "bevy_time")]
+    Deadline(Duration),
+    Check(Box<dyn Fn(&World) -> bool + Send + Sync>),
+}
+
+impl Condition {
+    fn is_met(&self, world: &World) -> bool {
+        match self {
+            Condition::Guard(dropped) => dropped.load(Ordering::Acquire),
+            #[cfg(feature = "bevy_time")]
+            Condition::Deadline(deadline) => world
+                .get_resource::<bevy_time::Time>()
+                .is_some_and(|time| time.elapsed() >= *deadline),
+            Condition::Check(check) => check(world),
+        }
+    }
+}
+
+/// Attached to a scalar field entity while it holds a temporary value.
+#[derive(Component)]
+struct Temporary {
+    /// The value before the first temporary value.
+    original:   Value,
+    restore_fn: fn(&mut EntityWorldMut, Value),
+    condition:  Condition,
+}
+
+/// Reverts the temporary value set by [`set`] when dropped.
+///
+/// The revert is applied by the next run of [`revert_expired`].
+#[must_use = "the temporary value is reverted when the guard is dropped"]
+pub struct TemporaryGuard {
+    dropped: Arc<AtomicBool>,
+}
+
+impl Drop for TemporaryGuard {
+    fn drop(&mut self) { self.dropped.store(true, Ordering::Release); }
+}
+
+/// Sets the scalar field `entity` to `value` until the returned guard is dropped.
+///
+/// If the field already holds a temporary value,
+/// the revert condition of the previous temporary value no longer applies.
+///
+/// # Panics
+/// Panics if `entity` is not a scalar field of type `T`.
+pub fn set<T: Send + Sync + 'static>(
+    world: &mut World,
+    entity: Entity,
+    value: T,
+) -> TemporaryGuard {
+    let dropped = Arc::new(AtomicBool::new(false));
+    insert(world, entity, value, Condition::Guard(Arc::clone(&dropped)));
+    TemporaryGuard { dropped }
+}
+
+/// Sets the scalar field `entity` to `value` until `revert` is met.
+///
+/// If the field already holds a temporary value,
+/// the revert condition of the previous temporary value no longer applies.
+///
+/// # Panics
+/// Panics if `entity` is not a scalar field of type `T`,
+/// or if `revert` is [`RevertWhen::Elapsed`] and the [`Time`](bevy_time::Time) resource is missing.
+pub fn set_until<T: Send + Sync + 'static>(
+    world: &mut World,
+    entity: Entity,
+    value: T,
+    revert: RevertWhen,
+) {
+    let condition = match revert {
+        #[cfg(feature = "bevy_time")]
+        RevertWhen::Elapsed(duration) => {
+            Condition::Deadline(world.resource::<bevy_time::Time>().elapsed() + duration)
+        }
+        RevertWhen::Condition(check) => Condition::Check(check),
+    };
+    insert(world, entity, value, condition);
+}
+
+fn insert<T: Send + Sync + 'static>(
+    world: &mut World,
+    entity: Entity,
+    value: T,
+    condition: Condition,
+) {
+    let mut entity = world.entity_mut(entity);
+    let mut data =
+        entity.get_mut::<ScalarData<T>>().expect("temporary values must match the field type");
+    let current = core::mem::replace(&mut data.0, value);
+    entity.get_mut::<ConfigNode>().expect("scalar fields are config nodes").bump();
+
+    let original = match entity.take::<Temporary>() {
+        Some(previous) => previous.original,
+        None => Box::new(current),
+    };
+    entity.insert(Temporary { original, restore_fn: restore_typed::<T>, condition });
+}
+
+fn restore_typed<T: Send + Sync + 'static>(entity: &mut EntityWorldMut, original: Value) {
+    let original = *original.downcast::<T>().expect("original value must have the field type");
+    entity.get_mut::<ScalarData<T>>().expect("temporary values must match the field type").0 =
+        original;
+    entity.get_mut::<ConfigNode>().expect("scalar fields are config nodes").bump();
+}
+
+/// Returns whether the field `entity` currently holds a temporary value.
+#[must_use]
+pub fn is_temporary(world: &World, entity: Entity) -> bool {
+    world.get::<Temporary>(entity).is_some()
+}
+
+/// Returns the value of the scalar field `entity` to be persisted,
+/// i.e. the original value if the field holds a temporary value,
+/// or the current value otherwise.
+///
+/// Returns `None` if `entity` is not a scalar field of type `T`.
+#[must_use]
+pub fn persisted_value<T: Send + Sync + 'static>(entity: EntityRef<'_>) -> Option<&T> {
+    match entity.get::<Temporary>() {
+        Some(temporary) => temporary.original.downcast_ref(),
+        None => entity.get::<ScalarData<T>>().map(|data| &data.0),
+    }
+}
+
+/// Immediately restores the original value of the field `entity`
+/// if it holds a temporary value.
+pub fn revert(world: &mut World, entity: Entity) {
+    let Ok(mut entity) = world.get_entity_mut(entity) else { return };
+    if let Some(temporary) = entity.take::<Temporary>() {
+        (temporary.restore_fn)(&mut entity, temporary.original);
+    }
+}
+
+/// Reverts all temporary values whose revert condition is met.
+///
+/// Added to [`Last`](bevy_app::Last) by [`AppExt::init_config`](crate::AppExt::init_config).
+pub fn revert_expired(world: &mut World) {
+    let mut query = world.query::<(Entity, &Temporary)>();
+    let expired: Vec<_> = query
+        .iter(world)
+        .filter(|(_, temporary)| temporary.condition.is_met(world))
+        .map(|(entity, _)| entity)
+        .collect();
+    for entity in expired {
+        revert(world, entity);
+    }
+}
@@ -1,27 +1,227 @@
 use alloc::string::String;
+use alloc::sync::Arc;
 use alloc::vec::Vec;
-use core::ops;
+use core::sync::atomic::{AtomicBool, AtomicU64, AtomicUsize, Ordering};
+use core::{fmt, ops};
 
 use bevy_ecs::component::Component;
 use bevy_ecs::entity::Entity;
-use bevy_ecs::world::EntityRef;
+use bevy_ecs::event::EntityEvent;
+use bevy_ecs::query::{Added, Has, Without};
+use bevy_ecs::system::{Commands, Query, SystemParam};
+use bevy_ecs::world::{EntityRef, World};
+use hashbrown::HashMap;
 
-use crate::FieldGeneration;
+use crate::{FieldGeneration, GenerationOverflow};
 
 /// Marks an entity as a config field node.
 #[derive(Component)]
 pub struct ConfigNode {
     /// Context information passed to
     /// [`ConfigFieldFor::spawn_world`](super::ConfigFieldFor::spawn_world).
-    pub path:       Vec<String>,
+    pub path:           Vec<String>,
     /// The generation of a field, used for change detection.
-    pub generation: FieldGeneration,
+    pub generation:     FieldGeneration,
+    /// The revision of the config tree containing this node.
+    pub revision:       TreeRevision,
+    /// Whether a bump was deferred by an active [`ChangeBatch`](crate::ChangeBatch).
+    pub(crate) pending: bool,
+}
+
+impl ConfigNode {
+    /// Marks the node as changed,
+    /// incrementing both its [generation](Self::generation)
+    /// and the [revision](Self::revision) of its tree.
+    ///
+    /// While a [`ChangeBatch`](crate::ChangeBatch) is active,
+    /// the increments are deferred until the batch ends,
+    /// and multiple bumps of the same node within a batch are applied as one.
+    ///
+    /// Code that modifies the data of a config node should always call this method
+    /// instead of updating the generation directly,
+    /// so that [`CachedReadConfig`](crate::CachedReadConfig) observes the change.
+    pub fn bump(&mut self) {
+        if self.revision.shared.is_active() {
+            self.pending = true;
+            self.revision.shared.pending.store(true, Ordering::Release);
+            return;
+        }
+        self.generation = self.generation.next_with(self.revision.shared.overflow());
+        self.revision.bump();
+    }
+
+    /// Returns the generation of this node after its deferred bump is applied,
+    /// or the current generation if no bump is deferred.
+    pub(crate) fn settled_generation(&self) -> FieldGeneration {
+        if self.pending {
+            self.generation.next_with(self.revision.shared.overflow())
+        } else {
+            self.generation
+        }
+    }
+
+    /// Applies the bump deferred by a [`ChangeBatch`](crate::ChangeBatch), if any.
+    pub(crate) fn apply_pending(&mut self) {
+        if core::mem::take(&mut self.pending) {
+            self.bump();
+        }
+    }
+}
+
+/// A counter shared by all nodes in the same config tree,
+/// incremented whenever any node in the tree is [bumped](ConfigNode::bump).
+///
+/// This allows detecting changes in a whole tree with a single comparison.
+#[derive(Clone, Default)]
+pub struct TreeRevision {
+    revision: Arc<AtomicU64>,
+    /// The state shared by all trees in the same world.
+    shared:   Arc<SharedState>,
+}
+
+impl TreeRevision {
+    /// Creates the revision counter of a new tree in a world with the state `shared`.
+    pub(crate) fn new(shared: Arc<SharedState>) -> Self {
+        Self { revision: Arc::default(), shared }
+    }
+
+    /// Returns the current revision.
+    #[must_use]
+    pub fn get(&self) -> u64 { self.revision.load(Ordering::Acquire) }
+
+    fn bump(&self) { self.revision.fetch_add(1, Ordering::AcqRel); }
+
+    /// Returns whether both revisions belong to the same config tree.
+    #[must_use]
+    pub fn same_tree(&self, other: &TreeRevision) -> bool {
+        Arc::ptr_eq(&self.revision, &other.revision)
+    }
+}
+
+/// The state shared by all config trees of a world,
+/// owned by its [`ChangeBatch`](crate::ChangeBatch).
+#[derive(Debug, Default)]
+pub(crate) struct SharedState {
+    /// The number of active batches.
+    depth:           AtomicUsize,
+    /// Whether any node has a deferred bump.
+    pending:         AtomicBool,
+    /// Whether generations wrap around under [`GenerationOverflow::Wrap`].
+    wrap_generation: AtomicBool,
+}
+
+impl SharedState {
+    pub(crate) fn begin(&self) { self.depth.fetch_add(1, Ordering::AcqRel); }
+
+    /// Returns `false` if there is no active batch.
+    pub(crate) fn end(&self) -> bool {
+        self.depth
+            .fetch_update(Ordering::AcqRel, Ordering::Acquire, |depth| depth.checked_sub(1))
+            .is_ok()
+    }
+
+    pub(crate) fn is_active(&self) -> bool { self.depth.load(Ordering::Acquire) > 0 }
+
+    /// Returns whether any node had a deferred bump, resetting the flag.
+    pub(crate) fn take_pending(&self) -> bool { self.pending.swap(false, Ordering::AcqRel) }
+
+    pub(crate) fn overflow(&self) -> GenerationOverflow {
+        if self.wrap_generation.load(Ordering::Relaxed) {
+            GenerationOverflow::Wrap
+        } else {
+            GenerationOverflow::Panic
+        }
+    }
+
+    pub(crate) fn set_overflow(&self, overflow: GenerationOverflow) {
+        self.wrap_generation.store(overflow == GenerationOverflow::Wrap, Ordering::Relaxed);
+    }
+}
+
+/// Triggered on a scalar field entity when its value has changed,
+/// propagating to each of its ancestors up to the root node.
+///
+/// This allows reacting to changes with observers instead of polling every frame,
+/// either for a specific field or for a whole subtree:
+///
+/// ```
+/// # use bevy_app::App;
+/// # use bevy_ecs::entity::Entity;
+/// # use bevy_ecs::query::With;
+/// use bevy_ecs::observer::On;
+/// use bevy_ecs::system::Query;
+/// use bevy_mod_config::{AppExt, Config, ConfigChanged, ConfigNode, RootNode};
+///
+/// # #[derive(Config)]
+/// # struct Settings { volume: f32 }
+/// fn log_change(changed: On<ConfigChanged>, nodes: Query<&ConfigNode>) {
+///     let node = nodes.get(changed.field).unwrap();
+///     println!("{} changed", node.path.join("."));
+/// }
+///
+/// let mut app = App::new();
+/// app.init_config::<(), Settings>("settings");
+///
+/// let world = app.world_mut();
+/// let root = world.query_filtered::<Entity, With<RootNode>>().single(world).unwrap();
+/// world.entity_mut(root).observe(log_change);
+/// ```
+///
+/// Changes are collected once per frame in [`Last`](bevy_app::Last)
+/// by [`notify_field_changes`](crate::manager::notify_field_changes),
+/// so the event is only triggered once for a field changed multiple times in the same frame.
+#[derive(EntityEvent, Debug, Clone, Copy)]
+#[entity_event(propagate = &'static ChildNodeOf, auto_propagate)]
+pub struct ConfigChanged {
+    /// The node currently observing the event,
+    /// i.e. the changed field or one of its ancestors during propagation.
+    #[event_target]
+    pub node:  Entity,
+    /// The scalar field that changed.
+    pub field: Entity,
+}
+
+/// Triggered on the root node after a [transaction](crate::WorldExt::transaction)
+/// has been applied.
+///
+/// Unlike [`ConfigChanged`], which is triggered for each changed field,
+/// this event is triggered once for all fields written in the transaction.
+#[derive(EntityEvent, Debug, Clone)]
+pub struct ConfigCommitted {
+    /// The root node of the config written in the transaction.
+    #[event_target]
+    pub root:   Entity,
+    /// The distinct scalar fields written in the transaction.
+    pub fields: Vec<Entity>,
 }
 
 /// Marks an entity as a root config node.
 #[derive(Component)]
 pub struct RootNode;
 
+/// Determines the position of a [root config node](RootNode) among the other roots,
+/// e.g. in the [`Egui`](crate::manager::Egui) editor.
+///
+/// Pinned roots are listed before unpinned roots.
+/// Roots are then sorted by [`position`](Self::position), then by key.
+/// Roots are positioned in registration order by default,
+/// i.e. the order in which [`init_config`](crate::AppExt::init_config) was called.
+///
+/// Use [`WorldExt::root_order`](crate::WorldExt::root_order) to reorder or pin a root.
+#[derive(Component, Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct RootOrder {
+    /// Whether the root is listed before all unpinned roots.
+    pub pinned:   bool,
+    /// The position of the root among the pinned or unpinned roots, in ascending order.
+    pub position: i64,
+}
+
+impl RootOrder {
+    /// Returns a key to sort roots by.
+    #[must_use]
+    pub fn sort_key(&self) -> (bool, i64) { (!self.pinned, self.position) }
+}
+
 /// Marks an entity as a child node of a config field.
 ///
 /// This is a relationship component.
@@ -42,10 +242,189 @@ impl ops::Deref for ChildNodeList {
     fn deref(&self) -> &Self::Target { &self.0 }
 }
 
-/// Marks an entity as a scalar config field.
+/// Marks an entity as a scalar config field,
+/// i.e. a leaf node storing its value in a [`ScalarData`](crate::ScalarData).
+///
+/// Nodes without this component are composite nodes such as structs and enums,
+/// whose data is stored in their [children](ChildNodeList).
 #[derive(Component)]
+#[require(crate::manager::NotifiedGeneration)]
 pub struct ScalarField;
 
+/// Marks a config node as a section marker.
+///
+/// [`#[derive(Config)]`](crate::Config) inserts this component
+/// for structs without any fields, e.g. `struct AdvancedSection;`.
+/// Such nodes hold no data, but managers may render them as a heading
+/// to visually group the sibling fields that follow them.
+#[derive(Component, Debug, Clone, Copy)]
+pub struct Section;
+
+/// A [`SystemParam`] to traverse the config nodes in the world without knowing their types.
+#[derive(SystemParam)]
+pub struct ConfigTree<'w, 's> {
+    nodes:    Query<'w, 's, (Entity, &'static ConfigNode, Has<ScalarField>)>,
+    children: Query<'w, 's, &'static ChildNodeList>,
+}
+
+impl ConfigTree<'_, '_> {
+    /// Iterates over all scalar config fields in the world.
+    pub fn iter_scalars(&self) -> impl Iterator<Item = (Entity, &ConfigNode)> {
+        self.nodes.iter().filter_map(|(entity, node, scalar)| scalar.then_some((entity, node)))
+    }
+
+    /// Iterates over the direct child nodes of `entity` in spawn order.
+    ///
+    /// The iterator is empty for scalar fields.
+    pub fn iter_children_of(&self, entity: Entity) -> impl Iterator<Item = (Entity, &ConfigNode)> {
+        self.children
+            .get(entity)
+            .into_iter()
+            .flat_map(|children| children.iter())
+            .filter_map(|&child| self.nodes.get(child).ok())
+            .map(|(child, node, _)| (child, node))
+    }
+
+    /// Returns whether `entity` is a [scalar field](ScalarField).
+    #[must_use]
+    pub fn is_scalar(&self, entity: Entity) -> bool {
+        self.nodes.get(entity).is_ok_and(|(_, _, scalar)| scalar)
+    }
+}
+
+/// A human-readable description of a config node.
+///
+/// [`#[derive(Config)]`](crate::Config) populates this component
+/// from the doc comments of each field.
+#[derive(Component, Debug, Clone, Copy)]
+pub struct Description(pub &'static str);
+
+/// Marks a config node as an advanced setting.
+///
+/// [`#[derive(Config)]`](crate::Config) inserts this component
+/// for fields annotated with `#[config(advanced)]`.
+/// Managers may hide advanced nodes and their descendants by default
+/// to reduce clutter for casual users.
+#[derive(Component, Debug, Clone, Copy)]
+pub struct Advanced;
+
+/// Excludes a config node from persistence.
+///
+/// [`#[derive(Config)]`](crate::Config) inserts this component
+/// for fields annotated with `#[config(serde(skip))]` and all their descendants.
+/// Such fields still appear in the tree and in editors,
+/// but the [`Serde`](crate::manager::Serde) manager neither saves nor loads them.
+#[derive(Component, Debug, Clone, Copy)]
+pub struct SkipSerde;
+
+/// Replaces the key of a config node in persisted data.
+///
+/// [`#[derive(Config)]`](crate::Config) inserts this component
+/// for fields annotated with `#[config(serde(rename = "key"))]`.
+/// The [`Serde`](crate::manager::Serde) manager uses the key verbatim
+/// in place of the field name, for this node and the paths of its descendants,
+/// while editors still display the field name.
+#[derive(Component, Debug, Clone, Copy)]
+pub struct SerdeRename(pub &'static str);
+
+/// The role required to edit a config node.
+///
+/// [`#[derive(Config)]`](crate::Config) inserts this component
+/// for fields annotated with `#[config(permission = ...)]`.
+/// Nodes without this component only require [`Player`](Permission::Player).
+///
+/// Permissions are ordered from the least to the most privileged role;
+/// a role may edit all nodes that require the same or a less privileged role.
+/// The permission of a node also applies to all of its descendants;
+/// use [`required_for`](Permission::required_for) to resolve the effective permission.
+#[derive(Component, Debug, Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Permission {
+    /// Editable by any player.
+    #[default]
+    Player,
+    /// Editable by administrators, e.g. the host of a multiplayer session.
+    Admin,
+    /// Only editable by the authoritative server.
+    ServerOnly,
+}
+
+impl Permission {
+    /// Whether a user with this role may edit a node requiring `required`.
+    #[must_use]
+    pub fn allows(self, required: Permission) -> bool { self >= required }
+
+    /// Resolves the permission required to edit `entity`,
+    /// i.e. the most privileged permission among the node and its ancestors.
+    pub fn required_for(world: &World, mut entity: Entity) -> Permission {
+        let mut permission = Permission::Player;
+        loop {
+            let node = world.entity(entity);
+            if let Some(&node_permission) = node.get::<Permission>() {
+                permission = permission.max(node_permission);
+            }
+            match node.get::<ChildNodeOf>() {
+                Some(&ChildNodeOf(parent)) => entity = parent,
+                None => return permission,
+            }
+        }
+    }
+}
+
+/// Marks a config node as deprecated.
+///
+/// [`#[derive(Config)]`](crate::Config) inserts this component
+/// for fields annotated with `#[config(deprecated(...))]`.
+/// Persistence managers still load deprecated fields from existing data,
+/// migrating the loaded value into the replacement field,
+/// but omit deprecated fields when saving.
+#[derive(Component, Debug, Clone)]
+pub struct Deprecated {
+    /// The full path of the field replacing this field, if any.
+    pub replaced_by: Option<Vec<String>>,
+    /// Writes the value of the deprecated field entity (the first argument)
+    /// into the replacement field entity (the second argument).
+    ///
+    /// If `None`, the loaded value is not migrated.
+    pub migrate:     Option<MigrateFn>,
+}
+
+/// Migrates the value of a [`Deprecated`] field entity into its replacement field entity.
+pub type MigrateFn = fn(&mut World, Entity, Entity) -> Result<(), MigrationError>;
+
+/// The error returned when the loaded value of a [`Deprecated`] field cannot be migrated.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MigrationError {
+    /// [`Deprecated::replaced_by`] does not refer to any config node.
+    UnresolvedReplacement,
+    /// The deprecated field is not a scalar field of the declared type.
+    SourceType,
+    /// The replacement field is not a scalar field of the mapped type.
+    TargetType,
+}
+
+impl fmt::Display for MigrationError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Self::UnresolvedReplacement => "the replacement field does not exist",
+            Self::SourceType => "the deprecated field is not a scalar field of the declared type",
+            Self::TargetType => "the replacement field is not a scalar field of the mapped type",
+        })
+    }
+}
+
+impl core::error::Error for MigrationError {}
+
+impl Deprecated {
+    /// Finds the entity of the replacement field in the world.
+    pub fn replacement(&self, world: &mut World) -> Option<Entity> {
+        let replaced_by = self.replaced_by.as_ref()?;
+        world
+            .query::<(Entity, &ConfigNode)>()
+            .iter(world)
+            .find_map(|(entity, node)| (&node.path == replaced_by).then_some(entity))
+    }
+}
+
 /// If a node entity has this component,
 /// it is conditionally "irrelevant" based on the state of another entity.
 ///
@@ -63,3 +442,80 @@ pub struct ConditionalRelevance {
     /// Tests whether a dependency entity is relevant with its current value.
     pub is_entity_relevant: fn(EntityRef) -> bool,
 }
+
+/// Declares a [`ConditionalRelevance`] dependency on the field at an absolute path,
+/// which may belong to a different root than this node,
+/// e.g. debug options that are only relevant when `general.developer_mode` is true.
+///
+/// Since other roots may not have been initialized when this node is spawned,
+/// the path is looked up by [`resolve_relevance_paths`],
+/// which inserts the corresponding [`ConditionalRelevance`] once the field exists.
+/// Until then, the node is considered relevant.
+///
+/// This cannot be combined with another [`ConditionalRelevance`] on the same node,
+/// such as the dependency of enum variant fields on the discriminant.
+#[derive(Component, Clone)]
+pub struct RelevancePath {
+    /// The path of the dependency field, starting with its root key.
+    pub path:               Vec<String>,
+    /// Tests whether the dependency entity is relevant with its current value.
+    pub is_entity_relevant: fn(EntityRef) -> bool,
+}
+
+/// Resolves each unresolved [`RelevancePath`] into a [`ConditionalRelevance`].
+///
+/// Added to [`PreUpdate`](bevy_app::PreUpdate) by [`AppExt::init_config`](crate::AppExt::init_config).
+/// The config nodes are only scanned when config nodes or relevance paths have been added
+/// since the last run, so unresolved paths do not cost a scan every frame.
+/// With the `tracing` feature, a warning is logged for each path that remains unresolved
+/// after a scan.
+pub fn resolve_relevance_paths(
+    mut commands: Commands,
+    pending: Query<(Entity, &RelevancePath), Without<ConditionalRelevance>>,
+    nodes: Query<(Entity, &ConfigNode)>,
+    added_nodes: Query<(), Added<ConfigNode>>,
+    added_paths: Query<(), Added<RelevancePath>>,
+) {
+    if pending.is_empty() || (added_nodes.is_empty() && added_paths.is_empty()) {
+        return;
+    }
+
+    let entities: HashMap<&[String], Entity> =
+        nodes.iter().map(|(entity, node)| (node.path.as_slice(), entity)).collect();
+    for (entity, relevance) in &pending {
+        if let Some(&dependency) = entities.get(relevance.path.as_slice()) {
+            commands.entity(entity).insert(ConditionalRelevance {
+                dependency,
+                is_entity_relevant: relevance.is_entity_relevant,
+            });
+        } else {
+            #[cfg(feature = "tracing")]
+            tracing::warn!(
+                node = %nodes.get(entity).map_or_else(|_| String::new(), |(_, node)| node.path.join(".")),
+                path = %relevance.path.join("."),
+                "relevance path of config node does not exist",
+            );
+        }
+    }
+}
+
+/// Declares that a config node is only relevant while [`is_relevant`](Self::is_relevant)
+/// returns true, usually checking the current `State` with
+/// [`in_states`](crate::state::in_states).
+///
+/// Inserted by `#[config(in_state(...))]`
+/// and maintained by [`update_state_relevance`](crate::state::update_state_relevance)
+/// with the `bevy_state` feature.
+#[derive(Component, Clone, Copy)]
+pub struct StateRelevance {
+    /// Tests whether the node is relevant in the current state of the world.
+    pub is_relevant: fn(&World) -> bool,
+}
+
+/// Marks a config node and its descendants as irrelevant in the current state of the app.
+///
+/// This is maintained by [`update_state_relevance`](crate::state::update_state_relevance)
+/// for nodes declared with `#[config(in_state(...))]`,
+/// but may also be inserted manually to hide a subtree temporarily.
+#[derive(Component)]
+pub struct OutOfState;
@@ -0,0 +1,594 @@
+//! Built-in config field types for settings that most games need.
+
+#[cfg(feature = "std")]
+extern crate std;
+
+#[cfg(feature = "bevy_input")]
+use alloc::collections::BTreeMap;
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::fmt;
+use core::ops::RangeInclusive;
+use core::str::FromStr;
+
+use super::impl_scalar_config_field_ as impl_scalar_config_field;
+
+/// A display resolution in physical pixels.
+///
+/// Resolutions are persisted as strings in the form `1920x1080`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Resolution {
+    /// The width in pixels.
+    pub width:  u32,
+    /// The height in pixels.
+    pub height: u32,
+}
+
+impl Resolution {
+    /// Creates a resolution from its dimensions.
+    #[must_use]
+    pub const fn new(width: u32, height: u32) -> Self { Self { width, height } }
+
+    /// Commonly used resolutions, in ascending order.
+    pub const COMMON: &'static [Resolution] = &[
+        Resolution::new(1280, 720),
+        Resolution::new(1366, 768),
+        Resolution::new(1600, 900),
+        Resolution::new(1920, 1080),
+        Resolution::new(2560, 1440),
+        Resolution::new(3440, 1440),
+        Resolution::new(3840, 2160),
+    ];
+}
+
+impl fmt::Display for Resolution {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}x{}", self.width, self.height)
+    }
+}
+
+/// The error returned when parsing a [`Resolution`] from a string fails.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseResolutionError;
+
+impl fmt::Display for ParseResolutionError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("expected a resolution in the form WIDTHxHEIGHT")
+    }
+}
+
+impl core::error::Error for ParseResolutionError {}
+
+impl FromStr for Resolution {
+    type Err = ParseResolutionError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (width, height) = s.split_once('x').ok_or(ParseResolutionError)?;
+        Ok(Self {
+            width:  width.trim().parse().map_err(|_| ParseResolutionError)?,
+            height: height.trim().parse().map_err(|_| ParseResolutionError)?,
+        })
+    }
+}
+
+#[cfg(feature = "serde")]
+impl serde::Serialize for Resolution {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for Resolution {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        struct Visitor;
+
+        impl serde::de::Visitor<'_> for Visitor {
+            type Value = Resolution;
+
+            fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                f.write_str("a resolution in the form WIDTHxHEIGHT")
+            }
+
+            fn visit_str<E: serde::de::Error>(self, v: &str) -> Result<Resolution, E> {
+                v.parse().map_err(E::custom)
+            }
+        }
+
+        deserializer.deserialize_str(Visitor)
+    }
+}
+
+impl_scalar_config_field!(
+    Resolution,
+    ResolutionMetadata,
+    |metadata: &ResolutionMetadata| metadata.default,
+    'a => Resolution,
+    |&value: &Resolution| value,
+);
+
+/// Metadata for [`Resolution`] fields.
+#[derive(Clone)]
+pub struct ResolutionMetadata {
+    /// The default value.
+    pub default: Resolution,
+    /// The resolutions offered in the UI dropdown,
+    /// in addition to those reported by the connected monitors.
+    pub presets: &'static [Resolution],
+}
+
+impl Default for ResolutionMetadata {
+    fn default() -> Self {
+        Self { default: Resolution::new(1920, 1080), presets: Resolution::COMMON }
+    }
+}
+
+/// An audio volume, stored as a linear amplitude factor from 0 (silent) to 1 (full volume).
+///
+/// Volumes are persisted as the linear factor.
+/// The egui editor displays them as a percentage or in decibels
+/// according to [`VolumeMetadata::display`].
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
+pub struct Volume(f32);
+
+impl Volume {
+    /// No sound.
+    pub const SILENT: Self = Self(0.0);
+    /// Full volume.
+    pub const FULL: Self = Self(1.0);
+
+    /// Creates a volume from a linear amplitude factor, clamped to `0.0..=1.0`.
+    ///
+    /// NaN is treated as silence.
+    #[must_use]
+    pub fn new(linear: f32) -> Self {
+        if linear.is_nan() { Self::SILENT } else { Self(linear.clamp(0.0, 1.0)) }
+    }
+
+    /// Returns the linear amplitude factor in `0.0..=1.0`.
+    #[must_use]
+    pub fn linear(self) -> f32 { self.0 }
+
+    /// Returns the volume in decibels relative to full volume.
+    ///
+    /// This is `0.0` at full volume and negative infinity when silent.
+    #[must_use]
+    pub fn decibels(self) -> f32 { 20.0 * libm::log10f(self.0) }
+
+    /// Creates a volume from decibels relative to full volume.
+    ///
+    /// Positive values are clamped to full volume.
+    #[must_use]
+    pub fn from_decibels(decibels: f32) -> Self { Self::new(libm::powf(10.0, decibels / 20.0)) }
+}
+
+impl Default for Volume {
+    fn default() -> Self { Self::FULL }
+}
+
+#[cfg(feature = "serde")]
+impl serde::Serialize for Volume {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_f32(self.0)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for Volume {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        <f32 as serde::Deserialize>::deserialize(deserializer).map(Volume::new)
+    }
+}
+
+impl_scalar_config_field!(
+    Volume,
+    VolumeMetadata,
+    |metadata: &VolumeMetadata| metadata.default,
+    'a => Volume,
+    |&value: &Volume| value,
+);
+
+/// Metadata for [`Volume`] fields.
+#[derive(Clone)]
+pub struct VolumeMetadata {
+    /// The default value.
+    pub default:      Volume,
+    /// How the volume is displayed in the UI.
+    pub display:      VolumeDisplay,
+    /// The lowest value of the decibel slider, below which the volume is silent.
+    pub min_decibels: f32,
+}
+
+impl Default for VolumeMetadata {
+    fn default() -> Self {
+        Self {
+            default:      Volume::FULL,
+            display:      VolumeDisplay::Percent,
+            min_decibels: -60.0,
+        }
+    }
+}
+
+/// The unit used to display a [`Volume`] in the UI.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum VolumeDisplay {
+    /// A linear slider from 0% to 100%.
+    #[default]
+    Percent,
+    /// A logarithmic slider in decibels,
+    /// from [`VolumeMetadata::min_decibels`] to 0 dB.
+    Decibels,
+}
+
+/// A piecewise linear response curve defined by control points,
+/// e.g. for mouse sensitivity, difficulty ramps or audio falloff.
+///
+/// Curves are persisted as an array of `[x, y]` pairs.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Curve {
+    points: Vec<[f32; 2]>,
+}
+
+impl Curve {
+    /// Creates a curve from control points in any order.
+    #[must_use]
+    pub fn new(points: impl IntoIterator<Item = [f32; 2]>) -> Self {
+        let mut points: Vec<_> = points.into_iter().collect();
+        points.sort_by(|[x1, _], [x2, _]| x1.total_cmp(x2));
+        Self { points }
+    }
+
+    /// Returns the control points, sorted by `x`.
+    #[must_use]
+    pub fn points(&self) -> &[[f32; 2]] { &self.points }
+
+    /// Replaces the control point at `index`, keeping the points sorted by `x`.
+    ///
+    /// `x` is clamped between the neighbouring points.
+    ///
+    /// # Panics
+    /// Panics if `index` is out of bounds.
+    pub fn set_point(&mut self, index: usize, [mut x, y]: [f32; 2]) {
+        if let Some(&[prev, _]) = index.checked_sub(1).and_then(|prev| self.points.get(prev)) {
+            x = x.max(prev);
+        }
+        if let Some(&[next, _]) = self.points.get(index + 1) {
+            x = x.min(next);
+        }
+        self.points[index] = [x, y];
+    }
+
+    /// Inserts a control point, keeping the points sorted by `x`.
+    ///
+    /// Returns the index of the new point.
+    pub fn insert_point(&mut self, point: [f32; 2]) -> usize {
+        let index = self.points.partition_point(|&[x, _]| x < point[0]);
+        self.points.insert(index, point);
+        index
+    }
+
+    /// Removes the control point at `index`.
+    ///
+    /// # Panics
+    /// Panics if `index` is out of bounds.
+    pub fn remove_point(&mut self, index: usize) { self.points.remove(index); }
+
+    /// Evaluates the curve at `x` by interpolating between the neighbouring control points.
+    ///
+    /// Values outside the control points are extended horizontally from the first and last point.
+    /// An empty curve evaluates to `0.0`.
+    #[must_use]
+    pub fn sample(&self, x: f32) -> f32 {
+        match self.points.iter().position(|&[px, _]| px >= x) {
+            None => self.points.last().map_or(0.0, |&[_, y]| y),
+            Some(0) => self.points[0][1],
+            Some(index) => {
+                let [x0, y0] = self.points[index - 1];
+                let [x1, y1] = self.points[index];
+                y0 + (y1 - y0) * (x - x0) / (x1 - x0)
+            }
+        }
+    }
+}
+
+#[cfg(feature = "serde")]
+impl serde::Serialize for Curve {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serde::Serialize::serialize(&self.points, serializer)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for Curve {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        <Vec<[f32; 2]> as serde::Deserialize>::deserialize(deserializer).map(Curve::new)
+    }
+}
+
+impl_scalar_config_field!(
+    Curve,
+    CurveMetadata,
+    |metadata: &CurveMetadata| metadata.default.clone(),
+    'a => &'a Curve,
+    core::convert::identity,
+    to_owned = Curve::clone,
+);
+
+/// Metadata for [`Curve`] fields.
+#[derive(Clone)]
+pub struct CurveMetadata {
+    /// The default value.
+    pub default: Curve,
+    /// The range of `x` values shown and editable in the UI.
+    pub x_range: RangeInclusive<f32>,
+    /// The range of `y` values shown and editable in the UI.
+    pub y_range: RangeInclusive<f32>,
+}
+
+impl Default for CurveMetadata {
+    fn default() -> Self {
+        Self {
+            default: Curve::new([[0.0, 0.0], [1.0, 1.0]]),
+            x_range: 0.0..=1.0,
+            y_range: 0.0..=1.0,
+        }
+    }
+}
+
+/// An ordered list of colors, e.g. for team colors or a colorblind-friendly scheme.
+///
+/// Palettes are persisted as an array of sRGB hex strings, e.g. `["#FF0000", "#0000FF80"]`.
+/// The metadata is the same as for a [`Vec<Color>`](bevy_color::Color) field.
+#[cfg(feature = "bevy_color")]
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct Palette(pub Vec<bevy_color::Color>);
+
+#[cfg(feature = "bevy_color")]
+impl Palette {
+    /// Returns the colors in the palette.
+    #[must_use]
+    pub fn colors(&self) -> &[bevy_color::Color] { &self.0 }
+}
+
+#[cfg(feature = "bevy_color")]
+impl core::ops::Deref for Palette {
+    type Target = Vec<bevy_color::Color>;
+
+    fn deref(&self) -> &Self::Target { &self.0 }
+}
+
+#[cfg(feature = "bevy_color")]
+impl core::ops::DerefMut for Palette {
+    fn deref_mut(&mut self) -> &mut Self::Target { &mut self.0 }
+}
+
+#[cfg(all(feature = "bevy_color", feature = "serde"))]
+impl serde::Serialize for Palette {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_seq(self.0.iter().map(|color| color.to_srgba().to_hex()))
+    }
+}
+
+#[cfg(all(feature = "bevy_color", feature = "serde"))]
+impl<'de> serde::Deserialize<'de> for Palette {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let hex = <Vec<alloc::string::String> as serde::Deserialize>::deserialize(deserializer)?;
+        hex.iter()
+            .map(|hex| bevy_color::Srgba::hex(hex).map(bevy_color::Color::from))
+            .collect::<Result<_, _>>()
+            .map(Palette)
+            .map_err(serde::de::Error::custom)
+    }
+}
+
+#[cfg(feature = "bevy_color")]
+impl_scalar_config_field!(
+    Palette,
+    crate::impls::VecMetadata<bevy_color::Color>,
+    |metadata: &crate::impls::VecMetadata<bevy_color::Color>| Palette(metadata.default.clone()),
+    'a => &'a [bevy_color::Color],
+    Palette::colors,
+    to_owned = |colors: &[bevy_color::Color]| Palette(colors.to_vec()),
+);
+
+/// A keyboard binding for an action, or unbound if `None`.
+///
+/// Use [`find_key_conflicts`] to detect keys bound to multiple fields.
+#[cfg(feature = "bevy_input")]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct KeyBinding(pub Option<bevy_input::keyboard::KeyCode>);
+
+#[cfg(all(feature = "bevy_input", feature = "serde"))]
+impl serde::Serialize for KeyBinding {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serde::Serialize::serialize(&self.0, serializer)
+    }
+}
+
+#[cfg(all(feature = "bevy_input", feature = "serde"))]
+impl<'de> serde::Deserialize<'de> for KeyBinding {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        serde::Deserialize::deserialize(deserializer).map(KeyBinding)
+    }
+}
+
+#[cfg(feature = "bevy_input")]
+impl_scalar_config_field!(
+    KeyBinding,
+    KeyBindingMetadata,
+    |metadata: &KeyBindingMetadata| metadata.default,
+    'a => KeyBinding,
+    |&value: &KeyBinding| value,
+);
+
+/// Metadata for [`KeyBinding`] fields.
+#[cfg(feature = "bevy_input")]
+#[derive(Default, Clone)]
+pub struct KeyBindingMetadata {
+    /// The default value.
+    pub default: KeyBinding,
+}
+
+/// A key bound to multiple [`KeyBinding`] fields.
+#[cfg(feature = "bevy_input")]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct KeyConflict {
+    /// The key bound to multiple fields.
+    pub key:   bevy_input::keyboard::KeyCode,
+    /// The paths of the fields bound to `key`, in ascending order.
+    pub paths: Vec<Vec<String>>,
+}
+
+/// Finds keys bound to more than one [`KeyBinding`] field in the world.
+///
+/// Conflicts are returned in ascending order of the key.
+#[cfg(feature = "bevy_input")]
+pub fn find_key_conflicts(world: &mut bevy_ecs::world::World) -> Vec<KeyConflict> {
+    let mut query = world.query::<(&crate::ConfigNode, &crate::ScalarData<KeyBinding>)>();
+    key_conflicts(query.iter(world).map(|(node, binding)| (node, binding.0)))
+}
+
+/// Groups the bound fields by key and keeps the keys bound more than once.
+#[cfg(feature = "bevy_input")]
+pub(crate) fn key_conflicts<'a>(
+    fields: impl IntoIterator<Item = (&'a crate::ConfigNode, KeyBinding)>,
+) -> Vec<KeyConflict> {
+    let mut by_key = BTreeMap::<_, Vec<_>>::new();
+    for (node, binding) in fields {
+        if let KeyBinding(Some(key)) = binding {
+            by_key.entry(key).or_default().push(node.path.clone());
+        }
+    }
+    by_key
+        .into_iter()
+        .filter(|(_, paths)| paths.len() > 1)
+        .map(|(key, mut paths)| {
+            paths.sort();
+            KeyConflict { key, paths }
+        })
+        .collect()
+}
+
+/// A language tag, e.g. `en-US`, chosen from the languages available at runtime.
+///
+/// The options offered in the UI come from [`LanguageMetadata::options`],
+/// which [`sync_language_options`] fills from the [`AvailableLanguages`] resource,
+/// so that the choices reflect the localization bundles actually loaded by the app.
+///
+/// Languages are persisted as the tag string.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord, Default)]
+pub struct Language(pub String);
+
+impl Language {
+    /// Returns the language tag.
+    #[must_use]
+    pub fn tag(&self) -> &str { &self.0 }
+}
+
+impl From<&str> for Language {
+    fn from(tag: &str) -> Self { Self(tag.into()) }
+}
+
+#[cfg(feature = "serde")]
+impl serde::Serialize for Language {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&self.0)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for Language {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        <String as serde::Deserialize>::deserialize(deserializer).map(Language)
+    }
+}
+
+impl_scalar_config_field!(
+    Language,
+    LanguageMetadata,
+    |metadata: &LanguageMetadata| Language::from(metadata.default),
+    'a => &'a str,
+    Language::tag,
+);
+
+/// Metadata for [`Language`] fields.
+#[derive(Default, Clone)]
+pub struct LanguageMetadata {
+    /// The default language tag.
+    pub default: &'static str,
+    /// The languages offered in the UI.
+    ///
+    /// This is replaced by [`sync_language_options`] whenever [`AvailableLanguages`] changes.
+    pub options: Vec<LanguageOption>,
+}
+
+/// A language offered by a [`Language`] field.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LanguageOption {
+    /// The language tag, e.g. `de-DE`.
+    pub tag:         String,
+    /// The name of the language in the language itself, e.g. `Deutsch`.
+    pub native_name: String,
+}
+
+impl LanguageOption {
+    /// Creates a language option.
+    pub fn new(tag: impl Into<String>, native_name: impl Into<String>) -> Self {
+        Self { tag: tag.into(), native_name: native_name.into() }
+    }
+}
+
+/// The languages available in the app, e.g. the loaded localization bundles.
+///
+/// Insert or update this resource when the bundles are loaded,
+/// and [`sync_language_options`] offers them in all [`Language`] fields
+/// if [`LanguagePlugin`] is added.
+#[derive(bevy_ecs::resource::Resource, Debug, Clone, Default)]
+pub struct AvailableLanguages(pub Vec<LanguageOption>);
+
+/// Adds [`sync_language_options`] to [`PreUpdate`](bevy_app::PreUpdate).
+#[derive(Default)]
+pub struct LanguagePlugin;
+
+impl bevy_app::Plugin for LanguagePlugin {
+    fn build(&self, app: &mut bevy_app::App) {
+        use bevy_ecs::schedule::IntoScheduleConfigs;
+        use bevy_ecs::schedule::common_conditions::resource_exists;
+
+        app.add_systems(
+            bevy_app::PreUpdate,
+            sync_language_options.run_if(resource_exists::<AvailableLanguages>),
+        );
+    }
+}
+
+/// Copies [`AvailableLanguages`] into the [options](LanguageMetadata::options)
+/// of all [`Language`] fields.
+///
+/// Added by [`LanguagePlugin`].
+/// All fields are updated when [`AvailableLanguages`] changes,
+/// and fields spawned later are updated on the next run.
+#[expect(clippy::needless_pass_by_value, reason = "system parameters are passed by value")]
+#[expect(clippy::type_complexity, reason = "queries are system parameters")]
+pub fn sync_language_options(
+    available: bevy_ecs::system::Res<AvailableLanguages>,
+    mut fields: bevy_ecs::system::ParamSet<(
+        bevy_ecs::system::Query<&mut crate::ScalarMetadata<Language>>,
+        bevy_ecs::system::Query<
+            &mut crate::ScalarMetadata<Language>,
+            bevy_ecs::query::Added<crate::ScalarMetadata<Language>>,
+        >,
+    )>,
+) {
+    use bevy_ecs::change_detection::DetectChanges;
+
+    if available.is_changed() {
+        for mut metadata in &mut fields.p0() {
+            metadata.0.options.clone_from(&available.0);
+        }
+    } else {
+        for mut metadata in &mut fields.p1() {
+            metadata.0.options.clone_from(&available.0);
+        }
+    }
+}
@@ -0,0 +1,190 @@
+//! Validation of invariants across sibling fields of a config struct.
+//!
+//! Validators of individual fields are registered on the
+//! [`Validation`](crate::manager::Validation) manager instead,
+//! which reports violations through the same [`ValidationFailed`] message
+//! and [`ValidationError`] component.
+//!
+//! Field-level metadata such as `min` and `max` can only constrain a single field.
+//! Invariants involving multiple fields, e.g. `min_fps <= max_fps`,
+//! are declared with <code>#[config(validate_struct = <i>path</i>)]</code> on the struct,
+//! where <code><i>path</i></code> is a function or closure
+//! taking the [reader](crate::ConfigField::Reader) of the struct
+//! and returning `Err` with a human-readable message if the invariant is violated.
+//!
+//! The validator is run by [`validate_structs`] whenever any field under the struct changes.
+//! Violations are reported in two ways:
+//!
+//! - A [`ValidationFailed`] message is written when the struct becomes invalid
+//!   or the error message changes.
+//! - A [`ValidationError`] component is attached to the node entity of the struct
+//!   as long as the struct remains invalid,
+//!   which the [`Egui`](crate::manager::Egui) manager shows as a warning under the group header.
+//!
+//! Validation does not reject or revert the offending writes,
+//! since it is unclear which of the fields should be changed;
+//! systems should handle the invalid state gracefully, e.g. by swapping the bounds.
+//!
+//! ```
+//! # use bevy_app::App;
+//! # use bevy_mod_config::{AppExt, Config};
+//! use bevy_mod_config::validate::ValidationFailed;
+//!
+//! #[derive(Config)]
+//! #[config(validate_struct = |video| {
+//!     if video.min_fps <= video.max_fps { Ok(()) } else { Err("min_fps exceeds max_fps".into()) }
+//! })]
+//! struct Video {
+//!     #[config(default = 30)]
+//!     min_fps: u32,
+//!     #[config(default = 20)]
+//!     max_fps: u32,
+//! }
+//!
+//! let mut app = App::new();
+//! app.init_config::<(), Video>("video");
+//! app.update();
+//!
+//! let messages = app.world().resource::<bevy_ecs::message::Messages<ValidationFailed>>();
+//! assert_eq!(
+//!     messages.iter_current_update_messages().next().unwrap().error,
+//!     "min_fps exceeds max_fps"
+//! );
+//! ```
+
+use alloc::boxed::Box;
+use alloc::string::String;
+use alloc::vec::Vec;
+
+use bevy_ecs::component::Component;
+use bevy_ecs::entity::Entity;
+use bevy_ecs::message::Message;
+use bevy_ecs::resource::Resource;
+use bevy_ecs::system::{Query, SystemState};
+use bevy_ecs::world::{Mut, World};
+
+use crate::{ConfigField, ConfigNode, SpawnHandle};
+
+/// The signature of validators declared with `#[config(validate_struct)]`.
+pub type ValidateFn<C> = for<'a> fn(<C as ConfigField>::Reader<'a>) -> Result<(), String>;
+
+/// Written when a config struct violates its `#[config(validate_struct)]` invariant,
+/// or a field is rejected by a validator of the [`Validation`](crate::manager::Validation) manager.
+///
+/// The message is only written when the node becomes invalid or the error message changes,
+/// not on every change to an already invalid node.
+#[derive(Message, Debug, Clone)]
+pub struct ValidationFailed {
+    /// The invalid node entity.
+    pub node:  Entity,
+    /// The path of the invalid node.
+    pub path:  Vec<String>,
+    /// The error message returned by the validator.
+    pub error: String,
+}
+
+/// Attached to the node entity of a config struct or field
+/// as long as it remains invalid.
+///
+/// This holds the latest error message returned by its validator.
+#[derive(Component, Debug, Clone)]
+pub struct ValidationError(pub String);
+
+/// The validators of all spawned config structs with `#[config(validate_struct)]`.
+#[derive(Resource, Default)]
+struct StructValidators {
+    validators: Vec<Box<dyn ErasedValidator>>,
+}
+
+trait ErasedValidator: Send + Sync {
+    fn node(&self) -> Entity;
+
+    /// Returns `None` if the struct has not changed since the last call.
+    fn validate(&mut self, world: &World) -> Option<Result<(), String>>;
+}
+
+type ValidatorParams<C> = (
+    Query<'static, 'static, <C as ConfigField>::ReadQueryData>,
+    Query<'static, 'static, (&'static ConfigNode, <C as ConfigField>::ChangedQueryData)>,
+);
+
+struct TypedValidator<C: ConfigField> {
+    spawn_handle: C::SpawnHandle,
+    state:        SystemState<ValidatorParams<C>>,
+    last_changed: Option<C::Changed>,
+    validate_fn:  ValidateFn<C>,
+}
+
+impl<C: ConfigField> ErasedValidator for TypedValidator<C> {
+    fn node(&self) -> Entity { self.spawn_handle.node() }
+
+    fn validate(&mut self, world: &World) -> Option<Result<(), String>> {
+        let (read_query, changed_query) = self.state.get(world).expect("queries are always valid");
+        let changed = C::changed(&changed_query, &self.spawn_handle);
+        if self.last_changed.as_ref() == Some(&changed) {
+            return None;
+        }
+        self.last_changed = Some(changed);
+        Some((self.validate_fn)(C::read_world(&read_query, &self.spawn_handle)))
+    }
+}
+
+/// Registers the validator of a config struct spawned at `spawn_handle`.
+///
+/// This is called by the code generated for `#[config(validate_struct)]`.
+#[doc(hidden)]
+pub fn register<C: ConfigField>(
+    world: &mut World,
+    spawn_handle: C::SpawnHandle,
+    validate_fn: ValidateFn<C>,
+) {
+    let validator = TypedValidator::<C> {
+        spawn_handle,
+        state: SystemState::new(world),
+        last_changed: None,
+        validate_fn,
+    };
+    world.get_resource_or_init::<StructValidators>().validators.push(Box::new(validator));
+}
+
+/// Runs the validators of all config structs that changed since the last run.
+///
+/// Added to [`Last`](bevy_app::Last) by [`AppExt::init_config`](crate::AppExt::init_config).
+/// Validators of despawned structs are dropped.
+pub fn validate_structs(world: &mut World) {
+    if !world.contains_resource::<StructValidators>() {
+        return;
+    }
+
+    world.resource_scope(|world, mut validators: Mut<StructValidators>| {
+        validators.validators.retain(|validator| world.get_entity(validator.node()).is_ok());
+
+        for validator in &mut validators.validators {
+            let Some(result) = validator.validate(world) else { continue };
+            report(world, validator.node(), result);
+        }
+    });
+}
+
+/// Updates the [`ValidationError`] of `node` with the latest validation result,
+/// writing a [`ValidationFailed`] message if the node became invalid or the error changed.
+pub(crate) fn report(world: &mut World, node: Entity, result: Result<(), String>) {
+    let mut entity = world.entity_mut(node);
+    match result {
+        Ok(()) => {
+            entity.remove::<ValidationError>();
+        }
+        Err(error) => {
+            if entity.get::<ValidationError>().is_some_and(|old| old.0 == error) {
+                return;
+            }
+            entity.insert(ValidationError(error.clone()));
+            let path = entity
+                .get::<ConfigNode>()
+                .expect("validated node must be a config node")
+                .path
+                .clone();
+            world.write_message(ValidationFailed { node, path, error });
+        }
+    }
+}
@@ -0,0 +1,150 @@
+//! Typed mutation of config fields.
+//!
+//! See [`WorldExt::update_config`](crate::WorldExt::update_config) for more information.
+
+use alloc::boxed::Box;
+use alloc::vec::Vec;
+use core::cell::RefCell;
+use core::fmt;
+
+use bevy_ecs::entity::Entity;
+use bevy_ecs::world::{EntityMut, World};
+
+use crate::{ConfigNode, DefaultFromMetadata, ScalarConstraint, ScalarData, ScalarMetadata};
+
+/// A deferred write to the world.
+type Write = Box<dyn FnOnce(&mut World)>;
+
+/// Collects the writes made through [`Writer`](crate::ConfigField::Writer)s
+/// so that they can be applied to the world together.
+#[derive(Default)]
+pub struct ConfigWrites {
+    writes: RefCell<Vec<Write>>,
+    fields: RefCell<Vec<Entity>>,
+}
+
+impl ConfigWrites {
+    /// Returns the distinct field entities written so far, in the order they were first written.
+    #[must_use]
+    pub fn fields(&self) -> Vec<Entity> {
+        let mut fields = Vec::new();
+        for &field in self.fields.borrow().iter() {
+            if !fields.contains(&field) {
+                fields.push(field);
+            }
+        }
+        fields
+    }
+
+    /// Applies all collected writes to `world` in the order they were made.
+    pub fn apply(self, world: &mut World) {
+        for write in self.writes.into_inner() {
+            write(world);
+        }
+    }
+
+    fn push(&self, field: Entity, write: impl FnOnce(&mut World) + 'static) {
+        self.fields.borrow_mut().push(field);
+        self.writes.borrow_mut().push(Box::new(write));
+    }
+}
+
+impl fmt::Debug for ConfigWrites {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ConfigWrites")
+            .field("fields", &self.fields.borrow())
+            .finish_non_exhaustive()
+    }
+}
+
+/// A value that can be written to a scalar field storing a [`ScalarData<T>`].
+///
+/// This is implemented for `T` itself,
+/// for [`EnumDiscriminant`](crate::EnumDiscriminant)s written to a discriminant field,
+/// and for the values of [`BareField`](crate::BareField)s.
+/// Unlike [`Into`], this allows integer literals to infer the type of the field.
+pub trait IntoScalarData<T> {
+    /// Converts the value into the stored data.
+    fn into_scalar_data(self) -> T;
+}
+
+impl<T> IntoScalarData<T> for T {
+    fn into_scalar_data(self) -> T { self }
+}
+
+/// The [`Writer`](crate::ConfigField::Writer) of a scalar config field
+/// storing its data in a [`ScalarData<T>`].
+///
+/// Writes are deferred until the end of [`update_config`](crate::WorldExt::update_config),
+/// where each written value is [constrained](ScalarConstraint) to the field metadata
+/// and each written field has its [generation](ConfigNode::generation) bumped.
+pub struct ScalarWriter<'a, T> {
+    entity: Entity,
+    writes: &'a ConfigWrites,
+    _ph:    core::marker::PhantomData<fn(T)>,
+}
+
+impl<'a, T: Send + Sync + 'static> ScalarWriter<'a, T> {
+    /// Creates a writer for the scalar field entity `entity`.
+    pub fn new(entity: Entity, writes: &'a ConfigWrites) -> Self {
+        Self { entity, writes, _ph: core::marker::PhantomData }
+    }
+
+    /// Replaces the value of the field.
+    pub fn set(&self, value: impl IntoScalarData<T>) {
+        let value = value.into_scalar_data();
+        self.update(move |data| *data = value);
+    }
+
+    /// Modifies the value of the field in place.
+    pub fn update(&self, update: impl FnOnce(&mut T) + 'static) {
+        let entity = self.entity;
+        self.writes.push(entity, move |world| {
+            let mut entity = world.entity_mut(entity);
+            update(
+                &mut entity
+                    .get_mut::<ScalarData<T>>()
+                    .expect("scalar data component must remain valid with Self type")
+                    .0,
+            );
+            if let Some(&constraint) = entity.get::<ScalarConstraint>() {
+                constraint.apply(&mut EntityMut::from(&mut entity));
+            }
+            entity.get_mut::<ConfigNode>().expect("scalar fields are config nodes").bump();
+        });
+    }
+}
+
+impl<T: DefaultFromMetadata + Send + Sync + 'static> ScalarWriter<'_, T> {
+    /// Modifies the [metadata](ScalarMetadata) of the field,
+    /// e.g. to limit the maximum frame rate to the refresh rate of the monitor.
+    ///
+    /// Managers such as the egui editor pick up the new metadata immediately.
+    /// If the current value violates the new constraints,
+    /// it is [constrained](DefaultFromMetadata::constrain) and the generation is bumped.
+    pub fn update_metadata(&self, update: impl FnOnce(&mut T::Metadata) + 'static) {
+        let entity = self.entity;
+        self.writes.push(entity, move |world| {
+            let mut entity = world.entity_mut(entity);
+            let mut metadata = entity
+                .get_mut::<ScalarMetadata<T>>()
+                .expect("scalar metadata component must remain valid with Self type");
+            update(&mut metadata.0);
+
+            let data = entity
+                .get::<ScalarData<T>>()
+                .expect("scalar data component must remain valid with Self type");
+            let metadata = entity.get::<ScalarMetadata<T>>().expect("checked above");
+            if let Some(value) = data.0.constrain(&metadata.0) {
+                entity.get_mut::<ScalarData<T>>().expect("checked above").0 = value;
+                entity.get_mut::<ConfigNode>().expect("scalar fields are config nodes").bump();
+            }
+        });
+    }
+}
+
+impl<T> Clone for ScalarWriter<'_, T> {
+    fn clone(&self) -> Self { *self }
+}
+
+impl<T> Copy for ScalarWriter<'_, T> {}
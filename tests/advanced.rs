@@ -0,0 +1,43 @@
+use bevy_ecs::query::Has;
+use bevy_mod_config::{Advanced, AppExt, Config, ConfigNode};
+
+#[derive(Config)]
+struct Settings {
+    #[config(default = 60)]
+    fps_limit:  u32,
+    #[config(advanced)]
+    renderer:   Renderer,
+    #[config(advanced = cfg!(not(debug_assertions)), default = true)]
+    validation: bool,
+}
+
+#[derive(Config)]
+struct Renderer {
+    #[config(default = 4)]
+    worker_threads: u32,
+}
+
+#[test]
+fn test_advanced_flag() {
+    let mut app = bevy_app::App::new();
+    app.init_config::<(), Settings>("settings");
+
+    let mut query = app.world_mut().query::<(&ConfigNode, Has<Advanced>)>();
+    let mut advanced: Vec<_> = query
+        .iter(app.world())
+        .map(|(node, is_advanced)| (node.path.join("."), is_advanced))
+        .collect();
+    advanced.sort();
+
+    assert_eq!(
+        advanced,
+        [
+            ("settings".to_string(), false),
+            ("settings.fps_limit".to_string(), false),
+            ("settings.renderer".to_string(), true),
+            // The flag is not inherited; managers are expected to hide the whole subtree.
+            ("settings.renderer.worker_threads".to_string(), false),
+            ("settings.validation".to_string(), cfg!(not(debug_assertions))),
+        ]
+    );
+}
@@ -0,0 +1,66 @@
+use bevy_ecs::system::RunSystemOnce;
+use bevy_mod_config::impls::ByValue;
+use bevy_mod_config::{AppExt, BareField, Config, ReadConfig, WorldExt};
+
+#[derive(Debug, Clone, PartialEq)]
+struct Token(u64);
+
+#[derive(Config)]
+struct Session {
+    #[config(default = Some((10, 20)))]
+    window_pos: BareField<(i32, i32), ByValue>,
+    #[config(default_with = Some(|| Token(7)))]
+    token:      BareField<Token>,
+}
+
+#[test]
+fn test_bare_field_defaults() {
+    let mut app = bevy_app::App::new();
+    app.init_config::<(), Session>("session");
+    app.update();
+
+    let (window_pos, token) = app
+        .world_mut()
+        .run_system_once(|session: ReadConfig<Session>| {
+            let session = session.read();
+            (session.window_pos, session.token.clone())
+        })
+        .unwrap();
+    assert_eq!(window_pos, (10, 20));
+    assert_eq!(token, Token(7));
+
+    app.world_mut().update_config::<Session>(|session| session.window_pos.set((30, 40)));
+    app.update();
+    let window_pos = app
+        .world_mut()
+        .run_system_once(|session: ReadConfig<Session>| session.read().window_pos)
+        .unwrap();
+    assert_eq!(window_pos, (30, 40));
+}
+
+#[cfg(feature = "serde_json")]
+#[test]
+fn test_bare_field_persisted() {
+    use bevy_mod_config::Lossy;
+    use bevy_mod_config::manager::serde::Json;
+
+    #[derive(Config)]
+    struct Window {
+        #[config(default = Some((1, 2)))]
+        pos: Lossy<BareField<(i32, i32)>>,
+    }
+
+    let mut app = bevy_app::App::new();
+    app.init_config_with::<Json, Window>("window", Json::new);
+    app.update();
+
+    let json = app.world().resource::<bevy_mod_config::manager::Instance<Json>>().instance.clone();
+    let mut value = json.to_value(app.world_mut()).unwrap();
+    assert_eq!(value["window.pos"], serde_json::json!([1, 2]));
+
+    value["window.pos"] = serde_json::json!([3, 4]);
+    json.from_value(app.world_mut(), value).unwrap();
+    let pos =
+        app.world_mut().run_system_once(|window: ReadConfig<Window>| window.read().pos.0).unwrap();
+    assert_eq!(pos, (3, 4));
+}
@@ -0,0 +1,41 @@
+use bevy_mod_config::{AppExt, CachedReadConfig, Config, WorldExt};
+
+#[derive(Config)]
+#[config(expose(owned))]
+struct Profile {
+    #[config(default = "Player")]
+    name:  String,
+    #[config(default = 3)]
+    lives: u32,
+    hud:   Hud,
+}
+
+#[derive(Config)]
+struct Hud {
+    #[config(default = 1.0)]
+    scale: f32,
+}
+
+#[test]
+fn test_cached_read() {
+    let mut app = bevy_app::App::new();
+    app.init_config::<(), Profile>("profile");
+
+    let system = app.register_system(|mut profile: CachedReadConfig<Profile>| {
+        let profile = profile.read();
+        (profile.name.clone(), profile.lives, profile.hud.scale, profile.name.as_ptr() as usize)
+    });
+
+    let (name, lives, scale, first_ptr) = app.world_mut().run_system(system).unwrap();
+    assert_eq!((name.as_str(), lives, scale), ("Player", 3, 1.0));
+
+    // Unchanged configs reuse the cached value.
+    let (_, _, _, ptr) = app.world_mut().run_system(system).unwrap();
+    assert_eq!(ptr, first_ptr);
+
+    // Changes to nested fields refresh the cache.
+    app.world_mut().update_config::<Profile>(|profile| profile.hud.scale.set(2.0));
+    let (name, lives, scale, ptr) = app.world_mut().run_system(system).unwrap();
+    assert_eq!((name.as_str(), lives, scale), ("Player", 3, 2.0));
+    assert_ne!(ptr, first_ptr);
+}
@@ -0,0 +1,81 @@
+use bevy_ecs::system::SystemState;
+use bevy_ecs::world::World;
+use bevy_mod_config::{AppExt, ChangeBatch, Config, ConfigNode, ReadConfigChange, WorldExt};
+
+#[derive(Config)]
+struct Audio {
+    #[config(default = 50)]
+    music: u32,
+    #[config(default = 80)]
+    sfx:   u32,
+}
+
+fn consume(state: &mut SystemState<ReadConfigChange<Audio>>, world: &mut World) -> bool {
+    state.get_mut(world).unwrap().consume_change()
+}
+
+#[test]
+fn test_batch_changes() {
+    let mut app = bevy_app::App::new();
+    app.init_config::<(), Audio>("audio");
+    let world = app.world_mut();
+
+    let mut state = SystemState::<ReadConfigChange<Audio>>::new(world);
+    assert!(consume(&mut state, world));
+
+    world.batch_changes(|world| {
+        world.update_config::<Audio>(|audio| audio.music.set(10u32));
+        assert!(!consume(&mut state, world));
+        world.update_config::<Audio>(|audio| audio.sfx.set(20u32));
+        assert!(!consume(&mut state, world));
+    });
+
+    assert!(consume(&mut state, world));
+    assert!(!consume(&mut state, world));
+}
+
+#[test]
+fn test_batch_across_frames() {
+    let mut app = bevy_app::App::new();
+    app.init_config::<(), Audio>("audio");
+    let world = app.world_mut();
+
+    let mut state = SystemState::<ReadConfigChange<Audio>>::new(world);
+    assert!(consume(&mut state, world));
+
+    world.resource_mut::<ChangeBatch>().begin();
+    world.update_config::<Audio>(|audio| audio.music.set(10u32));
+    assert!(!consume(&mut state, world));
+    app.update();
+    assert!(!consume(&mut state, app.world_mut()));
+
+    app.world_mut().resource_mut::<ChangeBatch>().end();
+    // The deferred bumps are applied at the start of the next frame.
+    app.update();
+    assert!(consume(&mut state, app.world_mut()));
+}
+
+#[test]
+fn test_batch_defers_bumps() {
+    let mut app = bevy_app::App::new();
+    app.init_config::<(), Audio>("audio");
+    let world = app.world_mut();
+    let generation = |world: &mut World| {
+        world
+            .query::<&ConfigNode>()
+            .iter(world)
+            .find(|node| node.path == ["audio", "music"])
+            .unwrap()
+            .generation
+    };
+    let initial = generation(world);
+
+    world.batch_changes(|world| {
+        world.update_config::<Audio>(|audio| audio.music.set(10u32));
+        world.update_config::<Audio>(|audio| audio.music.set(20u32));
+        assert_eq!(generation(world), initial);
+    });
+
+    // Both writes are applied as a single bump.
+    assert_eq!(generation(world), initial.next());
+}
@@ -0,0 +1,42 @@
+use std::collections::HashSet;
+
+use bevy_ecs::system::{RunSystemOnce, SystemState};
+use bevy_mod_config::{AppExt, Config, ReadConfig, WorldExt};
+
+#[derive(Config)]
+#[config(changed_derive(Hash))]
+struct Video {
+    #[config(default = 1280)]
+    width: u32,
+    mode:  Mode,
+}
+
+#[derive(Config)]
+#[config(changed_derive(Hash))]
+enum Mode {
+    Windowed,
+    Fullscreen { monitor: u32 },
+}
+
+#[test]
+fn test_changed_debug_hash() {
+    let mut app = bevy_app::App::new();
+    app.init_config::<(), Video>("video");
+
+    let mut state = SystemState::<ReadConfig<Video>>::new(app.world_mut());
+    let before = state.get(app.world()).unwrap().changed();
+    assert!(format!("{before:?}").starts_with("VideoChanged { width: "));
+
+    app.world_mut().update_config::<Video>(|video| video.width.set(1920));
+    let after = state.get(app.world()).unwrap().changed();
+    assert_ne!(before, after);
+
+    let seen: HashSet<_> = [before.clone(), after, before].into_iter().collect();
+    assert_eq!(seen.len(), 2);
+
+    app.world_mut()
+        .run_system_once(|video: ReadConfig<Video>| {
+            assert!(format!("{:?}", video.changed()).contains("mode: Windowed"));
+        })
+        .unwrap();
+}
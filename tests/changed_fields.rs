@@ -0,0 +1,67 @@
+use bevy_ecs::system::SystemState;
+use bevy_mod_config::{AppExt, ChangedFields, Config, ReadConfigChange, WorldExt};
+
+#[derive(Config)]
+struct Settings {
+    volume: f32,
+    window: Window,
+}
+
+#[derive(Config)]
+struct Window {
+    width:  u32,
+    height: u32,
+}
+
+#[test]
+fn test_consume_changes() {
+    let mut app = bevy_app::App::new();
+    app.init_config::<(), Settings>("settings");
+
+    let mut state = SystemState::<ReadConfigChange<Settings>>::new(app.world_mut());
+    let mut consume = |app: &mut bevy_app::App| -> ChangedFields {
+        let changes = state.get_mut(app.world_mut()).unwrap().consume_changes();
+        state.apply(app.world_mut());
+        changes
+    };
+
+    let changes = consume(&mut app);
+    assert_eq!(changes.iter().count(), 3);
+    assert!(consume(&mut app).is_empty());
+
+    app.world_mut().update_config::<Settings>(|settings| settings.window.height.set(600));
+    let changes = consume(&mut app);
+    assert_eq!(changes.iter().collect::<Vec<_>>(), [["window", "height"]]);
+    assert!(changes.contains("window"));
+    assert!(changes.contains("window.height"));
+    assert!(!changes.contains("window.width"));
+    assert!(!changes.contains("volume"));
+    assert!(changes.contains(""));
+
+    assert!(consume(&mut app).is_empty());
+}
+
+#[derive(Config)]
+struct Audio {
+    volume: f32,
+}
+
+#[test]
+fn test_consume_changes_independent() {
+    let mut app = bevy_app::App::new();
+    app.init_config::<(), Settings>("settings");
+    app.init_config::<(), Audio>("audio");
+
+    let mut state = SystemState::<ReadConfigChange<Settings>>::new(app.world_mut());
+    assert!(state.get_mut(app.world_mut()).unwrap().consume_change());
+    assert_eq!(state.get_mut(app.world_mut()).unwrap().consume_changes().iter().count(), 3);
+
+    app.world_mut().update_config::<Audio>(|audio| audio.volume.set(0.5));
+    assert!(!state.get_mut(app.world_mut()).unwrap().consume_change());
+    assert!(state.get_mut(app.world_mut()).unwrap().consume_changes().is_empty());
+
+    app.world_mut().update_config::<Settings>(|settings| settings.volume.set(0.5));
+    assert!(state.get_mut(app.world_mut()).unwrap().consume_change());
+    let changes = state.get_mut(app.world_mut()).unwrap().consume_changes();
+    assert_eq!(changes.iter().collect::<Vec<_>>(), [["volume"]]);
+}
@@ -0,0 +1,41 @@
+#![cfg(all(feature = "chrono", feature = "serde_json"))]
+
+use bevy_ecs::system::RunSystemOnce;
+use bevy_mod_config::manager::serde::Json;
+use bevy_mod_config::{AppExt, Config, ReadConfig, manager};
+use chrono::{DateTime, NaiveDate, NaiveTime, Utc};
+
+#[derive(Config)]
+struct Schedule {
+    #[config(default = NaiveTime::from_hms_opt(4, 0, 0).unwrap())]
+    daily_reset: NaiveTime,
+    #[config(default = NaiveDate::from_ymd_opt(2024, 12, 24).unwrap())]
+    event_start: NaiveDate,
+    maintenance: DateTime<Utc>,
+}
+
+#[test]
+fn test_chrono_rfc3339() {
+    let mut app = bevy_app::App::new();
+    app.init_config_with::<Json, Schedule>("schedule", Json::new);
+
+    let json = app.world().resource::<manager::Instance<Json>>().instance.clone();
+    assert_eq!(
+        json.to_string(app.world_mut()).unwrap(),
+        r#"{"schedule.daily_reset":"04:00:00","schedule.event_start":"2024-12-24","schedule.maintenance":"1970-01-01T00:00:00Z"}"#
+    );
+
+    json.from_reader(
+        app.world_mut(),
+        r#"{"schedule.daily_reset":"05:30:00","schedule.maintenance":"2025-03-01T12:00:00+02:00"}"#
+            .as_bytes(),
+    )
+    .unwrap();
+    app.world_mut()
+        .run_system_once(|schedule: ReadConfig<Schedule>| {
+            let schedule = schedule.read();
+            assert_eq!(schedule.daily_reset, NaiveTime::from_hms_opt(5, 30, 0).unwrap());
+            assert_eq!(schedule.maintenance.to_rfc3339(), "2025-03-01T10:00:00+00:00");
+        })
+        .unwrap();
+}
@@ -0,0 +1,214 @@
+use bevy_ecs::system::RunSystemOnce;
+use bevy_mod_config::{AppExt, Config, ConfigField, ConfigNode, ReadConfig, WorldExt};
+
+#[derive(Config)]
+#[config(expose(read))]
+struct Scene {
+    #[config(default = 2)]
+    layers: u32,
+    #[config(b.y.default = 3.0)]
+    line:   Line,
+}
+
+#[derive(Config)]
+#[config(expose(read))]
+struct Line {
+    a:         Point,
+    b:         Point,
+    #[config(default = 1.0, min = 0.5, max = 4.0)]
+    thickness: f32,
+}
+
+#[derive(Config, Clone, Debug, PartialEq)]
+#[config(compact, expose(read))]
+struct Point {
+    x: f32,
+    #[config(default = 1.0)]
+    y: f32,
+}
+
+fn read_line(app: &mut bevy_app::App) -> (Point, Point, f32) {
+    app.world_mut()
+        .run_system_once(|scene: ReadConfig<Scene>| {
+            let line = scene.read().line;
+            (
+                <Point as ConfigField>::to_owned(line.a),
+                <Point as ConfigField>::to_owned(line.b),
+                line.thickness,
+            )
+        })
+        .unwrap()
+}
+
+#[test]
+fn test_compact_read() {
+    let mut app = bevy_app::App::new();
+    app.init_config::<(), Scene>("scene");
+
+    app.world_mut()
+        .run_system_once(|scene: ReadConfig<Scene>| {
+            let scene = scene.read();
+            assert_eq!(scene.layers, 2);
+            let PointRead { x, y } = scene.line.a;
+            assert_eq!((x, y), (0.0, 1.0));
+            let PointRead { x, y } = scene.line.b;
+            assert_eq!((x, y), (0.0, 3.0));
+            assert_eq!(scene.line.thickness, 1.0);
+        })
+        .unwrap();
+}
+
+#[test]
+fn test_compact_single_node() {
+    let mut app = bevy_app::App::new();
+    app.init_config::<(), Scene>("scene");
+
+    let mut paths: Vec<_> = app
+        .world_mut()
+        .query::<&ConfigNode>()
+        .iter(app.world())
+        .map(|node| node.path.join("."))
+        .collect();
+    paths.sort();
+    assert_eq!(
+        paths,
+        [
+            "scene",
+            "scene.layers",
+            "scene.line",
+            "scene.line.a",
+            "scene.line.b",
+            "scene.line.thickness"
+        ]
+    );
+}
+
+#[test]
+fn test_compact_update() {
+    let mut app = bevy_app::App::new();
+    app.init_config::<(), Scene>("scene");
+
+    app.world_mut().update_config::<Scene>(|scene| {
+        scene.line.a.update(|a| a.x = 5.0);
+        scene.line.b.set(Point { x: -1.0, y: -2.0 });
+    });
+    assert_eq!(read_line(&mut app), (Point { x: 5.0, y: 1.0 }, Point { x: -1.0, y: -2.0 }, 1.0));
+}
+
+#[test]
+fn test_compact_changed() {
+    let mut app = bevy_app::App::new();
+    app.init_config::<(), Scene>("scene");
+
+    let generation = |app: &mut bevy_app::App, path: &'static str| {
+        app.world_mut()
+            .query::<&ConfigNode>()
+            .iter(app.world())
+            .find(|node| node.path.join(".") == path)
+            .unwrap()
+            .generation
+    };
+    let a = generation(&mut app, "scene.line.a");
+    let b = generation(&mut app, "scene.line.b");
+
+    app.world_mut().update_config::<Scene>(|scene| scene.line.a.update(|a| a.y = 7.0));
+    assert_ne!(generation(&mut app, "scene.line.a"), a);
+    assert_eq!(generation(&mut app, "scene.line.b"), b);
+}
+
+#[derive(Config, Clone)]
+#[config(compact)]
+struct Clamped {
+    #[config(default = 5, min = 0, max = 10)]
+    level: i32,
+}
+
+#[derive(Config)]
+struct Difficulty {
+    clamped: Clamped,
+}
+
+#[test]
+fn test_compact_constrained() {
+    let mut app = bevy_app::App::new();
+    app.init_config::<(), Difficulty>("difficulty");
+    app.world_mut()
+        .update_config::<Difficulty>(|difficulty| difficulty.clamped.set(Clamped { level: 25 }));
+
+    let level = app
+        .world_mut()
+        .run_system_once(|difficulty: ReadConfig<Difficulty>| difficulty.read().clamped.level)
+        .unwrap();
+    assert_eq!(level, 10);
+}
+
+#[derive(Config, Clone)]
+#[config(compact)]
+struct Range(#[config(default = 1)] u32, #[config(default_fn = |_: &_| 20)] u32);
+
+#[derive(Config)]
+struct Ranges {
+    first:  Range,
+    second: Range,
+}
+
+#[test]
+fn test_compact_default_fn() {
+    let mut app = bevy_app::App::new();
+    app.init_config::<(), Ranges>("ranges");
+
+    let ranges = app
+        .world_mut()
+        .run_system_once(|ranges: ReadConfig<Ranges>| {
+            let ranges = ranges.read();
+            (ranges.first.0, ranges.first.1, ranges.second.0, ranges.second.1)
+        })
+        .unwrap();
+    assert_eq!(ranges, (1, 20, 1, 20));
+}
+
+#[cfg(feature = "serde_json")]
+#[derive(Config)]
+struct Waypoints {
+    start: Waypoint,
+    end:   Waypoint,
+}
+
+#[cfg(feature = "serde_json")]
+#[derive(Config, Clone, serde::Serialize, serde::Deserialize)]
+#[config(compact)]
+struct Waypoint {
+    #[config(default = 1)]
+    x:     i32,
+    label: String,
+}
+
+#[cfg(feature = "serde_json")]
+#[test]
+fn test_compact_json() {
+    use std::io::Cursor;
+
+    use bevy_mod_config::manager::serde::Json;
+    use bevy_mod_config::manager::{self};
+
+    let mut app = bevy_app::App::new();
+    app.init_config::<Json, Waypoints>("waypoints");
+    let json = app.world().resource::<manager::Instance<Json>>().instance.clone();
+
+    let data = json.to_string(app.world_mut()).unwrap();
+    assert_eq!(
+        data,
+        r#"{"waypoints.end":{"x":1,"label":""},"waypoints.start":{"x":1,"label":""}}"#
+    );
+
+    json.from_reader(app.world_mut(), Cursor::new(r#"{"waypoints.end":{"x":4,"label":"goal"}}"#))
+        .unwrap();
+    let end = app
+        .world_mut()
+        .run_system_once(|waypoints: ReadConfig<Waypoints>| {
+            let end = waypoints.read().end;
+            (end.x, end.label.to_owned())
+        })
+        .unwrap();
+    assert_eq!(end, (4, String::from("goal")));
+}
@@ -0,0 +1,49 @@
+use bevy_ecs::system::RunSystemOnce;
+use bevy_mod_config::{AppExt, Config, ConfigNode, ReadConfig, WorldExt};
+
+#[derive(Config)]
+#[config(expose(owned))]
+struct Resolution {
+    #[config(default = 1920)]
+    width:        u32,
+    #[config(default = 1080)]
+    height:       u32,
+    #[config(computed = width as f32 / height as f32)]
+    aspect_ratio: f32,
+    #[config(computed = aspect_ratio > 1.0)]
+    landscape:    bool,
+}
+
+#[test]
+fn test_computed() {
+    let mut app = bevy_app::App::new();
+    app.init_config::<(), Resolution>("resolution");
+
+    app.world_mut()
+        .run_system_once(|resolution: ReadConfig<Resolution>| {
+            let resolution = resolution.read();
+            assert_eq!(resolution.aspect_ratio, 1920.0 / 1080.0);
+            assert!(resolution.landscape);
+        })
+        .unwrap();
+
+    app.world_mut().update_config::<Resolution>(|resolution| resolution.width.set(720));
+    app.world_mut()
+        .run_system_once(|resolution: ReadConfig<Resolution>| {
+            let owned: ResolutionOwned = resolution.read().to_owned();
+            assert_eq!(owned.aspect_ratio, 720.0 / 1080.0);
+            assert!(!owned.landscape);
+        })
+        .unwrap();
+}
+
+#[test]
+fn test_computed_not_spawned() {
+    let mut app = bevy_app::App::new();
+    app.init_config::<(), Resolution>("resolution");
+
+    let mut query = app.world_mut().query::<&ConfigNode>();
+    let mut paths: Vec<_> = query.iter(app.world()).map(|node| node.path.join(".")).collect();
+    paths.sort();
+    assert_eq!(paths, ["resolution", "resolution.height", "resolution.width"]);
+}
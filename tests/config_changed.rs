@@ -0,0 +1,109 @@
+use bevy_ecs::change_detection::DetectChangesMut;
+use bevy_ecs::entity::Entity;
+use bevy_ecs::observer::On;
+use bevy_ecs::query::With;
+use bevy_ecs::resource::Resource;
+use bevy_ecs::system::{Query, ResMut};
+use bevy_mod_config::{AppExt, ChangeBatch, Config, ConfigChanged, ConfigNode, RootNode, WorldExt};
+
+#[derive(Config)]
+struct Settings {
+    volume: f32,
+    window: Window,
+}
+
+#[derive(Config)]
+struct Window {
+    width:  u32,
+    height: u32,
+}
+
+#[derive(Resource, Default)]
+struct Observed(Vec<String>);
+
+fn field_entity(app: &mut bevy_app::App, path: &[&str]) -> Entity {
+    let mut query = app.world_mut().query::<(Entity, &ConfigNode)>();
+    query.iter(app.world()).find(|(_, node)| node.path == path).unwrap().0
+}
+
+#[test]
+fn test_observe_root() {
+    let mut app = bevy_app::App::new();
+    app.init_config::<(), Settings>("settings");
+    app.init_resource::<Observed>();
+
+    let root =
+        app.world_mut().query_filtered::<Entity, With<RootNode>>().single(app.world()).unwrap();
+    app.world_mut().entity_mut(root).observe(
+        |changed: On<ConfigChanged>, nodes: Query<&ConfigNode>, mut observed: ResMut<Observed>| {
+            observed.0.push(nodes.get(changed.field).unwrap().path.join("."));
+        },
+    );
+
+    app.update();
+    assert!(app.world().resource::<Observed>().0.is_empty());
+
+    app.world_mut().update_config::<Settings>(|settings| {
+        settings.window.width.set(800);
+        settings.volume.set(0.5);
+    });
+    app.update();
+
+    let mut observed = app.world().resource::<Observed>().0.clone();
+    observed.sort();
+    assert_eq!(observed, ["settings.volume", "settings.window.width"]);
+}
+
+#[test]
+fn test_observe_subtree() {
+    let mut app = bevy_app::App::new();
+    app.init_config::<(), Settings>("settings");
+    app.init_resource::<Observed>();
+
+    let window = field_entity(&mut app, &["settings", "window"]);
+    app.world_mut().entity_mut(window).observe(
+        |changed: On<ConfigChanged>, nodes: Query<&ConfigNode>, mut observed: ResMut<Observed>| {
+            observed.0.push(nodes.get(changed.field).unwrap().path.join("."));
+        },
+    );
+    app.update();
+
+    app.world_mut().update_config::<Settings>(|settings| {
+        settings.window.height.set(600);
+        settings.volume.set(0.5);
+    });
+    app.update();
+
+    assert_eq!(app.world().resource::<Observed>().0, ["settings.window.height"]);
+}
+
+#[test]
+fn test_ignore_unchanged_generation() {
+    let mut app = bevy_app::App::new();
+    app.init_config::<(), Settings>("settings");
+    app.init_resource::<Observed>();
+
+    let volume = field_entity(&mut app, &["settings", "volume"]);
+    app.world_mut().entity_mut(volume).observe(
+        |changed: On<ConfigChanged>, nodes: Query<&ConfigNode>, mut observed: ResMut<Observed>| {
+            observed.0.push(nodes.get(changed.field).unwrap().path.join("."));
+        },
+    );
+    app.update();
+
+    // Mutable access without a bump is not a change.
+    app.world_mut().get_mut::<ConfigNode>(volume).unwrap().set_changed();
+    app.update();
+    assert!(app.world().resource::<Observed>().0.is_empty());
+
+    // A bump deferred across frames is only reported once it is applied.
+    app.world_mut().resource_mut::<ChangeBatch>().begin();
+    app.world_mut().update_config::<Settings>(|settings| settings.volume.set(0.5));
+    app.update();
+    assert!(app.world().resource::<Observed>().0.is_empty());
+
+    app.world_mut().resource_mut::<ChangeBatch>().end();
+    app.update();
+    app.update();
+    assert_eq!(app.world().resource::<Observed>().0, ["settings.volume"]);
+}
@@ -0,0 +1,49 @@
+#![cfg(feature = "egui")]
+
+use bevy_ecs::system::RunSystemOnce;
+use bevy_mod_config::manager::Egui;
+use bevy_mod_config::manager::egui::Display;
+use bevy_mod_config::{AppExt, Config, ReadConfig, WorldExt};
+
+#[derive(Config)]
+struct Audio {
+    #[config(default = 0.5)]
+    volume: f32,
+}
+
+#[derive(Config)]
+struct Video {
+    #[config(default = 60)]
+    fps: u32,
+}
+
+fn read(app: &mut bevy_app::App) -> (f32, u32) {
+    app.world_mut()
+        .run_system_once(|audio: ReadConfig<Audio>, video: ReadConfig<Video>| {
+            (audio.read().volume, video.read().fps)
+        })
+        .unwrap()
+}
+
+#[test]
+fn test_reset_all_in_root() {
+    let mut app = bevy_app::App::new();
+    app.init_config::<Egui, Audio>("audio");
+    app.init_config::<Egui, Video>("video");
+    app.update();
+
+    app.world_mut().update_config::<Audio>(|audio| audio.volume.set(0.2));
+    app.world_mut().update_config::<Video>(|video| video.fps.set(30));
+    app.update();
+    assert_eq!(read(&mut app), (0.2, 30));
+
+    app.world_mut()
+        .run_system_once(|mut display: Display| display.reset_all(Some("audio")))
+        .unwrap();
+    app.update();
+    assert_eq!(read(&mut app), (0.5, 30));
+
+    app.world_mut().run_system_once(|mut display: Display| display.reset_all(None)).unwrap();
+    app.update();
+    assert_eq!(read(&mut app), (0.5, 60));
+}
@@ -0,0 +1,79 @@
+#![cfg(feature = "serde_json")]
+
+use bevy_mod_config::manager::serde::json::{HashScope, Json};
+use bevy_mod_config::{AppExt, Config, WorldExt, manager};
+
+#[derive(Config)]
+struct Video {
+    #[config(default = 60)]
+    fps:   u32,
+    debug: Overlay,
+}
+
+#[derive(Config)]
+struct Overlay {
+    wireframe: bool,
+}
+
+#[derive(Config)]
+struct Audio {
+    #[config(default = 0.5)]
+    volume: f32,
+}
+
+fn new_app(audio_first: bool) -> (bevy_app::App, Json) {
+    let mut app = bevy_app::App::new();
+    if audio_first {
+        app.init_config_with::<Json, Audio>("audio", Json::new);
+        app.init_config_with::<Json, Video>("video", Json::new);
+    } else {
+        app.init_config_with::<Json, Video>("video", Json::new);
+        app.init_config_with::<Json, Audio>("audio", Json::new);
+    }
+    let json = app.world().resource::<manager::Instance<Json>>().instance.clone();
+    (app, json)
+}
+
+fn hash(app: &mut bevy_app::App, json: &Json, scope: &HashScope) -> u64 {
+    json.content_hash(app.world_mut(), scope).unwrap()
+}
+
+#[test]
+fn test_hash_is_stable() {
+    let (mut app1, json1) = new_app(false);
+    let (mut app2, json2) = new_app(true);
+    assert_eq!(
+        hash(&mut app1, &json1, &HashScope::all()),
+        hash(&mut app2, &json2, &HashScope::all())
+    );
+}
+
+#[test]
+fn test_hash_changes_with_values() {
+    let (mut app, json) = new_app(false);
+    let before = hash(&mut app, &json, &HashScope::all());
+
+    app.world_mut().update_config::<Video>(|video| video.fps.set(30));
+    let after = hash(&mut app, &json, &HashScope::all());
+    assert_ne!(before, after);
+
+    app.world_mut().update_config::<Video>(|video| video.fps.set(60));
+    assert_eq!(before, hash(&mut app, &json, &HashScope::all()));
+}
+
+#[test]
+fn test_hash_scope() {
+    let (mut app, json) = new_app(false);
+    let scope = HashScope::all().include("video").exclude("video.debug");
+    let audio_scope = HashScope::all().include("audio");
+    let before = hash(&mut app, &json, &scope);
+    let audio_before = hash(&mut app, &json, &audio_scope);
+
+    app.world_mut().update_config::<Video>(|video| video.debug.wireframe.set(true));
+    app.world_mut().update_config::<Audio>(|audio| audio.volume.set(1.0));
+    assert_eq!(before, hash(&mut app, &json, &scope));
+    assert_ne!(audio_before, hash(&mut app, &json, &audio_scope));
+
+    app.world_mut().update_config::<Video>(|video| video.fps.set(30));
+    assert_ne!(before, hash(&mut app, &json, &scope));
+}
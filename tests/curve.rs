@@ -0,0 +1,36 @@
+#![cfg(feature = "serde_json")]
+
+use bevy_ecs::system::RunSystemOnce;
+use bevy_mod_config::manager::serde::Json;
+use bevy_mod_config::types::Curve;
+use bevy_mod_config::{AppExt, Config, ReadConfig, manager};
+
+#[derive(Config)]
+struct Mouse {
+    #[config(default = Curve::new([[0.0, 0.0], [0.5, 0.25], [1.0, 1.0]]))]
+    acceleration: Curve,
+}
+
+#[test]
+fn test_curve() {
+    let mut app = bevy_app::App::new();
+    app.init_config_with::<Json, Mouse>("mouse", Json::new);
+
+    let json = app.world().resource::<manager::Instance<Json>>().instance.clone();
+    assert_eq!(
+        json.to_string(app.world_mut()).unwrap(),
+        r#"{"mouse.acceleration":[[0.0,0.0],[0.5,0.25],[1.0,1.0]]}"#
+    );
+
+    // Points are sorted by x when loaded.
+    json.from_reader(app.world_mut(), r#"{"mouse.acceleration":[[1,2],[0,0]]}"#.as_bytes())
+        .unwrap();
+    app.world_mut()
+        .run_system_once(|mouse: ReadConfig<Mouse>| {
+            let mouse = mouse.read();
+            assert_eq!(mouse.acceleration.points(), [[0.0, 0.0], [1.0, 2.0]]);
+            assert_eq!(mouse.acceleration.sample(0.25), 0.5);
+            assert_eq!(mouse.acceleration.sample(2.0), 2.0);
+        })
+        .unwrap();
+}
@@ -0,0 +1,162 @@
+#![cfg(feature = "debug_server")]
+
+use std::io::{Read, Write};
+use std::net::TcpStream;
+use std::thread;
+use std::time::{Duration, Instant};
+
+use bevy_mod_config::manager::serde::Json;
+use bevy_mod_config::manager::serde::server::{ConfigServer, ConfigServerPlugin};
+use bevy_mod_config::{AppExt, Config, Permission};
+
+#[derive(Config)]
+struct Player {
+    #[config(default = 5.0)]
+    speed:  f32,
+    flight: Flight,
+    #[config(permission = Permission::Admin, default = 3)]
+    lives:  u32,
+}
+
+#[derive(Config)]
+struct Flight {
+    enabled: bool,
+}
+
+fn new_app(plugin: ConfigServerPlugin) -> bevy_app::App {
+    let mut app = bevy_app::App::new();
+    app.init_config_with::<Json, Player>("player", Json::new);
+    app.add_plugins(plugin);
+    app
+}
+
+/// Sends a raw request and updates the app until the response is received.
+fn request(app: &mut bevy_app::App, request: &str) -> String {
+    let addr = app.world().resource::<ConfigServer>().local_addr().unwrap();
+    let request = request.to_owned();
+    let client = thread::spawn(move || {
+        let mut stream = TcpStream::connect(addr).unwrap();
+        stream.write_all(request.as_bytes()).unwrap();
+        let mut response = String::new();
+        stream.read_to_string(&mut response).unwrap();
+        response
+    });
+    while !client.is_finished() {
+        app.update();
+        thread::sleep(Duration::from_millis(1));
+    }
+    client.join().unwrap()
+}
+
+fn body(response: &str) -> &str { response.split_once("\r\n\r\n").unwrap().1 }
+
+#[test]
+fn test_read_config() {
+    let mut app = new_app(ConfigServerPlugin::new("127.0.0.1:0"));
+
+    let response = request(&mut app, "GET /config HTTP/1.1\r\n\r\n");
+    assert!(response.starts_with("HTTP/1.1 200 OK"));
+    assert!(!response.contains("Access-Control-Allow-Origin"));
+    let values: serde_json::Value = serde_json::from_str(body(&response)).unwrap();
+    assert_eq!(
+        values,
+        serde_json::json!({"player.flight.enabled": false, "player.lives": 3, "player.speed": 5.0})
+    );
+
+    let response = request(&mut app, "GET /config/player.speed HTTP/1.1\r\n\r\n");
+    assert_eq!(body(&response), "5.0");
+
+    let response = request(&mut app, "GET /config/player.jump HTTP/1.1\r\n\r\n");
+    assert!(response.starts_with("HTTP/1.1 404 Not Found"));
+
+    let response =
+        request(&mut app, "PUT /config/player.speed HTTP/1.1\r\nContent-Length: 3\r\n\r\n8.0");
+    assert!(response.starts_with("HTTP/1.1 403 Forbidden"));
+}
+
+#[test]
+fn test_write_config() {
+    let mut app = new_app(ConfigServerPlugin::new("127.0.0.1:0").writable());
+
+    let response = request(
+        &mut app,
+        "PUT /config/player.flight.enabled HTTP/1.1\r\nContent-Length: 4\r\n\r\ntrue",
+    );
+    assert!(response.starts_with("HTTP/1.1 204 No Content"));
+    let response = request(&mut app, "GET /config/player.flight.enabled HTTP/1.1\r\n\r\n");
+    assert_eq!(body(&response), "true");
+
+    let response =
+        request(&mut app, "PUT /config/player.speed HTTP/1.1\r\nContent-Length: 6\r\n\r\n\"fast\"");
+    assert!(response.starts_with("HTTP/1.1 400 Bad Request"));
+}
+
+#[test]
+fn test_write_permission() {
+    let mut app = new_app(ConfigServerPlugin::new("127.0.0.1:0").writable());
+    let response =
+        request(&mut app, "PUT /config/player.lives HTTP/1.1\r\nContent-Length: 1\r\n\r\n9");
+    assert!(response.starts_with("HTTP/1.1 403 Forbidden"));
+    let response = request(&mut app, "GET /config/player.lives HTTP/1.1\r\n\r\n");
+    assert_eq!(body(&response), "3");
+
+    let response =
+        request(&mut app, "PUT /config/player.jump HTTP/1.1\r\nContent-Length: 1\r\n\r\n9");
+    assert!(response.starts_with("HTTP/1.1 404 Not Found"));
+    let response =
+        request(&mut app, "PUT /config/player.flight HTTP/1.1\r\nContent-Length: 2\r\n\r\n{}");
+    assert!(response.starts_with("HTTP/1.1 404 Not Found"));
+
+    let mut app =
+        new_app(ConfigServerPlugin::new("127.0.0.1:0").writable().role(Permission::Admin));
+    let response =
+        request(&mut app, "PUT /config/player.lives HTTP/1.1\r\nContent-Length: 1\r\n\r\n9");
+    assert!(response.starts_with("HTTP/1.1 204 No Content"));
+    let response = request(&mut app, "GET /config/player.lives HTTP/1.1\r\n\r\n");
+    assert_eq!(body(&response), "9");
+}
+
+#[test]
+fn test_percent_encoded_path() {
+    let mut app = new_app(ConfigServerPlugin::new("127.0.0.1:0"));
+    let response = request(&mut app, "GET /config/player%2Espeed HTTP/1.1\r\n\r\n");
+    assert_eq!(body(&response), "5.0");
+
+    let response = request(&mut app, "GET /config/player%2 HTTP/1.1\r\n\r\n");
+    assert!(response.starts_with("HTTP/1.1 400 Bad Request"));
+}
+
+#[test]
+fn test_slow_client_does_not_block() {
+    let mut app = new_app(ConfigServerPlugin::new("127.0.0.1:0"));
+    let addr = app.world().resource::<ConfigServer>().local_addr().unwrap();
+
+    let mut stream = TcpStream::connect(addr).unwrap();
+    stream.write_all(b"GET /config/player.speed HTTP/1.1\r\n").unwrap();
+    let start = Instant::now();
+    for _ in 0..10 {
+        app.update();
+    }
+    assert!(start.elapsed() < Duration::from_millis(200));
+
+    stream.write_all(b"\r\n").unwrap();
+    let client = thread::spawn(move || {
+        let mut response = String::new();
+        stream.read_to_string(&mut response).unwrap();
+        response
+    });
+    while !client.is_finished() {
+        app.update();
+        thread::sleep(Duration::from_millis(1));
+    }
+    assert_eq!(body(&client.join().unwrap()), "5.0");
+}
+
+#[test]
+fn test_bind_failure_disables_server() {
+    let app = new_app(ConfigServerPlugin::new("127.0.0.1:0"));
+    let addr = app.world().resource::<ConfigServer>().local_addr().unwrap();
+
+    let app = new_app(ConfigServerPlugin::new(addr.to_string()));
+    assert!(app.world().get_resource::<ConfigServer>().is_none());
+}
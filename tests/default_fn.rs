@@ -0,0 +1,51 @@
+use bevy_ecs::resource::Resource;
+use bevy_ecs::system::RunSystemOnce;
+use bevy_ecs::world::World;
+use bevy_mod_config::{AppExt, Config, ReadConfig};
+
+#[derive(Config)]
+struct Settings {
+    #[config(default_fn = available_threads, min = 1)]
+    worker_threads: u32,
+    #[config(default_fn = refresh_rate)]
+    fps_limit:      u32,
+    #[config(default = 2)]
+    msaa:           u32,
+}
+
+#[derive(Resource)]
+struct Display {
+    hz: u32,
+}
+
+fn available_threads() -> u32 { 6 }
+
+fn refresh_rate(world: &World) -> u32 { world.get_resource::<Display>().map_or(60, |d| d.hz) }
+
+#[test]
+fn test_default_fn() {
+    let mut app = bevy_app::App::new();
+    app.insert_resource(Display { hz: 144 });
+    app.init_config::<(), Settings>("settings");
+
+    app.world_mut()
+        .run_system_once(|settings: ReadConfig<Settings>| {
+            let settings = settings.read();
+            assert_eq!(settings.worker_threads, 6);
+            assert_eq!(settings.fps_limit, 144);
+            assert_eq!(settings.msaa, 2);
+        })
+        .unwrap();
+}
+
+#[test]
+fn test_default_fn_without_resource() {
+    let mut app = bevy_app::App::new();
+    app.init_config::<(), Settings>("settings");
+
+    app.world_mut()
+        .run_system_once(|settings: ReadConfig<Settings>| {
+            assert_eq!(settings.read().fps_limit, 60);
+        })
+        .unwrap();
+}
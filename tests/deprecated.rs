@@ -0,0 +1,92 @@
+#![cfg(feature = "serde_json")]
+
+use bevy_ecs::system::RunSystemOnce;
+use bevy_mod_config::manager::serde::Json;
+use bevy_mod_config::{AppExt, Config, ReadConfig, manager};
+
+#[derive(Config)]
+struct Video {
+    #[config(deprecated(replaced_by = "vsync_mode", map = vsync_mode_from_bool))]
+    vsync:      bool,
+    #[config(default = 1)]
+    vsync_mode: u8,
+    #[config(deprecated(replaced_by = "display.scale"))]
+    ui_scale:   f32,
+    display:    Display,
+    #[config(deprecated)]
+    legacy_gl:  bool,
+}
+
+#[derive(Config)]
+struct Display {
+    #[config(default = 1.0)]
+    scale: f32,
+}
+
+fn vsync_mode_from_bool(vsync: bool) -> u8 { u8::from(vsync) }
+
+#[test]
+fn test_deprecated_migration() {
+    let mut app = bevy_app::App::new();
+    app.init_config_with::<Json, Video>("video", Json::new);
+    let json = app.world().resource::<manager::Instance<Json>>().instance.clone();
+
+    json.from_reader(
+        app.world_mut(),
+        r#"{"video.vsync":false,"video.ui_scale":1.5,"video.legacy_gl":true}"#.as_bytes(),
+    )
+    .unwrap();
+    app.world_mut()
+        .run_system_once(|video: ReadConfig<Video>| {
+            let video = video.read();
+            assert_eq!(video.vsync_mode, 0);
+            assert_eq!(video.display.scale, 1.5);
+        })
+        .unwrap();
+
+    assert_eq!(
+        json.to_string(app.world_mut()).unwrap(),
+        r#"{"video.display.scale":1.5,"video.vsync_mode":0}"#
+    );
+}
+
+#[test]
+fn test_replacement_takes_precedence() {
+    let mut app = bevy_app::App::new();
+    app.init_config_with::<Json, Video>("video", Json::new);
+    let json = app.world().resource::<manager::Instance<Json>>().instance.clone();
+
+    json.from_reader(app.world_mut(), r#"{"video.vsync":false,"video.vsync_mode":2}"#.as_bytes())
+        .unwrap();
+    app.world_mut()
+        .run_system_once(|video: ReadConfig<Video>| assert_eq!(video.read().vsync_mode, 2))
+        .unwrap();
+}
+
+#[derive(Config)]
+struct Broken {
+    #[config(deprecated(replaced_by = "missing"))]
+    unresolved: u32,
+    #[config(deprecated(replaced_by = "ratio"))]
+    mismatched: u32,
+    #[config(default = 0.5)]
+    ratio:      f32,
+}
+
+#[test]
+fn test_failed_migration() {
+    let mut app = bevy_app::App::new();
+    app.init_config_with::<Json, Broken>("broken", Json::new);
+    let json = app.world().resource::<manager::Instance<Json>>().instance.clone();
+    let input = r#"{"broken.unresolved":1,"broken.mismatched":2}"#;
+
+    assert!(json.from_reader(app.world_mut(), input.as_bytes()).is_err());
+
+    let errors = json.from_reader_lenient(app.world_mut(), input.as_bytes()).unwrap();
+    let mut paths: Vec<_> = errors.iter().map(|err| err.path.join(".")).collect();
+    paths.sort();
+    assert_eq!(paths, ["broken.mismatched", "broken.unresolved"]);
+    app.world_mut()
+        .run_system_once(|broken: ReadConfig<Broken>| assert_eq!(broken.read().ratio, 0.5))
+        .unwrap();
+}
@@ -0,0 +1,72 @@
+#![cfg(feature = "bevy_diagnostic")]
+
+use bevy_diagnostic::{DiagnosticPath, DiagnosticsPlugin, DiagnosticsStore};
+use bevy_mod_config::manager::diagnostics::{self, Diagnostics};
+use bevy_mod_config::{AppExt, Config, WorldExt};
+
+#[derive(Config)]
+struct Camera {
+    #[config(default = 90.0, diagnostic = true)]
+    fov:   f32,
+    #[config(default = 1.0)]
+    speed: f32,
+}
+
+#[test]
+fn test_publish_values() {
+    let mut app = bevy_app::App::new();
+    app.add_plugins(DiagnosticsPlugin);
+    app.init_config::<Diagnostics, Camera>("camera");
+
+    app.update();
+    app.update();
+
+    let store = app.world().resource::<DiagnosticsStore>();
+    let fov = store.get(&DiagnosticPath::new("config/camera/fov")).unwrap();
+    assert_eq!(fov.value(), Some(90.0));
+    assert!(store.get(&DiagnosticPath::new("config/camera/speed")).is_none());
+}
+
+#[test]
+fn test_publish_changes() {
+    let mut app = bevy_app::App::new();
+    app.add_plugins(DiagnosticsPlugin);
+    app.init_config::<Diagnostics, Camera>("camera");
+    app.update();
+
+    app.world_mut().update_config::<Camera>(|camera| {
+        camera.fov.set(100.0);
+        camera.speed.set(2.0);
+    });
+    app.update();
+
+    let store = app.world().resource::<DiagnosticsStore>();
+    assert_eq!(store.get(&diagnostics::CHANGES).unwrap().value(), Some(2.0));
+}
+
+#[test]
+fn test_without_diagnostics_plugin() {
+    let mut app = bevy_app::App::new();
+    app.init_config::<Diagnostics, Camera>("camera");
+    app.update();
+    app.update();
+    assert!(app.world().get_resource::<DiagnosticsStore>().is_none());
+}
+
+#[test]
+fn test_publish_after_metadata_change() {
+    let mut app = bevy_app::App::new();
+    app.add_plugins(DiagnosticsPlugin);
+    app.init_config::<Diagnostics, Camera>("camera");
+    app.update();
+
+    app.world_mut().update_config::<Camera>(|camera| {
+        camera.speed.update_metadata(|metadata| metadata.diagnostic = true);
+    });
+    app.update();
+    app.update();
+
+    let store = app.world().resource::<DiagnosticsStore>();
+    let speed = store.get(&DiagnosticPath::new("config/camera/speed")).unwrap();
+    assert_eq!(speed.value(), Some(1.0));
+}
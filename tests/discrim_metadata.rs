@@ -0,0 +1,40 @@
+use bevy_mod_config::{AppExt, Config, ScalarMetadata};
+
+#[derive(Config)]
+#[config(expose(discrim), discrim(default = QualityDiscrim::Medium))]
+enum Quality {
+    Low,
+    Medium,
+    High,
+}
+
+#[derive(Config)]
+struct Graphics {
+    shadows:  Quality,
+    #[config(discrim.default = QualityDiscrim::High)]
+    textures: Quality,
+}
+
+#[test]
+fn test_discrim_metadata_nameable() {
+    let metadata = QualityDiscrimMetadata::default();
+    assert_eq!(metadata.default, QualityDiscrim::Medium);
+}
+
+#[test]
+fn test_discrim_metadata_downcast() {
+    let mut app = bevy_app::App::new();
+    app.init_config::<(), Graphics>("graphics");
+    app.update();
+
+    let mut query = app.world_mut().query::<&ScalarMetadata<QualityDiscrim>>();
+    let mut defaults: Vec<_> = query
+        .iter(app.world())
+        .map(|metadata| {
+            let metadata: &QualityDiscrimMetadata = &metadata.0;
+            metadata.default
+        })
+        .collect();
+    defaults.sort_by_key(|&discrim| discrim as u8);
+    assert_eq!(defaults, [QualityDiscrim::Medium, QualityDiscrim::High]);
+}
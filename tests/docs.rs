@@ -0,0 +1,97 @@
+use bevy_mod_config::manager::docs::{self, Docs, FieldDoc};
+use bevy_mod_config::{AppExt, Config};
+
+#[derive(Config)]
+struct Server {
+    /// The maximum number of connected players.
+    #[config(default = 16, min = 1, max = 64)]
+    max_players: u32,
+    /// Shown in the server browser.
+    ///
+    /// Pipes | are escaped.
+    #[config(default = "Lobby", max_length = Some(32))]
+    name:        String,
+    mode:        Mode,
+}
+
+#[derive(Config)]
+enum Mode {
+    Coop,
+    Versus {
+        #[config(default = 0.5)]
+        ratio: f32,
+    },
+}
+
+#[derive(Config)]
+struct Audio {
+    #[config(default = true)]
+    enabled: bool,
+}
+
+fn new_app() -> bevy_app::App {
+    let mut app = bevy_app::App::new();
+    app.init_config::<Docs, Server>("server");
+    app.init_config::<Docs, Audio>("audio");
+    app
+}
+
+#[test]
+fn test_collect() {
+    let mut app = new_app();
+    let docs = docs::collect(app.world_mut());
+    let paths: Vec<_> = docs.iter().map(|doc| doc.path.join(".")).collect();
+    assert_eq!(
+        paths,
+        [
+            "audio.enabled",
+            "server.max_players",
+            "server.name",
+            "server.mode.discrim",
+            "server.mode.Versus.ratio",
+        ]
+    );
+
+    assert_eq!(
+        docs[1],
+        FieldDoc {
+            path:        vec!["server".into(), "max_players".into()],
+            type_name:   "u32".into(),
+            default:     "16".into(),
+            constraints: Some("1 ..= 64".into()),
+            description: Some("The maximum number of connected players."),
+        }
+    );
+    assert_eq!(docs[2].type_name, "String");
+    assert_eq!(docs[2].default, "\"Lobby\"");
+    assert_eq!(docs[2].constraints.as_deref(), Some("at most 32 characters"));
+    assert_eq!(docs[3].type_name, "enum");
+    assert_eq!(docs[3].default, "Coop");
+    assert_eq!(docs[3].constraints.as_deref(), Some("Coop, Versus"));
+    assert_eq!(docs[4].constraints, None);
+}
+
+#[test]
+fn test_markdown() {
+    let mut app = new_app();
+    let markdown = docs::to_markdown(app.world_mut());
+    assert_eq!(
+        markdown,
+        "## `audio`
+
+| Path | Type | Default | Constraints | Description |
+| --- | --- | --- | --- | --- |
+| `audio.enabled` | `bool` | `true` |  |  |
+
+## `server`
+
+| Path | Type | Default | Constraints | Description |
+| --- | --- | --- | --- | --- |
+| `server.max_players` | `u32` | `16` | 1 ..= 64 | The maximum number of connected players. |
+| `server.name` | `String` | `\"Lobby\"` | at most 32 characters | Shown in the server \
+         browser.<br><br>Pipes \\| are escaped. |
+| `server.mode.discrim` | `enum` | `Coop` | Coop, Versus |  |
+| `server.mode.Versus.ratio` | `f32` | `0.5` |  |  |
+"
+    );
+}
@@ -50,6 +50,8 @@ fn test_run() {
     dump_json(&mut app);
     #[cfg(feature = "serde_json")]
     load_json(&mut app);
+    #[cfg(feature = "serde_json")]
+    value_round_trip(&mut app);
 }
 
 #[cfg(feature = "serde_json")]
@@ -113,3 +115,27 @@ fn load_json(app: &mut bevy_app::App) {
         })
         .unwrap();
 }
+
+#[cfg(feature = "serde_json")]
+fn value_round_trip(app: &mut bevy_app::App) {
+    use bevy_ecs::system::RunSystemOnce;
+
+    let (json, _) = app
+        .world_mut()
+        .resource::<bevy_mod_config::manager::Instance<ManagerType>>()
+        .instance
+        .clone();
+
+    let mut value = json.to_value(app.world_mut()).unwrap();
+    assert_eq!(value["ui.thickness"], 5);
+    assert_eq!(value["ui.color.Named.code"], "red");
+
+    value["ui.thickness"] = 7.into();
+    json.from_value(app.world_mut(), value).unwrap();
+
+    app.world_mut()
+        .run_system_once(|settings: ReadConfig<Settings>| {
+            assert_eq!(settings.read().thickness, 7);
+        })
+        .unwrap();
+}
@@ -0,0 +1,37 @@
+#![cfg(feature = "serde_json")]
+
+use bevy_ecs::system::RunSystemOnce;
+use bevy_mod_config::manager::serde::Json;
+use bevy_mod_config::manager::serde::json::DynamicValues;
+use bevy_mod_config::{AppExt, Config};
+use serde_json::json;
+
+#[derive(Config)]
+struct Player {
+    #[config(default = 5.0)]
+    speed: f32,
+    name:  String,
+}
+
+#[test]
+fn test_dynamic_values() {
+    let mut app = bevy_app::App::new();
+    app.init_config_with::<Json, Player>("player", Json::new);
+
+    app.world_mut()
+        .run_system_once(|values: DynamicValues| {
+            let mut entries: Vec<_> = values
+                .iter()
+                .map(|entry| (entry.path.join("."), entry.type_name, entry.value.unwrap()))
+                .collect();
+            entries.sort_by(|a, b| a.0.cmp(&b.0));
+            assert_eq!(
+                entries,
+                [
+                    ("player.name".to_owned(), std::any::type_name::<String>(), json!("")),
+                    ("player.speed".to_owned(), "f32", json!(5.0)),
+                ]
+            );
+        })
+        .unwrap();
+}
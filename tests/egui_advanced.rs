@@ -0,0 +1,86 @@
+#![cfg(feature = "egui")]
+
+use bevy_ecs::system::In;
+use bevy_egui::egui;
+use bevy_mod_config::manager::Egui;
+use bevy_mod_config::manager::egui::Display;
+use bevy_mod_config::{AppExt, Config};
+
+#[derive(Config)]
+struct Settings {
+    fps_limit: u32,
+    #[config(advanced)]
+    renderer:  Renderer,
+}
+
+#[derive(Config)]
+struct Renderer {
+    worker_threads: u32,
+}
+
+fn show_settings(
+    In((ctx, input)): In<(egui::Context, egui::RawInput)>,
+    mut display: Display,
+) -> Vec<(String, egui::Pos2)> {
+    // Searching expands all groups, so that their fields are drawn.
+    display.options().search = "settings".into();
+    let output = ctx.run_ui(input, |ui| {
+        egui::CentralPanel::default().show_inside(ui, |ui| {
+            display.show(ui);
+        });
+    });
+
+    fn collect(shape: &egui::Shape, texts: &mut Vec<(String, egui::Pos2)>) {
+        match shape {
+            egui::Shape::Text(text) => texts.push((text.galley.text().to_owned(), text.pos)),
+            egui::Shape::Vec(shapes) => shapes.iter().for_each(|shape| collect(shape, texts)),
+            _ => {}
+        }
+    }
+    let mut texts = Vec::new();
+    output.shapes.iter().for_each(|clipped| collect(&clipped.shape, &mut texts));
+    texts
+}
+
+fn click_at(pos: egui::Pos2) -> [egui::RawInput; 2] {
+    let button = |pressed| egui::Event::PointerButton {
+        pos,
+        button: egui::PointerButton::Primary,
+        pressed,
+        modifiers: egui::Modifiers::NONE,
+    };
+    [
+        egui::RawInput {
+            events: vec![egui::Event::PointerMoved(pos), button(true)],
+            ..Default::default()
+        },
+        egui::RawInput { events: vec![button(false)], ..Default::default() },
+    ]
+}
+
+#[test]
+fn test_show_advanced_checkbox() {
+    let mut app = bevy_app::App::new();
+    app.init_config::<Egui, Settings>("settings");
+    let system = app.register_system(show_settings);
+    let ctx = egui::Context::default();
+    let mut show = |input| app.world_mut().run_system_with(system, (ctx.clone(), input)).unwrap();
+
+    // The first pass lets the group headers settle their open state.
+    show(egui::RawInput::default());
+    let texts = show(egui::RawInput::default());
+    assert!(texts.iter().any(|(text, _)| text == "fps_limit"));
+    assert!(!texts.iter().any(|(text, _)| text == "worker_threads"));
+
+    let (_, checkbox) = texts
+        .iter()
+        .find(|(text, _)| text == "Show advanced")
+        .expect("the editor shows a checkbox for advanced fields");
+    for input in click_at(*checkbox + egui::vec2(2.0, 2.0)) {
+        show(input);
+    }
+
+    show(egui::RawInput::default());
+    let texts = show(egui::RawInput::default());
+    assert!(texts.iter().any(|(text, _)| text == "worker_threads"));
+}
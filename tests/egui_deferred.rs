@@ -0,0 +1,57 @@
+#![cfg(feature = "egui")]
+
+use bevy_ecs::system::{In, RunSystemOnce};
+use bevy_egui::egui;
+use bevy_mod_config::manager::Egui;
+use bevy_mod_config::manager::egui::DeferredDisplay;
+use bevy_mod_config::manager::egui::nav::NavAction;
+use bevy_mod_config::{AppExt, Config, ReadConfig};
+
+#[derive(Config)]
+struct Settings {
+    count: u32,
+}
+
+/// Shows the deferred editor while reading the same config in the same system,
+/// returning the value read before and after showing the editor.
+fn show_settings(
+    In((ctx, action)): In<(egui::Context, NavAction)>,
+    mut display: DeferredDisplay,
+    settings: ReadConfig<Settings>,
+) -> (u32, u32) {
+    // Searching expands the root group, so that its fields can be focused.
+    display.options().search = "settings".into();
+    let before = settings.read().count;
+    _ = ctx.run_ui(egui::RawInput::default(), |ui| {
+        action.apply(ui.ctx());
+        egui::CentralPanel::default().show_inside(ui, |ui| {
+            display.show(ui);
+        });
+    });
+    (before, settings.read().count)
+}
+
+fn read_count(app: &mut bevy_app::App) -> u32 {
+    app.world_mut().run_system_once(|settings: ReadConfig<Settings>| settings.read().count).unwrap()
+}
+
+#[test]
+fn test_deferred_edit() {
+    let mut app = bevy_app::App::new();
+    app.init_config::<Egui, Settings>("settings");
+    let system = app.register_system(show_settings);
+    let ctx = egui::Context::default();
+
+    for _ in 0..10 {
+        app.world_mut().run_system_with(system, (ctx.clone(), NavAction::Next)).unwrap();
+        let (before, after) =
+            app.world_mut().run_system_with(system, (ctx.clone(), NavAction::Increase)).unwrap();
+        // The edit is only applied when the commands of the system are flushed.
+        assert_eq!(before, after);
+        if read_count(&mut app) != 0 {
+            break;
+        }
+    }
+
+    assert_eq!(read_count(&mut app), 1);
+}
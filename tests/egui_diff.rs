@@ -0,0 +1,94 @@
+#![cfg(all(feature = "egui", feature = "serde_json"))]
+
+use bevy_ecs::system::{In, RunSystemOnce};
+use bevy_egui::egui;
+use bevy_mod_config::manager::Egui;
+use bevy_mod_config::manager::egui::Display;
+use bevy_mod_config::manager::egui::diff::DiffBaseline;
+use bevy_mod_config::manager::serde::json::Pretty;
+use bevy_mod_config::{AppExt, Config, ReadConfig, WorldExt};
+
+#[derive(Config)]
+struct Settings {
+    #[config(default = 60)]
+    fps:   u32,
+    #[config(default = true)]
+    vsync: bool,
+}
+
+fn show_diff(
+    In((ctx, input, baseline)): In<(egui::Context, egui::RawInput, DiffBaseline)>,
+    mut display: Display,
+) -> Vec<(String, egui::Pos2)> {
+    let output = ctx.run_ui(input, |ui| {
+        egui::CentralPanel::default().show_inside(ui, |ui| {
+            display.show_diff(ui, &baseline);
+        });
+    });
+
+    fn collect(shape: &egui::Shape, texts: &mut Vec<(String, egui::Pos2)>) {
+        match shape {
+            egui::Shape::Text(text) => texts.push((text.galley.text().to_owned(), text.pos)),
+            egui::Shape::Vec(shapes) => shapes.iter().for_each(|shape| collect(shape, texts)),
+            _ => {}
+        }
+    }
+    let mut texts = Vec::new();
+    output.shapes.iter().for_each(|clipped| collect(&clipped.shape, &mut texts));
+    texts
+}
+
+fn click_at(pos: egui::Pos2) -> [egui::RawInput; 2] {
+    let button = |pressed| egui::Event::PointerButton {
+        pos,
+        button: egui::PointerButton::Primary,
+        pressed,
+        modifiers: egui::Modifiers::NONE,
+    };
+    [
+        egui::RawInput {
+            events: vec![egui::Event::PointerMoved(pos), button(true)],
+            ..Default::default()
+        },
+        egui::RawInput { events: vec![button(false)], ..Default::default() },
+    ]
+}
+
+#[test]
+fn test_diff_and_revert() {
+    let mut app = bevy_app::App::new();
+    app.init_config::<(Pretty, Egui), Settings>("settings");
+    let baseline = DiffBaseline::capture(app.world_mut());
+    let system = app.register_system(show_diff);
+    let ctx = egui::Context::default();
+
+    let texts = app
+        .world_mut()
+        .run_system_with(system, (ctx.clone(), egui::RawInput::default(), baseline.clone()))
+        .unwrap();
+    assert!(texts.iter().any(|(text, _)| text == "No changes"));
+
+    app.world_mut().update_config::<Settings>(|settings| settings.fps.set(30));
+    let texts = app
+        .world_mut()
+        .run_system_with(system, (ctx.clone(), egui::RawInput::default(), baseline.clone()))
+        .unwrap();
+    assert!(texts.iter().any(|(text, _)| text == "settings.fps"));
+    assert!(texts.iter().any(|(text, _)| text == "60 → 30"));
+    assert!(!texts.iter().any(|(text, _)| text == "settings.vsync"));
+
+    let (_, revert_pos) = texts
+        .iter()
+        .find(|(text, _)| text == "Revert")
+        .expect("the changed field has a revert button");
+    let target = *revert_pos + egui::vec2(2.0, 2.0);
+    for input in click_at(target) {
+        app.world_mut().run_system_with(system, (ctx.clone(), input, baseline.clone())).unwrap();
+    }
+
+    let fps = app
+        .world_mut()
+        .run_system_once(|settings: ReadConfig<Settings>| settings.read().fps)
+        .unwrap();
+    assert_eq!(fps, 60);
+}
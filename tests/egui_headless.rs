@@ -0,0 +1,25 @@
+#![cfg(all(feature = "egui_manager", not(feature = "egui")))]
+
+use bevy_ecs::system::RunSystemOnce;
+use bevy_mod_config::manager::Egui;
+use bevy_mod_config::{AppExt, Config, ReadConfig};
+
+#[derive(Config)]
+struct Server {
+    #[config(default = 16)]
+    max_players: u32,
+    motd:        String,
+}
+
+#[test]
+fn test_headless_egui_manager() {
+    let mut app = bevy_app::App::new();
+    app.init_config::<Egui, Server>("server");
+    app.update();
+
+    let max_players = app
+        .world_mut()
+        .run_system_once(|server: ReadConfig<Server>| server.read().max_players)
+        .unwrap();
+    assert_eq!(max_players, 16);
+}
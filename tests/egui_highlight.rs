@@ -0,0 +1,80 @@
+#![cfg(feature = "egui")]
+
+use bevy_ecs::system::In;
+use bevy_egui::egui;
+use bevy_mod_config::manager::Egui;
+use bevy_mod_config::manager::egui::Display;
+use bevy_mod_config::{AppExt, Config, WorldExt};
+
+#[derive(Config)]
+struct Settings {
+    #[config(default = 60)]
+    fps:   u32,
+    #[config(default = true)]
+    vsync: bool,
+}
+
+/// Shows the editor at `time` and returns the number of highlighted fields.
+fn show(
+    In((ctx, mut input, time)): In<(egui::Context, egui::RawInput, f64)>,
+    mut display: Display,
+) -> usize {
+    display.options().highlight_duration = 1.0;
+    input.time = Some(time);
+    let output = ctx.run_ui(input, |ui| {
+        egui::CentralPanel::default().show_inside(ui, |ui| display.show(ui));
+    });
+
+    let highlight = ctx.global_style().visuals.selection.bg_fill.gamma_multiply(0.5);
+    fn count(shape: &egui::Shape, highlight: egui::Color32) -> usize {
+        match shape {
+            egui::Shape::Rect(rect) => usize::from(rect.fill == highlight),
+            egui::Shape::Vec(shapes) => shapes.iter().map(|shape| count(shape, highlight)).sum(),
+            _ => 0,
+        }
+    }
+    output.shapes.iter().map(|clipped| count(&clipped.shape, highlight)).sum()
+}
+
+fn click_at(pos: egui::Pos2) -> [egui::RawInput; 2] {
+    let button = |pressed| egui::Event::PointerButton {
+        pos,
+        button: egui::PointerButton::Primary,
+        pressed,
+        modifiers: egui::Modifiers::NONE,
+    };
+    [
+        egui::RawInput {
+            events: vec![egui::Event::PointerMoved(pos), button(true)],
+            ..Default::default()
+        },
+        egui::RawInput { events: vec![button(false)], ..Default::default() },
+    ]
+}
+
+#[test]
+fn test_change_highlight() {
+    let mut app = bevy_app::App::new();
+    app.init_config::<Egui, Settings>("settings");
+    let system = app.register_system(show);
+    let ctx = egui::Context::default();
+
+    // Expand the root group so that its fields are shown.
+    app.world_mut().run_system_with(system, (ctx.clone(), Default::default(), 0.0)).unwrap();
+    for input in click_at(egui::pos2(30.0, 12.0)) {
+        app.world_mut().run_system_with(system, (ctx.clone(), input, 0.0)).unwrap();
+    }
+    let highlighted =
+        app.world_mut().run_system_with(system, (ctx.clone(), Default::default(), 0.1)).unwrap();
+    assert_eq!(highlighted, 0);
+
+    app.world_mut().update_config::<Settings>(|settings| settings.fps.set(30));
+    let highlighted =
+        app.world_mut().run_system_with(system, (ctx.clone(), Default::default(), 0.2)).unwrap();
+    assert_eq!(highlighted, 1);
+
+    // The highlight fades out after the highlight duration.
+    let highlighted =
+        app.world_mut().run_system_with(system, (ctx.clone(), Default::default(), 1.5)).unwrap();
+    assert_eq!(highlighted, 0);
+}
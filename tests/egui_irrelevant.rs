@@ -0,0 +1,64 @@
+#![cfg(feature = "egui")]
+
+use bevy_ecs::system::In;
+use bevy_egui::egui;
+use bevy_mod_config::manager::Egui;
+use bevy_mod_config::manager::egui::Display;
+use bevy_mod_config::{AppExt, Config};
+
+#[derive(Config)]
+struct Settings {
+    shape: Shape,
+}
+
+#[derive(Config)]
+enum Shape {
+    Circle { radius: u32 },
+    Square { side: u32 },
+}
+
+fn show_settings(
+    In((ctx, show_irrelevant)): In<(egui::Context, bool)>,
+    mut display: Display,
+) -> Vec<String> {
+    // Searching expands all groups, so that their fields are drawn.
+    display.options().search = "settings".into();
+    display.options().show_irrelevant = show_irrelevant;
+    let output = ctx.run_ui(egui::RawInput::default(), |ui| {
+        egui::CentralPanel::default().show_inside(ui, |ui| {
+            display.show(ui);
+        });
+    });
+
+    fn collect(shape: &egui::Shape, texts: &mut Vec<String>) {
+        match shape {
+            egui::Shape::Text(text) => texts.push(text.galley.text().to_owned()),
+            egui::Shape::Vec(shapes) => shapes.iter().for_each(|shape| collect(shape, texts)),
+            _ => {}
+        }
+    }
+    let mut texts = Vec::new();
+    output.shapes.iter().for_each(|clipped| collect(&clipped.shape, &mut texts));
+    texts
+}
+
+#[test]
+fn test_irrelevant_variants() {
+    let mut app = bevy_app::App::new();
+    app.init_config::<Egui, Settings>("settings");
+    let system = app.register_system(show_settings);
+    let ctx = egui::Context::default();
+    let mut show = |show_irrelevant| {
+        // The first pass lets the group headers settle their open state.
+        app.world_mut().run_system_with(system, (ctx.clone(), show_irrelevant)).unwrap();
+        app.world_mut().run_system_with(system, (ctx.clone(), show_irrelevant)).unwrap()
+    };
+
+    let texts = show(false);
+    assert!(texts.iter().any(|text| text == "radius"));
+    assert!(!texts.iter().any(|text| text == "side"));
+
+    let texts = show(true);
+    assert!(texts.iter().any(|text| text == "radius"));
+    assert!(texts.iter().any(|text| text == "side"));
+}
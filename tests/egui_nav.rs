@@ -0,0 +1,65 @@
+#![cfg(feature = "egui")]
+
+use bevy_ecs::system::{In, RunSystemOnce};
+use bevy_egui::egui;
+use bevy_mod_config::manager::Egui;
+use bevy_mod_config::manager::egui::Display;
+use bevy_mod_config::manager::egui::nav::NavAction;
+use bevy_mod_config::{AppExt, Config, ReadConfig};
+
+#[derive(Config)]
+struct Settings {
+    first:  u32,
+    second: u32,
+}
+
+fn show_settings(
+    In((ctx, action)): In<(egui::Context, NavAction)>,
+    mut display: Display,
+) -> egui::FullOutput {
+    // Searching expands the root group, so that its fields can be focused.
+    display.options().search = "settings".into();
+    ctx.run_ui(egui::RawInput::default(), |ui| {
+        action.apply(ui.ctx());
+        egui::CentralPanel::default().show_inside(ui, |ui| {
+            display.show(ui);
+        });
+    })
+}
+
+fn read_settings(app: &mut bevy_app::App) -> (u32, u32) {
+    app.world_mut()
+        .run_system_once(|settings: ReadConfig<Settings>| {
+            let settings = settings.read();
+            (settings.first, settings.second)
+        })
+        .unwrap()
+}
+
+#[test]
+fn test_navigate_in_declaration_order() {
+    let mut app = bevy_app::App::new();
+    app.init_config::<Egui, Settings>("settings");
+    let system = app.register_system(show_settings);
+    let ctx = egui::Context::default();
+
+    let mut increased = Vec::new();
+    for _ in 0..20 {
+        if increased.len() == 2 {
+            break;
+        }
+        let before = read_settings(&mut app);
+        app.world_mut().run_system_with(system, (ctx.clone(), NavAction::Next)).unwrap();
+        app.world_mut().run_system_with(system, (ctx.clone(), NavAction::Increase)).unwrap();
+        let after = read_settings(&mut app);
+        if after.0 != before.0 {
+            increased.push("first");
+        }
+        if after.1 != before.1 {
+            increased.push("second");
+        }
+    }
+
+    assert_eq!(increased, ["first", "second"]);
+    assert_eq!(read_settings(&mut app), (1, 1));
+}
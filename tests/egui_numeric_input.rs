@@ -0,0 +1,68 @@
+#![cfg(feature = "egui")]
+
+use bevy_egui::egui;
+use bevy_mod_config::impls::NumericMetadata;
+use bevy_mod_config::manager::egui::{DefaultStyle, Editable};
+
+struct Field {
+    value: u32,
+    temp:  Option<String>,
+}
+
+impl Field {
+    fn show(&mut self, ctx: &egui::Context, events: Vec<egui::Event>) -> egui::Response {
+        let mut resp = None;
+        let input = egui::RawInput { events, ..Default::default() };
+        _ = ctx.run_ui(input, |ui| {
+            egui::CentralPanel::default().show_inside(ui, |ui| {
+                resp = Some(<u32 as Editable<DefaultStyle>>::show(
+                    ui,
+                    &mut self.value,
+                    &NumericMetadata::default(),
+                    &mut self.temp,
+                    "field",
+                    &DefaultStyle::default(),
+                ));
+            });
+        });
+        resp.unwrap()
+    }
+}
+
+fn click(pos: egui::Pos2) -> Vec<egui::Event> {
+    let button = |pressed| egui::Event::PointerButton {
+        pos,
+        button: egui::PointerButton::Primary,
+        pressed,
+        modifiers: egui::Modifiers::NONE,
+    };
+    vec![egui::Event::PointerMoved(pos), button(true), button(false)]
+}
+
+fn key(key: egui::Key) -> egui::Event {
+    egui::Event::Key {
+        key,
+        physical_key: None,
+        pressed: true,
+        repeat: false,
+        modifiers: egui::Modifiers::NONE,
+    }
+}
+
+#[test]
+fn test_invalid_input_is_unchanged() {
+    let ctx = egui::Context::default();
+    let mut field = Field { value: 5, temp: None };
+
+    let rect = field.show(&ctx, Vec::new()).rect;
+    field.show(&ctx, click(rect.center()));
+
+    let resp = field.show(&ctx, vec![egui::Event::Text("x".into())]);
+    assert!(!resp.changed());
+    assert_eq!(field.value, 5);
+    assert_eq!(field.temp.as_deref(), Some("5x"));
+
+    let resp = field.show(&ctx, vec![key(egui::Key::Backspace), egui::Event::Text("7".into())]);
+    assert!(resp.changed());
+    assert_eq!(field.value, 57);
+}
@@ -0,0 +1,93 @@
+#![cfg(all(feature = "egui", feature = "serde_json"))]
+
+use bevy_ecs::system::{In, RunSystemOnce};
+use bevy_egui::egui;
+use bevy_mod_config::manager::Egui;
+use bevy_mod_config::manager::egui::Display;
+use bevy_mod_config::manager::serde::Json;
+use bevy_mod_config::{AppExt, Config, ReadConfig};
+
+#[derive(Config)]
+struct Settings {
+    #[config(default = 60)]
+    fps: u32,
+}
+
+fn show(
+    In((ctx, input)): In<(egui::Context, egui::RawInput)>,
+    mut display: Display,
+) -> Vec<(String, egui::Pos2)> {
+    let output = ctx.run_ui(input, |ui| {
+        egui::CentralPanel::default().show_inside(ui, |ui| display.show(ui));
+    });
+
+    fn collect(shape: &egui::Shape, texts: &mut Vec<(String, egui::Pos2)>) {
+        match shape {
+            egui::Shape::Text(text) => texts.push((text.galley.text().to_owned(), text.pos)),
+            egui::Shape::Vec(shapes) => shapes.iter().for_each(|shape| collect(shape, texts)),
+            _ => {}
+        }
+    }
+    let mut texts = Vec::new();
+    output.shapes.iter().for_each(|clipped| collect(&clipped.shape, &mut texts));
+    texts
+}
+
+fn click_at(pos: egui::Pos2, button: egui::PointerButton) -> [egui::RawInput; 2] {
+    let button = |pressed| egui::Event::PointerButton {
+        pos,
+        button,
+        pressed,
+        modifiers: egui::Modifiers::NONE,
+    };
+    [
+        egui::RawInput {
+            events: vec![egui::Event::PointerMoved(pos), button(true)],
+            ..Default::default()
+        },
+        egui::RawInput { events: vec![button(false)], ..Default::default() },
+    ]
+}
+
+fn paste(text: &str) -> egui::RawInput {
+    egui::RawInput { events: vec![egui::Event::Paste(text.into())], ..Default::default() }
+}
+
+fn read_fps(app: &mut bevy_app::App) -> u32 {
+    app.world_mut().run_system_once(|settings: ReadConfig<Settings>| settings.read().fps).unwrap()
+}
+
+#[test]
+fn test_paste_from_clipboard() {
+    let mut app = bevy_app::App::new();
+    app.init_config::<(Json, Egui), Settings>("settings");
+    let system = app.register_system(show);
+    let ctx = egui::Context::default();
+
+    let click = |app: &mut bevy_app::App, label: &str, button| {
+        let texts = app
+            .world_mut()
+            .run_system_with(system, (ctx.clone(), egui::RawInput::default()))
+            .unwrap();
+        let (_, pos) = texts.iter().find(|(text, _)| text == label).expect("the label is shown");
+        for input in click_at(*pos + egui::vec2(2.0, 2.0), button) {
+            app.world_mut().run_system_with(system, (ctx.clone(), input)).unwrap();
+        }
+    };
+    click(&mut app, "settings", egui::PointerButton::Primary);
+    click(&mut app, "fps", egui::PointerButton::Secondary);
+
+    let texts =
+        app.world_mut().run_system_with(system, (ctx.clone(), egui::RawInput::default())).unwrap();
+    assert!(texts.iter().any(|(text, _)| text == "Paste value"));
+
+    // Text that is not valid for the field type is ignored.
+    app.world_mut().run_system_with(system, (ctx.clone(), paste("\"fast\""))).unwrap();
+    assert_eq!(read_fps(&mut app), 60);
+
+    app.world_mut().run_system_with(system, (ctx.clone(), paste("30"))).unwrap();
+    assert_eq!(read_fps(&mut app), 30);
+    let texts =
+        app.world_mut().run_system_with(system, (ctx.clone(), egui::RawInput::default())).unwrap();
+    assert!(!texts.iter().any(|(text, _)| text == "Paste value"));
+}
@@ -0,0 +1,31 @@
+#![cfg(feature = "egui_manager")]
+
+use bevy_mod_config::manager::Egui;
+use bevy_mod_config::manager::egui::{DefaultStyle, LabelPlacement, Style};
+use bevy_mod_config::{AppExt, Config};
+
+#[derive(Config)]
+struct Hud {
+    #[config(default = 1.0)]
+    scale: f32,
+}
+
+#[test]
+fn test_default_style_layout() {
+    let style = DefaultStyle::default();
+    assert_eq!(style.label_width(), None);
+    assert_eq!(style.label_placement(), LabelPlacement::Left);
+    assert_eq!(style.indent(), None);
+
+    let style = DefaultStyle::default()
+        .with_label_width(120.0)
+        .with_label_placement(LabelPlacement::Above)
+        .with_indent(8.0);
+    assert_eq!(style.label_width(), Some(120.0));
+    assert_eq!(style.label_placement(), LabelPlacement::Above);
+    assert_eq!(style.indent(), Some(8.0));
+
+    let mut app = bevy_app::App::new();
+    app.init_config_with::<Egui, Hud>("hud", || Egui::with_style(style));
+    app.update();
+}
@@ -0,0 +1,122 @@
+#![cfg(feature = "egui")]
+
+use bevy_ecs::system::In;
+use bevy_egui::egui;
+use bevy_mod_config::manager::Egui;
+use bevy_mod_config::manager::egui::Display;
+use bevy_mod_config::{AppExt, Config};
+
+#[derive(Config)]
+struct Audio {
+    #[config(default = 0.5)]
+    volume: f32,
+}
+
+#[derive(Config)]
+struct Video {
+    #[config(default = 60)]
+    fps: u32,
+}
+
+struct Frame {
+    texts:         Vec<(String, egui::Pos2)>,
+    /// The centers of the diagonal line segments, i.e. of the window close buttons.
+    close_buttons: Vec<egui::Pos2>,
+}
+
+fn show(
+    In((ctx, input, reopen)): In<(egui::Context, egui::RawInput, bool)>,
+    mut display: Display,
+) -> Frame {
+    if reopen {
+        display.reopen_windows();
+    }
+    let output = ctx.run_ui(input, |ui| display.show_windows(ui.ctx()));
+
+    fn collect(shape: &egui::Shape, frame: &mut Frame) {
+        match shape {
+            egui::Shape::Text(text) => frame.texts.push((text.galley.text().to_owned(), text.pos)),
+            egui::Shape::LineSegment { points: [from, to], .. }
+                if from.x != to.x && from.y != to.y =>
+            {
+                frame.close_buttons.push(from.lerp(*to, 0.5));
+            }
+            egui::Shape::Vec(shapes) => shapes.iter().for_each(|shape| collect(shape, frame)),
+            _ => {}
+        }
+    }
+    let mut frame = Frame { texts: Vec::new(), close_buttons: Vec::new() };
+    output.shapes.iter().for_each(|clipped| collect(&clipped.shape, &mut frame));
+    frame
+}
+
+fn click_at(pos: egui::Pos2) -> [egui::RawInput; 2] {
+    let button = |pressed| egui::Event::PointerButton {
+        pos,
+        button: egui::PointerButton::Primary,
+        pressed,
+        modifiers: egui::Modifiers::NONE,
+    };
+    [
+        egui::RawInput {
+            events: vec![egui::Event::PointerMoved(pos), button(true)],
+            ..Default::default()
+        },
+        egui::RawInput { events: vec![button(false)], ..Default::default() },
+    ]
+}
+
+fn titles(frame: &Frame) -> Vec<&str> {
+    let mut titles: Vec<_> = frame
+        .texts
+        .iter()
+        .map(|(text, _)| text.as_str())
+        .filter(|text| ["audio", "video"].contains(text))
+        .collect();
+    titles.sort_unstable();
+    titles.dedup();
+    titles
+}
+
+#[test]
+fn test_close_and_reopen_windows() {
+    let mut app = bevy_app::App::new();
+    app.init_config::<Egui, Audio>("audio");
+    app.init_config::<Egui, Video>("video");
+    let system = app.register_system(show);
+    let ctx = egui::Context::default();
+    let run = |app: &mut bevy_app::App, input, reopen| {
+        app.world_mut().run_system_with(system, (ctx.clone(), input, reopen)).unwrap()
+    };
+
+    run(&mut app, egui::RawInput::default(), false);
+    let frame = run(&mut app, egui::RawInput::default(), false);
+    assert_eq!(titles(&frame), ["audio", "video"]);
+
+    // Click the close button in the title bar of the audio window,
+    // which is above the root group of the same name in the window.
+    let (_, title) = frame
+        .texts
+        .iter()
+        .filter(|(text, _)| text == "audio")
+        .min_by(|(_, a), (_, b)| a.y.total_cmp(&b.y))
+        .unwrap();
+    let close = frame
+        .close_buttons
+        .iter()
+        .filter(|pos| pos.x > title.x && (pos.y - title.y).abs() < 20.0)
+        .min_by(|a, b| a.distance(*title).total_cmp(&b.distance(*title)))
+        .expect("the audio window has a close button");
+    for input in click_at(*close) {
+        run(&mut app, input, false);
+    }
+    let frame = run(&mut app, egui::RawInput::default(), false);
+    assert_eq!(titles(&frame), ["video"]);
+
+    // Closed windows stay closed in later frames.
+    let frame = run(&mut app, egui::RawInput::default(), false);
+    assert_eq!(titles(&frame), ["video"]);
+
+    let frame = run(&mut app, egui::RawInput::default(), true);
+    assert_eq!(titles(&frame), ["audio", "video"]);
+}
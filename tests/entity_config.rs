@@ -0,0 +1,76 @@
+use bevy_ecs::system::RunSystemOnce;
+use bevy_mod_config::{AppExt, Config, ConfigNode, InsertEntityConfig, ReadEntityConfig};
+
+#[derive(Config)]
+struct Controls {
+    #[config(default = 1.0)]
+    sensitivity: f32,
+    invert_y:    bool,
+}
+
+#[test]
+fn test_entity_config_lifecycle() {
+    let mut app = bevy_app::App::new();
+    app.init_entity_config::<(), Controls>();
+
+    let world = app.world_mut();
+    let player1 = world.spawn_empty().id();
+    let player2 = world.spawn_empty().id();
+    world.commands().entity(player1).queue(InsertEntityConfig::<Controls>::new("player1"));
+    world.commands().entity(player2).queue(InsertEntityConfig::<Controls>::new("player2"));
+    world.flush();
+
+    app.world_mut()
+        .run_system_once(move |controls: ReadEntityConfig<Controls>| {
+            assert_eq!(controls.read(player1).unwrap().sensitivity, 1.0);
+            assert!(!controls.read(player2).unwrap().invert_y);
+            assert_eq!(controls.iter().count(), 2);
+        })
+        .unwrap();
+
+    app.world_mut().despawn(player1);
+    app.world_mut().flush();
+
+    let mut query = app.world_mut().query::<&ConfigNode>();
+    let mut roots: Vec<_> = query.iter(app.world()).map(|node| node.path[0].clone()).collect();
+    roots.dedup();
+    assert_eq!(roots, ["player2"]);
+
+    // The key is released with the despawned instance.
+    let player3 = app.world_mut().spawn_empty().id();
+    app.world_mut()
+        .commands()
+        .entity(player3)
+        .queue(InsertEntityConfig::<Controls>::new("player1"));
+    app.world_mut().flush();
+    app.world_mut()
+        .run_system_once(move |controls: ReadEntityConfig<Controls>| {
+            assert!(controls.read(player1).is_none());
+            assert!(controls.read(player3).is_some());
+        })
+        .unwrap();
+}
+
+#[test]
+fn test_entity_config_reinsert() {
+    let mut app = bevy_app::App::new();
+    app.init_entity_config::<(), Controls>();
+
+    let player = app.world_mut().spawn_empty().id();
+    for key in ["player", "player", "renamed"] {
+        app.world_mut().commands().entity(player).queue(InsertEntityConfig::<Controls>::new(key));
+        app.world_mut().flush();
+    }
+
+    // Each insertion despawns the tree it replaces.
+    let mut query = app.world_mut().query::<&ConfigNode>();
+    let mut roots: Vec<_> = query.iter(app.world()).map(|node| node.path[0].clone()).collect();
+    roots.dedup();
+    assert_eq!(roots, ["renamed"]);
+    app.world_mut()
+        .run_system_once(move |controls: ReadEntityConfig<Controls>| {
+            assert_eq!(controls.read(player).unwrap().sensitivity, 1.0);
+            assert_eq!(controls.iter().count(), 1);
+        })
+        .unwrap();
+}
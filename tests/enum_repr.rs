@@ -0,0 +1,94 @@
+#![cfg(feature = "serde_json")]
+
+use bevy_ecs::system::RunSystemOnce;
+use bevy_mod_config::manager::serde::json::{EnumRepr, Json};
+use bevy_mod_config::{AppExt, Config, ReadConfig, WorldExt, manager};
+
+#[derive(Config)]
+struct Settings {
+    #[config(default = 3)]
+    thickness: i32,
+    color:     Color,
+}
+
+#[derive(Config)]
+#[config(expose(read, discrim))]
+enum Color {
+    White,
+    Rgb(f32, f32, f32),
+    Named { code: String, shade: Shade },
+}
+
+#[derive(Config)]
+#[config(expose(read))]
+enum Shade {
+    Light,
+    Dark { depth: u8 },
+}
+
+fn new_app(enum_repr: EnumRepr) -> (bevy_app::App, Json) {
+    let mut app = bevy_app::App::new();
+    app.init_config_with::<Json, Settings>("ui", move || Json::new().with_enum_repr(enum_repr));
+    let json = app.world().resource::<manager::Instance<Json>>().instance.clone();
+    (app, json)
+}
+
+#[test]
+fn test_tagged() {
+    let (mut app, json) = new_app(EnumRepr::Tagged);
+    assert_eq!(
+        json.to_string(app.world_mut()).unwrap(),
+        r#"{"ui.color":{"discrim":"White","value":{}},"ui.thickness":3}"#
+    );
+
+    json.from_reader(
+        app.world_mut(),
+        r#"{"ui.color":{"discrim":"Named","value":{"code":"red","shade":{"discrim":"Dark","value":{"depth":2}}}}}"#.as_bytes(),
+    )
+    .unwrap();
+    app.world_mut()
+        .run_system_once(|settings: ReadConfig<Settings>| {
+            let settings = settings.read();
+            let ColorRead::Named { code, shade } = settings.color else {
+                panic!("variant should be loaded")
+            };
+            assert_eq!(code, "red");
+            assert!(matches!(shade, ShadeRead::Dark { depth: 2 }));
+        })
+        .unwrap();
+
+    assert_eq!(
+        json.to_string(app.world_mut()).unwrap(),
+        r#"{"ui.color":{"discrim":"Named","value":{"code":"red","shade":{"discrim":"Dark","value":{"depth":2}}}},"ui.thickness":3}"#
+    );
+}
+
+#[test]
+fn test_external() {
+    let (mut app, json) = new_app(EnumRepr::External);
+    assert_eq!(
+        json.to_string(app.world_mut()).unwrap(),
+        r#"{"ui.color":"White","ui.thickness":3}"#
+    );
+
+    app.world_mut().update_config::<Settings>(|settings| {
+        settings.color.discrim.set(ColorDiscrim::Rgb);
+    });
+    assert_eq!(
+        json.to_string(app.world_mut()).unwrap(),
+        r#"{"ui.color":{"Rgb":{"0":0.0,"1":0.0,"2":0.0}},"ui.thickness":3}"#
+    );
+
+    json.from_reader(app.world_mut(), r#"{"ui.color":"White"}"#.as_bytes()).unwrap();
+    app.world_mut()
+        .run_system_once(|settings: ReadConfig<Settings>| {
+            assert!(matches!(settings.read().color, ColorRead::White));
+        })
+        .unwrap();
+}
+
+#[test]
+fn test_tagged_rejects_flat_value() {
+    let (mut app, json) = new_app(EnumRepr::Tagged);
+    assert!(json.from_reader(app.world_mut(), r#"{"ui.color":5}"#.as_bytes()).is_err());
+}
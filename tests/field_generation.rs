@@ -0,0 +1,79 @@
+use core::num::NonZeroU64;
+
+use bevy_ecs::system::{RunSystemOnce, SystemState};
+use bevy_mod_config::{
+    AppExt, Config, ConfigNode, FieldGeneration, GenerationOverflow, ReadConfig, ReadConfigChange,
+    WorldExt,
+};
+
+#[derive(Config)]
+struct Settings {
+    volume: u32,
+    line:   Line,
+}
+
+#[derive(Config)]
+struct Line {
+    thickness: f32,
+}
+
+fn generations(app: &mut bevy_app::App) -> (Option<FieldGeneration>, Option<FieldGeneration>) {
+    app.world_mut()
+        .run_system_once(|settings: ReadConfig<Settings>| {
+            assert_eq!(settings.field_generation("line.missing"), None);
+            (settings.field_generation("volume"), settings.field_generation("line.thickness"))
+        })
+        .unwrap()
+}
+
+#[test]
+fn test_field_generation() {
+    let mut app = bevy_app::App::new();
+    app.init_config::<(), Settings>("settings");
+
+    let (volume, thickness) = generations(&mut app);
+    assert!(volume.is_some() && thickness.is_some());
+
+    app.world_mut().update_config::<Settings>(|settings| settings.line.thickness.set(2.0));
+    let (new_volume, new_thickness) = generations(&mut app);
+    assert_eq!(new_volume, volume);
+    assert!(new_thickness > thickness);
+}
+
+fn set_volume_generation(app: &mut bevy_app::App, generation: FieldGeneration) {
+    let world = app.world_mut();
+    let mut query = world.query::<&mut ConfigNode>();
+    for mut node in query.iter_mut(world) {
+        if node.path.join(".") == "settings.volume" {
+            node.generation = generation;
+        }
+    }
+}
+
+#[test]
+fn test_generation_overflow_wrap() {
+    let mut app = bevy_app::App::new();
+    app.init_config::<(), Settings>("settings");
+    app.world_mut().set_generation_overflow(GenerationOverflow::Wrap);
+
+    let max = FieldGeneration::new(NonZeroU64::MAX);
+    set_volume_generation(&mut app, max);
+    let mut state = SystemState::<ReadConfigChange<Settings>>::new(app.world_mut());
+    assert!(state.get_mut(app.world_mut()).unwrap().consume_change());
+
+    app.world_mut().update_config::<Settings>(|settings| settings.volume.set(1));
+    let (volume, _) = generations(&mut app);
+    assert_eq!(volume, Some(FieldGeneration::default()));
+    assert_eq!(FieldGeneration::default().changes_since(max), 1);
+    assert!(state.get_mut(app.world_mut()).unwrap().consume_change());
+}
+
+#[test]
+#[should_panic = "field generation overflow"]
+fn test_generation_overflow_panic() {
+    let mut app = bevy_app::App::new();
+    app.init_config::<(), Settings>("settings");
+
+    set_volume_generation(&mut app, FieldGeneration::new(NonZeroU64::MAX));
+    app.world_mut().update_config::<Settings>(|settings| settings.volume.set(1));
+}
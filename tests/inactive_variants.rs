@@ -0,0 +1,91 @@
+#![cfg(feature = "serde_json")]
+
+use bevy_ecs::system::RunSystemOnce;
+use bevy_mod_config::manager::serde::InactiveVariants;
+use bevy_mod_config::manager::serde::json::Json;
+use bevy_mod_config::{AppExt, Config, ReadConfig, WorldExt, manager};
+
+#[derive(Config)]
+struct Settings {
+    #[config(default = 3)]
+    thickness: i32,
+    shape:     Shape,
+}
+
+#[derive(Config)]
+#[config(expose(read, discrim))]
+enum Shape {
+    Circle {
+        #[config(default = 1.0)]
+        radius: f32,
+    },
+    Rect {
+        #[config(default = 2.0)]
+        width:  f32,
+        #[config(default = 4.0)]
+        height: f32,
+    },
+}
+
+fn new_app(inactive_variants: InactiveVariants) -> (bevy_app::App, Json) {
+    let mut app = bevy_app::App::new();
+    app.init_config_with::<Json, Settings>("ui", move || {
+        Json::new().with_inactive_variants(inactive_variants)
+    });
+    let json = app.world().resource::<manager::Instance<Json>>().instance.clone();
+    (app, json)
+}
+
+#[test]
+fn test_save_all_variants_by_default() {
+    let (mut app, json) = new_app(InactiveVariants::Save);
+    assert_eq!(
+        json.to_string(app.world_mut()).unwrap(),
+        r#"{"ui.shape.Circle.radius":1.0,"ui.shape.Rect.height":4.0,"ui.shape.Rect.width":2.0,"ui.shape.discrim":"Circle","ui.thickness":3}"#
+    );
+}
+
+#[test]
+fn test_skip_inactive_variants() {
+    let (mut app, json) = new_app(InactiveVariants::Skip);
+    assert_eq!(
+        json.to_string(app.world_mut()).unwrap(),
+        r#"{"ui.shape.Circle.radius":1.0,"ui.shape.discrim":"Circle","ui.thickness":3}"#
+    );
+
+    app.world_mut().update_config::<Settings>(|settings| {
+        settings.shape.discrim.set(ShapeDiscrim::Rect);
+    });
+    assert_eq!(
+        json.to_string(app.world_mut()).unwrap(),
+        r#"{"ui.shape.Rect.height":4.0,"ui.shape.Rect.width":2.0,"ui.shape.discrim":"Rect","ui.thickness":3}"#
+    );
+}
+
+#[test]
+fn test_load_defaults_inactive_variants() {
+    let (mut app, json) = new_app(InactiveVariants::Skip);
+    json.from_reader(
+        app.world_mut(),
+        r#"{"ui.shape.Rect.width":5.0,"ui.shape.discrim":"Rect"}"#.as_bytes(),
+    )
+    .unwrap();
+    app.world_mut()
+        .run_system_once(|settings: ReadConfig<Settings>| {
+            let ShapeRead::Rect { width, height } = settings.read().shape else {
+                panic!("active variant should be loaded")
+            };
+            assert_eq!(width, 5.0);
+            assert_eq!(height, 4.0);
+        })
+        .unwrap();
+
+    app.world_mut().update_config::<Settings>(|settings| {
+        settings.shape.discrim.set(ShapeDiscrim::Circle);
+    });
+    app.world_mut()
+        .run_system_once(|settings: ReadConfig<Settings>| {
+            assert!(matches!(settings.read().shape, ShapeRead::Circle { radius: 1.0 }));
+        })
+        .unwrap();
+}
@@ -0,0 +1,41 @@
+#![cfg(feature = "json5")]
+
+use bevy_ecs::system::RunSystemOnce;
+use bevy_mod_config::manager::serde::Json;
+use bevy_mod_config::{AppExt, Config, ReadConfig, manager};
+
+#[derive(Config)]
+struct Settings {
+    #[config(default = 3)]
+    thickness: i32,
+    #[config(default = 1.0)]
+    scale:     f32,
+}
+
+#[test]
+fn test_json5() {
+    let mut app = bevy_app::App::new();
+    app.init_config_with::<Json, Settings>("ui", Json::new);
+
+    let json = app.world().resource::<manager::Instance<Json>>().instance.clone();
+    json.from_json5_str(
+        app.world_mut(),
+        r#"{
+            // Thicker lines for readability.
+            "ui.thickness": 5,
+            /* block comments are fine too */
+            "ui.scale": .5,
+        }"#,
+    )
+    .unwrap();
+    app.world_mut()
+        .run_system_once(|settings: ReadConfig<Settings>| {
+            let settings = settings.read();
+            assert_eq!(settings.thickness, 5);
+            assert_eq!(settings.scale, 0.5);
+        })
+        .unwrap();
+
+    // Output is still standard JSON.
+    assert_eq!(json.to_string(app.world_mut()).unwrap(), r#"{"ui.scale":0.5,"ui.thickness":5}"#);
+}
@@ -0,0 +1,98 @@
+#![cfg(feature = "serde_json")]
+
+use std::io::Cursor;
+
+use bevy_ecs::system::RunSystemOnce;
+use bevy_mod_config::manager::serde::json::Json;
+use bevy_mod_config::manager::serde::{DuplicateKey, KeyCase};
+use bevy_mod_config::{AppExt, Config, ReadConfig, manager};
+
+#[derive(Config)]
+struct Settings {
+    line_width: u32,
+    mode:       Mode,
+}
+
+#[derive(Config)]
+struct Theme {
+    line_width: u32,
+}
+
+#[derive(Config)]
+#[config(expose(read))]
+enum Mode {
+    Simple,
+    FixedSize { max_count: u32 },
+}
+
+#[test]
+fn test_key_case() {
+    let mut app = bevy_app::App::new();
+    app.init_config_with::<Json, Settings>("user_interface", || {
+        Json::new().with_key_case(KeyCase::Camel)
+    });
+
+    let json = app.world().resource::<manager::Instance<Json>>().instance.clone();
+    let data = json.to_string(app.world_mut()).unwrap();
+    assert_eq!(
+        data,
+        r#"{"userInterface.lineWidth":0,"userInterface.mode.discrim":"simple","userInterface.mode.fixedSize.maxCount":0}"#
+    );
+
+    json.from_reader(
+        app.world_mut(),
+        Cursor::new(
+            r#"{"userInterface.lineWidth":3,"userInterface.mode.discrim":"fixedSize","userInterface.mode.fixedSize.maxCount":5}"#,
+        ),
+    )
+    .unwrap();
+    app.world_mut()
+        .run_system_once(|settings: ReadConfig<Settings>| {
+            let settings = settings.read();
+            assert_eq!(settings.line_width, 3);
+            assert!(matches!(settings.mode, ModeRead::FixedSize { max_count: 5 }));
+        })
+        .unwrap();
+}
+
+#[test]
+fn test_key_collision() {
+    let mut app = bevy_app::App::new();
+    app.init_config_with::<Json, Settings>("user_interface", || {
+        Json::new().with_key_case(KeyCase::Camel)
+    });
+    app.init_config::<Json, Theme>("userInterface");
+
+    let json = app.world().resource::<manager::Instance<Json>>().instance.clone();
+    let duplicates: Vec<_> = json.duplicate_keys().cloned().collect();
+    assert_eq!(
+        duplicates,
+        [DuplicateKey {
+            key:      vec!["userInterface".into(), "lineWidth".into()],
+            kept:     vec!["user_interface".into(), "line_width".into()],
+            excluded: vec!["userInterface".into(), "line_width".into()],
+        }]
+    );
+
+    // The field of the later root is neither saved nor loaded.
+    json.from_reader(app.world_mut(), Cursor::new(r#"{"userInterface.lineWidth":3}"#)).unwrap();
+    assert_eq!(
+        json.to_string(app.world_mut()).unwrap(),
+        r#"{"userInterface.lineWidth":3,"userInterface.mode.discrim":"simple","userInterface.mode.fixedSize.maxCount":0}"#
+    );
+    app.world_mut()
+        .run_system_once(|settings: ReadConfig<Settings>, theme: ReadConfig<Theme>| {
+            assert_eq!(settings.read().line_width, 3);
+            assert_eq!(theme.read().line_width, 0);
+        })
+        .unwrap();
+}
+
+#[test]
+fn test_convert() {
+    assert_eq!(KeyCase::Snake.convert("FixedSize"), "fixed_size");
+    assert_eq!(KeyCase::Kebab.convert("line_width"), "line-width");
+    assert_eq!(KeyCase::Camel.convert("line_width"), "lineWidth");
+    assert_eq!(KeyCase::Camel.convert("Rgb2Color"), "rgb2Color");
+    assert_eq!(KeyCase::Preserve.convert("line_width"), "line_width");
+}
@@ -0,0 +1,110 @@
+use bevy_ecs::system::RunSystemOnce;
+use bevy_mod_config::{
+    AppExt, Config, ConfigNode, InsertEntityConfig, KeyCollision, ReadConfig, RootNode,
+};
+
+#[derive(Config)]
+struct Audio {
+    #[config(default = 1.0)]
+    volume: f32,
+}
+
+#[derive(Config)]
+struct AudioExtras {
+    #[config(default = true)]
+    spatial: bool,
+}
+
+fn paths(app: &mut bevy_app::App) -> Vec<String> {
+    let mut query = app.world_mut().query::<&ConfigNode>();
+    let mut paths: Vec<_> = query.iter(app.world()).map(|node| node.path.join(".")).collect();
+    paths.sort();
+    paths
+}
+
+#[test]
+#[should_panic = "Cannot initialize multiple root config fields of the same type"]
+fn test_panic_by_default() {
+    let mut app = bevy_app::App::new();
+    app.init_config::<(), Audio>("audio");
+    app.init_config::<(), Audio>("audio");
+}
+
+#[test]
+fn test_skip_same_type() {
+    let mut app = bevy_app::App::new();
+    app.insert_resource(KeyCollision::Skip);
+    app.init_config::<(), Audio>("audio");
+    app.init_config::<(), Audio>("audio");
+
+    assert_eq!(paths(&mut app), ["audio", "audio.volume"]);
+    app.world_mut()
+        .run_system_once(|audio: ReadConfig<Audio>| assert_eq!(audio.read().volume, 1.0))
+        .unwrap();
+}
+
+#[test]
+#[should_panic = "used by another type"]
+fn test_skip_other_type() {
+    let mut app = bevy_app::App::new();
+    app.insert_resource(KeyCollision::Skip);
+    app.init_config::<(), Audio>("audio");
+    app.init_config::<(), AudioExtras>("audio");
+}
+
+#[test]
+fn test_merge() {
+    let mut app = bevy_app::App::new();
+    app.insert_resource(KeyCollision::Merge);
+    app.init_config::<(), Audio>("audio");
+    app.init_config::<(), AudioExtras>("audio");
+    app.init_config::<(), AudioExtras>("audio");
+
+    assert_eq!(paths(&mut app), ["audio", "audio", "audio.spatial", "audio.volume"]);
+    let mut roots = app.world_mut().query::<&RootNode>();
+    assert_eq!(roots.iter(app.world()).count(), 2);
+
+    app.world_mut()
+        .run_system_once(|audio: ReadConfig<Audio>, extras: ReadConfig<AudioExtras>| {
+            assert_eq!(audio.read().volume, 1.0);
+            assert!(extras.read().spatial);
+        })
+        .unwrap();
+}
+
+#[derive(Config)]
+struct AudioOverride {
+    #[config(default = 0.5)]
+    volume: f32,
+}
+
+#[test]
+#[should_panic = "Cannot merge config field \"audio.volume\""]
+fn test_merge_duplicate_field() {
+    let mut app = bevy_app::App::new();
+    app.insert_resource(KeyCollision::Merge);
+    app.init_config::<(), Audio>("audio");
+    app.init_config::<(), AudioOverride>("audio");
+}
+
+#[test]
+#[should_panic = "Cannot reuse config key \"audio\""]
+fn test_merged_key_kept_after_despawn() {
+    let mut app = bevy_app::App::new();
+    app.insert_resource(KeyCollision::Merge);
+    app.init_entity_config::<(), Audio>();
+
+    let owner = app.world_mut().spawn_empty().id();
+    app.world_mut().commands().entity(owner).queue(InsertEntityConfig::<Audio>::new("audio"));
+    app.world_mut().flush();
+    app.init_config::<(), AudioExtras>("audio");
+
+    // The merged root still uses the key after the entity config is despawned.
+    app.world_mut().despawn(owner);
+    app.world_mut().flush();
+    assert_eq!(paths(&mut app), ["audio", "audio.spatial"]);
+
+    let other = app.world_mut().spawn_empty().id();
+    app.world_mut().commands().entity(other).queue(InsertEntityConfig::<Audio>::new("audio"));
+    app.world_mut().flush();
+}
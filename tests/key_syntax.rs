@@ -0,0 +1,66 @@
+#![cfg(feature = "serde_json")]
+
+use bevy_ecs::system::RunSystemOnce;
+use bevy_mod_config::manager::serde::json::{Json, KeySyntax};
+use bevy_mod_config::{AppExt, Config, ReadConfig, manager};
+
+#[derive(Config)]
+struct Network {
+    #[config(serde(rename = "eu.west"))]
+    eu_west: Server,
+    #[config(default = 3)]
+    retries: u32,
+}
+
+#[derive(Config)]
+struct Server {
+    #[config(default = 8080)]
+    port: u16,
+}
+
+#[test]
+fn test_escaped_segment() {
+    let mut app = bevy_app::App::new();
+    app.init_config_with::<Json, Network>("network", Json::new);
+    let json = app.world().resource::<manager::Instance<Json>>().instance.clone();
+
+    assert_eq!(
+        json.to_string(app.world_mut()).unwrap(),
+        r#"{"network.eu\\.west.port":8080,"network.retries":3}"#
+    );
+
+    json.from_reader(app.world_mut(), r#"{"network.eu\\.west.port":443}"#.as_bytes()).unwrap();
+    app.world_mut()
+        .run_system_once(|network: ReadConfig<Network>| {
+            assert_eq!(network.read().eu_west.port, 443);
+        })
+        .unwrap();
+}
+
+#[test]
+fn test_custom_separator() {
+    let mut app = bevy_app::App::new();
+    app.init_config_with::<Json, Network>("network", || {
+        Json::new().with_key_syntax(KeySyntax { separator: '/', escape: '~' })
+    });
+    let json = app.world().resource::<manager::Instance<Json>>().instance.clone();
+
+    assert_eq!(
+        json.to_string(app.world_mut()).unwrap(),
+        r#"{"network/eu.west/port":8080,"network/retries":3}"#
+    );
+
+    json.from_reader(app.world_mut(), r#"{"network/retries":5}"#.as_bytes()).unwrap();
+    app.world_mut()
+        .run_system_once(|network: ReadConfig<Network>| {
+            assert_eq!(network.read().retries, 5);
+        })
+        .unwrap();
+}
+
+#[test]
+fn test_split_round_trip() {
+    let syntax = KeySyntax { separator: '/', escape: '~' };
+    let path = ["a/b".to_string(), "~c".to_string(), String::new()];
+    assert_eq!(syntax.split(&syntax.join(&path)), path);
+}
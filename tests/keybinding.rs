@@ -0,0 +1,52 @@
+#![cfg(feature = "bevy_input")]
+
+use bevy_input::keyboard::KeyCode;
+use bevy_mod_config::types::{KeyBinding, KeyConflict, find_key_conflicts};
+use bevy_mod_config::{AppExt, Config};
+
+#[derive(Config)]
+struct Controls {
+    #[config(default = KeyBinding(Some(KeyCode::KeyW)))]
+    forward:  KeyBinding,
+    #[config(default = KeyBinding(Some(KeyCode::KeyS)))]
+    backward: KeyBinding,
+    #[config(default = KeyBinding(Some(KeyCode::KeyW)))]
+    jump:     KeyBinding,
+    #[config(default = KeyBinding(None))]
+    crouch:   KeyBinding,
+}
+
+#[derive(Config)]
+struct DebugKeys {
+    #[config(default = KeyBinding(Some(KeyCode::KeyS)))]
+    screenshot: KeyBinding,
+    #[config(default = KeyBinding(None))]
+    console:    KeyBinding,
+}
+
+#[test]
+fn test_key_conflicts() {
+    let mut app = bevy_app::App::new();
+    app.init_config::<(), Controls>("controls");
+    app.init_config::<(), DebugKeys>("debug");
+
+    assert_eq!(
+        find_key_conflicts(app.world_mut()),
+        [
+            KeyConflict {
+                key:   KeyCode::KeyS,
+                paths: vec![
+                    vec!["controls".to_string(), "backward".to_string()],
+                    vec!["debug".to_string(), "screenshot".to_string()],
+                ],
+            },
+            KeyConflict {
+                key:   KeyCode::KeyW,
+                paths: vec![
+                    vec!["controls".to_string(), "forward".to_string()],
+                    vec!["controls".to_string(), "jump".to_string()],
+                ],
+            },
+        ]
+    );
+}
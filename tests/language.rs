@@ -0,0 +1,55 @@
+use bevy_ecs::system::RunSystemOnce;
+use bevy_mod_config::types::{AvailableLanguages, Language, LanguageOption, LanguagePlugin};
+use bevy_mod_config::{AppExt, Config, ReadConfig, ScalarMetadata};
+
+#[derive(Config)]
+struct General {
+    #[config(default = "en-US")]
+    language: Language,
+}
+
+fn options(app: &mut bevy_app::App) -> Vec<LanguageOption> {
+    let mut query = app.world_mut().query::<&ScalarMetadata<Language>>();
+    query.single(app.world()).unwrap().0.options.clone()
+}
+
+#[test]
+fn test_language() {
+    let mut app = bevy_app::App::new();
+    app.init_config::<(), General>("general");
+    app.add_plugins(LanguagePlugin);
+
+    app.world_mut()
+        .run_system_once(|general: ReadConfig<General>| {
+            assert_eq!(general.read().language, "en-US");
+        })
+        .unwrap();
+
+    app.update();
+    assert!(options(&mut app).is_empty());
+
+    let available =
+        vec![LanguageOption::new("en-US", "English"), LanguageOption::new("de-DE", "Deutsch")];
+    app.insert_resource(AvailableLanguages(available.clone()));
+    app.update();
+    assert_eq!(options(&mut app), available);
+}
+
+#[derive(Config)]
+struct Accessibility {
+    #[config(default = "en-US")]
+    subtitles: Language,
+}
+
+#[test]
+fn test_language_spawned_later() {
+    let mut app = bevy_app::App::new();
+    app.add_plugins(LanguagePlugin);
+    let available = vec![LanguageOption::new("ja-JP", "日本語")];
+    app.insert_resource(AvailableLanguages(available.clone()));
+    app.update();
+
+    app.init_config::<(), Accessibility>("accessibility");
+    app.update();
+    assert_eq!(options(&mut app), available);
+}
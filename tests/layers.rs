@@ -0,0 +1,110 @@
+use bevy_ecs::entity::Entity;
+use bevy_ecs::system::RunSystemOnce;
+use bevy_ecs::world::World;
+use bevy_mod_config::manager::layer::{self, Layer, Layers};
+use bevy_mod_config::{AppExt, Config, ConfigNode, ReadConfig};
+
+#[derive(Config)]
+struct Settings {
+    #[config(default = 60)]
+    fps: u32,
+}
+
+fn fps_entity(world: &mut World) -> Entity {
+    world
+        .query::<(Entity, &ConfigNode)>()
+        .iter(world)
+        .find(|(_, node)| node.path == ["settings", "fps"])
+        .expect("fps field must be spawned")
+        .0
+}
+
+fn read_fps(world: &mut World) -> u32 {
+    world.run_system_once(|settings: ReadConfig<Settings>| settings.read().fps).unwrap()
+}
+
+#[test]
+fn test_layers() {
+    let mut app = bevy_app::App::new();
+    app.init_config::<Layers, Settings>("settings");
+    let world = app.world_mut();
+    let fps = fps_entity(world);
+    assert_eq!(layer::provenance(world, fps), Some(Layer::Default));
+
+    layer::set(world, fps, Layer::Cli, 30_u32);
+    assert_eq!(read_fps(world), 30);
+    assert_eq!(layer::provenance(world, fps), Some(Layer::Cli));
+
+    // A lower layer does not override the CLI value.
+    layer::set(world, fps, Layer::File, 144_u32);
+    assert_eq!(read_fps(world), 30);
+
+    layer::clear(world, Layer::Cli);
+    assert_eq!(read_fps(world), 144);
+    assert_eq!(layer::provenance(world, fps), Some(Layer::File));
+
+    layer::clear(world, Layer::File);
+    assert_eq!(read_fps(world), 60);
+    assert_eq!(layer::provenance(world, fps), Some(Layer::Default));
+}
+
+#[test]
+fn test_same_value_does_not_bump() {
+    let mut app = bevy_app::App::new();
+    app.init_config::<Layers, Settings>("settings");
+    let world = app.world_mut();
+    let fps = fps_entity(world);
+    let generation = world.get::<ConfigNode>(fps).unwrap().generation;
+
+    // The CLI layer provides the same value as the default layer.
+    layer::set(world, fps, Layer::Cli, 60_u32);
+    assert_eq!(layer::provenance(world, fps), Some(Layer::Cli));
+    assert_eq!(world.get::<ConfigNode>(fps).unwrap().generation, generation);
+
+    layer::set(world, fps, Layer::Cli, 30_u32);
+    assert_ne!(world.get::<ConfigNode>(fps).unwrap().generation, generation);
+}
+
+#[test]
+fn test_update_config_layer() {
+    let mut app = bevy_app::App::new();
+    app.init_config::<Layers, Settings>("settings");
+    let world = app.world_mut();
+    let fps = fps_entity(world);
+
+    layer::update_config::<Settings>(world, Layer::Cli, |settings| settings.fps.set(30));
+    assert_eq!(read_fps(world), 30);
+    assert_eq!(layer::provenance(world, fps), Some(Layer::Cli));
+
+    // The runtime capture does not claim the attributed write.
+    app.update();
+    assert_eq!(layer::provenance(app.world_mut(), fps), Some(Layer::Cli));
+
+    layer::clear(app.world_mut(), Layer::Cli);
+    assert_eq!(read_fps(app.world_mut()), 60);
+}
+
+#[cfg(feature = "serde_json")]
+#[test]
+fn test_overrides_not_persisted() {
+    use bevy_mod_config::manager;
+    use bevy_mod_config::manager::serde::Json;
+
+    let mut app = bevy_app::App::new();
+    app.init_config_with::<(Json, Layers), Settings>("settings", || (Json::new(), Layers));
+    let json = app.world().resource::<manager::Instance<(Json, Layers)>>().instance.0.clone();
+    let world = app.world_mut();
+    let fps = fps_entity(world);
+
+    layer::set(world, fps, Layer::File, 144_u32);
+    layer::set(world, fps, Layer::Env, 30_u32);
+    assert_eq!(read_fps(world), 30);
+    assert_eq!(json.to_string(world).unwrap(), r#"{"settings.fps":144}"#);
+
+    layer::set(world, fps, Layer::Cli, 20_u32);
+    assert_eq!(json.to_string(world).unwrap(), r#"{"settings.fps":144}"#);
+
+    // Values changed at runtime are saved even while an override is active.
+    layer::set(world, fps, Layer::Runtime, 90_u32);
+    assert_eq!(json.to_string(world).unwrap(), r#"{"settings.fps":90}"#);
+}
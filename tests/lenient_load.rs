@@ -0,0 +1,88 @@
+#![cfg(feature = "serde_json")]
+
+use bevy_ecs::system::RunSystemOnce;
+use bevy_mod_config::manager::serde::KeyError;
+use bevy_mod_config::manager::serde::json::{EnumRepr, Json};
+use bevy_mod_config::{AppExt, Config, ReadConfig, manager};
+
+#[derive(Config)]
+struct Settings {
+    #[config(default = 3)]
+    thickness: i32,
+    #[config(default = 0.5)]
+    opacity:   f32,
+    label:     String,
+    mode:      Mode,
+}
+
+#[derive(Config)]
+#[config(expose(read))]
+enum Mode {
+    Simple,
+    Fixed { count: u32 },
+}
+
+fn new_app(enum_repr: EnumRepr) -> (bevy_app::App, Json) {
+    let mut app = bevy_app::App::new();
+    app.init_config_with::<Json, Settings>("ui", move || Json::new().with_enum_repr(enum_repr));
+    let json = app.world().resource::<manager::Instance<Json>>().instance.clone();
+    (app, json)
+}
+
+#[test]
+fn test_lenient_collects_errors() {
+    let (mut app, json) = new_app(EnumRepr::Flat);
+    let errors = json
+        .from_reader_lenient(
+            app.world_mut(),
+            r#"{"ui.thickness":"thick","ui.opacity":0.25,"ui.label":7,"ui.mode.discrim":"Fixed"}"#
+                .as_bytes(),
+        )
+        .unwrap();
+
+    let paths: Vec<_> = errors.iter().map(|err| err.path.join(".")).collect();
+    assert_eq!(paths, ["ui.thickness", "ui.label"]);
+
+    app.world_mut()
+        .run_system_once(|settings: ReadConfig<Settings>| {
+            let settings = settings.read();
+            assert_eq!(settings.thickness, 3);
+            assert_eq!(settings.opacity, 0.25);
+            assert_eq!(settings.label, "");
+            assert!(matches!(settings.mode, ModeRead::Fixed { count: 0 }));
+        })
+        .unwrap();
+}
+
+#[test]
+fn test_lenient_tagged() {
+    let (mut app, json) = new_app(EnumRepr::Tagged);
+    let errors: Vec<KeyError> = json
+        .from_reader_lenient(
+            app.world_mut(),
+            r#"{"ui.mode":{"discrim":"Fixed","value":{"count":-1}},"ui.opacity":0.25}"#.as_bytes(),
+        )
+        .unwrap();
+    assert_eq!(errors.len(), 1);
+    assert_eq!(errors[0].path, ["ui", "mode", "Fixed", "count"]);
+
+    app.world_mut()
+        .run_system_once(|settings: ReadConfig<Settings>| {
+            let settings = settings.read();
+            assert_eq!(settings.opacity, 0.25);
+            assert!(matches!(settings.mode, ModeRead::Fixed { count: 0 }));
+        })
+        .unwrap();
+}
+
+#[test]
+fn test_strict_aborts() {
+    let (mut app, json) = new_app(EnumRepr::Flat);
+    assert!(json.from_reader(app.world_mut(), r#"{"ui.thickness":"thick"}"#.as_bytes()).is_err());
+}
+
+#[test]
+fn test_lenient_rejects_malformed_input() {
+    let (mut app, json) = new_app(EnumRepr::Flat);
+    assert!(json.from_reader_lenient(app.world_mut(), r#"{"ui.thickness":"#.as_bytes()).is_err());
+}
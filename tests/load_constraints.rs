@@ -0,0 +1,63 @@
+#![cfg(feature = "serde_json")]
+
+use std::io::Cursor;
+
+use bevy_ecs::system::RunSystemOnce;
+use bevy_mod_config::manager::serde::Json;
+use bevy_mod_config::manager::serde::json::SetConfigValue;
+use bevy_mod_config::{AppExt, Config, ReadConfig, manager};
+
+#[derive(Config)]
+struct Profile {
+    #[config(default = "Player", max_length = Some(8))]
+    name:    String,
+    #[config(default = 50, min = 0, max = 100)]
+    volume:  i32,
+    #[config(default = 1.0, min = 0.5, max = 2.0)]
+    scale:   f32,
+    #[config(max_length = Some(2), element.max = 9)]
+    presets: Vec<u8>,
+}
+
+fn assert_profile(app: &mut bevy_app::App, expected: (&'static str, i32, f32, &'static [u8])) {
+    app.world_mut()
+        .run_system_once(move |profile: ReadConfig<Profile>| {
+            let profile = profile.read();
+            assert_eq!((profile.name, profile.volume, profile.scale, profile.presets), expected);
+        })
+        .unwrap();
+}
+
+#[test]
+fn test_load_clamps_values() {
+    let mut app = bevy_app::App::new();
+    app.init_config_with::<Json, Profile>("profile", Json::new);
+
+    let json = app.world().resource::<manager::Instance<Json>>().instance.clone();
+    json.from_reader(
+        app.world_mut(),
+        Cursor::new(
+            r#"{
+                "profile.name": "ÄÄÄÄÄÄÄÄÄÄ",
+                "profile.volume": 9000,
+                "profile.scale": -3.0,
+                "profile.presets": [1, 20, 3]
+            }"#,
+        ),
+    )
+    .unwrap();
+
+    assert_profile(&mut app, ("ÄÄÄÄÄÄÄÄ", 100, 0.5, &[1, 9]));
+}
+
+#[test]
+fn test_set_value_clamps_values() {
+    let mut app = bevy_app::App::new();
+    app.init_config_with::<Json, Profile>("profile", Json::new);
+
+    let world = app.world_mut();
+    world.commands().queue(SetConfigValue::new("profile.volume", -5).unwrap());
+    world.flush();
+
+    assert_profile(&mut app, ("Player", 0, 1.0, &[]));
+}
@@ -0,0 +1,51 @@
+#![cfg(all(feature = "egui", feature = "serde_json"))]
+
+use bevy_ecs::system::RunSystemOnce;
+use bevy_mod_config::manager::serde::Json;
+use bevy_mod_config::{AppExt, Config, Lossy, ReadConfig, impl_scalar_config_field, manager};
+
+/// A scalar type that is serializable but has no egui editor.
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct Seed(u64);
+
+impl serde::Serialize for Seed {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        self.0.serialize(serializer)
+    }
+}
+
+impl<'de> serde::Deserialize<'de> for Seed {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        u64::deserialize(deserializer).map(Seed)
+    }
+}
+
+#[derive(Default, Clone)]
+struct SeedMetadata;
+
+impl_scalar_config_field!(Seed, SeedMetadata, |_: &SeedMetadata| Seed(42), 'a => Seed, |&seed: &Seed| seed);
+
+#[derive(Config)]
+struct Terrain {
+    #[config(default = 8)]
+    size: u32,
+    seed: Lossy<Seed>,
+}
+
+type ManagerType = (Json, manager::Egui);
+
+#[test]
+fn test_lossy() {
+    let mut app = bevy_app::App::new();
+    app.init_config_with::<ManagerType, Terrain>("terrain", || (Json::new(), Default::default()));
+
+    let json = app.world().resource::<manager::Instance<ManagerType>>().instance.0.clone();
+    assert_eq!(json.to_string(app.world_mut()).unwrap(), r#"{"terrain.seed":42,"terrain.size":8}"#);
+
+    json.from_reader(app.world_mut(), r#"{"terrain.seed":7}"#.as_bytes()).unwrap();
+    app.world_mut()
+        .run_system_once(|terrain: ReadConfig<Terrain>| {
+            assert_eq!(terrain.read().seed, &Seed(7));
+        })
+        .unwrap();
+}
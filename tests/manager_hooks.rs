@@ -0,0 +1,99 @@
+use std::any::TypeId;
+
+use bevy_ecs::bundle::Bundle;
+use bevy_ecs::entity::Entity;
+use bevy_ecs::world::World;
+use bevy_mod_config::{AppExt, Config, ConfigNode, RootNode, WorldExt, manager};
+
+#[derive(Default)]
+struct Counter {
+    live:  usize,
+    roots: Vec<(String, Entity, TypeId)>,
+}
+
+impl manager::Manager for Counter {
+    fn init_root(&mut self, root: &manager::RootInfo) {
+        self.roots.push((root.key.into(), root.entity, root.type_id));
+    }
+}
+
+impl<T> manager::Supports<T> for Counter {
+    fn new_entity_for_type(&mut self) -> impl Bundle { self.live += 1; }
+
+    fn remove_entity_for_type(&mut self, _entity: Entity) { self.live -= 1; }
+}
+
+#[derive(Config)]
+struct Settings {
+    width:  u32,
+    height: u32,
+}
+
+#[test]
+fn test_remove_hook() {
+    let mut app = bevy_app::App::new();
+    app.init_config::<Counter, Settings>("window");
+    assert_eq!(app.world().resource::<manager::Instance<Counter>>().live, 2);
+
+    let world = app.world_mut();
+    let fields: Vec<Entity> = world
+        .query::<(Entity, &ConfigNode)>()
+        .iter(world)
+        .filter(|(_, node)| node.path.len() == 2)
+        .map(|(entity, _)| entity)
+        .collect();
+    for entity in fields {
+        world.despawn(entity);
+    }
+    assert_eq!(app.world().resource::<manager::Instance<Counter>>().live, 0);
+}
+
+#[test]
+fn test_init_root() {
+    let mut app = bevy_app::App::new();
+    app.init_config::<Counter, Settings>("window");
+
+    let roots = &app.world().resource::<manager::Instance<Counter>>().roots;
+    assert_eq!(roots.len(), 1);
+    let (key, entity, type_id) = &roots[0];
+    assert_eq!(key, "window");
+    assert_eq!(*type_id, TypeId::of::<Settings>());
+    assert!(app.world().entity(*entity).contains::<RootNode>());
+}
+
+#[derive(Default)]
+struct ChangeLog {
+    changed: Vec<String>,
+}
+
+impl manager::Manager for ChangeLog {
+    fn on_field_changed(&mut self, world: &mut World, entity: Entity) {
+        let node = world.entity(entity).get::<ConfigNode>().unwrap();
+        self.changed.push(node.path.join("."));
+    }
+}
+
+impl<T> manager::Supports<T> for ChangeLog {
+    fn new_entity_for_type(&mut self) -> impl Bundle {}
+}
+
+#[test]
+fn test_on_field_changed() {
+    let mut app = bevy_app::App::new();
+    app.init_config::<ChangeLog, Settings>("window");
+
+    app.update();
+    assert!(app.world().resource::<manager::Instance<ChangeLog>>().changed.is_empty());
+
+    app.world_mut().update_config::<Settings>(|settings| {
+        settings.width.set(800);
+    });
+    app.world_mut().update_config::<Settings>(|settings| {
+        settings.width.set(1024);
+    });
+    app.update();
+    assert_eq!(app.world().resource::<manager::Instance<ChangeLog>>().changed, ["window.width"]);
+
+    app.update();
+    assert_eq!(app.world().resource::<manager::Instance<ChangeLog>>().changed.len(), 1);
+}
@@ -0,0 +1,56 @@
+#![cfg(feature = "serde_json")]
+
+use bevy_app::{AppLabel, SubApp};
+use bevy_ecs::change_detection::DetectChanges;
+use bevy_mod_config::manager::serde::Json;
+use bevy_mod_config::mirror::Mirrored;
+use bevy_mod_config::{AppExt, Config, manager};
+
+#[derive(Config)]
+#[config(expose(read))]
+struct Graphics {
+    #[config(default = 4)]
+    msaa_samples: u32,
+    hdr:          bool,
+}
+
+#[derive(Debug, PartialEq)]
+struct RenderSettings {
+    msaa_samples: u32,
+    hdr:          bool,
+}
+
+#[derive(AppLabel, Debug, Clone, PartialEq, Eq, Hash)]
+struct RenderLike;
+
+#[test]
+fn test_mirror_to_sub_app() {
+    let mut app = bevy_app::App::new();
+    app.insert_sub_app(RenderLike, SubApp::new());
+    app.init_config_with::<Json, Graphics>("graphics", Json::new);
+    app.mirror_config::<Graphics, _>(RenderLike, |graphics: GraphicsRead<'_>| RenderSettings {
+        msaa_samples: graphics.msaa_samples,
+        hdr:          graphics.hdr,
+    });
+
+    app.update();
+    let sub_world = app.sub_app(RenderLike).world();
+    assert_eq!(
+        sub_world.resource::<Mirrored<RenderSettings>>().0,
+        RenderSettings { msaa_samples: 4, hdr: false }
+    );
+    let tick = sub_world.resource_ref::<Mirrored<RenderSettings>>().last_changed();
+
+    // Unchanged configs are not written again.
+    app.update();
+    let sub_world = app.sub_app(RenderLike).world();
+    assert_eq!(sub_world.resource_ref::<Mirrored<RenderSettings>>().last_changed(), tick);
+
+    let json = app.world().resource::<manager::Instance<Json>>().instance.clone();
+    json.from_reader(app.world_mut(), r#"{"graphics.hdr":true}"#.as_bytes()).unwrap();
+    app.update();
+    assert_eq!(
+        app.sub_app(RenderLike).world().resource::<Mirrored<RenderSettings>>().0,
+        RenderSettings { msaa_samples: 4, hdr: true }
+    );
+}
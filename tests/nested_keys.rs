@@ -0,0 +1,62 @@
+#![cfg(feature = "serde_json")]
+
+use std::io::Cursor;
+
+use bevy_ecs::system::RunSystemOnce;
+use bevy_mod_config::manager::serde::Json;
+use bevy_mod_config::{AppExt, Config, ReadConfig, manager};
+
+#[derive(Config)]
+struct Settings {
+    thickness: i32,
+    rgb:       Rgb,
+}
+
+#[derive(Config)]
+struct Rgb {
+    red:   f32,
+    green: f32,
+    blue:  f32,
+}
+
+fn assert_loaded(app: &mut bevy_app::App) {
+    app.world_mut()
+        .run_system_once(|settings: ReadConfig<Settings>| {
+            let settings = settings.read();
+            assert_eq!(settings.thickness, 5);
+            assert_eq!(settings.rgb.red, 0.25);
+            assert_eq!(settings.rgb.green, 0.5);
+            assert_eq!(settings.rgb.blue, 0.75);
+        })
+        .unwrap();
+}
+
+const MIXED: &str = r#"{
+    "ui": {
+        "thickness": 5,
+        "rgb.red": 0.25,
+        "rgb": { "green": 0.5 },
+        "unknown": { "ignored": true }
+    },
+    "ui.rgb.blue": 0.75
+}"#;
+
+#[test]
+fn test_mixed_keys_reader() {
+    let mut app = bevy_app::App::new();
+    app.init_config_with::<Json, Settings>("ui", Json::new);
+    let json = app.world().resource::<manager::Instance<Json>>().instance.clone();
+
+    json.from_reader(app.world_mut(), Cursor::new(MIXED)).unwrap();
+    assert_loaded(&mut app);
+}
+
+#[test]
+fn test_mixed_keys_value() {
+    let mut app = bevy_app::App::new();
+    app.init_config_with::<Json, Settings>("ui", Json::new);
+    let json = app.world().resource::<manager::Instance<Json>>().instance.clone();
+
+    json.from_value(app.world_mut(), serde_json::from_str(MIXED).unwrap()).unwrap();
+    assert_loaded(&mut app);
+}
@@ -0,0 +1,49 @@
+use bevy_ecs::system::RunSystemOnce;
+use bevy_mod_config::{AppExt, Config, ReadConfig, WorldExt};
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct Meters(f32);
+
+bevy_mod_config::impl_config_newtype!(Meters => f32, unit = "m");
+
+#[derive(Debug, Clone, PartialEq)]
+struct Callsign(String);
+
+bevy_mod_config::impl_config_newtype!(Callsign => String);
+
+#[derive(Config)]
+struct Drone {
+    #[config(default = 10.0, min = 0.0, max = 100.0)]
+    altitude: Meters,
+    #[config(default = "alpha")]
+    callsign: Callsign,
+}
+
+fn read(app: &mut bevy_app::App) -> (Meters, Callsign) {
+    app.world_mut()
+        .run_system_once(|drone: ReadConfig<Drone>| {
+            let drone = drone.read();
+            (*drone.altitude, drone.callsign.clone())
+        })
+        .unwrap()
+}
+
+#[test]
+fn test_newtype_default_and_update() {
+    let mut app = bevy_app::App::new();
+    app.init_config::<(), Drone>("drone");
+    app.update();
+    assert_eq!(read(&mut app), (Meters(10.0), Callsign("alpha".into())));
+
+    app.world_mut().update_config::<Drone>(|drone| drone.altitude.set(Meters(25.0)));
+    app.update();
+    assert_eq!(read(&mut app).0, Meters(25.0));
+}
+
+#[cfg(feature = "serde_json")]
+#[test]
+fn test_newtype_serializes_transparently() {
+    assert_eq!(serde_json::to_string(&Meters(1.5)).unwrap(), "1.5");
+    let callsign: Callsign = serde_json::from_str("\"bravo\"").unwrap();
+    assert_eq!(callsign, Callsign("bravo".into()));
+}
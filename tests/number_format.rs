@@ -0,0 +1,54 @@
+use bevy_mod_config::impls::NumberFormat;
+use bevy_mod_config::{AppExt, Config, ScalarMetadata};
+
+#[test]
+fn test_default_format() {
+    let format = NumberFormat::default();
+    assert_eq!(format.format(1234567.5), "1234567.5");
+    assert_eq!(format.format(-42), "-42");
+}
+
+#[test]
+fn test_decimals() {
+    let format = NumberFormat { decimals: Some(3), ..NumberFormat::default() };
+    assert_eq!(format.format(0.5), "0.500");
+    assert_eq!(format.format(1.23456), "1.235");
+    assert_eq!(format.format(7u32), "7");
+}
+
+#[test]
+fn test_thousands_separator() {
+    let format = NumberFormat { thousands_separator: Some(','), ..NumberFormat::default() };
+    assert_eq!(format.format(1_234_567u64), "1,234,567");
+    assert_eq!(format.format(-1_234_567i64), "-1,234,567");
+    assert_eq!(format.format(123), "123");
+    assert_eq!(format.format(1234.5), "1,234.5");
+    assert_eq!(format.strip_separators("1,234,567"), "1234567");
+}
+
+#[test]
+fn test_scientific() {
+    let format = NumberFormat { scientific: true, ..NumberFormat::default() };
+    assert_eq!(format.format(0.000_001_5), "1.5e-6");
+    assert_eq!(format.format(1_500_000u32), "1.5e6");
+
+    let format = NumberFormat { scientific: true, decimals: Some(2), ..NumberFormat::default() };
+    assert_eq!(format.format(123_456.0), "1.23e5");
+}
+
+#[derive(Config)]
+struct Economy {
+    #[config(default = 1_000_000, format.thousands_separator = Some('_'))]
+    gold: u64,
+}
+
+#[test]
+fn test_format_metadata() {
+    let mut app = bevy_app::App::new();
+    app.init_config::<(), Economy>("economy");
+    app.update();
+
+    let mut query = app.world_mut().query::<&ScalarMetadata<u64>>();
+    let metadata = query.single(app.world()).unwrap();
+    assert_eq!(metadata.0.format.format(metadata.0.default), "1_000_000");
+}
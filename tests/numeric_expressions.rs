@@ -0,0 +1,23 @@
+#![cfg(feature = "egui")]
+
+use bevy_mod_config::manager::egui::evaluate_expression;
+
+#[test]
+fn test_evaluate_arithmetic() {
+    assert_eq!(evaluate_expression("1920/2"), Some(960.0));
+    assert_eq!(evaluate_expression("0.5*3"), Some(1.5));
+    assert_eq!(evaluate_expression(" 1 + 2 * 3 "), Some(7.0));
+    assert_eq!(evaluate_expression("(1 + 2) * 3"), Some(9.0));
+    assert_eq!(evaluate_expression("-2 - -3"), Some(1.0));
+    assert_eq!(evaluate_expression("1e3 / 4"), Some(250.0));
+    assert_eq!(evaluate_expression("2.5e-1*4"), Some(1.0));
+}
+
+#[test]
+fn test_evaluate_invalid() {
+    assert_eq!(evaluate_expression(""), None);
+    assert_eq!(evaluate_expression("1 +"), None);
+    assert_eq!(evaluate_expression("(1 + 2"), None);
+    assert_eq!(evaluate_expression("1 + 2)"), None);
+    assert_eq!(evaluate_expression("two"), None);
+}
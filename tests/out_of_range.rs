@@ -0,0 +1,59 @@
+#![cfg(feature = "serde_json")]
+
+use bevy_ecs::system::RunSystemOnce;
+use bevy_mod_config::manager::serde::OutOfRange;
+use bevy_mod_config::manager::serde::json::Json;
+use bevy_mod_config::{AppExt, Config, ReadConfig, manager};
+
+#[derive(Config)]
+struct Audio {
+    #[config(default = 50, min = 0, max = 100)]
+    volume: i32,
+    #[config(default = 1.0, min = 0.5, max = 2.0)]
+    pitch:  f32,
+}
+
+fn new_app() -> (bevy_app::App, Json) {
+    let mut app = bevy_app::App::new();
+    app.init_config_with::<Json, Audio>("audio", || {
+        Json::new().with_out_of_range(OutOfRange::Reject)
+    });
+    let json = app.world().resource::<manager::Instance<Json>>().instance.clone();
+    (app, json)
+}
+
+fn assert_audio(app: &mut bevy_app::App, expected: (i32, f32)) {
+    app.world_mut()
+        .run_system_once(move |audio: ReadConfig<Audio>| {
+            let audio = audio.read();
+            assert_eq!((audio.volume, audio.pitch), expected);
+        })
+        .unwrap();
+}
+
+#[test]
+fn test_reject_errors() {
+    let (mut app, json) = new_app();
+    let err = json.from_reader(app.world_mut(), r#"{"audio.volume":9000}"#.as_bytes()).unwrap_err();
+    assert!(err.to_string().contains("constraints"), "{err}");
+    assert_audio(&mut app, (50, 1.0));
+}
+
+#[test]
+fn test_reject_lenient() {
+    let (mut app, json) = new_app();
+    let errors = json
+        .from_reader_lenient(app.world_mut(), r#"{"audio.volume":-5,"audio.pitch":1.5}"#.as_bytes())
+        .unwrap();
+    assert_eq!(errors.len(), 1);
+    assert_eq!(errors[0].path, ["audio", "volume"]);
+    assert_audio(&mut app, (50, 1.5));
+}
+
+#[test]
+fn test_reject_accepts_valid_values() {
+    let (mut app, json) = new_app();
+    json.from_reader(app.world_mut(), r#"{"audio.volume":100,"audio.pitch":0.5}"#.as_bytes())
+        .unwrap();
+    assert_audio(&mut app, (100, 0.5));
+}
@@ -0,0 +1,34 @@
+#![cfg(all(feature = "bevy_color", feature = "serde_json"))]
+
+use bevy_color::Color;
+use bevy_ecs::system::RunSystemOnce;
+use bevy_mod_config::manager::serde::Json;
+use bevy_mod_config::types::Palette;
+use bevy_mod_config::{AppExt, Config, ReadConfig, manager};
+
+#[derive(Config)]
+struct Teams {
+    #[config(default = vec![Color::srgb(1.0, 0.0, 0.0), Color::srgb(0.0, 0.0, 1.0)])]
+    colors: Palette,
+}
+
+#[test]
+fn test_palette_hex() {
+    let mut app = bevy_app::App::new();
+    app.init_config_with::<Json, Teams>("teams", Json::new);
+
+    let json = app.world().resource::<manager::Instance<Json>>().instance.clone();
+    assert_eq!(
+        json.to_string(app.world_mut()).unwrap(),
+        r##"{"teams.colors":["#FF0000","#0000FF"]}"##
+    );
+
+    json.from_reader(app.world_mut(), r##"{"teams.colors":["#00FF00"]}"##.as_bytes()).unwrap();
+    app.world_mut()
+        .run_system_once(|teams: ReadConfig<Teams>| {
+            assert_eq!(teams.read().colors, [Color::srgb(0.0, 1.0, 0.0)]);
+        })
+        .unwrap();
+
+    assert!(json.from_reader(app.world_mut(), r#"{"teams.colors":["red"]}"#.as_bytes()).is_err());
+}
@@ -0,0 +1,50 @@
+use bevy_ecs::entity::Entity;
+use bevy_mod_config::{AppExt, Config, ConfigNode, Permission};
+
+#[derive(Config)]
+struct Session {
+    #[config(default = 1.0)]
+    sensitivity: f32,
+    #[config(permission = Permission::Admin)]
+    rules:       Rules,
+}
+
+#[derive(Config)]
+struct Rules {
+    #[config(default = 8)]
+    max_players: u32,
+    #[config(permission = Permission::ServerOnly)]
+    tick_rate:   u32,
+    #[config(permission = Permission::Player)]
+    motd:        String,
+}
+
+#[test]
+fn test_required_permission() {
+    let mut app = bevy_app::App::new();
+    app.init_config::<(), Session>("session");
+
+    let mut query = app.world_mut().query::<(Entity, &ConfigNode)>();
+    let world = app.world();
+    let mut permissions: Vec<_> = query
+        .iter(world)
+        .map(|(entity, node)| (node.path.join("."), Permission::required_for(world, entity)))
+        .collect();
+    permissions.sort();
+
+    assert_eq!(
+        permissions,
+        [
+            ("session".to_string(), Permission::Player),
+            ("session.rules".to_string(), Permission::Admin),
+            // Descendants cannot lower the permission of their ancestors.
+            ("session.rules.max_players".to_string(), Permission::Admin),
+            ("session.rules.motd".to_string(), Permission::Admin),
+            ("session.rules.tick_rate".to_string(), Permission::ServerOnly),
+            ("session.sensitivity".to_string(), Permission::Player),
+        ]
+    );
+
+    assert!(Permission::Admin.allows(Permission::Player));
+    assert!(!Permission::Admin.allows(Permission::ServerOnly));
+}
@@ -0,0 +1,136 @@
+#![cfg(feature = "serde_json")]
+
+use bevy_ecs::error::Result;
+use bevy_ecs::system::RunSystemOnce;
+use bevy_mod_config::manager::serde::Json;
+use bevy_mod_config::manager::serde::persistent::{self, ConfigDocument};
+use bevy_mod_config::{AppExt, Config, ReadConfig, WorldExt, manager};
+use bevy_persistent::{Persistent, StorageFormat};
+use serde_json::json;
+
+#[derive(Config)]
+struct Settings {
+    #[config(default = 60)]
+    fps:  u32,
+    name: String,
+}
+
+fn new_app() -> bevy_app::App {
+    let mut app = bevy_app::App::new();
+    app.init_config_with::<Json, Settings>("settings", Json::new);
+    app
+}
+
+#[test]
+fn test_export() {
+    let mut app = new_app();
+    let world = app.world_mut();
+    world
+        .run_system_once::<_, Result, _>(persistent::export_document::<Json, _>(|json| json))
+        .unwrap()
+        .unwrap();
+    assert_eq!(
+        world.resource::<ConfigDocument>().0,
+        json!({"settings.fps": 60, "settings.name": ""})
+    );
+
+    let json = world.resource::<manager::Instance<Json>>().instance.clone();
+    assert!(json.is_dirty(world, "settings"), "exporting does not mark the fields clean");
+
+    world.resource_mut::<ConfigDocument>().0 = json!({});
+    world
+        .run_system_once::<_, Result, _>(persistent::export_document::<Json, _>(|json| json))
+        .unwrap()
+        .unwrap();
+    assert_eq!(world.resource::<ConfigDocument>().0, json!({}), "unchanged config is not exported");
+
+    world.update_config::<Settings>(|settings| settings.fps.set(30));
+    world
+        .run_system_once::<_, Result, _>(persistent::export_document::<Json, _>(|json| json))
+        .unwrap()
+        .unwrap();
+    assert_eq!(
+        world.resource::<ConfigDocument>().0,
+        json!({"settings.fps": 30, "settings.name": ""})
+    );
+}
+
+#[test]
+fn test_import() {
+    let mut app = new_app();
+    let world = app.world_mut();
+    world.insert_resource(ConfigDocument(json!({"settings.name": "server"})));
+    world
+        .run_system_once::<_, Result, _>(persistent::import_document::<Json, _>(|json| json))
+        .unwrap()
+        .unwrap();
+    world
+        .run_system_once(|settings: ReadConfig<Settings>| {
+            let settings = settings.read();
+            assert_eq!((settings.fps, settings.name), (60, "server"));
+        })
+        .unwrap();
+}
+
+#[test]
+fn test_document_roundtrip() {
+    let document = ConfigDocument(json!({"settings.fps": 30}));
+    let text = serde_json::to_string(&document).unwrap();
+    assert_eq!(text, r#"{"settings.fps":30}"#);
+    assert_eq!(serde_json::from_str::<ConfigDocument>(&text).unwrap(), document);
+}
+
+#[test]
+fn test_import_invalid() {
+    let mut app = new_app();
+    let world = app.world_mut();
+    world.insert_resource(ConfigDocument(json!({"settings.fps": "fast"})));
+    let result = world
+        .run_system_once::<_, Result, _>(persistent::import_document::<Json, _>(|json| json))
+        .unwrap();
+    assert!(result.is_err());
+}
+
+#[test]
+fn test_bevy_persistent_roundtrip() {
+    let path = std::env::temp_dir()
+        .join(format!("bevy_mod_config_persistent_{}.toml", std::process::id()));
+    let _ = std::fs::remove_file(&path);
+    let new_persistent = || {
+        Persistent::<ConfigDocument>::builder()
+            .name("settings")
+            .format(StorageFormat::Toml)
+            .path(&path)
+            .default(ConfigDocument::default())
+            .build()
+            .unwrap()
+    };
+
+    let mut app = new_app();
+    let world = app.world_mut();
+    world.update_config::<Settings>(|settings| {
+        settings.fps.set(144);
+        settings.name.set(String::from("client"));
+    });
+    world
+        .run_system_once::<_, Result, _>(persistent::export_document::<Json, _>(|json| json))
+        .unwrap()
+        .unwrap();
+    new_persistent().set(world.resource::<ConfigDocument>().clone()).unwrap();
+
+    let mut app = new_app();
+    let world = app.world_mut();
+    world.insert_resource(new_persistent().get().clone());
+    world
+        .run_system_once::<_, Result, _>(persistent::import_document::<Json, _>(|json| json))
+        .unwrap()
+        .unwrap();
+    world
+        .run_system_once(|settings: ReadConfig<Settings>| {
+            let settings = settings.read();
+            assert_eq!((settings.fps, settings.name), (144, "client"));
+        })
+        .unwrap();
+
+    std::fs::remove_file(&path).unwrap();
+}
@@ -0,0 +1,38 @@
+#![cfg(all(feature = "egui", feature = "serde_json"))]
+
+use bevy_ecs::system::RunSystemOnce;
+use bevy_mod_config::manager::egui::pinned::PinnedFields;
+use bevy_mod_config::manager::serde::json::Json;
+use bevy_mod_config::{AppExt, Config, ReadConfig, WorldExt, manager};
+
+#[derive(Config)]
+struct Video {
+    #[config(default = 60)]
+    fps: u32,
+}
+
+#[test]
+fn test_pinned_fields_persisted() {
+    let mut app = bevy_app::App::new();
+    app.init_config_with::<Json, Video>("video", Json::new);
+    app.init_config_with::<Json, PinnedFields>("pinned", Json::new);
+    app.update();
+
+    app.world_mut().update_config::<PinnedFields>(|pinned| {
+        pinned.set(PinnedFields(vec!["video.fps".into()]));
+    });
+    app.update();
+
+    let json = app.world().resource::<manager::Instance<Json>>().instance.clone();
+    assert_eq!(
+        json.to_string(app.world_mut()).unwrap(),
+        r#"{"pinned":["video.fps"],"video.fps":60}"#
+    );
+
+    json.from_reader(app.world_mut(), r#"{"pinned":[]}"#.as_bytes()).unwrap();
+    let pinned = app
+        .world_mut()
+        .run_system_once(|pinned: ReadConfig<PinnedFields>| pinned.read().0.clone())
+        .unwrap();
+    assert!(pinned.is_empty());
+}
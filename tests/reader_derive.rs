@@ -0,0 +1,37 @@
+use bevy_ecs::system::RunSystemOnce;
+use bevy_mod_config::{AppExt, Config, ReadConfig};
+
+#[derive(Config)]
+#[config(reader_derive(Debug, PartialEq))]
+struct Video {
+    #[config(default = 1280)]
+    width:  u32,
+    window: Window,
+}
+
+#[derive(Config)]
+#[config(reader_derive(Debug, PartialEq))]
+enum Window {
+    Windowed {
+        #[config(default = "Game")]
+        title: String,
+    },
+    Fullscreen,
+}
+
+#[test]
+fn test_reader_debug() {
+    let mut app = bevy_app::App::new();
+    app.init_config::<(), Video>("video");
+
+    app.world_mut()
+        .run_system_once(|video: ReadConfig<Video>| {
+            let video = video.read();
+            assert_eq!(
+                format!("{video:?}"),
+                r#"VideoRead { width: 1280, window: Windowed { title: "Game" } }"#
+            );
+            assert_eq!(video, video.clone());
+        })
+        .unwrap();
+}
@@ -0,0 +1,127 @@
+use core::time::Duration;
+
+use bevy_ecs::system::RunSystemOnce;
+use bevy_mod_config::manager::record::{self, Recorder, Recording, Replay, TypeMismatch};
+use bevy_mod_config::{AppExt, ChangeBatch, Config, ReadConfig, WorldExt};
+
+#[derive(Config)]
+struct Settings {
+    #[config(default = 60)]
+    fps:    u32,
+    #[config(default = 1.0)]
+    volume: f32,
+}
+
+#[derive(Config)]
+struct MismatchSettings {
+    #[config(default = false)]
+    fps:    bool,
+    #[config(default = 1.0)]
+    volume: f32,
+}
+
+fn read_settings(app: &mut bevy_app::App) -> (u32, f32) {
+    app.world_mut()
+        .run_system_once(|settings: ReadConfig<Settings>| {
+            let settings = settings.read();
+            (settings.fps, settings.volume)
+        })
+        .unwrap()
+}
+
+fn record_session() -> Recording {
+    let mut app = bevy_app::App::new();
+    app.init_config::<Recorder, Settings>("settings");
+    record::record(app.world_mut(), Duration::ZERO);
+
+    app.world_mut().update_config::<Settings>(|settings| settings.fps.set(30));
+    record::record(app.world_mut(), Duration::from_secs(1));
+
+    app.world_mut().update_config::<Settings>(|settings| settings.volume.set(0.5));
+    record::record(app.world_mut(), Duration::from_secs(2));
+
+    app.world().resource::<Recording>().clone()
+}
+
+#[test]
+fn test_record_replay() {
+    let recording = record_session();
+    assert_eq!(recording.changes().len(), 4);
+
+    let mut app = bevy_app::App::new();
+    app.init_config::<Recorder, Settings>("settings");
+    app.world_mut().update_config::<Settings>(|settings| settings.fps.set(144));
+
+    let mut replay = Replay::new(recording);
+    assert!(replay.advance(app.world_mut(), Duration::ZERO).is_empty());
+    assert_eq!(read_settings(&mut app), (60, 1.0));
+
+    assert!(replay.advance(app.world_mut(), Duration::from_secs(1)).is_empty());
+    assert_eq!(read_settings(&mut app), (30, 1.0));
+    assert!(!replay.is_finished());
+
+    assert!(replay.advance(app.world_mut(), Duration::from_secs(2)).is_empty());
+    assert_eq!(read_settings(&mut app), (30, 0.5));
+    assert!(replay.is_finished());
+}
+
+#[test]
+fn test_replay_type_mismatch() {
+    let recording = record_session();
+
+    let mut app = bevy_app::App::new();
+    app.init_config::<Recorder, MismatchSettings>("settings");
+
+    let mut replay = Replay::new(recording);
+    let errors = replay.advance(app.world_mut(), Duration::from_secs(2));
+    assert_eq!(
+        errors,
+        [
+            TypeMismatch { path: vec!["settings".into(), "fps".into()] },
+            TypeMismatch { path: vec!["settings".into(), "fps".into()] },
+        ]
+    );
+    assert!(replay.is_finished());
+
+    let volume = app
+        .world_mut()
+        .run_system_once(|settings: ReadConfig<MismatchSettings>| settings.read().volume)
+        .unwrap();
+    assert_eq!(volume, 0.5);
+}
+
+#[test]
+fn test_replay_in_batch_is_not_recorded() {
+    let recording = record_session();
+
+    let mut app = bevy_app::App::new();
+    app.init_config::<Recorder, Settings>("settings");
+
+    let mut replay = Replay::new(recording);
+    app.world_mut().resource_mut::<ChangeBatch>().begin();
+    assert!(replay.advance(app.world_mut(), Duration::from_secs(2)).is_empty());
+    record::record(app.world_mut(), Duration::from_secs(2));
+    app.world_mut().resource_mut::<ChangeBatch>().end();
+    app.update();
+    assert_eq!(read_settings(&mut app), (30, 0.5));
+
+    // The replayed values must not be recorded again once the deferred bumps are applied.
+    record::record(app.world_mut(), Duration::from_secs(3));
+    assert!(app.world().resource::<Recording>().changes().is_empty());
+}
+
+#[cfg(feature = "serde_json")]
+#[test]
+fn test_recording_round_trip() {
+    let recording = record_session();
+    let json = serde_json::to_string(&recording).unwrap();
+    let recording: Recording = serde_json::from_str(&json).unwrap();
+    assert_eq!(recording.changes().len(), 4);
+
+    let mut app = bevy_app::App::new();
+    app.init_config::<Recorder, Settings>("settings");
+
+    let mut replay = Replay::new(recording);
+    assert!(replay.advance(app.world_mut(), Duration::from_secs(2)).is_empty());
+    assert_eq!(read_settings(&mut app), (30, 0.5));
+}
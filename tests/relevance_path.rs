@@ -0,0 +1,81 @@
+use bevy_ecs::entity::Entity;
+use bevy_mod_config::{AppExt, ConditionalRelevance, Config, ConfigNode, WorldExt};
+
+#[derive(Config)]
+struct General {
+    developer_mode: bool,
+    #[config(default = 2)]
+    log_level:      u8,
+}
+
+#[derive(Config)]
+struct DebugOptions {
+    #[config(relevant_if = "general.developer_mode")]
+    show_colliders: bool,
+    #[config(relevant_if(path = "general.log_level", when = |level: &u8| *level >= 3))]
+    trace_ai:       bool,
+}
+
+fn find_node(app: &mut bevy_app::App, path: &str) -> Entity {
+    let mut query = app.world_mut().query::<(Entity, &ConfigNode)>();
+    query
+        .iter(app.world())
+        .find_map(|(entity, node)| (node.path.join(".") == path).then_some(entity))
+        .unwrap()
+}
+
+fn is_relevant(app: &mut bevy_app::App, path: &str) -> bool {
+    let node = find_node(app, path);
+    let relevance =
+        app.world().get::<ConditionalRelevance>(node).expect("relevance path must be resolved");
+    (relevance.is_entity_relevant)(app.world().entity(relevance.dependency))
+}
+
+#[test]
+fn test_relevance_path_across_roots() {
+    let mut app = bevy_app::App::new();
+    // The dependency root is initialized after the dependent root.
+    app.init_config::<(), DebugOptions>("debug");
+    app.init_config::<(), General>("general");
+    app.update();
+
+    let node = find_node(&mut app, "debug.show_colliders");
+    let dependency = app.world().get::<ConditionalRelevance>(node).unwrap().dependency;
+    assert_eq!(dependency, find_node(&mut app, "general.developer_mode"));
+    assert!(!is_relevant(&mut app, "debug.show_colliders"));
+    assert!(!is_relevant(&mut app, "debug.trace_ai"));
+
+    app.world_mut().update_config::<General>(|general| {
+        general.developer_mode.set(true);
+        general.log_level.set(3);
+    });
+    assert!(is_relevant(&mut app, "debug.show_colliders"));
+    assert!(is_relevant(&mut app, "debug.trace_ai"));
+}
+
+#[test]
+fn test_relevance_path_unresolved() {
+    let mut app = bevy_app::App::new();
+    app.init_config::<(), DebugOptions>("debug");
+    app.update();
+
+    let node = find_node(&mut app, "debug.show_colliders");
+    assert!(app.world().get::<ConditionalRelevance>(node).is_none());
+}
+
+#[test]
+fn test_relevance_path_resolved_later() {
+    let mut app = bevy_app::App::new();
+    app.init_config::<(), DebugOptions>("debug");
+    app.update();
+    app.update();
+
+    let node = find_node(&mut app, "debug.show_colliders");
+    assert!(app.world().get::<ConditionalRelevance>(node).is_none());
+
+    app.init_config::<(), General>("general");
+    app.update();
+
+    assert!(!is_relevant(&mut app, "debug.show_colliders"));
+    assert!(!is_relevant(&mut app, "debug.trace_ai"));
+}
@@ -0,0 +1,100 @@
+#![cfg(all(feature = "serde_json", feature = "bevy_tasks"))]
+
+use bevy_ecs::entity::Entity;
+use bevy_ecs::message::Messages;
+use bevy_ecs::system::RunSystemOnce;
+use bevy_mod_config::manager::Layers;
+use bevy_mod_config::manager::layer::{self, Layer};
+use bevy_mod_config::manager::serde::Json;
+use bevy_mod_config::manager::serde::remote::{
+    RemoteConfigFetched, RemoteConfigPlugin, RemoteError,
+};
+use bevy_mod_config::{AppExt, Config, ConfigNode, ReadConfig, manager};
+use bevy_tasks::{IoTaskPool, TaskPool};
+
+#[derive(Config)]
+struct Tuning {
+    #[config(default = 1.0)]
+    xp_multiplier: f32,
+    #[config(default = 10)]
+    max_party:     u32,
+}
+
+fn new_app(payload: Result<&'static str, &'static str>) -> bevy_app::App {
+    IoTaskPool::get_or_init(TaskPool::new);
+
+    let mut app = bevy_app::App::new();
+    app.init_config_with::<(Json, Layers), Tuning>("tuning", || (Json::new(), Layers));
+    app.add_plugins(RemoteConfigPlugin::with_manager(
+        "https://example.com/tuning.json",
+        move |url| {
+            assert_eq!(url, "https://example.com/tuning.json");
+            payload.map(|payload| payload.as_bytes().to_vec()).map_err(String::from)
+        },
+        |managers: &(Json, Layers)| &managers.0,
+    ));
+    app
+}
+
+fn wait_for_fetch(app: &mut bevy_app::App) -> Result<(), RemoteError> {
+    for _ in 0..1000 {
+        app.update();
+        let mut messages = app.world_mut().resource_mut::<Messages<RemoteConfigFetched>>();
+        if let Some(fetched) = messages.drain().next() {
+            return fetched.result;
+        }
+        std::thread::sleep(std::time::Duration::from_millis(1));
+    }
+    panic!("remote config was not fetched");
+}
+
+fn read(app: &mut bevy_app::App) -> (f32, u32) {
+    app.world_mut()
+        .run_system_once(|tuning: ReadConfig<Tuning>| {
+            let tuning = tuning.read();
+            (tuning.xp_multiplier, tuning.max_party)
+        })
+        .unwrap()
+}
+
+fn field(app: &mut bevy_app::App, name: &str) -> Entity {
+    let mut query = app.world_mut().query::<(Entity, &ConfigNode)>();
+    query.iter(app.world()).find(|(_, node)| node.path == ["tuning", name]).unwrap().0
+}
+
+#[test]
+fn test_remote_override() {
+    let mut app = new_app(Ok(r#"{"tuning.xp_multiplier": 2.0}"#));
+    wait_for_fetch(&mut app).unwrap();
+
+    assert_eq!(read(&mut app), (2.0, 10));
+    let xp_multiplier = field(&mut app, "xp_multiplier");
+    assert_eq!(layer::provenance(app.world(), xp_multiplier), Some(Layer::Remote));
+
+    layer::clear(app.world_mut(), Layer::Remote);
+    assert_eq!(read(&mut app), (1.0, 10));
+}
+
+#[test]
+fn test_remote_errors() {
+    let mut app = new_app(Err("connection refused"));
+    assert!(
+        matches!(wait_for_fetch(&mut app), Err(RemoteError::Fetch(err)) if err == "connection refused")
+    );
+
+    let mut app = new_app(Ok("not json"));
+    assert!(matches!(wait_for_fetch(&mut app), Err(RemoteError::Invalid(_))));
+    assert_eq!(read(&mut app), (1.0, 10));
+}
+
+#[test]
+fn test_remote_override_not_persisted() {
+    let mut app = new_app(Ok(r#"{"tuning.xp_multiplier": 2.0}"#));
+    wait_for_fetch(&mut app).unwrap();
+    assert_eq!(read(&mut app), (2.0, 10));
+
+    let json = app.world().resource::<manager::Instance<(Json, Layers)>>().instance.0.clone();
+    let value = json.to_value(app.world_mut()).unwrap();
+    assert_eq!(value["tuning.xp_multiplier"], 1.0);
+    assert_eq!(value["tuning.max_party"], 10);
+}
@@ -0,0 +1,141 @@
+use bevy_ecs::entity::Entity;
+use bevy_ecs::query::With;
+use bevy_ecs::system::RunSystemOnce;
+use bevy_mod_config::{AppExt, Config, ReadConfig, RootNode, VariantSwitch, WorldExt, reset};
+
+#[derive(Config)]
+struct Settings {
+    color:  Color,
+    shade:  Shade,
+    #[config(discrim.on_switch = VariantSwitch::Reset)]
+    accent: Shade,
+}
+
+#[derive(Config)]
+#[config(expose(read, discrim), reset_variants)]
+enum Color {
+    Black,
+    Rgb(#[config(default = 255)] u8, #[config(default = 128)] u8, #[config(default = 0)] u8),
+}
+
+#[derive(Config)]
+#[config(expose(read, discrim))]
+enum Shade {
+    None,
+    Gray {
+        #[config(default = 10)]
+        level: u8,
+    },
+}
+
+fn read_settings(app: &mut bevy_app::App) -> (Option<[u8; 3]>, Option<u8>) {
+    app.world_mut()
+        .run_system_once(|settings: ReadConfig<Settings>| {
+            let settings = settings.read();
+            let color = match settings.color {
+                ColorRead::Black => None,
+                ColorRead::Rgb(r, g, b) => Some([r, g, b]),
+            };
+            let shade = match settings.shade {
+                ShadeRead::None => None,
+                ShadeRead::Gray { level } => Some(level),
+            };
+            (color, shade)
+        })
+        .unwrap()
+}
+
+fn switch_away_and_back(app: &mut bevy_app::App) {
+    app.world_mut().update_config::<Settings>(|settings| {
+        settings.color.discrim.set(ColorDiscrim::Black);
+        settings.shade.discrim.set(ShadeDiscrim::None);
+    });
+    app.update();
+    app.world_mut().update_config::<Settings>(|settings| {
+        settings.color.discrim.set(ColorDiscrim::Rgb);
+        settings.shade.discrim.set(ShadeDiscrim::Gray);
+    });
+    app.update();
+}
+
+#[test]
+fn test_reset_variants_on_switch() {
+    let mut app = bevy_app::App::new();
+    app.init_config::<(), Settings>("settings");
+    app.update();
+
+    app.world_mut().update_config::<Settings>(|settings| {
+        settings.color.discrim.set(ColorDiscrim::Rgb);
+        settings.shade.discrim.set(ShadeDiscrim::Gray);
+    });
+    app.update();
+    assert_eq!(read_settings(&mut app), (Some([255, 128, 0]), Some(10)));
+
+    app.world_mut().update_config::<Settings>(|settings| {
+        settings.color.v_Rgb.0.set(1);
+        settings.shade.v_Gray.level.set(2);
+    });
+    app.update();
+    assert_eq!(read_settings(&mut app), (Some([1, 128, 0]), Some(2)));
+
+    switch_away_and_back(&mut app);
+    // Only `Color` resets its variants; `Shade` keeps the previous values.
+    assert_eq!(read_settings(&mut app), (Some([255, 128, 0]), Some(2)));
+}
+
+#[test]
+fn test_reset_to_default() {
+    let mut app = bevy_app::App::new();
+    app.init_config::<(), Settings>("settings");
+    app.update();
+    let root =
+        app.world_mut().query_filtered::<Entity, With<RootNode>>().single(app.world()).unwrap();
+
+    app.world_mut().update_config::<Settings>(|settings| {
+        settings.shade.discrim.set(ShadeDiscrim::Gray);
+        settings.shade.v_Gray.level.set(2);
+    });
+    app.update();
+    assert_eq!(read_settings(&mut app), (None, Some(2)));
+
+    reset::reset_to_default(app.world_mut(), root);
+    app.update();
+    assert_eq!(read_settings(&mut app), (None, None));
+
+    app.world_mut().update_config::<Settings>(|settings| {
+        settings.shade.discrim.set(ShadeDiscrim::Gray);
+    });
+    assert_eq!(read_settings(&mut app), (None, Some(10)));
+}
+
+#[test]
+fn test_reset_variants_field_override() {
+    let mut app = bevy_app::App::new();
+    app.init_config::<(), Settings>("settings");
+    app.update();
+
+    app.world_mut().update_config::<Settings>(|settings| {
+        settings.accent.discrim.set(ShadeDiscrim::Gray);
+    });
+    app.update();
+    app.world_mut().update_config::<Settings>(|settings| {
+        settings.accent.v_Gray.level.set(2);
+    });
+    app.update();
+
+    app.world_mut().update_config::<Settings>(|settings| {
+        settings.accent.discrim.set(ShadeDiscrim::None);
+    });
+    app.update();
+    app.world_mut().update_config::<Settings>(|settings| {
+        settings.accent.discrim.set(ShadeDiscrim::Gray);
+    });
+    let level = app
+        .world_mut()
+        .run_system_once(|settings: ReadConfig<Settings>| match settings.read().accent {
+            ShadeRead::None => None,
+            ShadeRead::Gray { level } => Some(level),
+        })
+        .unwrap();
+    assert_eq!(level, Some(10));
+}
@@ -0,0 +1,31 @@
+#![cfg(feature = "serde_json")]
+
+use bevy_ecs::system::RunSystemOnce;
+use bevy_mod_config::manager::serde::Json;
+use bevy_mod_config::types::Resolution;
+use bevy_mod_config::{AppExt, Config, ReadConfig, manager};
+
+#[derive(Config)]
+struct Video {
+    resolution: Resolution,
+}
+
+#[test]
+fn test_resolution() {
+    let mut app = bevy_app::App::new();
+    app.init_config_with::<Json, Video>("video", Json::new);
+
+    let json = app.world().resource::<manager::Instance<Json>>().instance.clone();
+    assert_eq!(json.to_string(app.world_mut()).unwrap(), r#"{"video.resolution":"1920x1080"}"#);
+
+    json.from_reader(app.world_mut(), r#"{"video.resolution":"2560x1440"}"#.as_bytes()).unwrap();
+    app.world_mut()
+        .run_system_once(|video: ReadConfig<Video>| {
+            assert_eq!(video.read().resolution, Resolution::new(2560, 1440));
+        })
+        .unwrap();
+
+    assert!(
+        json.from_reader(app.world_mut(), r#"{"video.resolution":"wide"}"#.as_bytes()).is_err()
+    );
+}
@@ -0,0 +1,52 @@
+use bevy_ecs::query::With;
+use bevy_mod_config::{AppExt, Config, ConfigNode, RootNode, RootOrder, WorldExt};
+
+#[derive(Config)]
+struct Video {
+    fps: u32,
+}
+
+#[derive(Config)]
+struct Audio {
+    volume: f32,
+}
+
+#[derive(Config)]
+struct Controls {
+    sensitivity: f32,
+}
+
+fn sorted_keys(app: &mut bevy_app::App) -> Vec<String> {
+    let world = app.world_mut();
+    let mut roots: Vec<_> = world
+        .query_filtered::<(&ConfigNode, &RootOrder), With<RootNode>>()
+        .iter(world)
+        .map(|(node, order)| (order.sort_key(), node.path[0].clone()))
+        .collect();
+    roots.sort();
+    roots.into_iter().map(|(_, key)| key).collect()
+}
+
+#[test]
+fn test_registration_order() {
+    let mut app = bevy_app::App::new();
+    app.init_config::<(), Video>("video");
+    app.init_config::<(), Audio>("audio");
+    app.init_config::<(), Controls>("controls");
+
+    assert_eq!(sorted_keys(&mut app), ["video", "audio", "controls"]);
+}
+
+#[test]
+fn test_reorder_and_pin() {
+    let mut app = bevy_app::App::new();
+    app.init_config::<(), Video>("video");
+    app.init_config::<(), Audio>("audio");
+    app.init_config::<(), Controls>("controls");
+
+    app.world_mut().root_order::<Video>().position = 10;
+    assert_eq!(sorted_keys(&mut app), ["audio", "controls", "video"]);
+
+    app.world_mut().root_order::<Controls>().pinned = true;
+    assert_eq!(sorted_keys(&mut app), ["controls", "audio", "video"]);
+}
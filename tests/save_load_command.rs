@@ -0,0 +1,57 @@
+#![cfg(feature = "serde_json")]
+
+use bevy_ecs::system::RunSystemOnce;
+use bevy_mod_config::manager::serde::json::{Json, LoadConfigCommand, SaveConfigCommand};
+use bevy_mod_config::{AppExt, Config, ReadConfig, WorldExt, manager};
+
+#[derive(Config)]
+struct Audio {
+    #[config(default = 80)]
+    volume: u32,
+    muted:  bool,
+}
+
+type ManagerType = (Json, manager::Recorder);
+
+fn json_manager(managers: &ManagerType) -> &Json { &managers.0 }
+
+#[test]
+fn test_save_load_commands() {
+    let path = std::env::temp_dir()
+        .join(format!("bevy_mod_config_save_load_command_{}.json", std::process::id()));
+
+    let mut app = bevy_app::App::new();
+    app.init_config_with::<ManagerType, Audio>("audio", || (Json::new(), manager::Recorder));
+
+    let json = app.world().resource::<manager::Instance<ManagerType>>().instance.0.clone();
+    let world = app.world_mut();
+    world.update_config::<Audio>(|audio| audio.volume.set(30));
+    assert!(json.is_dirty(world, "audio"));
+    world.commands().queue(SaveConfigCommand::with_manager(&path, json_manager));
+    world.flush();
+    assert_eq!(
+        std::fs::read_to_string(&path).unwrap(),
+        r#"{"audio.muted":false,"audio.volume":30}"#
+    );
+    assert!(!json.is_dirty(world, "audio"));
+
+    // The file is replaced through a temporary file, which does not remain.
+    let leftovers = std::fs::read_dir(path.parent().unwrap())
+        .unwrap()
+        .filter(|entry| {
+            let name = entry.as_ref().unwrap().file_name();
+            let name = name.to_string_lossy();
+            name.starts_with(path.file_name().unwrap().to_str().unwrap()) && name.ends_with(".tmp")
+        })
+        .count();
+    assert_eq!(leftovers, 0);
+
+    world.update_config::<Audio>(|audio| audio.volume.set(50));
+    assert!(json.is_dirty(world, "audio"));
+    world.commands().queue(LoadConfigCommand::with_manager(&path, json_manager));
+    world.flush();
+    world.run_system_once(|audio: ReadConfig<Audio>| assert_eq!(audio.read().volume, 30)).unwrap();
+    assert!(!json.is_dirty(world, "audio"));
+
+    std::fs::remove_file(&path).unwrap();
+}
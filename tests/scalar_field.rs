@@ -0,0 +1,76 @@
+use bevy_ecs::entity::Entity;
+use bevy_ecs::query::With;
+use bevy_ecs::system::RunSystemOnce;
+use bevy_mod_config::{AppExt, Config, ConfigTree, RootNode};
+
+#[derive(Config)]
+struct Settings {
+    volume: f32,
+    names:  Vec<String>,
+    window: Window,
+    mode:   Mode,
+}
+
+#[derive(Config)]
+struct Window {
+    width:  u32,
+    height: u32,
+}
+
+#[derive(Config)]
+enum Mode {
+    Windowed,
+    Fullscreen { monitor: u32 },
+}
+
+#[test]
+fn test_iter_scalars() {
+    let mut app = bevy_app::App::new();
+    app.init_config::<(), Settings>("settings");
+
+    app.world_mut()
+        .run_system_once(|tree: ConfigTree| {
+            let mut paths: Vec<_> =
+                tree.iter_scalars().map(|(_, node)| node.path.join(".")).collect();
+            paths.sort();
+            assert_eq!(
+                paths,
+                [
+                    "settings.mode.Fullscreen.monitor",
+                    "settings.mode.discrim",
+                    "settings.names",
+                    "settings.volume",
+                    "settings.window.height",
+                    "settings.window.width",
+                ]
+            );
+        })
+        .unwrap();
+}
+
+#[test]
+fn test_iter_children_of() {
+    let mut app = bevy_app::App::new();
+    app.init_config::<(), Settings>("settings");
+
+    let root =
+        app.world_mut().query_filtered::<Entity, With<RootNode>>().single(app.world()).unwrap();
+    app.world_mut()
+        .run_system_once(move |tree: ConfigTree| {
+            assert!(!tree.is_scalar(root));
+            let children: Vec<_> = tree
+                .iter_children_of(root)
+                .map(|(entity, node)| (node.path.join("."), tree.is_scalar(entity)))
+                .collect();
+            assert_eq!(
+                children,
+                [
+                    ("settings.volume".to_owned(), true),
+                    ("settings.names".to_owned(), true),
+                    ("settings.window".to_owned(), false),
+                    ("settings.mode".to_owned(), false),
+                ]
+            );
+        })
+        .unwrap();
+}
@@ -0,0 +1,80 @@
+#![cfg(feature = "serde_json")]
+
+use bevy_mod_config::manager::serde::json::SetValueError;
+use bevy_mod_config::manager::serde::{Json, scripting};
+use bevy_mod_config::{AppExt, Config, WorldExt};
+use serde_json::json;
+
+#[derive(Config)]
+struct Player {
+    #[config(default = 5.0)]
+    speed:  f32,
+    flight: Flight,
+}
+
+#[derive(Config)]
+struct Flight {
+    enabled: bool,
+}
+
+#[derive(Config)]
+struct Audio {
+    #[config(default = 0.5)]
+    volume: f32,
+}
+
+fn new_app() -> bevy_app::App {
+    let mut app = bevy_app::App::new();
+    app.init_config_with::<Json, Player>("player", Json::new);
+    app.init_config_with::<Json, Audio>("audio", Json::new);
+    app.update();
+    app
+}
+
+#[test]
+fn test_paths_and_access() {
+    let mut app = new_app();
+    let world = app.world_mut();
+
+    assert_eq!(scripting::paths(world), ["audio.volume", "player.flight.enabled", "player.speed"]);
+    assert_eq!(scripting::get(world, "player.speed").unwrap(), json!(5.0));
+
+    scripting::set(world, "player.flight.enabled", json!(true)).unwrap();
+    assert_eq!(scripting::get(world, "player.flight.enabled").unwrap(), json!(true));
+
+    assert!(matches!(scripting::get(world, "player.jump"), Err(SetValueError::NotFound(_))));
+    assert!(matches!(scripting::get(world, "player.flight"), Err(SetValueError::NotScalar(_))));
+    assert!(matches!(
+        scripting::set(world, "player.speed", json!("fast")),
+        Err(SetValueError::Invalid(..))
+    ));
+}
+
+#[test]
+fn test_subscriptions() {
+    let mut app = new_app();
+    let player = scripting::subscribe(app.world_mut(), "player");
+    let all = scripting::subscribe(app.world_mut(), "");
+
+    scripting::set(app.world_mut(), "player.speed", json!(6.0)).unwrap();
+    app.world_mut().update_config::<Audio>(|audio| audio.volume.set(0.25));
+    app.update();
+    scripting::set(app.world_mut(), "player.speed", json!(7.0)).unwrap();
+    app.update();
+
+    let changes = scripting::poll(app.world_mut(), player);
+    assert_eq!(changes.len(), 1);
+    assert_eq!(changes[0].path, "player.speed");
+    assert_eq!(changes[0].value, json!(7.0));
+    assert!(scripting::poll(app.world_mut(), player).is_empty());
+
+    let mut paths: Vec<_> =
+        scripting::poll(app.world_mut(), all).into_iter().map(|change| change.path).collect();
+    paths.sort();
+    assert_eq!(paths, ["audio.volume", "player.speed"]);
+
+    scripting::unsubscribe(app.world_mut(), player);
+    scripting::set(app.world_mut(), "player.speed", json!(8.0)).unwrap();
+    app.update();
+    assert!(scripting::poll(app.world_mut(), player).is_empty());
+}
@@ -0,0 +1,52 @@
+use bevy_ecs::query::Has;
+use bevy_ecs::system::RunSystemOnce;
+use bevy_mod_config::{AppExt, ChildNodeList, Config, ConfigNode, ReadConfig, Section};
+
+#[derive(Config)]
+struct Graphics {
+    #[config(default = 1.0)]
+    brightness: f32,
+    advanced:   AdvancedSection,
+    #[config(default = 4)]
+    msaa:       u32,
+}
+
+#[derive(Config)]
+struct AdvancedSection;
+
+#[test]
+fn test_section_node() {
+    let mut app = bevy_app::App::new();
+    app.init_config::<(), Graphics>("graphics");
+
+    let mut query = app.world_mut().query::<(&ConfigNode, Has<Section>, Has<ChildNodeList>)>();
+    let mut nodes: Vec<_> = query
+        .iter(app.world())
+        .map(|(node, is_section, has_children)| (node.path.join("."), is_section, has_children))
+        .collect();
+    nodes.sort();
+
+    assert_eq!(
+        nodes,
+        [
+            ("graphics".to_string(), false, true),
+            ("graphics.advanced".to_string(), true, false),
+            ("graphics.brightness".to_string(), false, false),
+            ("graphics.msaa".to_string(), false, false),
+        ]
+    );
+}
+
+#[test]
+fn test_read_around_section() {
+    let mut app = bevy_app::App::new();
+    app.init_config::<(), Graphics>("graphics");
+
+    app.world_mut()
+        .run_system_once(|graphics: ReadConfig<Graphics>| {
+            let graphics = graphics.read();
+            assert_eq!(graphics.brightness, 1.0);
+            assert_eq!(graphics.msaa, 4);
+        })
+        .unwrap();
+}
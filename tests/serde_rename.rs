@@ -0,0 +1,60 @@
+#![cfg(feature = "serde_json")]
+
+use bevy_ecs::system::RunSystemOnce;
+use bevy_mod_config::manager::serde::KeyCase;
+use bevy_mod_config::manager::serde::json::Json;
+use bevy_mod_config::{AppExt, Config, ConfigNode, ReadConfig, SerdeRename, manager};
+
+#[derive(Config)]
+struct Audio {
+    #[config(serde(rename = "masterVolume"), default = 0.8)]
+    volume:  f32,
+    #[config(serde(rename = "fx"))]
+    effects: Effects,
+}
+
+#[derive(Config)]
+struct Effects {
+    #[config(default = 2)]
+    reverb_level: u32,
+}
+
+#[test]
+fn test_rename_round_trip() {
+    let mut app = bevy_app::App::new();
+    app.init_config_with::<Json, Audio>("audio", || Json::new().with_key_case(KeyCase::Snake));
+    let json = app.world().resource::<manager::Instance<Json>>().instance.clone();
+
+    assert_eq!(
+        json.to_string(app.world_mut()).unwrap(),
+        r#"{"audio.fx.reverb_level":2,"audio.masterVolume":0.8}"#
+    );
+
+    json.from_reader(
+        app.world_mut(),
+        r#"{"audio.masterVolume":0.5,"audio.fx.reverb_level":7}"#.as_bytes(),
+    )
+    .unwrap();
+    app.world_mut()
+        .run_system_once(|audio: ReadConfig<Audio>| {
+            let audio = audio.read();
+            assert_eq!(audio.volume, 0.5);
+            assert_eq!(audio.effects.reverb_level, 7);
+        })
+        .unwrap();
+}
+
+#[test]
+fn test_rename_keeps_node_path() {
+    let mut app = bevy_app::App::new();
+    app.init_config_with::<Json, Audio>("audio", Json::new);
+
+    let mut query = app.world_mut().query::<(&ConfigNode, &SerdeRename)>();
+    let mut renamed: Vec<_> =
+        query.iter(app.world()).map(|(node, rename)| (node.path.join("."), rename.0)).collect();
+    renamed.sort();
+    assert_eq!(
+        renamed,
+        [("audio.effects".to_string(), "fx"), ("audio.volume".to_string(), "masterVolume")]
+    );
+}
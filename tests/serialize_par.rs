@@ -0,0 +1,39 @@
+#![cfg(all(feature = "serde_json", feature = "bevy_tasks"))]
+
+use bevy_mod_config::manager::serde::Json;
+use bevy_mod_config::{AppExt, Config, manager};
+
+macro_rules! enemies {
+    ($($ident:ident),*) => {
+        $(
+            #[derive(Config)]
+            struct $ident {
+                #[config(default = 100)]
+                health: u32,
+                #[config(default = 0.1)]
+                speed:  f32,
+                name:   String,
+            }
+        )*
+
+        fn init_enemies(app: &mut bevy_app::App) {
+            $(
+                app.init_config_with::<Json, $ident>(stringify!($ident), Json::new);
+            )*
+        }
+    };
+}
+
+// Each root must have a distinct type.
+enemies!(Goblin, Orc, Troll, Skeleton, Zombie, Bat, Slime, Spider, Wolf, Dragon);
+
+#[test]
+fn test_serialize_all_par() {
+    let mut app = bevy_app::App::new();
+    init_enemies(&mut app);
+
+    let json = app.world().resource::<manager::Instance<Json>>().instance.clone();
+    let sequential = json.to_string(app.world_mut()).unwrap();
+    let parallel = json.serialize_all_par(app.world_mut(), Vec::new()).unwrap();
+    assert_eq!(String::from_utf8(parallel).unwrap(), sequential);
+}
@@ -0,0 +1,78 @@
+#![cfg(feature = "serde_json")]
+
+use bevy_ecs::system::{Command, RunSystemOnce};
+use bevy_mod_config::manager::serde::Json;
+use bevy_mod_config::manager::serde::json::{SetConfigValue, SetValueError};
+use bevy_mod_config::{AppExt, Config, ConfigNode, ReadConfig};
+
+#[derive(Config)]
+struct Player {
+    #[config(default = 5.0)]
+    speed:  f32,
+    flight: Flight,
+}
+
+#[derive(Config)]
+struct Flight {
+    enabled: bool,
+}
+
+#[test]
+fn test_set_value_by_path() {
+    let mut app = bevy_app::App::new();
+    app.init_config_with::<Json, Player>("player", Json::new);
+
+    let world = app.world_mut();
+    world.commands().queue(SetConfigValue::new("player.speed", 20.0).unwrap());
+    world.commands().queue(SetConfigValue::new("player.flight.enabled", true).unwrap());
+    world.flush();
+
+    world
+        .run_system_once(|player: ReadConfig<Player>| {
+            let player = player.read();
+            assert_eq!(player.speed, 20.0);
+            assert!(player.flight.enabled);
+        })
+        .unwrap();
+}
+
+#[test]
+fn test_set_value_errors() {
+    let mut app = bevy_app::App::new();
+    app.init_config_with::<Json, Player>("player", Json::new);
+
+    let world = app.world_mut();
+    let err = SetConfigValue::new("player.jump", 1).unwrap().apply(world).unwrap_err();
+    assert!(matches!(err, SetValueError::NotFound(path) if path == "player.jump"));
+
+    let err = SetConfigValue::new("player.flight", true).unwrap().apply(world).unwrap_err();
+    assert!(matches!(err, SetValueError::NotScalar(_)));
+
+    let err = SetConfigValue::new("player.speed", "fast").unwrap().apply(world).unwrap_err();
+    assert!(matches!(err, SetValueError::Invalid(..)));
+}
+
+fn speed_generation(app: &mut bevy_app::App) -> bevy_mod_config::FieldGeneration {
+    app.world_mut()
+        .query::<&ConfigNode>()
+        .iter(app.world())
+        .find(|node| node.path.join(".") == "player.speed")
+        .unwrap()
+        .generation
+}
+
+#[test]
+fn test_set_same_value() {
+    let mut app = bevy_app::App::new();
+    app.init_config_with::<Json, Player>("player", Json::new);
+    let json = app.world().resource::<bevy_mod_config::manager::Instance<Json>>().instance.clone();
+    let generation = speed_generation(&mut app);
+
+    SetConfigValue::new("player.speed", 5.0).unwrap().apply(app.world_mut()).unwrap();
+    assert_eq!(speed_generation(&mut app), generation);
+    json.from_reader(app.world_mut(), std::io::Cursor::new(r#"{"player.speed":5.0}"#)).unwrap();
+    assert_eq!(speed_generation(&mut app), generation);
+
+    SetConfigValue::new("player.speed", 6.0).unwrap().apply(app.world_mut()).unwrap();
+    assert!(speed_generation(&mut app) > generation);
+}
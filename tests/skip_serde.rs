@@ -0,0 +1,51 @@
+#![cfg(feature = "serde_json")]
+
+use bevy_ecs::system::RunSystemOnce;
+use bevy_mod_config::manager::serde::Json;
+use bevy_mod_config::{AppExt, Config, ReadConfig, manager};
+
+#[derive(Config)]
+struct Audio {
+    #[config(default = 0.8)]
+    volume:  f32,
+    #[config(serde(skip), default = 1.0)]
+    duck:    f32,
+    #[config(serde(skip))]
+    session: Session,
+}
+
+#[derive(Config)]
+struct Session {
+    #[config(default = 3)]
+    muted_channels: u32,
+}
+
+#[test]
+fn test_skip_save() {
+    let mut app = bevy_app::App::new();
+    app.init_config_with::<Json, Audio>("audio", Json::new);
+    let json = app.world().resource::<manager::Instance<Json>>().instance.clone();
+
+    assert_eq!(json.to_string(app.world_mut()).unwrap(), r#"{"audio.volume":0.8}"#);
+}
+
+#[test]
+fn test_skip_load() {
+    let mut app = bevy_app::App::new();
+    app.init_config_with::<Json, Audio>("audio", Json::new);
+    let json = app.world().resource::<manager::Instance<Json>>().instance.clone();
+
+    json.from_reader(
+        app.world_mut(),
+        r#"{"audio.volume":0.5,"audio.duck":0.2,"audio.session.muted_channels":0}"#.as_bytes(),
+    )
+    .unwrap();
+    app.world_mut()
+        .run_system_once(|audio: ReadConfig<Audio>| {
+            let audio = audio.read();
+            assert_eq!(audio.volume, 0.5);
+            assert_eq!(audio.duck, 1.0);
+            assert_eq!(audio.session.muted_channels, 3);
+        })
+        .unwrap();
+}
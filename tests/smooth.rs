@@ -0,0 +1,56 @@
+#![cfg(feature = "bevy_time")]
+
+use core::time::Duration;
+
+use bevy_ecs::entity::Entity;
+use bevy_ecs::system::RunSystemOnce;
+use bevy_mod_config::manager::smooth::{self, ReadSmoothed, Smoothing};
+use bevy_mod_config::{AppExt, Config, WorldExt};
+use bevy_time::Time;
+
+#[derive(Config)]
+struct Camera {
+    #[config(default = 90.0, smoothing = Some(Duration::from_secs(1)))]
+    fov:   f32,
+    #[config(default = 1.0)]
+    speed: f32,
+}
+
+fn read(app: &mut bevy_app::App, path: &'static [&'static str]) -> f32 {
+    app.world_mut()
+        .run_system_once(move |smoothed: ReadSmoothed<f32>| {
+            let entity: Entity = smoothed.entity(path).unwrap();
+            smoothed.get(entity).unwrap()
+        })
+        .unwrap()
+}
+
+fn step(app: &mut bevy_app::App, delta: Duration) {
+    app.world_mut().resource_mut::<Time>().advance_by(delta);
+    app.update();
+}
+
+#[test]
+fn test_smooth_transition() {
+    let mut app = bevy_app::App::new();
+    app.init_resource::<Time>();
+    app.init_config::<Smoothing, Camera>("camera");
+    app.add_systems(bevy_app::Update, smooth::update::<f32>);
+    app.update();
+    assert_eq!(read(&mut app, &["camera", "fov"]), 90.0);
+
+    app.world_mut().update_config::<Camera>(|camera| {
+        camera.fov.set(30.0);
+        camera.speed.set(2.0);
+    });
+
+    step(&mut app, Duration::from_millis(250));
+    assert_eq!(read(&mut app, &["camera", "fov"]), 75.0);
+    step(&mut app, Duration::from_millis(250));
+    assert_eq!(read(&mut app, &["camera", "fov"]), 60.0);
+    step(&mut app, Duration::from_secs(1));
+    assert_eq!(read(&mut app, &["camera", "fov"]), 30.0);
+
+    // Fields without smoothing yield the raw value immediately.
+    assert_eq!(read(&mut app, &["camera", "speed"]), 2.0);
+}
@@ -0,0 +1,44 @@
+#![cfg(feature = "serde_json")]
+
+use bevy_ecs::system::SystemState;
+use bevy_mod_config::manager::serde::Json;
+use bevy_mod_config::{AppExt, Config, ConfigSnapshot, ReadConfig, manager};
+
+#[derive(Config)]
+#[config(expose(read))]
+struct Pathfinding {
+    #[config(default = 8)]
+    max_depth:      u32,
+    allow_diagonal: bool,
+}
+
+#[derive(Debug, PartialEq)]
+struct Params {
+    max_depth:      u32,
+    allow_diagonal: bool,
+}
+
+#[test]
+fn test_snapshot_across_threads() {
+    let mut app = bevy_app::App::new();
+    app.init_config_with::<Json, Pathfinding>("pathfinding", Json::new);
+
+    let mut state = SystemState::<ReadConfig<Pathfinding>>::new(app.world_mut());
+    let snapshot: ConfigSnapshot<Pathfinding, Params> =
+        state.get(app.world()).unwrap().snapshot(|config| Params {
+            max_depth:      config.max_depth,
+            allow_diagonal: config.allow_diagonal,
+        });
+
+    let task_snapshot = snapshot.clone();
+    let result = std::thread::spawn(move || task_snapshot.max_depth * 2).join().unwrap();
+    assert_eq!(result, 16);
+    assert!(!snapshot.is_stale(&state.get(app.world()).unwrap()));
+
+    let json = app.world().resource::<manager::Instance<Json>>().instance.clone();
+    json.from_reader(app.world_mut(), r#"{"pathfinding.max_depth":3}"#.as_bytes()).unwrap();
+
+    // The snapshot keeps the captured values but is now stale.
+    assert_eq!(*snapshot, Params { max_depth: 8, allow_diagonal: false });
+    assert!(snapshot.is_stale(&state.get(app.world()).unwrap()));
+}
@@ -0,0 +1,217 @@
+#![cfg(feature = "serde_json")]
+
+use std::fs;
+
+use bevy_ecs::system::RunSystemOnce;
+use bevy_mod_config::manager::serde::Json;
+use bevy_mod_config::manager::serde::storage::FileStorage;
+use bevy_mod_config::{AppExt, Config, ReadConfig, WorldExt, manager};
+
+#[derive(Config)]
+struct Video {
+    #[config(default = 60)]
+    fps: u32,
+}
+
+#[derive(Config)]
+struct Audio {
+    #[config(default = 1.0)]
+    volume: f32,
+}
+
+fn new_app() -> bevy_app::App {
+    let mut app = bevy_app::App::new();
+    app.init_config_with::<Json, Video>("video", Json::new);
+    app.init_config_with::<Json, Audio>("audio", Json::new);
+    app
+}
+
+#[test]
+fn test_split_files() {
+    let dir = std::env::temp_dir().join(format!("bevy_mod_config_split_{}", std::process::id()));
+    fs::create_dir_all(&dir).unwrap();
+    let storage = FileStorage::new(&dir);
+
+    let mut app = new_app();
+    let json = app.world().resource::<manager::Instance<Json>>().instance.clone();
+    let manifest = json.save_split(app.world_mut(), &storage).unwrap();
+    assert_eq!(manifest.files.keys().collect::<Vec<_>>(), ["audio", "video"]);
+    assert_eq!(fs::read_to_string(dir.join("video.json")).unwrap(), r#"{"video.fps":60}"#);
+    let leftover = fs::read_dir(&dir)
+        .unwrap()
+        .any(|entry| entry.unwrap().file_name().to_string_lossy().ends_with(".tmp"));
+    assert!(!leftover);
+
+    fs::write(dir.join("video.json"), r#"{"video.fps":144,"audio.volume":0.0}"#).unwrap();
+    fs::write(dir.join("audio.json"), r#"{"audio.volume":0.5}"#).unwrap();
+
+    let mut app = new_app();
+    json.load_split(app.world_mut(), &storage).unwrap();
+    app.world_mut()
+        .run_system_once(|video: ReadConfig<Video>, audio: ReadConfig<Audio>| {
+            assert_eq!(video.read().fps, 144);
+            // Keys of other roots are ignored in a root file.
+            assert_eq!(audio.read().volume, 0.5);
+        })
+        .unwrap();
+
+    fs::remove_dir_all(&dir).unwrap();
+}
+
+#[test]
+fn test_manifest_rejects_paths() {
+    let dir = std::env::temp_dir().join(format!("bevy_mod_config_manifest_{}", std::process::id()));
+    fs::create_dir_all(&dir).unwrap();
+    let storage = FileStorage::new(&dir);
+
+    let mut app = new_app();
+    let json = app.world().resource::<manager::Instance<Json>>().instance.clone();
+    for file_name in ["../video.json", "nested/video.json", r"nested\video.json", ".."] {
+        let manifest = serde_json::json!({ "video": file_name }).to_string();
+        fs::write(dir.join("manifest.json"), manifest).unwrap();
+        let err = json.load_split(app.world_mut(), &storage).unwrap_err();
+        assert_eq!(err.kind(), std::io::ErrorKind::InvalidData);
+    }
+
+    fs::remove_dir_all(&dir).unwrap();
+}
+
+#[test]
+fn test_save_if_dirty() {
+    let dir = std::env::temp_dir().join(format!("bevy_mod_config_dirty_{}", std::process::id()));
+    fs::create_dir_all(&dir).unwrap();
+    let storage = FileStorage::new(&dir);
+
+    let mut app = new_app();
+    let json = app.world().resource::<manager::Instance<Json>>().instance.clone();
+    assert!(json.save_if_dirty(app.world_mut(), &storage, "video").unwrap());
+    assert!(!json.save_if_dirty(app.world_mut(), &storage, "video").unwrap());
+
+    json.from_value(app.world_mut(), serde_json::json!({"video.fps": 30, "audio.volume": 0.5}))
+        .unwrap();
+    assert!(json.is_dirty(app.world_mut(), "audio"));
+    assert!(json.save_if_dirty(app.world_mut(), &storage, "video").unwrap());
+    assert!(!json.is_dirty(app.world_mut(), "video"));
+    assert!(json.is_dirty(app.world_mut(), "audio"));
+
+    json.load_root(app.world_mut(), &storage, "video").unwrap();
+    assert!(!json.is_dirty(app.world_mut(), "video"));
+
+    fs::remove_dir_all(&dir).unwrap();
+}
+
+#[cfg(feature = "bevy_tasks")]
+#[test]
+fn test_save_async() {
+    use bevy_mod_config::manager::serde::async_save::PendingSaves;
+    use bevy_tasks::{IoTaskPool, TaskPool};
+
+    IoTaskPool::get_or_init(TaskPool::new);
+    let dir = std::env::temp_dir().join(format!("bevy_mod_config_async_{}", std::process::id()));
+    fs::create_dir_all(&dir).unwrap();
+    let storage = FileStorage::new(&dir);
+
+    let mut app = new_app();
+    let json = app.world().resource::<manager::Instance<Json>>().instance.clone();
+    json.save_root_async(app.world_mut(), &storage, "video").unwrap();
+    // The fields stay dirty until the file has been written.
+    assert!(json.is_dirty(app.world_mut(), "video"));
+
+    while !app.world().resource::<PendingSaves>().is_empty() {
+        std::thread::sleep(std::time::Duration::from_millis(1));
+        app.update();
+    }
+    assert_eq!(fs::read_to_string(dir.join("video.json")).unwrap(), r#"{"video.fps":60}"#);
+    assert!(!json.is_dirty(app.world_mut(), "video"));
+
+    fs::remove_dir_all(&dir).unwrap();
+}
+
+/// Delays the first write, so that an unordered second write would finish before it.
+#[cfg(feature = "bevy_tasks")]
+#[derive(Clone)]
+struct SlowFirstWrite {
+    inner:   FileStorage,
+    delayed: std::sync::Arc<std::sync::atomic::AtomicBool>,
+}
+
+#[cfg(feature = "bevy_tasks")]
+impl bevy_mod_config::manager::serde::storage::ConfigStorage for SlowFirstWrite {
+    fn read_bytes(&self, name: &str) -> std::io::Result<Option<Vec<u8>>> {
+        self.inner.read_bytes(name)
+    }
+
+    fn write_bytes(&self, name: &str, bytes: &[u8]) -> std::io::Result<()> {
+        if !self.delayed.swap(true, std::sync::atomic::Ordering::SeqCst) {
+            std::thread::sleep(std::time::Duration::from_millis(100));
+        }
+        self.inner.write_bytes(name, bytes)
+    }
+
+    fn rename(&self, from: &str, to: &str) -> std::io::Result<()> { self.inner.rename(from, to) }
+}
+
+#[cfg(feature = "bevy_tasks")]
+#[test]
+fn test_save_async_ordered() {
+    use bevy_mod_config::manager::serde::async_save::PendingSaves;
+    use bevy_tasks::{IoTaskPool, TaskPool};
+
+    IoTaskPool::get_or_init(TaskPool::new);
+    let dir = std::env::temp_dir().join(format!("bevy_mod_config_ordered_{}", std::process::id()));
+    fs::create_dir_all(&dir).unwrap();
+    let storage = SlowFirstWrite { inner: FileStorage::new(&dir), delayed: Default::default() };
+
+    let mut app = new_app();
+    let json = app.world().resource::<manager::Instance<Json>>().instance.clone();
+    json.save_root_async(app.world_mut(), &storage, "video").unwrap();
+    app.world_mut().update_config::<Video>(|video| video.fps.set(30));
+    json.save_root_async(app.world_mut(), &storage, "video").unwrap();
+    app.world_mut().update_config::<Video>(|video| video.fps.set(144));
+    json.save_root_async(app.world_mut(), &storage, "video").unwrap();
+
+    while !app.world().resource::<PendingSaves>().is_empty() {
+        std::thread::sleep(std::time::Duration::from_millis(1));
+        app.update();
+    }
+    assert_eq!(fs::read_to_string(dir.join("video.json")).unwrap(), r#"{"video.fps":144}"#);
+    assert!(!json.is_dirty(app.world_mut(), "video"));
+
+    fs::remove_dir_all(&dir).unwrap();
+}
+
+#[test]
+fn test_corrupt_fallback() {
+    use bevy_ecs::message::Messages;
+    use bevy_mod_config::manager::serde::json::{CorruptFile, LoadFailed, LoadOutcome};
+
+    let dir = std::env::temp_dir().join(format!("bevy_mod_config_corrupt_{}", std::process::id()));
+    fs::create_dir_all(&dir).unwrap();
+    let storage = FileStorage::new(&dir);
+
+    let mut app = new_app();
+    let json = app.world().resource::<manager::Instance<Json>>().instance.clone();
+
+    let outcome = json.load_root_or_default(app.world_mut(), &storage, "video", CorruptFile::Keep);
+    assert_eq!(outcome, LoadOutcome::Missing);
+
+    // Values changed before the load are reset too, not just restored.
+    app.world_mut().update_config::<Video>(|video| video.fps.set(30));
+    fs::write(dir.join("video.json"), r#"{"video.fps":"fast"}"#).unwrap();
+    let outcome =
+        json.load_root_or_default(app.world_mut(), &storage, "video", CorruptFile::RenameAside);
+    assert_eq!(outcome, LoadOutcome::Corrupted);
+    app.world_mut()
+        .run_system_once(|video: ReadConfig<Video>| assert_eq!(video.read().fps, 60))
+        .unwrap();
+
+    assert!(!dir.join("video.json").exists());
+    assert!(dir.join("video.json.corrupt").exists());
+    let messages = app.world().resource::<Messages<LoadFailed>>();
+    let failed: Vec<_> = messages.iter_current_update_messages().collect();
+    assert_eq!(failed.len(), 1);
+    assert_eq!(failed[0].root, "video");
+    assert_eq!(failed[0].moved_to.as_deref(), Some("video.json.corrupt"));
+
+    fs::remove_dir_all(&dir).unwrap();
+}
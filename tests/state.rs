@@ -0,0 +1,118 @@
+#![cfg(feature = "bevy_state")]
+
+use bevy_ecs::entity::Entity;
+use bevy_ecs::system::Local;
+use bevy_mod_config::{AppExt, Config, ConfigNode, OutOfState, ReadConfigChange, WorldExt};
+use bevy_state::app::{AppExtStates, StatesPlugin};
+use bevy_state::state::{NextState, States};
+
+#[derive(States, Default, Debug, Clone, PartialEq, Eq, Hash)]
+enum AppState {
+    #[default]
+    Game,
+    Editor,
+    Paused,
+}
+
+#[derive(Config)]
+struct Settings {
+    #[config(default = 1.0)]
+    volume: f32,
+    #[config(in_state(AppState::Editor, AppState::Paused))]
+    grid:   Grid,
+}
+
+#[derive(Config)]
+struct Grid {
+    #[config(default = 16)]
+    size: u32,
+}
+
+#[derive(Config)]
+#[config(in_state(AppState::Editor))]
+struct EditorSettings {
+    #[config(default = 10)]
+    undo_limit: u32,
+}
+
+fn set_state(app: &mut bevy_app::App, state: AppState) {
+    app.world_mut().resource_mut::<NextState<AppState>>().set(state);
+    app.update();
+}
+
+fn is_out_of_state(app: &mut bevy_app::App, path: &str) -> bool {
+    let mut query = app.world_mut().query::<(Entity, &ConfigNode)>();
+    let entity = query
+        .iter(app.world())
+        .find_map(|(entity, node)| (node.path.join(".") == path).then_some(entity))
+        .unwrap();
+    app.world().entity(entity).contains::<OutOfState>()
+}
+
+#[test]
+fn test_field_in_state() {
+    let mut app = bevy_app::App::new();
+    app.add_plugins(StatesPlugin).init_state::<AppState>();
+    app.init_config::<(), Settings>("settings");
+
+    app.update();
+    assert!(is_out_of_state(&mut app, "settings.grid"));
+    assert!(!is_out_of_state(&mut app, "settings.volume"));
+
+    set_state(&mut app, AppState::Paused);
+    assert!(!is_out_of_state(&mut app, "settings.grid"));
+
+    set_state(&mut app, AppState::Game);
+    assert!(is_out_of_state(&mut app, "settings.grid"));
+}
+
+#[test]
+fn test_root_in_state_defers_changes() {
+    let mut app = bevy_app::App::new();
+    app.add_plugins(StatesPlugin).init_state::<AppState>();
+    app.init_config::<(), EditorSettings>("editor");
+    app.update();
+    assert!(is_out_of_state(&mut app, "editor"));
+
+    let system = app.register_system(
+        |mut editor: ReadConfigChange<EditorSettings>, mut seen: Local<u32>| {
+            if editor.consume_change() {
+                *seen += 1;
+            }
+            *seen
+        },
+    );
+    assert_eq!(app.world_mut().run_system(system).unwrap(), 0);
+
+    app.world_mut().update_config::<EditorSettings>(|editor| editor.undo_limit.set(20));
+    assert_eq!(app.world_mut().run_system(system).unwrap(), 0);
+
+    set_state(&mut app, AppState::Editor);
+    assert!(!is_out_of_state(&mut app, "editor"));
+    assert_eq!(app.world_mut().run_system(system).unwrap(), 1);
+    assert_eq!(app.world_mut().run_system(system).unwrap(), 1);
+}
+
+#[test]
+fn test_field_in_state_defers_changes() {
+    let mut app = bevy_app::App::new();
+    app.add_plugins(StatesPlugin).init_state::<AppState>();
+    app.init_config::<(), Settings>("settings");
+    app.update();
+
+    let system = app.register_system(|mut settings: ReadConfigChange<Settings>| {
+        let changed = settings.consume_change();
+        let fields = settings.consume_changes();
+        (changed, fields.iter().map(|path| path.join(".")).collect::<Vec<_>>())
+    });
+    let (changed, fields) = app.world_mut().run_system(system).unwrap();
+    assert!(changed);
+    assert_eq!(fields, ["volume"]);
+
+    app.world_mut().update_config::<Settings>(|settings| settings.grid.size.set(32));
+    assert_eq!(app.world_mut().run_system(system).unwrap(), (false, Vec::new()));
+
+    set_state(&mut app, AppState::Editor);
+    assert_eq!(app.world_mut().run_system(system).unwrap(), (true, vec!["grid.size".into()]));
+    assert_eq!(app.world_mut().run_system(system).unwrap(), (false, Vec::new()));
+}
@@ -0,0 +1,100 @@
+use std::sync::Arc;
+use std::sync::atomic::{AtomicBool, Ordering};
+
+use bevy_ecs::entity::Entity;
+use bevy_ecs::system::RunSystemOnce;
+use bevy_mod_config::temporary::{self, RevertWhen};
+use bevy_mod_config::{AppExt, Config, ConfigNode, ReadConfig, WorldExt};
+
+#[derive(Config)]
+struct Graphics {
+    #[config(default = 4)]
+    shadows: u32,
+    #[config(default = true)]
+    vsync:   bool,
+}
+
+fn new_app() -> bevy_app::App {
+    let mut app = bevy_app::App::new();
+    app.init_config::<(), Graphics>("graphics");
+    app.update();
+    app
+}
+
+fn field(app: &mut bevy_app::App, name: &str) -> Entity {
+    let mut query = app.world_mut().query::<(Entity, &ConfigNode)>();
+    query.iter(app.world()).find(|(_, node)| node.path == ["graphics", name]).unwrap().0
+}
+
+fn read(app: &mut bevy_app::App) -> (u32, bool) {
+    app.world_mut()
+        .run_system_once(|graphics: ReadConfig<Graphics>| {
+            let graphics = graphics.read();
+            (graphics.shadows, graphics.vsync)
+        })
+        .unwrap()
+}
+
+#[test]
+fn test_guard_reverts_on_drop() {
+    let mut app = new_app();
+    let shadows = field(&mut app, "shadows");
+    app.world_mut().update_config::<Graphics>(|graphics| graphics.shadows.set(2));
+
+    let guard = temporary::set(app.world_mut(), shadows, 0u32);
+    app.update();
+    assert_eq!(read(&mut app), (0, true));
+    assert!(temporary::is_temporary(app.world(), shadows));
+
+    drop(guard);
+    app.update();
+    assert_eq!(read(&mut app), (2, true));
+    assert!(!temporary::is_temporary(app.world(), shadows));
+}
+
+#[test]
+fn test_condition_reverts_when_met() {
+    let mut app = new_app();
+    let vsync = field(&mut app, "vsync");
+    let done = Arc::new(AtomicBool::new(false));
+
+    let check = Arc::clone(&done);
+    temporary::set_until(
+        app.world_mut(),
+        vsync,
+        false,
+        RevertWhen::Condition(Box::new(move |_| check.load(Ordering::Relaxed))),
+    );
+    app.update();
+    assert_eq!(read(&mut app), (4, false));
+
+    done.store(true, Ordering::Relaxed);
+    app.update();
+    assert_eq!(read(&mut app), (4, true));
+}
+
+#[test]
+fn test_nested_overrides_restore_original() {
+    let mut app = new_app();
+    let shadows = field(&mut app, "shadows");
+
+    let first = temporary::set(app.world_mut(), shadows, 1u32);
+    let second = temporary::set(app.world_mut(), shadows, 0u32);
+    drop(first);
+    app.update();
+    assert_eq!(read(&mut app), (0, true));
+
+    drop(second);
+    app.update();
+    assert_eq!(read(&mut app), (4, true));
+}
+
+#[test]
+fn test_manual_revert() {
+    let mut app = new_app();
+    let shadows = field(&mut app, "shadows");
+
+    let _guard = temporary::set(app.world_mut(), shadows, 0u32);
+    temporary::revert(app.world_mut(), shadows);
+    assert_eq!(read(&mut app), (4, true));
+}
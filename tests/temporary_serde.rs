@@ -0,0 +1,30 @@
+#![cfg(feature = "serde_json")]
+
+use bevy_ecs::entity::Entity;
+use bevy_mod_config::manager::serde::Json;
+use bevy_mod_config::{AppExt, Config, ConfigNode, manager, temporary};
+
+#[derive(Config)]
+struct Graphics {
+    #[config(default = 4)]
+    shadows: u32,
+}
+
+#[test]
+fn test_save_original_value() {
+    let mut app = bevy_app::App::new();
+    app.init_config::<Json, Graphics>("graphics");
+    let json = app.world().resource::<manager::Instance<Json>>().instance.clone();
+
+    let mut query = app.world_mut().query::<(Entity, &ConfigNode)>();
+    let field =
+        query.iter(app.world()).find(|(_, node)| node.path == ["graphics", "shadows"]).unwrap().0;
+
+    let guard = temporary::set(app.world_mut(), field, 0u32);
+    assert_eq!(json.to_string(app.world_mut()).unwrap(), r#"{"graphics.shadows":4}"#);
+    assert_eq!(json.to_value(app.world_mut()).unwrap()["graphics.shadows"], 4);
+
+    drop(guard);
+    app.update();
+    assert_eq!(json.to_string(app.world_mut()).unwrap(), r#"{"graphics.shadows":4}"#);
+}
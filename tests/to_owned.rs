@@ -0,0 +1,43 @@
+use bevy_ecs::resource::Resource;
+use bevy_ecs::system::{Commands, RunSystemOnce};
+use bevy_mod_config::{AppExt, Config, ReadConfig};
+
+#[derive(Config)]
+#[config(expose(owned))]
+struct Profile {
+    #[config(default = "Player")]
+    name:   String,
+    #[config(default = 3)]
+    lives:  u32,
+    avatar: Avatar,
+}
+
+#[derive(Config)]
+#[config(expose(owned))]
+enum Avatar {
+    Builtin(#[config(default = 1)] u8),
+    Custom {
+        #[config(default = "avatar.png")]
+        path: String,
+    },
+}
+
+#[derive(Resource)]
+struct SavedProfile(ProfileOwned);
+
+#[test]
+fn test_to_owned() {
+    let mut app = bevy_app::App::new();
+    app.init_config::<(), Profile>("profile");
+
+    app.world_mut()
+        .run_system_once(|profile: ReadConfig<Profile>, mut commands: Commands| {
+            commands.insert_resource(SavedProfile(profile.read().to_owned()));
+        })
+        .unwrap();
+
+    let saved = &app.world().resource::<SavedProfile>().0;
+    assert_eq!(saved.name, "Player");
+    assert_eq!(saved.lives, 3);
+    assert!(matches!(saved.avatar, AvatarOwned::Builtin(1)));
+}
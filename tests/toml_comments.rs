@@ -0,0 +1,38 @@
+#![cfg(feature = "toml")]
+
+use bevy_ecs::system::RunSystemOnce;
+use bevy_mod_config::manager::serde::Toml;
+use bevy_mod_config::{AppExt, Config, ReadConfig, manager};
+
+#[derive(Config)]
+struct Settings {
+    /// Maximum frames per second.
+    #[config(default = 60)]
+    fps:   u32,
+    #[config(default = 1.0)]
+    scale: f32,
+}
+
+#[test]
+fn test_round_trip_preserves_comments() {
+    let mut app = bevy_app::App::new();
+    app.init_config::<Toml, Settings>("video");
+    let toml = app.world().resource::<manager::Instance<Toml>>().instance.clone();
+
+    let input = "# Hand-written header\n[video]\nscale = 2.0 # hi-dpi\nunknown = true\n";
+    let mut document = toml.from_str(app.world_mut(), input).unwrap();
+    app.world_mut()
+        .run_system_once(|settings: ReadConfig<Settings>| {
+            let settings = settings.read();
+            assert_eq!(settings.fps, 60);
+            assert_eq!(settings.scale, 2.0);
+        })
+        .unwrap();
+
+    toml.update_document(app.world_mut(), &mut document).unwrap();
+    assert_eq!(
+        document.to_string(),
+        "# Hand-written header\n[video]\nscale = 2.0 # hi-dpi\nunknown = true\n# Maximum frames \
+         per second.\nfps = 60\n"
+    );
+}
@@ -0,0 +1,99 @@
+#![cfg(feature = "tracing")]
+
+use std::fmt::Write;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Mutex};
+
+use bevy_mod_config::manager::Tracing;
+use bevy_mod_config::manager::trace::TARGET;
+use bevy_mod_config::{AppExt, Config, WorldExt};
+use tracing::field::{Field, Visit};
+use tracing::span::{Attributes, Id, Record};
+use tracing::subscriber::Interest;
+use tracing::{Event, Metadata, Subscriber};
+
+#[derive(Config)]
+struct Camera {
+    #[config(default = 90.0)]
+    fov:   f32,
+    #[config(default = 1.0)]
+    speed: f32,
+}
+
+/// Collects the fields of each event emitted under [`TARGET`] while enabled.
+#[derive(Clone, Default)]
+struct Collector(Arc<Mutex<Vec<String>>>, Arc<AtomicBool>);
+
+struct FieldWriter(String);
+
+impl Visit for FieldWriter {
+    fn record_debug(&mut self, field: &Field, value: &dyn std::fmt::Debug) {
+        write!(self.0, "{}={:?} ", field.name(), value).unwrap();
+    }
+}
+
+impl Subscriber for Collector {
+    fn register_callsite(&self, _: &'static Metadata<'static>) -> Interest { Interest::sometimes() }
+
+    fn enabled(&self, metadata: &Metadata<'_>) -> bool {
+        metadata.target() == TARGET && self.1.load(Ordering::Relaxed)
+    }
+
+    fn new_span(&self, _: &Attributes<'_>) -> Id { Id::from_u64(1) }
+
+    fn record(&self, _: &Id, _: &Record<'_>) {}
+
+    fn record_follows_from(&self, _: &Id, _: &Id) {}
+
+    fn event(&self, event: &Event<'_>) {
+        let mut writer = FieldWriter(String::new());
+        event.record(&mut writer);
+        self.0.lock().unwrap().push(writer.0.trim_end().to_owned());
+    }
+
+    fn enter(&self, _: &Id) {}
+
+    fn exit(&self, _: &Id) {}
+}
+
+#[test]
+fn test_trace_changes() {
+    let collector = Collector::default();
+    let events = collector.0.clone();
+    let enabled = collector.1.clone();
+    enabled.store(true, Ordering::Relaxed);
+
+    // Systems may run on other threads, so a thread-local default subscriber is not enough.
+    tracing::subscriber::set_global_default(collector).unwrap();
+
+    let mut app = bevy_app::App::new();
+    app.init_config::<Tracing, Camera>("camera");
+    app.update();
+    assert!(events.lock().unwrap().is_empty());
+
+    app.world_mut().update_config::<Camera>(|camera| camera.fov.set(100.0));
+    app.update();
+
+    assert_eq!(
+        *events.lock().unwrap(),
+        ["message=config field changed path=camera.fov old=90.0 new=100.0"]
+    );
+    events.lock().unwrap().clear();
+
+    // Changes are neither formatted nor logged while the events are disabled.
+    enabled.store(false, Ordering::Relaxed);
+    app.world_mut().update_config::<Camera>(|camera| camera.fov.set(110.0));
+    app.update();
+    assert!(events.lock().unwrap().is_empty());
+
+    // After enabling again, fields are first seen with their current values.
+    enabled.store(true, Ordering::Relaxed);
+    app.update();
+    assert!(events.lock().unwrap().is_empty());
+    app.world_mut().update_config::<Camera>(|camera| camera.fov.set(120.0));
+    app.update();
+    assert_eq!(
+        *events.lock().unwrap(),
+        ["message=config field changed path=camera.fov old=110.0 new=120.0"]
+    );
+}
@@ -0,0 +1,66 @@
+use bevy_ecs::entity::Entity;
+use bevy_ecs::observer::On;
+use bevy_ecs::query::With;
+use bevy_ecs::resource::Resource;
+use bevy_ecs::system::{ResMut, RunSystemOnce};
+use bevy_mod_config::{
+    AppExt, Config, ConfigCommitted, ConfigNode, FieldGeneration, ReadConfig, RootNode, WorldExt,
+};
+
+#[derive(Config)]
+struct Video {
+    width:  u32,
+    height: u32,
+    vsync:  bool,
+}
+
+#[derive(Resource, Default)]
+struct Commits(Vec<ConfigCommitted>);
+
+#[test]
+fn test_transaction() {
+    let mut app = bevy_app::App::new();
+    app.init_config::<(), Video>("video");
+    app.init_resource::<Commits>();
+    app.add_observer(|commit: On<ConfigCommitted>, mut commits: ResMut<Commits>| {
+        commits.0.push((*commit).clone());
+    });
+
+    let root =
+        app.world_mut().query_filtered::<Entity, With<RootNode>>().single(app.world()).unwrap();
+
+    app.world_mut().transaction::<Video>(|tx| {
+        tx.width.set(1920);
+        tx.height.set(1080);
+        tx.width.set(2560);
+    });
+
+    let commits = &app.world().resource::<Commits>().0;
+    assert_eq!(commits.len(), 1);
+    assert_eq!(commits[0].root, root);
+    assert_eq!(commits[0].fields.len(), 2);
+    assert_eq!(
+        app.world().entity(root).get::<ConfigNode>().unwrap().generation,
+        FieldGeneration::default().next()
+    );
+
+    app.world_mut()
+        .run_system_once(|video: ReadConfig<Video>| {
+            let video = video.read();
+            assert_eq!((video.width, video.height), (2560, 1080));
+        })
+        .unwrap();
+}
+
+#[test]
+fn test_empty_transaction() {
+    let mut app = bevy_app::App::new();
+    app.init_config::<(), Video>("video");
+    app.init_resource::<Commits>();
+    app.add_observer(|commit: On<ConfigCommitted>, mut commits: ResMut<Commits>| {
+        commits.0.push((*commit).clone());
+    });
+
+    app.world_mut().transaction::<Video>(|_| {});
+    assert!(app.world().resource::<Commits>().0.is_empty());
+}
@@ -0,0 +1,76 @@
+use bevy_ecs::system::{Commands, RunSystemOnce};
+use bevy_mod_config::{AppExt, CommandsExt, Config, ReadConfig, WorldExt};
+
+#[derive(Config)]
+#[config(expose(read))]
+struct Video {
+    #[config(default = 1280)]
+    width:  u32,
+    #[config(default = 720)]
+    height: u32,
+    mode:   Mode,
+}
+
+#[derive(Config)]
+#[config(expose(read, discrim))]
+enum Mode {
+    Windowed,
+    Fullscreen { monitor: u32 },
+}
+
+fn assert_video(app: &mut bevy_app::App, expected: (u32, u32, Option<u32>)) {
+    app.world_mut()
+        .run_system_once(move |video: ReadConfig<Video>| {
+            let video = video.read();
+            let monitor = match video.mode {
+                ModeRead::Windowed => None,
+                ModeRead::Fullscreen { monitor } => Some(monitor),
+            };
+            assert_eq!((video.width, video.height, monitor), expected);
+        })
+        .unwrap();
+}
+
+#[test]
+fn test_update_config_world() {
+    let mut app = bevy_app::App::new();
+    app.init_config::<(), Video>("video");
+
+    app.world_mut().update_config::<Video>(|video| {
+        video.width.set(1920);
+        video.height.update(|height| *height += 360);
+        video.mode.discrim.set(ModeDiscrim::Fullscreen);
+        video.mode.v_Fullscreen.monitor.set(2);
+    });
+    assert_video(&mut app, (1920, 1080, Some(2)));
+}
+
+#[test]
+fn test_update_config_commands() {
+    let mut app = bevy_app::App::new();
+    app.init_config::<(), Video>("video");
+
+    app.world_mut()
+        .run_system_once(|mut commands: Commands| {
+            commands.update_config::<Video>(|video| video.width.set(800));
+        })
+        .unwrap();
+    assert_video(&mut app, (800, 720, None));
+}
+
+#[derive(Config)]
+struct Audio {
+    #[config(default = 50, min = 0, max = 100)]
+    volume: u32,
+}
+
+#[test]
+fn test_update_config_constrained() {
+    let mut app = bevy_app::App::new();
+    app.init_config::<(), Audio>("audio");
+    app.world_mut().update_config::<Audio>(|audio| audio.volume.set(250));
+
+    let volume =
+        app.world_mut().run_system_once(|audio: ReadConfig<Audio>| audio.read().volume).unwrap();
+    assert_eq!(volume, 100);
+}
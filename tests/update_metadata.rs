@@ -0,0 +1,39 @@
+use bevy_ecs::system::RunSystemOnce;
+use bevy_mod_config::{AppExt, Config, ConfigNode, ReadConfig, WorldExt};
+
+#[derive(Config)]
+struct Video {
+    #[config(default = 240, min = 30, max = 360)]
+    max_fps: u32,
+}
+
+fn read_max_fps(app: &mut bevy_app::App) -> u32 {
+    app.world_mut().run_system_once(|video: ReadConfig<Video>| video.read().max_fps).unwrap()
+}
+
+fn max_fps_generation(app: &mut bevy_app::App) -> bevy_mod_config::FieldGeneration {
+    let mut query = app.world_mut().query::<&ConfigNode>();
+    query.iter(app.world()).find(|node| node.path == ["video", "max_fps"]).unwrap().generation
+}
+
+#[test]
+fn test_update_metadata() {
+    let mut app = bevy_app::App::new();
+    app.init_config::<(), Video>("video");
+
+    let generation = max_fps_generation(&mut app);
+    app.world_mut().update_config::<Video>(|video| {
+        video.max_fps.update_metadata(|metadata| metadata.max = 300)
+    });
+    assert_eq!(read_max_fps(&mut app), 240);
+    assert_eq!(max_fps_generation(&mut app), generation);
+
+    app.world_mut().update_config::<Video>(|video| {
+        video.max_fps.update_metadata(|metadata| metadata.max = 144)
+    });
+    assert_eq!(read_max_fps(&mut app), 144);
+    assert_eq!(max_fps_generation(&mut app), generation.next());
+
+    app.world_mut().update_config::<Video>(|video| video.max_fps.set(100));
+    assert_eq!(read_max_fps(&mut app), 100);
+}
@@ -0,0 +1,45 @@
+#![cfg(all(feature = "url", feature = "serde_json"))]
+
+use bevy_ecs::system::RunSystemOnce;
+use bevy_mod_config::impls::UrlMetadata;
+use bevy_mod_config::manager::serde::Json;
+use bevy_mod_config::{AppExt, Config, ReadConfig, manager};
+
+#[derive(Config)]
+struct Network {
+    #[config(default = "https://example.com/api", schemes = &["https"])]
+    endpoint: url::Url,
+}
+
+#[test]
+fn test_url() {
+    let mut app = bevy_app::App::new();
+    app.init_config_with::<Json, Network>("network", Json::new);
+
+    let json = app.world().resource::<manager::Instance<Json>>().instance.clone();
+    assert_eq!(
+        json.to_string(app.world_mut()).unwrap(),
+        r#"{"network.endpoint":"https://example.com/api"}"#
+    );
+
+    json.from_reader(app.world_mut(), r#"{"network.endpoint":"https://example.org/"}"#.as_bytes())
+        .unwrap();
+    app.world_mut()
+        .run_system_once(|network: ReadConfig<Network>| {
+            assert_eq!(network.read().endpoint.as_str(), "https://example.org/");
+        })
+        .unwrap();
+
+    assert!(
+        json.from_reader(app.world_mut(), r#"{"network.endpoint":"not a url"}"#.as_bytes())
+            .is_err()
+    );
+}
+
+#[test]
+fn test_url_schemes() {
+    let metadata = UrlMetadata { default: "https://example.com", schemes: &["https"] };
+    assert!(metadata.parse("https://example.org").is_ok());
+    assert_eq!(metadata.parse("http://example.org").unwrap_err(), "Scheme must be one of: https");
+    assert!(metadata.parse("example.org").is_err());
+}
@@ -0,0 +1,95 @@
+use bevy_ecs::entity::Entity;
+use bevy_ecs::message::Messages;
+use bevy_ecs::query::With;
+use bevy_mod_config::validate::{ValidationError, ValidationFailed};
+use bevy_mod_config::{AppExt, Config, ConfigNode, RootNode, WorldExt};
+
+#[derive(Config)]
+struct Settings {
+    #[config(default = 0.5)]
+    volume: f32,
+    fps:    Fps,
+}
+
+#[derive(Config)]
+#[config(expose(read), validate_struct = validate_fps)]
+struct Fps {
+    #[config(default = 30)]
+    min_fps: u32,
+    #[config(default = 60)]
+    max_fps: u32,
+}
+
+fn validate_fps(fps: FpsRead) -> Result<(), String> {
+    if fps.min_fps <= fps.max_fps {
+        Ok(())
+    } else {
+        Err(format!("min_fps ({}) exceeds max_fps ({})", fps.min_fps, fps.max_fps))
+    }
+}
+
+fn drain_failures(app: &mut bevy_app::App) -> Vec<ValidationFailed> {
+    app.world_mut().resource_mut::<Messages<ValidationFailed>>().drain().collect()
+}
+
+fn fps_error(app: &mut bevy_app::App) -> Option<String> {
+    let mut query = app.world_mut().query::<(&ConfigNode, &ValidationError)>();
+    query
+        .iter(app.world())
+        .find(|(node, _)| node.path == ["settings", "fps"])
+        .map(|(_, error)| error.0.clone())
+}
+
+#[test]
+fn test_validate_struct() {
+    let mut app = bevy_app::App::new();
+    app.init_config::<(), Settings>("settings");
+
+    app.update();
+    assert!(drain_failures(&mut app).is_empty());
+    assert_eq!(fps_error(&mut app), None);
+
+    app.world_mut().update_config::<Settings>(|settings| settings.fps.min_fps.set(90));
+    app.update();
+    let failures = drain_failures(&mut app);
+    assert_eq!(failures.len(), 1);
+    assert_eq!(failures[0].path, ["settings", "fps"]);
+    assert_eq!(failures[0].error, "min_fps (90) exceeds max_fps (60)");
+    assert_eq!(fps_error(&mut app).as_deref(), Some("min_fps (90) exceeds max_fps (60)"));
+
+    // Changes outside the struct do not revalidate it.
+    app.world_mut().update_config::<Settings>(|settings| settings.volume.set(1.0));
+    app.update();
+    assert!(drain_failures(&mut app).is_empty());
+
+    app.world_mut().update_config::<Settings>(|settings| settings.fps.max_fps.set(120));
+    app.update();
+    assert!(drain_failures(&mut app).is_empty());
+    assert_eq!(fps_error(&mut app), None);
+}
+
+#[derive(Config)]
+#[config(validate_struct = |range| {
+    if range.low < range.high { Ok(()) } else { Err("empty range".into()) }
+})]
+struct Range {
+    #[config(default = 1.0)]
+    low:  f32,
+    #[config(default = 1.0)]
+    high: f32,
+}
+
+#[test]
+fn test_validate_struct_closure() {
+    let mut app = bevy_app::App::new();
+    app.init_config::<(), Range>("range");
+
+    app.update();
+    let failures = drain_failures(&mut app);
+    assert_eq!(failures.len(), 1);
+    assert_eq!(failures[0].error, "empty range");
+
+    let node =
+        app.world_mut().query_filtered::<Entity, With<RootNode>>().single(app.world()).unwrap();
+    assert_eq!(failures[0].node, node);
+}
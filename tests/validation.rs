@@ -0,0 +1,101 @@
+use bevy_ecs::message::Messages;
+use bevy_mod_config::manager::Validation;
+use bevy_mod_config::validate::{ValidationError, ValidationFailed};
+use bevy_mod_config::{AppExt, Config, ConfigNode, WorldExt};
+
+#[derive(Config)]
+struct Settings {
+    #[config(default = 0.5)]
+    volume: f32,
+    #[config(default = 60)]
+    fps:    u32,
+}
+
+fn new_app() -> bevy_app::App {
+    let mut app = bevy_app::App::new();
+    app.init_config_with::<Validation, Settings>("settings", || {
+        Validation::new()
+            .with_validator("settings.fps", |&fps: &u32| {
+                if fps >= 24 { Ok(()) } else { Err(format!("{fps} fps is too low")) }
+            })
+            .with_validator("settings.fps", |&fps: &u32| {
+                if fps % 2 == 0 { Ok(()) } else { Err("fps should be even".into()) }
+            })
+    });
+    app.update();
+    app
+}
+
+fn drain_failures(app: &mut bevy_app::App) -> Vec<ValidationFailed> {
+    app.world_mut().resource_mut::<Messages<ValidationFailed>>().drain().collect()
+}
+
+fn field_error(app: &mut bevy_app::App, field: &str) -> Option<String> {
+    let mut query = app.world_mut().query::<(&ConfigNode, &ValidationError)>();
+    query
+        .iter(app.world())
+        .find(|(node, _)| node.path == ["settings", field])
+        .map(|(_, error)| error.0.clone())
+}
+
+#[test]
+fn test_validate_on_change() {
+    let mut app = new_app();
+    assert!(drain_failures(&mut app).is_empty());
+
+    app.world_mut().update_config::<Settings>(|settings| settings.fps.set(15));
+    app.update();
+    let failures = drain_failures(&mut app);
+    assert_eq!(failures.len(), 1);
+    assert_eq!(failures[0].path, ["settings", "fps"]);
+    assert_eq!(failures[0].error, "15 fps is too low");
+    assert_eq!(field_error(&mut app, "fps").as_deref(), Some("15 fps is too low"));
+
+    app.world_mut().update_config::<Settings>(|settings| settings.fps.set(61));
+    app.update();
+    assert_eq!(drain_failures(&mut app).len(), 1);
+    assert_eq!(field_error(&mut app, "fps").as_deref(), Some("fps should be even"));
+
+    app.world_mut().update_config::<Settings>(|settings| settings.fps.set(30));
+    app.update();
+    assert!(drain_failures(&mut app).is_empty());
+    assert_eq!(field_error(&mut app, "fps"), None);
+}
+
+#[test]
+fn test_unvalidated_field() {
+    let mut app = new_app();
+    app.world_mut().update_config::<Settings>(|settings| settings.volume.set(-1.0));
+    app.update();
+    assert!(drain_failures(&mut app).is_empty());
+    assert_eq!(field_error(&mut app, "volume"), None);
+}
+
+#[test]
+fn test_validate_on_spawn() {
+    let mut app = bevy_app::App::new();
+    app.init_config_with::<Validation, Settings>("settings", || {
+        Validation::new().with_validator("settings.fps", |&fps: &u32| {
+            if fps > 60 { Ok(()) } else { Err(format!("{fps} fps is too low")) }
+        })
+    });
+    app.update();
+
+    let failures = drain_failures(&mut app);
+    assert_eq!(failures.len(), 1);
+    assert_eq!(field_error(&mut app, "fps").as_deref(), Some("60 fps is too low"));
+}
+
+#[test]
+fn test_validator_type_mismatch() {
+    let mut app = bevy_app::App::new();
+    app.init_config_with::<Validation, Settings>("settings", || {
+        Validation::new().with_validator("settings.fps", |_: &f32| Err("unreachable".into()))
+    });
+    app.update();
+
+    app.world_mut().update_config::<Settings>(|settings| settings.fps.set(30));
+    app.update();
+    assert!(drain_failures(&mut app).is_empty());
+    assert_eq!(field_error(&mut app, "fps"), None);
+}
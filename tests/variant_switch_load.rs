@@ -0,0 +1,79 @@
+#![cfg(feature = "serde_json")]
+
+use bevy_ecs::system::RunSystemOnce;
+use bevy_mod_config::manager::serde::json::Json;
+use bevy_mod_config::{AppExt, Config, ReadConfig, WorldExt, manager};
+
+#[derive(Config)]
+#[config(expose(read, discrim))]
+enum Shape {
+    Circle {
+        #[config(default = 1.0)]
+        radius: f32,
+    },
+    Square {
+        #[config(default = 2.0)]
+        side: f32,
+    },
+}
+
+#[derive(Config)]
+#[config(expose(read, discrim), reset_variants)]
+enum ResetShape {
+    Circle {
+        #[config(default = 1.0)]
+        radius: f32,
+    },
+    Square {
+        #[config(default = 2.0)]
+        side: f32,
+    },
+}
+
+#[derive(Config)]
+struct Mixed {
+    kept:  Shape,
+    reset: ResetShape,
+}
+
+fn radii(app: &mut bevy_app::App) -> (f32, f32) {
+    app.world_mut()
+        .run_system_once(|mixed: ReadConfig<Mixed>| {
+            let mixed = mixed.read();
+            let ShapeRead::Circle { radius: kept } = mixed.kept else {
+                panic!("kept shape should be a circle")
+            };
+            let ResetShapeRead::Circle { radius: reset } = mixed.reset else {
+                panic!("reset shape should be a circle")
+            };
+            (kept, reset)
+        })
+        .unwrap()
+}
+
+#[test]
+fn test_load_switches_variants() {
+    let mut app = bevy_app::App::new();
+    app.init_config_with::<Json, Mixed>("mixed", Json::new);
+    app.update();
+
+    app.world_mut().update_config::<Mixed>(|mixed| {
+        mixed.kept.v_Circle.radius.set(5.0);
+        mixed.reset.v_Circle.radius.set(5.0);
+    });
+    app.update();
+
+    let json = app.world().resource::<manager::Instance<Json>>().instance.clone();
+    json.from_reader(
+        app.world_mut(),
+        r#"{"mixed.kept.discrim":"Square","mixed.reset.discrim":"Square"}"#.as_bytes(),
+    )
+    .unwrap();
+    app.update();
+
+    app.world_mut().update_config::<Mixed>(|mixed| {
+        mixed.kept.discrim.set(ShapeDiscrim::Circle);
+        mixed.reset.discrim.set(ResetShapeDiscrim::Circle);
+    });
+    assert_eq!(radii(&mut app), (5.0, 1.0));
+}
@@ -0,0 +1,33 @@
+#![cfg(feature = "serde_json")]
+
+use bevy_ecs::system::RunSystemOnce;
+use bevy_mod_config::manager::serde::Json;
+use bevy_mod_config::{AppExt, Config, ReadConfig, manager};
+
+#[derive(Config)]
+struct Settings {
+    #[config(default = vec![1, 2], element.max = 10)]
+    presets: Vec<u32>,
+}
+
+#[test]
+fn test_vec_field() {
+    let mut app = bevy_app::App::new();
+    app.init_config_with::<Json, Settings>("audio", Json::new);
+
+    app.world_mut()
+        .run_system_once(|settings: ReadConfig<Settings>| {
+            assert_eq!(settings.read().presets, [1, 2]);
+        })
+        .unwrap();
+
+    let json = app.world().resource::<manager::Instance<Json>>().instance.clone();
+    assert_eq!(json.to_string(app.world_mut()).unwrap(), r#"{"audio.presets":[1,2]}"#);
+
+    json.from_reader(app.world_mut(), r#"{"audio.presets":[3,1,4]}"#.as_bytes()).unwrap();
+    app.world_mut()
+        .run_system_once(|settings: ReadConfig<Settings>| {
+            assert_eq!(settings.read().presets, [3, 1, 4]);
+        })
+        .unwrap();
+}
@@ -0,0 +1,35 @@
+#![cfg(feature = "serde_json")]
+
+use bevy_ecs::system::RunSystemOnce;
+use bevy_mod_config::manager::serde::Json;
+use bevy_mod_config::types::Volume;
+use bevy_mod_config::{AppExt, Config, ReadConfig, manager};
+
+#[derive(Config)]
+struct Audio {
+    #[config(default = Volume::new(0.5))]
+    music: Volume,
+}
+
+#[test]
+fn test_volume() {
+    let mut app = bevy_app::App::new();
+    app.init_config_with::<Json, Audio>("audio", Json::new);
+
+    let json = app.world().resource::<manager::Instance<Json>>().instance.clone();
+    assert_eq!(json.to_string(app.world_mut()).unwrap(), r#"{"audio.music":0.5}"#);
+
+    // Out-of-range values are clamped on load.
+    json.from_reader(app.world_mut(), r#"{"audio.music":1.5}"#.as_bytes()).unwrap();
+    app.world_mut()
+        .run_system_once(|audio: ReadConfig<Audio>| assert_eq!(audio.read().music, Volume::FULL))
+        .unwrap();
+}
+
+#[test]
+fn test_decibels() {
+    assert_eq!(Volume::FULL.decibels(), 0.0);
+    assert_eq!(Volume::SILENT.decibels(), f32::NEG_INFINITY);
+    assert!((Volume::from_decibels(-20.0).linear() - 0.1).abs() < 1e-6);
+    assert_eq!(Volume::from_decibels(6.0), Volume::FULL);
+}